@@ -27,13 +27,13 @@ section .text
 	public strlen
 	strlen:
                 xor rcx, rcx
-	_strlen_loop:
+	.loop:
 		mov al, byte [rdi]
 		cmp al, 0
-		je @[_strlen_end]
+		je @[.end]
 		inc rcx
 		inc rdi
-		jmp @[_strlen_loop]
-	_strlen_end:
+		jmp @[.loop]
+	.end:
 		mov rax, rcx
 		ret
@@ -5,7 +5,7 @@
 
 use std::collections::BTreeSet;
 
-const PLACEHOLDER: &'static str = "// pasm - src/shr/ins_switch.rs
+const PLACEHOLDER: &str = "// pasm - src/shr/ins_switch.rs
 // ----------------------------
 // made by matissoss
 // licensed under MPL 2.0
@@ -25,9 +25,10 @@ use crate::shr::mnemonic::Mnemonic;
 #[cfg(not(feature = \"refresh\"))]
 const N : Option<Mnemonic> = None;
 ";
-const SOME: &'static str = "s";
-const BUF: &'static str = "r";
+const SOME: &str = "s";
+const BUF: &str = "r";
 
+#[allow(clippy::derive_ord_xor_partial_ord)]
 #[derive(Clone, PartialEq, Eq, Debug, Ord)]
 enum Branch {
     Last(char, usize, String),
@@ -36,6 +37,7 @@ enum Branch {
 }
 
 use std::cmp::{Ordering, PartialOrd};
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Branch {
     fn partial_cmp(&self, rhs: &Branch) -> Option<Ordering> {
         use Branch::*;
@@ -74,25 +76,27 @@ fn main() {
 }
 
 fn print_tree(tree: Vec<(usize, BTreeSet<Box<Branch>>)>, writer: &mut impl std::io::Write) {
-    writer.write(PLACEHOLDER.as_bytes()).unwrap();
+    writer.write_all(PLACEHOLDER.as_bytes()).unwrap();
     writer
-        .write(b"\n#[cfg(not(feature = \"refresh\"))]")
+        .write_all(b"\n#[cfg(not(feature = \"refresh\"))]")
         .unwrap();
     writer
-        .write(b"\n#[rustfmt::skip]\npub fn mnem_fromstr(str: &str) -> Option<Mnemonic> {\n")
+        .write_all(b"\n#[rustfmt::skip]\npub fn mnem_fromstr(str: &str) -> Option<Mnemonic> {\n")
         .unwrap();
-    writer.write(b"\tuse Mnemonic::*;\n").unwrap();
-    writer.write(b"\tlet r = str.as_bytes();\n").unwrap();
-    writer.write(b"\tmatch r.len() {\n\t\t").unwrap();
+    writer.write_all(b"\tuse Mnemonic::*;\n").unwrap();
+    writer.write_all(b"\tlet r = str.as_bytes();\n").unwrap();
+    writer.write_all(b"\tmatch r.len() {\n\t\t").unwrap();
     for (size, branches) in tree {
         if !branches.is_empty() {
             writer
-                .write(&format!("{size}=>match {BUF}[0]{{{}}}", print_branch(branches)).as_bytes())
+                .write_all(
+                    format!("{size}=>match {BUF}[0]{{{}}}", print_branch(branches)).as_bytes(),
+                )
                 .unwrap();
         }
     }
-    writer.write(b"_ =>N,\n\t}").unwrap();
-    writer.write(b"\n}").unwrap();
+    writer.write_all(b"_ =>N,\n\t}").unwrap();
+    writer.write_all(b"\n}").unwrap();
 }
 
 fn btreeset_fastinit<T>(val: T) -> BTreeSet<T>
@@ -19,12 +19,15 @@ const VER: u8 = 0x5;
 const NO_ASSEMBLE: u8 = 0x6;
 const NO_CHECK: u8 = 0x7;
 const QUIET: u8 = 0x8;
+const OPTIMIZE: u8 = 0x9;
 
 #[derive(Default)]
 pub struct Cli {
     target: Option<String>,   // -f flag
+    cpu: Option<String>,      // --cpu flag
     infile: Option<PathBuf>,  // -i flag
     outfile: Option<PathBuf>, // -o flag
+    ipaths: Vec<PathBuf>,     // -I flag
     flags: Flags,             // -/--flag
 }
 
@@ -35,6 +38,12 @@ impl Cli {
     pub fn outfile(&self) -> &Option<PathBuf> {
         &self.outfile
     }
+    pub fn cpu(&self) -> Option<&str> {
+        self.cpu.as_deref()
+    }
+    pub fn include_paths(&self) -> &[PathBuf] {
+        &self.ipaths
+    }
     pub fn nocolor(&self) -> bool {
         self.flags.get(NOCOL).unwrap()
     }
@@ -53,6 +62,9 @@ impl Cli {
     pub fn quiet(&self) -> bool {
         self.flags.get(QUIET).unwrap()
     }
+    pub fn optimize(&self) -> bool {
+        self.flags.get(OPTIMIZE).unwrap()
+    }
     pub fn check(&self) -> bool {
         self.flags.get(NO_ASSEMBLE).unwrap()
     }
@@ -80,15 +92,24 @@ impl Cli {
                 "-o" | "--output" => {
                     cli.outfile = val.map(|v| v.into());
                 }
+                "-I" | "--include" => {
+                    if let Some(val) = val {
+                        cli.ipaths.push(val.into());
+                    }
+                }
                 "-f" => {
                     cli.target = val.map(|v| v.into());
                 }
+                "--cpu" => {
+                    cli.cpu = val.map(|v| v.into());
+                }
                 "-C" | "--skip-check" => cli.flags.set(NO_CHECK, true),
                 "-v" | "--version" => cli.flags.set(VER, true),
                 "-s" | "--supported-instructions" => cli.flags.set(SUPPORTED_INS, true),
                 "-S" | "--supported-instructions-raw" => cli.flags.set(SUPPORTEDINSR, true),
                 "-n" | "--nocolor" => cli.flags.set(NOCOL, true),
                 "-t" | "--time" => cli.flags.set(QUIET, false),
+                "-O" | "--optimize" => cli.flags.set(OPTIMIZE, true),
                 _ => continue,
             }
         }
@@ -11,7 +11,7 @@ pub const CAN_H66O: u8 = 0x3; // can use 0x66 override
 pub const IMM_LEBE: u8 = 0x4; // immediate must be formatted as little endian or big endian; 0 = le, 1 = be
 pub const CAN_SEGM: u8 = 0x5; // can use segment override
 pub const USE_MODRM: u8 = 0x6; // can use modrm
-const _RESERVED_FLAG_7: u8 = 0x7;
+pub const FORCE_SIB: u8 = 0x7; // ModRM.r/m always uses SIB byte (AMX sibmem)
 const _RESERVED_FLAG_8: u8 = 0x8;
 pub const IMM: u8 = 0x9; // immediate at index (second byte of addt is index, first one is size)
 pub const SET_MODRM: u8 = 0xA; // MODRM.mod is set to byte specified in addt2
@@ -21,9 +21,10 @@ pub const FIXED_SIZE: u8 = 0xC;
 use std::iter::Iterator;
 
 use crate::{
-    core::{apx, disp, evex, modrm, rex, sib, vex},
+    core::{apx, disp, evex, modrm, rex, sib, vex, xop},
     shr::{
         booltable::BoolTable16,
+        error::Error,
         instruction::{Instruction, Operand},
         mem::Mem,
         mnemonic::Mnemonic,
@@ -71,6 +72,7 @@ pub const PREFIX_REX: u8 = 0b001;
 pub const PREFIX_VEX: u8 = 0b010;
 pub const PREFIX_EVEX: u8 = 0b011;
 pub const PREFIX_APX: u8 = 0b100;
+pub const PREFIX_XOP: u8 = 0b101;
 
 // size = 16B
 #[repr(C)]
@@ -228,6 +230,17 @@ impl GenAPI {
         self
     }
 
+    // AMD XOP: same layout as VEX, but map_select (0x08, 0x09 or 0x0A) is stored as is
+    #[inline(always)]
+    pub const fn xop(mut self, vex_details: VexDetails) -> Self {
+        self.set_fpfx(PREFIX_XOP);
+        self.prefix = (vex_details.vex_we as u16) << 7
+            | ((vex_details.map_select & 0b11111) as u16) << 2
+            | pp(vex_details.pp) as u16;
+        self.addt = ((vex_details.vlength.data as u16) << 0x08) | self.addt & 0x00FF;
+        self
+    }
+
     // Immediate-related things go here
     #[inline(always)]
     pub const fn imm_atindex(mut self, idx: u16, size: u16) -> Self {
@@ -262,6 +275,71 @@ impl GenAPI {
     pub fn get_opcode(&self) -> &[u8] {
         &self.opcode[0..self.opcode_len()]
     }
+    /// returns true if instruction is encoded with EVEX prefix
+    /// (VEX instructions are promoted to EVEX, if they use EVEX-only features)
+    pub fn uses_evex(&self, ins: &Instruction) -> bool {
+        match self.get_fpfx() {
+            PREFIX_EVEX => true,
+            // disp8 is not scaled in promoted instructions operating on GPRs
+            PREFIX_VEX => {
+                ins.needs_evex()
+                    && !self.flags.at(STRICT_PFX)
+                    && (!ins.needs_apx_extension() || is_vector(ins))
+            }
+            _ => false,
+        }
+    }
+    /// checks if relocation types of symbols match fields, that they are written into
+    /// (8-byte relocations can only be used in `mov r64` and 8-byte data directives)
+    pub fn check_relocs(&self, ins: &Instruction, bits: u8) -> Result<(), Error> {
+        let imm = if self.flags.at(IMM) {
+            Some((
+                ((self.addt & 0x00_0F) as usize),
+                ((self.addt & 0x00_F0) >> 4) as usize,
+            ))
+        } else {
+            None
+        };
+        for (s, idx) in ins.get_symbs().into_iter() {
+            let reltype = match s.reltype() {
+                Some(r) => r,
+                None => continue,
+            };
+            let field = if s.is_deref() {
+                if !self.flags.at(USE_MODRM) {
+                    continue;
+                }
+                if bits == 16 {
+                    2
+                } else {
+                    4
+                }
+            } else {
+                match imm {
+                    Some((i, size)) if i == idx => size,
+                    _ => continue,
+                }
+            };
+            let qword_mov = ins.mnemonic == Mnemonic::MOV
+                && matches!(ins.dst(), Some(Operand::Register(r)) if r.size() == Size::Qword);
+            let err = if reltype.size() == 8 && !(qword_mov || is_data(ins.mnemonic)) {
+                format!(
+                    "relocation `{}` can only be used in `mov r64` and 8-byte data directives",
+                    reltype.name()
+                )
+            } else if field != 0 && field != reltype.size() {
+                format!(
+                    "relocation `{}` ({} bytes) cannot be used in {field}-byte field of this instruction",
+                    reltype.name(),
+                    reltype.size(),
+                )
+            } else {
+                continue;
+            };
+            return Err(Error::new(err, 20));
+        }
+        Ok(())
+    }
     // you can have max 2 relocations returned, because of variants like:
     // mov .deref @symbol, @other_symbol
     // (it is in fact a valid variant: first operand is mem, second is immediate)
@@ -311,8 +389,10 @@ impl GenAPI {
             base.push(segm);
         }
 
-        let not_std_prefix =
-            prefix_flag == PREFIX_VEX || prefix_flag == PREFIX_EVEX || prefix_flag == PREFIX_APX;
+        let not_std_prefix = prefix_flag == PREFIX_VEX
+            || prefix_flag == PREFIX_EVEX
+            || prefix_flag == PREFIX_APX
+            || prefix_flag == PREFIX_XOP;
         if fx_size {
             if let Some(size_ovr) = gen_sizeovr_fixed_size(ins_size, bits) {
                 base.push(size_ovr);
@@ -332,13 +412,12 @@ impl GenAPI {
             base.push(self.prefix.to_be_bytes()[1]);
         }
 
+        // is instruction encoded with EVEX (displacement is then scaled: disp8*N)
+        let evex_enc = self.uses_evex(ins);
+
         // Prefixes
         match prefix_flag {
-            PREFIX_REX => {
-                if rex != 0x00 {
-                    base.push(rex);
-                }
-            }
+            PREFIX_REX if rex != 0x00 => base.push(rex),
             PREFIX_VEX => {
                 if ins.needs_apx_extension() {
                     for b in apx::apx(&self, ins, bits).into_iter() {
@@ -370,6 +449,11 @@ impl GenAPI {
                     base.push(b);
                 }
             }
+            PREFIX_XOP => {
+                for b in xop::xop(&self, ins).into_iter() {
+                    base.push(b);
+                }
+            }
             _ => {}
         }
 
@@ -380,33 +464,52 @@ impl GenAPI {
 
         // ModRM, max. 1B
         if self.flags.at(USE_MODRM) {
-            base.push(modrm::modrm(&modrm_rm, &modrm_reg, &self));
+            let disp = disp::DispCtx {
+                forced: ins.forced_disp(),
+                evex: evex_enc,
+            };
+            base.push(modrm::modrm(&modrm_rm, &modrm_reg, &self, disp));
             // SIB, max. 1B
             // we cannot generate sib for bits == 16
             if bits != 16 {
-                if let Some(sib) = sib::gen_sib_ins(&modrm_rm) {
+                let sib = if self.flags.at(FORCE_SIB) {
+                    sib::gen_sib_forced(&modrm_rm)
+                } else {
+                    sib::gen_sib_ins(&modrm_rm)
+                };
+                if let Some(sib) = sib {
                     base.push(sib);
                 }
             }
             // DISP, max. 4B
-            if let Some(disp) = disp::gen_disp_ins(&modrm_rm, bits) {
+            if let Some(disp) = disp::gen_disp_ins(&modrm_rm, bits, disp) {
                 for b in disp {
                     base.push(b);
                 }
             } else {
-                for (s, _) in ins.get_symbs().into_iter() {
+                for (s, idx) in ins.get_symbs().into_iter() {
                     if !s.is_deref() {
                         continue;
                     }
 
                     let reltype = s.reltype().unwrap_or(default_rel);
+                    // linker can only rewrite legacy encoding (without REX2 or extended EVEX)
+                    let reltype = if matches!(prefix_flag, PREFIX_NONE | PREFIX_REX)
+                        && !ins.needs_apx_extension()
+                    {
+                        reltype.relax(ins.mnemonic, idx == 1, rex != 0x00)
+                    } else {
+                        reltype
+                    };
                     let addend = s.addend().unwrap_or_default();
                     rels.push(Relocation {
                         symbol: s.symbol,
+                        sub: None,
                         offset: base.len(),
-                        addend: addend - reltype.size() as i32,
+                        addend: addend - reltype.pc_adjust(),
                         shidx: 0,
                         reltype,
+                        line: 0,
                     });
                     if bits != 16 {
                         base.push(0);
@@ -495,16 +598,42 @@ impl GenAPI {
                     }
                 }
                 Some(Operand::Symbol(s)) => {
-                    let reltype = s.reltype().unwrap_or(default_rel);
-                    rels.push(Relocation {
-                        symbol: s.symbol,
-                        offset: base.len(),
-                        addend: s.addend().unwrap_or_default() - reltype.size() as i32,
-                        shidx: 0,
-                        reltype,
-                    });
+                    if s.sub().is_some() {
+                        // label difference is absolute value, that fills whole field
+                        let reltype = s.reltype().unwrap_or(match size {
+                            1 => RelocationType::ABS8,
+                            2 => RelocationType::ABS16,
+                            8 => RelocationType::ABS64,
+                            _ => RelocationType::ABS32,
+                        });
+                        rels.push(Relocation {
+                            symbol: s.symbol,
+                            sub: s.sub(),
+                            offset: base.len(),
+                            addend: s.addend().unwrap_or_default(),
+                            shidx: 0,
+                            reltype,
+                            line: 0,
+                        });
+                    } else {
+                        // symbols in data directives are absolute addresses
+                        let reltype = s.reltype().unwrap_or(if is_data(ins.mnemonic) {
+                            RelocationType::abs(size)
+                        } else {
+                            default_rel
+                        });
+                        rels.push(Relocation {
+                            symbol: s.symbol,
+                            sub: None,
+                            offset: base.len(),
+                            addend: s.addend().unwrap_or_default() - reltype.pc_adjust(),
+                            shidx: 0,
+                            reltype,
+                            line: 0,
+                        });
+                    }
                     if size == 0 {
-                        for _ in 0..s.reltype().unwrap_or(default_rel).size() {
+                        for _ in 0..rels.last().map(|r| r.size()).unwrap_or_default() {
                             base.push(0);
                         }
                     } else {
@@ -556,6 +685,8 @@ impl GenAPI {
             [MODRM_REG, MODRM_RM , _        ] => [ins.src() , ins.dst() , ins.ssrc()],
             [MODRM_RM , MODRM_REG, _        ] => [ins.dst() , ins.src() , ins.ssrc()],
             [MODRM_REG, VEX_VVVV , MODRM_RM ] => [ins.ssrc(), ins.dst() , ins.src() ],
+            // 4th operand in ModRM.r/m, 3rd one is in imm8[7:4] (FMA4/XOP with W1)
+            [MODRM_REG, VEX_VVVV , TSRC     ] => [ins.get(3), ins.dst() , ins.src() ],
             [MODRM_RM , VEX_VVVV , MODRM_REG] => [ins.dst() , ins.ssrc(), ins.src() ],
             [VEX_VVVV , MODRM_REG, MODRM_RM ] => [ins.ssrc(), ins.src() , ins.dst() ],
             [VEX_VVVV , MODRM_REG, _        ] => [None      , ins.src() , ins.dst() ],
@@ -568,10 +699,21 @@ impl GenAPI {
         self.flags.set(STRICT_PFX, true);
         self
     }
+    #[inline(always)]
+    pub const fn force_sib(mut self) -> Self {
+        self.flags.set(FORCE_SIB, true);
+        self
+    }
+    // (E)VEX and XOP share pp, map_select, w/e and vlength fields
+    #[inline(always)]
+    const fn has_vex_fields(&self) -> bool {
+        let fpfx = self.get_fpfx();
+        fpfx == PREFIX_VEX || fpfx == PREFIX_EVEX || fpfx == PREFIX_XOP
+    }
     // fails if (E)VEX flag is not set
     #[inline(always)]
     pub const fn get_pp(&self) -> Option<u8> {
-        if self.get_fpfx() == PREFIX_VEX || self.get_fpfx() == PREFIX_EVEX {
+        if self.has_vex_fields() {
             Some((self.prefix & 0b11) as u8)
         } else {
             None
@@ -580,7 +722,7 @@ impl GenAPI {
     // fails if (E)VEX flag is not set
     #[inline(always)]
     pub const fn get_map_select(&self) -> Option<u8> {
-        if self.get_fpfx() == PREFIX_VEX || self.get_fpfx() == PREFIX_EVEX {
+        if self.has_vex_fields() {
             Some(((self.prefix & 0b0111_1100) >> 2) as u8)
         } else {
             None
@@ -589,7 +731,7 @@ impl GenAPI {
     // fails if (E)VEX flag is not set
     #[inline(always)]
     pub const fn get_vex_we(&self) -> Option<bool> {
-        if self.get_fpfx() == PREFIX_VEX || self.get_fpfx() == PREFIX_EVEX {
+        if self.has_vex_fields() {
             Some(self.prefix & 0b1000_0000 == 0b1000_0000)
         } else {
             None
@@ -598,7 +740,7 @@ impl GenAPI {
     // fails if (E)VEX flag is not set
     #[inline(always)]
     pub const fn get_vex_vlength(&self) -> Option<MegaBool> {
-        if self.get_fpfx() == PREFIX_VEX || self.get_fpfx() == PREFIX_EVEX {
+        if self.has_vex_fields() {
             Some(MegaBool::from_byte(((self.addt & 0xFF00) >> 8) as u8))
         } else {
             None
@@ -656,6 +798,19 @@ impl GenAPI {
 }
 
 // Assembling helper functions
+fn is_vector(ins: &Instruction) -> bool {
+    ins.iter().any(|o| {
+        matches!(o, Operand::Register(r)
+            if matches!(r.size(), Size::Xword | Size::Yword | Size::Zword))
+    })
+}
+const fn is_data(mnemonic: Mnemonic) -> bool {
+    use Mnemonic::*;
+    matches!(
+        mnemonic,
+        BYTELE | BYTEBE | WORDLE | WORDBE | DWORDLE | DWORDBE | QWORDLE | QWORDBE
+    )
+}
 fn gen_addt_pfx(ins: &Instruction) -> Option<u8> {
     use Mnemonic as Ins;
     if let Some(s) = ins.get_addt() {
@@ -691,10 +846,13 @@ fn gen_size_ovr(
         }
         64 => match sz {
             Size::Word => arr[0] = Some(0x66),
-            Size::Qword => {
-                if !(rexw || ins.mnemonic.defaults_to_64bit() || ins.uses_cr() || ins.uses_dr()) {
-                    arr[0] = Some(0x66);
-                }
+            Size::Qword
+                if !(rexw
+                    || ins.mnemonic.defaults_to_64bit()
+                    || ins.uses_cr()
+                    || ins.uses_dr()) =>
+            {
+                arr[0] = Some(0x66)
             }
             _ => {}
         },
@@ -879,9 +1037,9 @@ const fn pp(v: u8) -> u8 {
 }
 const fn map_select(v: u8) -> u8 {
     match v {
-        0x0F => 0b00001,
-        0x38 => 0b00010,
-        0x3A => 0b00011,
+        0x0F | 0b001 => 0b00001,
+        0x38 | 0b010 => 0b00010,
+        0x3A | 0b011 => 0b00011,
         _ => 0b00000,
     }
 }
@@ -10,7 +10,7 @@
 //  - matissoss
 
 use crate::{
-    core::api::GenAPI,
+    core::api::{GenAPI, STRICT_PFX},
     shr::{
         instruction::{Instruction, Operand},
         size::Size,
@@ -33,7 +33,7 @@ pub fn apx(ctx: &GenAPI, ins: &Instruction, bits: u8) -> StackVec<u8, 4> {
     match ctx.get_apx_eevex_version() {
         Some(APXVariant::EvexExtension) => eevex_evex(ctx, ins),
         Some(APXVariant::VexExtension) => {
-            if ins.needs_evex() {
+            if ins.needs_evex() && !ctx.flags.at(STRICT_PFX) {
                 eevex_evex(ctx, ins)
             } else {
                 eevex_vex(ctx, ins)
@@ -90,7 +90,7 @@ fn rex2(ctx: &GenAPI, ins: &Instruction) -> StackVec<u8, 4> {
         rex2_0 |= (evex_x4 as u8) << 5;
     } else {
         rex2_0 |= evex_b3 as u8;
-        rex2_0 |= (evex_b4 as u8) << 1;
+        rex2_0 |= (evex_b4 as u8) << 4;
     }
 
     vec.push(rex2_0);
@@ -130,7 +130,7 @@ fn eevex_legacy(ctx: &GenAPI, ins: &Instruction, bits: u8) -> StackVec<u8, 4> {
             | (!evex_x3 as u8) << 6
             | (!evex_b3 as u8) << 5
             | (!evex_r4 as u8) << 4
-            | (!evex_b4 as u8) << 3
+            | (evex_b4 as u8) << 3
             | 1 << 2,
     );
 
@@ -157,7 +157,7 @@ fn eevex_cond(ctx: &GenAPI, ins: &Instruction) -> StackVec<u8, 4> {
             | (!evex_x3 as u8) << 6
             | (!evex_b3 as u8) << 5
             | (!evex_r4 as u8) << 4
-            | (!evex_b4 as u8) << 3
+            | (evex_b4 as u8) << 3
             | 1 << 2,
     );
 
@@ -184,22 +184,24 @@ fn eevex_vex(ctx: &GenAPI, ins: &Instruction) -> StackVec<u8, 4> {
     let [[evex_b4, evex_b3], [evex_x4, evex_x3]] = ebits(&modrm_rm);
     let [[evex_v4, _], [_, _]] = ebits(&evex_vvvv);
 
+    // VEX instructions with APX variant (ALDTILECFG, ...) store these fields in APX prefix layout
+    let map_select = ctx
+        .get_map_select()
+        .unwrap_or(ctx.get_apx_eevex_map_select());
+    let pp = ctx.get_pp().unwrap_or(ctx.get_apx_eevex_pp());
+    let vex_we = ctx.get_vex_we().unwrap_or(ctx.get_apx_eevex_vex_we());
+
     vec.push(0x62);
     vec.push(
         (!evex_r3 as u8) << 7
             | (!evex_x3 as u8) << 6
             | (!evex_b3 as u8) << 5
             | (!evex_r4 as u8) << 4
-            | (!evex_b4 as u8) << 3
-            | ctx.get_map_select().unwrap_or(0) & 0b111,
+            | (evex_b4 as u8) << 3
+            | map_select & 0b111,
     );
 
-    vec.push(
-        (ctx.get_vex_we().unwrap_or(false) as u8) << 7
-            | gen_evex4v(&evex_vvvv) << 3
-            | (!evex_x4 as u8) << 2
-            | ctx.get_pp().unwrap_or(0b00),
-    );
+    vec.push((vex_we as u8) << 7 | gen_evex4v(&evex_vvvv) << 3 | (!evex_x4 as u8) << 2 | pp);
     vec.push(
         ((ins.size() == Size::Yword) as u8) << 4
             | (!evex_v4 as u8) << 3
@@ -258,7 +260,7 @@ fn eevex_evex(ctx: &GenAPI, ins: &Instruction) -> StackVec<u8, 4> {
     if mem {
         evex1 |= (!evex_x3 as u8) << 6;
         evex1 |= (!evex_b3 as u8) << 5;
-        evex1 |= (!evex_b4 as u8) << 3;
+        evex1 |= (evex_b4 as u8) << 3;
 
         // this is the EEVEX.U field
         evex2 |= (!evex_x4 as u8) << 2;
@@ -292,3 +294,23 @@ fn ebits(op: &Option<Operand>) -> [[bool; 2]; 2] {
         [[false; 2]; 2]
     }
 }
+
+#[cfg(all(test, not(feature = "refresh")))]
+mod tests {
+    use crate::core::assemble_lines;
+    #[test]
+    fn teevex_b4_0() {
+        // EVEX.B4 (P0 bit 3) has positive polarity, unlike other register extension bits
+        let bin = assemble_lines("aadd rax, qword [rbx]\naadd rax, qword [r19]\n", 64);
+        assert_eq!(
+            bin,
+            [0x62, 0xF4, 0xFC, 0x08, 0x03, 0x03, 0x62, 0xFC, 0xFC, 0x08, 0x03, 0x03]
+        );
+    }
+    #[test]
+    fn trex2_0() {
+        // REX2: M0 R4 X4 B4 W R3 X3 B3
+        let bin = assemble_lines("pushp r16\npopp r25\n", 64);
+        assert_eq!(bin, [0xD5, 0x18, 0x50, 0xD5, 0x19, 0x59]);
+    }
+}
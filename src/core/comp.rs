@@ -113,7 +113,7 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
         Mnemonic::JP => ins_jmplike(ins, [&[0x0F, 0x8A], &[], &[0x7A]], 0, bits),
         Mnemonic::JS => ins_jmplike(ins, [&[0x0F, 0x88], &[], &[0x78]], 0, bits),
         Mnemonic::JL => ins_jmplike(ins, [&[0x0F, 0x8C], &[], &[0x7C]], 0, bits),
-        Mnemonic::JG => ins_jmplike(ins, [&[0x0F, 0x8F], &[], &[0x7C]], 0, bits),
+        Mnemonic::JG => ins_jmplike(ins, [&[0x0F, 0x8F], &[], &[0x7F]], 0, bits),
         Mnemonic::JE | Mnemonic::JZ => ins_jmplike(ins, [&[0x0F, 0x84], &[], &[0x74]], 0, bits),
         Mnemonic::JAE => ins_jmplike(ins, [&[0x0F, 0x83], &[], &[0x73]], 0, bits),
         Mnemonic::JBE => ins_jmplike(ins, [&[0x0F, 0x86], &[], &[0x76]], 0, bits),
@@ -1752,7 +1752,7 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             let mut api = GenAPI::new()
                 .modrm(true, None)
                 .vex(VexDetails::new().pp(0x66).map_select(0x0F).vex_we(false));
-            if ins.dst().unwrap().is_mem() {
+            if ins.dst().unwrap().is_mem() || ins.store() {
                 api = api.opcode(&[0x7F]);
             } else {
                 api = api.opcode(&[0x6F]).ord(&[MODRM_REG, MODRM_RM]);
@@ -1788,7 +1788,7 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             let mut api = GenAPI::new()
                 .modrm(true, None)
                 .vex(VexDetails::new().map_select(0x0F).vex_we(false));
-            if ins.dst().unwrap().is_mem() {
+            if ins.dst().unwrap().is_mem() || ins.store() {
                 api = api.opcode(&[0x29]);
             } else {
                 api = api.opcode(&[0x28]).ord(&[MODRM_REG, MODRM_RM]);
@@ -1799,7 +1799,7 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             let mut api = GenAPI::new()
                 .modrm(true, None)
                 .vex(VexDetails::new().pp(0x66).map_select(0x0F).vex_we(false));
-            if ins.dst().unwrap().is_mem() {
+            if ins.dst().unwrap().is_mem() || ins.store() {
                 api = api.opcode(&[0x29]);
             } else {
                 api = api.opcode(&[0x28]).ord(&[MODRM_REG, MODRM_RM]);
@@ -1810,7 +1810,7 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             let mut api = GenAPI::new()
                 .modrm(true, None)
                 .vex(VexDetails::new().map_select(0x0F).vex_we(false));
-            if ins.dst().unwrap().is_mem() {
+            if ins.dst().unwrap().is_mem() || ins.store() {
                 api = api.opcode(&[0x11]);
             } else {
                 api = api.opcode(&[0x10]).ord(&[MODRM_REG, MODRM_RM]);
@@ -1821,7 +1821,7 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             let mut api = GenAPI::new()
                 .modrm(true, None)
                 .vex(VexDetails::new().pp(0x66).map_select(0x0F).vex_we(false));
-            if ins.dst().unwrap().is_mem() {
+            if ins.dst().unwrap().is_mem() || ins.store() {
                 api = api.opcode(&[0x11]);
             } else {
                 api = api.opcode(&[0x10]).ord(&[MODRM_REG, MODRM_RM]);
@@ -2457,6 +2457,12 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
             .modrm(true, None)
             .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM]),
+        // AVX10.2 zero-extending move: EVEX.128.F3.0F.W0 7E /r
+        Mnemonic::VMOVD if is_xmm_xmm(ins) => GenAPI::new()
+            .opcode(&[0x7E])
+            .modrm(true, None)
+            .ord(&[MODRM_REG, MODRM_RM])
+            .evex(VexDetails::new().pp(0xF3).map_select(MAP0F).vex_we(false)),
         Mnemonic::VMOVD | Mnemonic::VMOVQ => {
             let mut api = GenAPI::new().modrm(true, None).vex(
                 VexDetails::new()
@@ -3465,6 +3471,44 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             .opcode_prefix(0x66)
             .rex()
             .ord(&[MODRM_REG, MODRM_RM]),
+        // GFNI
+        Mnemonic::GF2P8AFFINEQB => GenAPI::new()
+            .opcode(&[0x0F, 0x3A, 0xCE])
+            .modrm(true, None)
+            .imm_atindex(2, 1)
+            .opcode_prefix(0x66)
+            .rex()
+            .ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::GF2P8AFFINEINVQB => GenAPI::new()
+            .opcode(&[0x0F, 0x3A, 0xCF])
+            .modrm(true, None)
+            .imm_atindex(2, 1)
+            .opcode_prefix(0x66)
+            .rex()
+            .ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::GF2P8MULB => GenAPI::new()
+            .opcode(&[0x0F, 0x38, 0xCF])
+            .modrm(true, None)
+            .opcode_prefix(0x66)
+            .rex()
+            .ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VGF2P8AFFINEQB => GenAPI::new()
+            .opcode(&[0xCE])
+            .modrm(true, None)
+            .imm_atindex(3, 1)
+            .vex(VexDetails::new().pp(0x66).map_select(0x3A).vex_we(true))
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM]),
+        Mnemonic::VGF2P8AFFINEINVQB => GenAPI::new()
+            .opcode(&[0xCF])
+            .modrm(true, None)
+            .imm_atindex(3, 1)
+            .vex(VexDetails::new().pp(0x66).map_select(0x3A).vex_we(true))
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM]),
+        Mnemonic::VGF2P8MULB => GenAPI::new()
+            .opcode(&[0xCF])
+            .modrm(true, None)
+            .vex(VexDetails::new().pp(0x66).map_select(0x38).vex_we(false))
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM]),
         // cvt-part1
         Mnemonic::CVTPD2PI => GenAPI::new()
             .opcode_prefix(0x66)
@@ -3817,6 +3861,17 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
                     .vex_we(ins.size() == Size::Qword),
             )
             .ord(&[MODRM_REG, MODRM_RM, VEX_VVVV]),
+        // TBM: bextr r, r/m, imm32
+        Mnemonic::BEXTR if matches!(ins.ssrc(), Some(Operand::Imm(_))) => GenAPI::new()
+            .opcode(&[0x10])
+            .modrm(true, None)
+            .xop(
+                VexDetails::new()
+                    .map_select(0x0A)
+                    .vex_we(ins.size() == Size::Qword),
+            )
+            .ord(&[MODRM_REG, MODRM_RM])
+            .imm_atindex(2, 4),
         Mnemonic::BEXTR => GenAPI::new()
             .opcode(&[0xF7])
             .vex(
@@ -5454,7 +5509,13 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             .ord(&[MODRM_RM, MODRM_REG])
             .imm_atindex(2, 1)
             .strict_pfx()
-            .vex(VexDetails::new().pp(0x66).map_select(MAP3A).vex_we(false)),
+            .vex(
+                VexDetails::new()
+                    .pp(0x66)
+                    .map_select(MAP3A)
+                    .vex_we(false)
+                    .vlength(Some(true)),
+            ),
         Mnemonic::VEXTRACTI32X4 => GenAPI::new()
             .opcode(&[0x39])
             .modrm(true, None)
@@ -5844,7 +5905,7 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
             .imm_atindex(3, 1)
             .strict_pfx()
-            .vex(VexDetails::new().pp(0x66).map_select(MAP3A).vex_we(true)),
+            .vex(VexDetails::new().pp(0x66).map_select(MAP3A).vex_we(false)),
         Mnemonic::VMASKMOVPS => {
             let mut api = GenAPI::new()
                 .strict_pfx()
@@ -5910,6 +5971,12 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
 
             api
         }
+        // AVX10.2 zero-extending move: EVEX.128.F3.MAP5.W0 6E /r
+        Mnemonic::VMOVW if is_xmm_xmm(ins) => GenAPI::new()
+            .opcode(&[0x6E])
+            .modrm(true, None)
+            .ord(&[MODRM_REG, MODRM_RM])
+            .evex(VexDetails::new().pp(0xF3).map_select(MAP5).vex_we(false)),
         Mnemonic::VMOVW => {
             let mut api = GenAPI::new()
                 .evex(VexDetails::new().pp(0x66).map_select(MAP5).vex_we(false))
@@ -5943,12 +6010,12 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
             .evex(VexDetails::new().pp(0xF2).map_select(MAP38).vex_we(true)),
         Mnemonic::VPBLENDD => GenAPI::new()
-            .opcode(&[0x68])
+            .opcode(&[0x02])
             .modrm(true, None)
             .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
             .imm_atindex(3, 1)
             .strict_pfx()
-            .vex(VexDetails::new().pp(0xF2).map_select(MAP38).vex_we(true)),
+            .vex(VexDetails::new().pp(0x66).map_select(MAP3A).vex_we(false)),
         Mnemonic::VPBLENDMB => GenAPI::new()
             .opcode(&[0x66])
             .modrm(true, None)
@@ -6357,10 +6424,10 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             .ord(&[MODRM_REG, MODRM_RM])
             .evex(VexDetails::new().pp(0x66).map_select(MAP38).vex_we(true)),
         Mnemonic::VPMADD52LUQ => GenAPI::new()
-            .opcode(&[0xB5])
+            .opcode(&[0xB4])
             .modrm(true, None)
             .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
-            .evex(VexDetails::new().pp(0x66).map_select(MAP38).vex_we(false)),
+            .evex(VexDetails::new().pp(0x66).map_select(MAP38).vex_we(true)),
         Mnemonic::VPMADD52HUQ => GenAPI::new()
             .opcode(&[0xB5])
             .modrm(true, None)
@@ -9073,9 +9140,367 @@ pub fn get_genapi(ins: &'_ Instruction, bits: u8) -> GenAPI {
             .modrm(true, None)
             .vex(VexDetails::new().map_select(0x38).pp(0x66))
             .ord(&[MODRM_REG, MODRM_RM]),
+
+        // AMX
+        Mnemonic::LDTILECFG => ins_amx(0x49, 0x00).modrm(true, Some(0)),
+        Mnemonic::STTILECFG => ins_amx(0x49, 0x66).modrm(true, Some(0)),
+        Mnemonic::TILELOADD => ins_amx(0x4B, 0xF2)
+            .modrm(true, None)
+            .force_sib()
+            .ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::TILELOADDT1 => ins_amx(0x4B, 0x66)
+            .modrm(true, None)
+            .force_sib()
+            .ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::TILESTORED => ins_amx(0x4B, 0xF3)
+            .modrm(true, None)
+            .force_sib()
+            .ord(&[MODRM_RM, MODRM_REG]),
+        Mnemonic::TILEZERO => ins_amx(0x49, 0xF2).modrm(true, None).ord(&[MODRM_REG]),
+        Mnemonic::TILERELEASE => GenAPI::new().opcode(&[0x49, 0xC0]).strict_pfx().vex(
+            VexDetails::new()
+                .map_select(0x38)
+                .vex_we(false)
+                .vlength(Some(false)),
+        ),
+        Mnemonic::TDPBSSD => ins_amx(0x5E, 0xF2)
+            .modrm(true, None)
+            .ord(&[MODRM_REG, MODRM_RM, VEX_VVVV]),
+        Mnemonic::TDPBSUD => ins_amx(0x5E, 0xF3)
+            .modrm(true, None)
+            .ord(&[MODRM_REG, MODRM_RM, VEX_VVVV]),
+        Mnemonic::TDPBUSD => ins_amx(0x5E, 0x66)
+            .modrm(true, None)
+            .ord(&[MODRM_REG, MODRM_RM, VEX_VVVV]),
+        Mnemonic::TDPBUUD => ins_amx(0x5E, 0x00)
+            .modrm(true, None)
+            .ord(&[MODRM_REG, MODRM_RM, VEX_VVVV]),
+        Mnemonic::TDPBF16PS => ins_amx(0x5C, 0xF3)
+            .modrm(true, None)
+            .ord(&[MODRM_REG, MODRM_RM, VEX_VVVV]),
+        Mnemonic::TDPFP16PS => ins_amx(0x5C, 0xF2)
+            .modrm(true, None)
+            .ord(&[MODRM_REG, MODRM_RM, VEX_VVVV]),
+        Mnemonic::ALDTILECFG => ins_aamx(0x49, 0x00).modrm(true, Some(0)),
+        Mnemonic::ASTTILECFG => ins_aamx(0x49, 0x66).modrm(true, Some(0)),
+        Mnemonic::ATILELOADD => ins_aamx(0x4B, 0xF2)
+            .modrm(true, None)
+            .force_sib()
+            .ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::ATILELOADDT1 => ins_aamx(0x4B, 0x66)
+            .modrm(true, None)
+            .force_sib()
+            .ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::ATILESTORED => ins_aamx(0x4B, 0xF3)
+            .modrm(true, None)
+            .force_sib()
+            .ord(&[MODRM_RM, MODRM_REG]),
+
+        // AMD XOP
+        Mnemonic::VPCMOV => {
+            let w = is4_w(ins);
+            is4_ord(ins_xop(0xA2, 0x08, w), w)
+        }
+        Mnemonic::VPPERM => {
+            let w = is4_w(ins);
+            is4_ord(ins_xop(0xA3, 0x08, w), w)
+        }
+        Mnemonic::VPROTB => ins_xop_shift(ins, 0x90, 0xC0),
+        Mnemonic::VPROTW => ins_xop_shift(ins, 0x91, 0xC1),
+        Mnemonic::VPROTD => ins_xop_shift(ins, 0x92, 0xC2),
+        Mnemonic::VPROTQ => ins_xop_shift(ins, 0x93, 0xC3),
+        Mnemonic::VPSHLB => ins_xop_shift(ins, 0x94, 0),
+        Mnemonic::VPSHLW => ins_xop_shift(ins, 0x95, 0),
+        Mnemonic::VPSHLD => ins_xop_shift(ins, 0x96, 0),
+        Mnemonic::VPSHLQ => ins_xop_shift(ins, 0x97, 0),
+        Mnemonic::VPSHAB => ins_xop_shift(ins, 0x98, 0),
+        Mnemonic::VPSHAW => ins_xop_shift(ins, 0x99, 0),
+        Mnemonic::VPSHAD => ins_xop_shift(ins, 0x9A, 0),
+        Mnemonic::VPSHAQ => ins_xop_shift(ins, 0x9B, 0),
+        Mnemonic::VPCOMB => ins_xop(0xCC, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPCOMW => ins_xop(0xCD, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPCOMD => ins_xop(0xCE, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPCOMQ => ins_xop(0xCF, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPCOMUB => ins_xop(0xEC, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPCOMUW => ins_xop(0xED, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPCOMUD => ins_xop(0xEE, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPCOMUQ => ins_xop(0xEF, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VFRCZPS => ins_xop(0x80, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VFRCZPD => ins_xop(0x81, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VFRCZSS => ins_xop(0x82, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VFRCZSD => ins_xop(0x83, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDBW => ins_xop(0xC1, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDBD => ins_xop(0xC2, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDBQ => ins_xop(0xC3, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDWD => ins_xop(0xC6, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDWQ => ins_xop(0xC7, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDDQ => ins_xop(0xCB, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDUBW => ins_xop(0xD1, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDUBD => ins_xop(0xD2, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDUBQ => ins_xop(0xD3, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDUWD => ins_xop(0xD6, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDUWQ => ins_xop(0xD7, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHADDUDQ => ins_xop(0xDB, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHSUBBW => ins_xop(0xE1, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHSUBWD => ins_xop(0xE2, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPHSUBDQ => ins_xop(0xE3, 0x09, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VPMACSSWW => ins_xop(0x85, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPMACSSWD => ins_xop(0x86, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPMACSSDQL => ins_xop(0x87, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPMACSSDD => ins_xop(0x8E, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPMACSSDQH => ins_xop(0x8F, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPMACSWW => ins_xop(0x95, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPMACSWD => ins_xop(0x96, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPMACSDQL => ins_xop(0x97, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPMACSDD => ins_xop(0x9E, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPMACSDQH => ins_xop(0x9F, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPMADCSSWD => ins_xop(0xA6, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VPMADCSWD => ins_xop(0xB6, 0x08, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+
+        // AMD FMA4
+        Mnemonic::VFMADDSUBPS => ins_fma4(ins, 0x5C),
+        Mnemonic::VFMADDSUBPD => ins_fma4(ins, 0x5D),
+        Mnemonic::VFMSUBADDPS => ins_fma4(ins, 0x5E),
+        Mnemonic::VFMSUBADDPD => ins_fma4(ins, 0x5F),
+        Mnemonic::VFMADDPS => ins_fma4(ins, 0x68),
+        Mnemonic::VFMADDPD => ins_fma4(ins, 0x69),
+        Mnemonic::VFMADDSS => ins_fma4(ins, 0x6A),
+        Mnemonic::VFMADDSD => ins_fma4(ins, 0x6B),
+        Mnemonic::VFMSUBPS => ins_fma4(ins, 0x6C),
+        Mnemonic::VFMSUBPD => ins_fma4(ins, 0x6D),
+        Mnemonic::VFMSUBSS => ins_fma4(ins, 0x6E),
+        Mnemonic::VFMSUBSD => ins_fma4(ins, 0x6F),
+        Mnemonic::VFNMADDPS => ins_fma4(ins, 0x78),
+        Mnemonic::VFNMADDPD => ins_fma4(ins, 0x79),
+        Mnemonic::VFNMADDSS => ins_fma4(ins, 0x7A),
+        Mnemonic::VFNMADDSD => ins_fma4(ins, 0x7B),
+        Mnemonic::VFNMSUBPS => ins_fma4(ins, 0x7C),
+        Mnemonic::VFNMSUBPD => ins_fma4(ins, 0x7D),
+        Mnemonic::VFNMSUBSS => ins_fma4(ins, 0x7E),
+        Mnemonic::VFNMSUBSD => ins_fma4(ins, 0x7F),
+
+        // AMD TBM
+        Mnemonic::BLCFILL => ins_tbm(ins, 0x01, 1),
+        Mnemonic::BLSFILL => ins_tbm(ins, 0x01, 2),
+        Mnemonic::BLCS => ins_tbm(ins, 0x01, 3),
+        Mnemonic::TZMSK => ins_tbm(ins, 0x01, 4),
+        Mnemonic::BLCIC => ins_tbm(ins, 0x01, 5),
+        Mnemonic::BLSIC => ins_tbm(ins, 0x01, 6),
+        Mnemonic::T1MSKC => ins_tbm(ins, 0x01, 7),
+        Mnemonic::BLCMSK => ins_tbm(ins, 0x02, 1),
+        Mnemonic::BLCI => ins_tbm(ins, 0x02, 6),
+
+        // AMD system instructions
+        Mnemonic::MONITORX => GenAPI::new().opcode(&[0x0F, 0x01, 0xFA]),
+        Mnemonic::MWAITX => GenAPI::new().opcode(&[0x0F, 0x01, 0xFB]),
+        Mnemonic::RDPRU => GenAPI::new().opcode(&[0x0F, 0x01, 0xFD]),
+        Mnemonic::INVLPGB => GenAPI::new().opcode(&[0x0F, 0x01, 0xFE]),
+        Mnemonic::TLBSYNC => GenAPI::new().opcode(&[0x0F, 0x01, 0xFF]),
+
+        // AVX10.2
+        Mnemonic::VMINMAXBF16 => ins_avx10(0x52, MAP3A, 0xF2, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VMINMAXPD => ins_avx10(0x52, MAP3A, 0x66, true)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VMINMAXPH => ins_avx10(0x52, MAP3A, 0x00, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VMINMAXPS => ins_avx10(0x52, MAP3A, 0x66, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VMINMAXSD => ins_avx10(0x53, MAP3A, 0x66, true)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VMINMAXSH => ins_avx10(0x53, MAP3A, 0x00, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VMINMAXSS => ins_avx10(0x53, MAP3A, 0x66, false)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .imm_atindex(3, 1),
+        Mnemonic::VCVTBF162IBS => ins_avx10(0x69, MAP5, 0xF2, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCVTBF162IUBS => ins_avx10(0x6B, MAP5, 0xF2, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCVTTBF162IBS => ins_avx10(0x68, MAP5, 0xF2, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCVTTBF162IUBS => ins_avx10(0x6A, MAP5, 0xF2, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCVTPH2IBS => ins_avx10(0x69, MAP5, 0x00, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCVTPH2IUBS => ins_avx10(0x6B, MAP5, 0x00, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCVTTPH2IBS => ins_avx10(0x68, MAP5, 0x00, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCVTTPH2IUBS => ins_avx10(0x6A, MAP5, 0x00, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCVTPS2IBS => ins_avx10(0x69, MAP5, 0x66, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCVTPS2IUBS => ins_avx10(0x6B, MAP5, 0x66, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCVTTPS2IBS => ins_avx10(0x68, MAP5, 0x66, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCVTTPS2IUBS => ins_avx10(0x6A, MAP5, 0x66, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VADDBF16 => {
+            ins_avx10(0x58, MAP5, 0x66, false).ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+        }
+        Mnemonic::VSUBBF16 => {
+            ins_avx10(0x5C, MAP5, 0x66, false).ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+        }
+        Mnemonic::VMULBF16 => {
+            ins_avx10(0x59, MAP5, 0x66, false).ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+        }
+        Mnemonic::VDIVBF16 => {
+            ins_avx10(0x5E, MAP5, 0x66, false).ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+        }
+        Mnemonic::VMAXBF16 => {
+            ins_avx10(0x5F, MAP5, 0x66, false).ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+        }
+        Mnemonic::VMINBF16 => {
+            ins_avx10(0x5D, MAP5, 0x66, false).ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+        }
+        Mnemonic::VSCALEFBF16 => {
+            ins_avx10(0x2C, MAP6, 0x00, false).ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+        }
+        Mnemonic::VSQRTBF16 => ins_avx10(0x51, MAP5, 0x66, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VRCPBF16 => ins_avx10(0x4C, MAP6, 0x00, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VRSQRTBF16 => ins_avx10(0x4E, MAP6, 0x00, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCOMXSD => ins_avx10(0x2F, MAP0F, 0xF2, true).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCOMXSH => ins_avx10(0x2F, MAP5, 0xF3, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VCOMXSS => ins_avx10(0x2F, MAP0F, 0xF3, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VUCOMXSD => ins_avx10(0x2E, MAP0F, 0xF2, true).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VUCOMXSH => ins_avx10(0x2E, MAP5, 0xF3, false).ord(&[MODRM_REG, MODRM_RM]),
+        Mnemonic::VUCOMXSS => ins_avx10(0x2E, MAP0F, 0xF3, false).ord(&[MODRM_REG, MODRM_RM]),
     }
 }
 
+// AMD XOP: XOP.mmmmm.W opcode /r
+fn ins_xop(opc: u8, map_select: u8, we: bool) -> GenAPI {
+    GenAPI::new()
+        .opcode(&[opc])
+        .modrm(true, None)
+        .xop(VexDetails::new().map_select(map_select).vex_we(we))
+}
+
+// XOP shifts and rotates:
+//  - W0: xmm1, xmm2/m128, xmm3 (count in XOP.vvvv)
+//  - W1: xmm1, xmm2, xmm3/m128 (count in ModRM.r/m)
+//  - xmm1, xmm2/m128, imm8 (only VPROT*, `opc_imm` in map 8)
+fn ins_xop_shift(ins: &Instruction, opc: u8, opc_imm: u8) -> GenAPI {
+    match ins.ssrc() {
+        Some(Operand::Imm(_)) => ins_xop(opc_imm, 0x08, false)
+            .ord(&[MODRM_REG, MODRM_RM])
+            .imm_atindex(2, 1),
+        Some(Operand::Mem(_)) => ins_xop(opc, 0x09, true).ord(&[MODRM_REG, VEX_VVVV, MODRM_RM]),
+        _ => ins_xop(opc, 0x09, false).ord(&[MODRM_REG, MODRM_RM, VEX_VVVV]),
+    }
+}
+
+// AMD FMA4: VEX.66.0F3A.W opcode /r is4
+fn ins_fma4(ins: &Instruction, opc: u8) -> GenAPI {
+    let w = is4_w(ins);
+    let api = GenAPI::new()
+        .opcode(&[opc])
+        .modrm(true, None)
+        .vex(VexDetails::new().pp(0x66).map_select(0x3A).vex_we(w));
+    is4_ord(api, w)
+}
+
+// 4-operand instructions (FMA4, VPCMOV, VPPERM) use W1 if memory is 4th operand
+fn is4_w(ins: &Instruction) -> bool {
+    matches!(ins.get(3), Some(Operand::Mem(_)))
+}
+
+// register, which is not in ModRM.r/m, is encoded in imm8[7:4]
+fn is4_ord(api: GenAPI, we: bool) -> GenAPI {
+    if we {
+        api.ord(&[MODRM_REG, VEX_VVVV, TSRC, MODRM_RM])
+            .imm_atindex(2, 1)
+    } else {
+        api.ord(&[MODRM_REG, VEX_VVVV, MODRM_RM]).imm_atindex(3, 1)
+    }
+}
+
+// AMD TBM: XOP.map9.W opcode /ext, destination in XOP.vvvv
+fn ins_tbm(ins: &Instruction, opc: u8, ext: u8) -> GenAPI {
+    GenAPI::new()
+        .opcode(&[opc])
+        .modrm(true, Some(ext))
+        .xop(
+            VexDetails::new()
+                .map_select(0x09)
+                .vex_we(ins.size() == Size::Qword),
+        )
+        .ord(&[VEX_VVVV, MODRM_RM])
+}
+
+// returns true if both operands of `ins` are XMM registers
+fn is_xmm_xmm(ins: &Instruction) -> bool {
+    matches!(
+        (ins.dst(), ins.src()),
+        (Some(Operand::Register(d)), Some(Operand::Register(s)))
+            if d.size() == Size::Xword && s.size() == Size::Xword
+    )
+}
+
+// AVX10.2 instructions: EVEX.pp.map.W opcode /r
+fn ins_avx10(opc: u8, map_select: u8, pp: u8, we: bool) -> GenAPI {
+    GenAPI::new()
+        .opcode(&[opc])
+        .modrm(true, None)
+        .evex(VexDetails::new().pp(pp).map_select(map_select).vex_we(we))
+}
+
+// AMX instructions are VEX.L0.0F38.W0 (VEX is used even if operand is 512-bit)
+fn ins_amx(opc: u8, pp: u8) -> GenAPI {
+    GenAPI::new().opcode(&[opc]).strict_pfx().vex(
+        VexDetails::new()
+            .map_select(0x38)
+            .pp(pp)
+            .vex_we(false)
+            .vlength(Some(false)),
+    )
+}
+
+// EVEX-promoted (APX) AMX instructions: EVEX.L0.MAP38.W0
+fn ins_aamx(opc: u8, pp: u8) -> GenAPI {
+    GenAPI::new().opcode(&[opc]).strict_pfx().apx(
+        APXVariant::VexExtension,
+        VexDetails::new().map_select(MAP38).pp(pp),
+        false,
+    )
+}
+
 // #  #   #   ####  #####  #####  #   #   ####  #####  #   ###   #   #   ####
 // #  ##  #  #        #    #   #  #   #  #        #    #  #   #  ##  #  #
 // #  # # #   ###     #    ####   #   #  #        #    #  #   #  # # #   ###
@@ -9629,7 +10054,7 @@ fn ins_aadd(
                         .imm_atindex(2, 1)
                 } else {
                     GenAPI::new()
-                        .opcode(opc_rm8)
+                        .opcode(opc_r8m8)
                         .modrm(true, None)
                         .ord(&[MODRM_REG, MODRM_RM])
                 }
@@ -9650,7 +10075,7 @@ fn ins_aadd(
                     .imm_atindex(2, isz)
             } else {
                 GenAPI::new()
-                    .opcode(opc_rm)
+                    .opcode(opc_rm64)
                     .modrm(true, None)
                     .ord(&[MODRM_REG, MODRM_RM])
             }
@@ -9949,19 +10374,22 @@ fn ins_mov(ins: &Instruction, _: u8) -> GenAPI {
                             .modrm(true, None)
                             .ord(&[MODRM_RM, MODRM_REG])
                             .rex()
+                    } else if ins.load() {
+                        let opc = match dst.size() {
+                            Size::Byte => 0x8A,
+                            Size::Word | Size::Dword | Size::Qword => 0x8B,
+                            _ => invalid(27),
+                        };
+                        GenAPI::new()
+                            .opcode(&[opc])
+                            .modrm(true, None)
+                            .ord(&[OpOrd::MODRM_REG, OpOrd::MODRM_RM])
+                            .rex()
                     } else {
-                        let opc = if let Operand::Register(_) = src {
-                            match dst.size() {
-                                Size::Byte => 0x88,
-                                Size::Word | Size::Dword | Size::Qword => 0x89,
-                                _ => invalid(28),
-                            }
-                        } else {
-                            match dst.size() {
-                                Size::Byte => 0x8A,
-                                Size::Word | Size::Dword | Size::Qword => 0x8B,
-                                _ => invalid(27),
-                            }
+                        let opc = match dst.size() {
+                            Size::Byte => 0x88,
+                            Size::Word | Size::Dword | Size::Qword => 0x89,
+                            _ => invalid(28),
                         };
                         GenAPI::new().opcode(&[opc]).modrm(true, None).rex()
                     }
@@ -9991,7 +10419,10 @@ fn ins_mov(ins: &Instruction, _: u8) -> GenAPI {
                             .opcode(&[0xB8 + r.to_byte()])
                             .rex()
                             .imm_atindex(1, 8)
-                    } else if r.ebits()[1] && r.size() == Size::Qword {
+                    } else if r.size() == Size::Qword
+                        && (r.ebits()[1] || s.reltype().is_some_and(|t| t.is_tls()))
+                    {
+                        // TLS offsets are signed, so they have to be sign-extended
                         GenAPI::new()
                             .opcode(&[0xC7])
                             .rex()
@@ -10083,10 +10514,14 @@ fn add_like_ins(ins: &Instruction, opc: &[u8; 9], ovrreg: u8, bits: u8) -> GenAP
             if s.is_deref() {
                 let opc = match dstr.size() {
                     Size::Byte => opc[7],
-                    Size::Word | Size::Dword | Size::Qword => opc[6],
+                    Size::Word | Size::Dword | Size::Qword => opc[8],
                     _ => invalid(17),
                 };
-                GenAPI::new().opcode(&[opc]).modrm(true, None).rex()
+                GenAPI::new()
+                    .opcode(&[opc])
+                    .modrm(true, None)
+                    .ord(&[MODRM_REG, MODRM_RM])
+                    .rex()
             } else {
                 let srci = ins.src().unwrap().size();
                 if let Size::Dword | Size::Word = srci {
@@ -10183,17 +10618,25 @@ fn add_like_ins(ins: &Instruction, opc: &[u8; 9], ovrreg: u8, bits: u8) -> GenAP
                 .rex()
                 .imm_atindex(1, size)
         }
-        (Operand::Register(r), Operand::Mem(_) | Operand::Register(_)) => {
+        // r <- r/m form (used for memory source or with `{load}`)
+        (Operand::Register(r), Operand::Mem(_) | Operand::Register(_))
+            if ins.load() || !matches!(ins.src(), Some(Operand::Register(_))) =>
+        {
             let opc = match r.size() {
                 Size::Byte => opc[7],
-                Size::Word | Size::Dword | Size::Qword => opc[6],
+                Size::Word | Size::Dword | Size::Qword => opc[8],
                 _ => invalid(17),
             };
-            GenAPI::new().opcode(&[opc]).modrm(true, None).rex()
+            GenAPI::new()
+                .opcode(&[opc])
+                .modrm(true, None)
+                .ord(&[MODRM_REG, MODRM_RM])
+                .rex()
         }
-        (Operand::Mem(_) | Operand::Symbol(_), Operand::Register(_)) => {
+        // r/m <- r form
+        (Operand::Register(_) | Operand::Mem(_) | Operand::Symbol(_), Operand::Register(_)) => {
             let opc = match ins.dst().unwrap().size() {
-                Size::Byte => opc[7],
+                Size::Byte => opc[5],
                 Size::Word | Size::Dword | Size::Qword => opc[6],
                 _ => invalid(15),
             };
@@ -10276,7 +10719,9 @@ fn ins_cmp(ins: &Instruction, _: u8) -> GenAPI {
                 .rex()
                 .imm_atindex(1, size)
         }
-        (Operand::Register(r), Operand::Mem(_) | Operand::Register(_)) => {
+        (Operand::Register(r), Operand::Mem(_) | Operand::Register(_))
+            if !ins.store() || !matches!(ins.src(), Some(Operand::Register(_))) =>
+        {
             let opc = match r.size() {
                 Size::Byte => 0x3A,
                 Size::Word | Size::Dword | Size::Qword => 0x3B,
@@ -10288,8 +10733,8 @@ fn ins_cmp(ins: &Instruction, _: u8) -> GenAPI {
                 .ord(&[MODRM_REG, MODRM_RM])
                 .rex()
         }
-        (Operand::Mem(m), Operand::Register(_)) => {
-            let opc = match m.size() {
+        (Operand::Register(_) | Operand::Mem(_), Operand::Register(_)) => {
+            let opc = match ins.dst().unwrap().size() {
                 Size::Byte => 0x38,
                 Size::Word | Size::Dword | Size::Qword => 0x39,
                 _ => invalid(9),
@@ -10457,6 +10902,7 @@ fn ins_lea(_: &Instruction, _: u8) -> GenAPI {
         .opcode(&[0x8D])
         .modrm(true, None)
         .ord(&[MODRM_REG, MODRM_RM])
+        .rex()
 }
 
 // opc[0] = rel16/32
@@ -10545,3 +10991,57 @@ fn ins_shrtjmp(_: &Instruction, opc: Vec<u8>) -> GenAPI {
 fn invalid(ctx: i32) -> ! {
     panic!("Unexpected thing that should not happen - code {ctx}")
 }
+
+#[cfg(all(test, not(feature = "refresh")))]
+mod tests {
+    use crate::core::assemble_lines;
+    #[test]
+    fn taadd_0() {
+        // `reg <- r/m` forms use `02 /r` (byte) and `03 /r` opcodes
+        let bin = assemble_lines(
+            "aadd al, byte [rbx]\naadd rax, qword [rbx]\naadd byte [rbx], al\naadd qword [rbx], rax",
+            64,
+        );
+        assert_eq!(
+            bin,
+            [
+                0x62, 0xF4, 0x7C, 0x08, 0x02, 0x03, 0x62, 0xF4, 0xFC, 0x08, 0x03, 0x03, 0x62, 0xF4,
+                0x7C, 0x08, 0x00, 0x03, 0x62, 0xF4, 0xFC, 0x08, 0x01, 0x03
+            ]
+        );
+    }
+    #[test]
+    fn tamx_0() {
+        // tile loads and stores always use SIB byte; r16-r31 in memory operand need extended EVEX
+        let bin = assemble_lines(
+            "tilezero tmm5\ntilerelease\ntdpbssd tmm0, tmm1, tmm2
+tileloadd tmm1, dword [rax + rcx * 4]\ntileloadd tmm0, dword [rax]
+tilestored dword [r16 + rdi * 2 + 8], tmm3",
+            64,
+        );
+        assert_eq!(
+            bin,
+            [
+                0xC4, 0xE2, 0x7B, 0x49, 0xE8, 0xC4, 0xE2, 0x78, 0x49, 0xC0, 0xC4, 0xE2, 0x6B, 0x5E,
+                0xC1, 0xC4, 0xE2, 0x7B, 0x4B, 0x0C, 0x88, 0xC4, 0xE2, 0x7B, 0x4B, 0x04, 0x20, 0x62,
+                0xFA, 0x7E, 0x08, 0x4B, 0x5C, 0x78, 0x08
+            ]
+        );
+    }
+    #[test]
+    fn tvex_map_0() {
+        // VEX definitions may use MAP0F/MAP38/MAP3A constants instead of 0x0F/0x38/0x3A
+        let bin = assemble_lines(
+            "vpermd ymm0, ymm1, ymm2\nvpblendd ymm0, ymm1, ymm2, 3
+vinserti128 ymm0, ymm1, xmm2, 1\nvextracti128 xmm0, ymm1, 1",
+            64,
+        );
+        assert_eq!(
+            bin,
+            [
+                0xC4, 0xE2, 0x75, 0x36, 0xC2, 0xC4, 0xE3, 0x75, 0x02, 0xC2, 0x03, 0xC4, 0xE3, 0x75,
+                0x38, 0xC2, 0x01, 0xC4, 0xE3, 0x7D, 0x39, 0xC8, 0x01
+            ]
+        );
+    }
+}
@@ -3,29 +3,106 @@
 // made by matissoss
 // licensed under MPL 2.0
 
-use crate::shr::{instruction::Operand, mem::Mem, reg::Register};
+use crate::shr::{instruction::Operand, mem::Mem, size::Size};
 
-pub fn gen_disp_ins(dst: &Option<Operand>, bits: u8) -> Option<Vec<u8>> {
+/// how displacement is encoded
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DispCtx {
+    // displacement size forced with `{disp8}` or `{disp32}`
+    pub forced: Option<Size>,
+    // instruction is encoded with EVEX, so disp8 is scaled by N (disp8*N)
+    pub evex: bool,
+}
+
+pub fn gen_disp_ins(dst: &Option<Operand>, bits: u8, ctx: DispCtx) -> Option<Vec<u8>> {
     if let Some(Operand::Mem(mem)) = dst {
-        return gen_disp(mem, bits);
+        return gen_disp(mem, bits, ctx);
     }
     None
 }
 
-pub fn gen_disp(mem: &Mem, bits: u8) -> Option<Vec<u8>> {
-    if let Some((offs, sz)) = mem.offset_x86() {
-        if sz == 1 && !mem.is_riprel() {
-            Some(vec![offs[0]])
-        } else if bits == 16 {
-            Some(offs[0..2].to_vec())
-        } else {
-            Some(offs.to_vec())
+pub fn gen_disp(mem: &Mem, bits: u8, ctx: DispCtx) -> Option<Vec<u8>> {
+    let offset = mem.offset().unwrap_or(0);
+    let offs = offset.to_le_bytes();
+    match disp_size(mem, ctx)? {
+        1 if ctx.evex && !mem.is_riprel() => Some(vec![(offset / evex_n(mem).max(1) as i32) as u8]),
+        1 => Some(vec![offs[0]]),
+        _ if bits == 16 => Some(offs[0..2].to_vec()),
+        _ => Some(offs.to_vec()),
+    }
+}
+
+/// returns size of displacement encoded for `mem` (1 - disp8, 4 - disp32 or disp16)
+pub fn disp_size(mem: &Mem, ctx: DispCtx) -> Option<usize> {
+    if mem.is_riprel() {
+        return mem.offset().map(|_| 4);
+    }
+    // SIB without base always has disp32
+    if mem.is_nobase() {
+        return Some(4);
+    }
+    let base = match mem.base() {
+        Some(b) => b,
+        None => return mem.offset_x86().map(|(_, sz)| sz),
+    };
+    let offset = mem.offset();
+    // in EVEX disp8 is multiplied by N, so it can be used only for multiples of N
+    let disp8 = match (offset, ctx.evex) {
+        (Some(o), true) => {
+            let n = evex_n(mem) as i32;
+            o == 0 || (n != 0 && o % n == 0 && i8::try_from(o / n).is_ok())
         }
-    } else if let (Some(Register::RBP | Register::BP | Register::EBP), Some(_)) =
-        (mem.base(), mem.index())
-    {
-        Some(vec![0; if bits != 16 { 4 } else { 2 }])
-    } else {
-        None
+        (Some(o), false) => i8::try_from(o).is_ok(),
+        (None, _) => true,
+    };
+    match (ctx.forced, offset) {
+        (Some(Size::Byte), _) if disp8 => Some(1),
+        (Some(_), _) => Some(4),
+        (None, Some(_)) if disp8 => Some(1),
+        (None, Some(_)) => Some(4),
+        // [rbp] and [r13] cannot be encoded without displacement (mod = 00 with this base
+        // means RIP-relative or no base), so we use disp8 = 0
+        (None, None) if base.to_byte() == 0b101 && mem.addrsize() != Size::Word => Some(1),
+        (None, None) => None,
+    }
+}
+
+// N in disp8*N: size of memory operand (element size if broadcasted);
+// 0 if it is unknown (like with VSIB), then only zero displacement can use disp8
+fn evex_n(mem: &Mem) -> u8 {
+    match mem.size() {
+        _ if mem.is_vsib() => 0,
+        Size::B80 => 0,
+        sz => sz.into(),
+    }
+}
+
+#[cfg(all(test, not(feature = "refresh")))]
+mod tests {
+    use crate::core::assemble_lines;
+    #[test]
+    fn tdisp8n_0() {
+        // EVEX compresses displacement by size of memory operand (disp8*N)
+        let bin = assemble_lines(
+            "vmovaps zmm0, zword [rax + 64]\nvmovaps zmm0, zword [rax + 65]\n\
+            vmovss xmm16, dword [rax + 12]",
+            64,
+        );
+        assert_eq!(
+            bin,
+            [
+                0x62, 0xF1, 0x7C, 0x48, 0x28, 0x40, 0x01, 0x62, 0xF1, 0x7C, 0x48, 0x28, 0x80, 65,
+                0, 0, 0, 0x62, 0xE1, 0x7E, 0x08, 0x10, 0x40, 0x03
+            ]
+        );
+        // N is size of broadcasted element
+        let bin = assemble_lines(
+            "vaddps zmm0, zmm1, {bcst}, dword [rax + 8]\nvaddpd zmm0, zmm1, {bcst}, qword [rax - 1024]",
+            64,
+        );
+        assert_eq!(
+            bin,
+            [0x62, 0xF1, 0x74, 0x58, 0x58, 0x40, 0x02, 0x62, 0xF1, 0xF5, 0x58, 0x58, 0x40, 0x80]
+        );
     }
 }
@@ -15,7 +15,7 @@ use crate::shr::{
 const EVEX: u8 = 0x62;
 
 // opcode maps
-pub const MAP0F: u8 = 0b000; // 0x0F
+pub const MAP0F: u8 = 0b001; // 0x0F
 pub const MAP38: u8 = 0b010; // 0x0F 0x38
 pub const MAP3A: u8 = 0b011; // 0x0F 0x3A
 pub const MAP4: u8 = 0b100;
@@ -55,6 +55,10 @@ pub fn evex(ctx: &GenAPI, ins: &Instruction) -> [u8; 4] {
         evex3 |= ((isz == Size::Zword) as u8) << 6 | ((isz == Size::Yword) as u8) << 5
     }
 
+    // AVX10.2: 256-bit embedded rounding/{sae} is encoded with EVEX.U = 0
+    let evex_u = !(ins.size_full_gt() == Size::Yword
+        && (ins.evex_er().is_some() || ins.evex_sae() == Some(true))) as u8;
+
     let evex_we = if ctx.get_vex_we() == Some(true) {
         true
     } else if bcst {
@@ -69,7 +73,7 @@ pub fn evex(ctx: &GenAPI, ins: &Instruction) -> [u8; 4] {
             | (!evex_b as u8) << 5
             | (!evex_r0 as u8) << 4
             | ctx.get_map_select().unwrap() & 0b111,
-        (evex_we as u8) << 7 | gen_evex4v(&evex_vvvv) << 3 | 1 << 2 | ctx.get_pp().unwrap(),
+        (evex_we as u8) << 7 | gen_evex4v(&evex_vvvv) << 3 | evex_u << 2 | ctx.get_pp().unwrap(),
         evex3,
     ]
 }
@@ -90,3 +94,25 @@ fn ebits(op: &Option<Operand>) -> [[bool; 2]; 2] {
         [[false; 2]; 2]
     }
 }
+
+#[cfg(all(test, not(feature = "refresh")))]
+mod tests {
+    use crate::core::assemble_lines;
+    #[test]
+    fn tavx10_2_0() {
+        // `{er}` on YMM instruction clears EVEX.U
+        let bin = assemble_lines(
+            "vminmaxpd zmm1, zmm2, zmm3, 5\nvcvtps2ibs zmm1, zmm2\nvcomxsd xmm1, xmm2
+vmovw xmm1, xmm2\nvaddps ymm1, ymm2, ymm3, {rz-sae}",
+            64,
+        );
+        assert_eq!(
+            bin,
+            [
+                0x62, 0xF3, 0xED, 0x48, 0x52, 0xCB, 0x05, 0x62, 0xF5, 0x7D, 0x48, 0x69, 0xCA, 0x62,
+                0xF1, 0xFF, 0x08, 0x2F, 0xCA, 0x62, 0xF5, 0x7E, 0x08, 0x6E, 0xCA, 0x62, 0xF1, 0x68,
+                0x78, 0x58, 0xCB
+            ]
+        );
+    }
+}
@@ -5,14 +5,49 @@ pub mod modrm;
 pub mod rex;
 pub mod sib;
 
+// shortest-encoding optimization (`-O` / `optimize`)
+pub mod opt;
+
+// multi-byte NOPs (padding for `align`)
+pub mod nop;
+
 // new api!
 pub mod api;
 
 // core for AVX
 pub mod vex;
 
+// core for AMD XOP
+pub mod xop;
+
 // core for AVX-512
 pub mod evex;
 
 // core for Intel APX
 pub mod apx;
+
+// assembles instructions (one per line, without labels and directives) in tests of encoders
+#[cfg(all(test, not(feature = "refresh")))]
+pub fn assemble_lines(src: &str, bits: u8) -> Vec<u8> {
+    use crate::{
+        pre::par::{par, LineResult},
+        shr::reloc::RelType,
+    };
+    use api::AssembleResult;
+    let mut out = Vec::new();
+    for line in src.lines() {
+        let ins = match par(line.trim()) {
+            LineResult::Instruction(i) => i,
+            LineResult::None => continue,
+            _ => panic!("expected instruction: {line}"),
+        };
+        match comp::get_genapi(&ins, bits)
+            .assemble(&ins, bits, RelType::REL32)
+            .0
+        {
+            AssembleResult::WLargeImm(d) => out.extend(d),
+            AssembleResult::NoLargeImm(d) => out.extend(d.iter()),
+        }
+    }
+    out
+}
@@ -3,23 +3,23 @@
 // made by matissoss
 // licensed under MPL 2.0
 
-use crate::core::api;
+use crate::core::{api, disp};
 use crate::shr::{instruction::Operand, reg::Register, size::Size};
 
 //          aka modrm_rm                aka modrm_reg
-pub fn modrm(dst: &Option<Operand>, src: &Option<Operand>, ctx: &api::GenAPI) -> u8 {
+pub fn modrm(
+    dst: &Option<Operand>,
+    src: &Option<Operand>,
+    ctx: &api::GenAPI,
+    disp: disp::DispCtx,
+) -> u8 {
     let reg = ctx.get_modrm().deserialize();
     let mut mod_ = if let Some(Operand::Mem(m)) = dst {
-        if let Some((_, sz)) = m.offset_x86() {
-            if m.is_riprel() {
-                0b00
-            } else if sz == 1 {
-                0b01
-            } else {
-                0b10
-            }
-        } else {
-            0b00
+        match disp::disp_size(m, disp) {
+            _ if m.is_riprel() || m.is_nobase() => 0b00,
+            Some(1) => 0b01,
+            Some(_) => 0b10,
+            None => 0b00,
         }
     } else if let Some(Operand::Symbol(_)) = dst {
         0b00
@@ -48,19 +48,15 @@ pub fn modrm(dst: &Option<Operand>, src: &Option<Operand>, ctx: &api::GenAPI) ->
                     (Some(Register::SI), None) => 0b100,
                     (Some(Register::DI), None) => 0b101,
                     (Some(Register::BX), None) => 0b111,
-                    (None, None) => {
-                        if m.offset_x86().is_some() {
-                            0b101
-                        } else {
-                            0b000
-                        }
-                    }
+                    (None, None) if m.offset_x86().is_some() => 0b101,
                     _ => 0b000,
                 }
             } else if m.is_sib() {
                 0b100
             } else if m.is_riprel() {
                 0b101
+            } else if ctx.flags.at(api::FORCE_SIB) {
+                0b100
             } else {
                 gen_rmreg(dst)
             }
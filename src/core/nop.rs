@@ -0,0 +1,58 @@
+// pasm - src/core/nop.rs
+// ----------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+// recommended multi-byte NOP sequences (from Intel SDM, `NOP` instruction)
+const NOPS: [&[u8]; 9] = [
+    &[0x90],
+    &[0x66, 0x90],
+    &[0x0F, 0x1F, 0x00],
+    &[0x0F, 0x1F, 0x40, 0x00],
+    &[0x0F, 0x1F, 0x44, 0x00, 0x00],
+    &[0x66, 0x0F, 0x1F, 0x44, 0x00, 0x00],
+    &[0x0F, 0x1F, 0x80, 0x00, 0x00, 0x00, 0x00],
+    &[0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[0x66, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+];
+
+// in 16-bit mode ModRM has other layout (no SIB, disp16), so only sequences, that decode
+// as single instruction there are used
+const NOPS16: [&[u8]; 6] = [
+    &[0x90],
+    &[0x66, 0x90],
+    &[0x0F, 0x1F, 0x00],
+    &[0x0F, 0x1F, 0x40, 0x00],
+    &[0x0F, 0x1F, 0x80, 0x00, 0x00],
+    &[0x66, 0x0F, 0x1F, 0x80, 0x00, 0x00],
+];
+
+/// appends `len` bytes of NOPs into `buf` (using as few instructions as possible)
+pub fn nops(buf: &mut Vec<u8>, mut len: usize, bits: u8) {
+    let table: &[&[u8]] = if bits == 16 { &NOPS16 } else { &NOPS };
+    while len > 0 {
+        let n = len.min(table.len());
+        buf.extend(table[n - 1]);
+        len -= n;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn tnops_0() {
+        let mut buf = Vec::new();
+        nops(&mut buf, 3, 64);
+        assert_eq!(buf, [0x0F, 0x1F, 0x00]);
+        let mut buf = Vec::new();
+        nops(&mut buf, 11, 32);
+        assert_eq!(
+            buf,
+            [0x66, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00, 0x66, 0x90]
+        );
+        let mut buf = Vec::new();
+        nops(&mut buf, 7, 16);
+        assert_eq!(buf, [0x66, 0x0F, 0x1F, 0x80, 0x00, 0x00, 0x90]);
+    }
+}
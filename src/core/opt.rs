@@ -0,0 +1,318 @@
+// pasm - src/core/opt.rs
+// ----------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+// optimization mode (`-O` flag or `optimize` directive).
+//
+// every rewrite here has to keep semantics of instruction and has to be deterministic
+// (same input always results in same output). Optimization is done in 2 steps:
+//  - `optimize` rewrites operands before checking and encoding
+//    (narrowing registers, swapping operands of commutative instructions, etc.)
+//  - `genapi` replaces `GenAPI` returned by `comp::get_genapi` with shorter one
+//    (sign-extended imm8 forms, accumulator forms, REX/REX2 instead of EVEX)
+
+use crate::{
+    core::{
+        api::{GenAPI, OpOrd::*, VexDetails, PREFIX_APX},
+        apx::APXVariant,
+        evex::MAP4,
+    },
+    shr::{
+        instruction::{Instruction, Operand, OperandOwned},
+        mnemonic::Mnemonic,
+        num::Number,
+        reg::{Purpose, Register},
+        size::Size,
+    },
+};
+
+/// rewrites operands of `ins` into shorter, but equivalent form
+pub fn optimize(ins: &mut Instruction, bits: u8) {
+    use Mnemonic::*;
+    match ins.mnemonic {
+        ADD | OR | ADC | SBB | AND | SUB | XOR | CMP | MOV | AADD | AOR | AADC | ASBB | AAND
+        | ASUB | AXOR => truncate_imm(ins, 1),
+        IMUL => truncate_imm(ins, 2),
+        // `push imm` is sign-extended from imm8/imm32 to stack width
+        PUSH if bits != 16 => {
+            if let Some(Operand::Imm(n)) = ins.dst() {
+                let v = n.get_raw();
+                if v <= u32::MAX as u64 || i32::try_from(v as i64).is_ok() {
+                    ins.set(0, OperandOwned::Imm(Number::new(v as u32 as u64)));
+                }
+            }
+        }
+        _ => {}
+    }
+    match ins.mnemonic {
+        // `mov r64, imm32` zero-extends: `mov eax, 1` is same as `mov rax, 1`
+        MOV if bits == 64 => {
+            if let (Some(Operand::Register(r)), Some(Operand::Imm(n))) = (ins.dst(), ins.src()) {
+                if r.purpose().is_gpr() && r.size() == Size::Qword && n.get_raw() <= u32::MAX as u64
+                {
+                    let r = Register::new(Purpose::General, Size::Dword, r.ebits(), r.to_byte());
+                    ins.set(0, OperandOwned::Register(r));
+                }
+            }
+        }
+        // register in ModRM.r/m needs 3-byte VEX (VEX.B), but register in ModRM.reg does not
+        VMOVAPS | VMOVAPD | VMOVUPS | VMOVUPD | VMOVDQA => {
+            if let (Some(Operand::Register(d)), Some(Operand::Register(s))) = (ins.dst(), ins.src())
+            {
+                if !ins.needs_evex() && !ins.load() && s.ebits()[1] && !d.ebits()[1] {
+                    ins.set_store();
+                }
+            }
+        }
+        VADDPS | VADDPD | VMULPS | VMULPD | VANDPS | VANDPD | VORPS | VORPD | VXORPS | VXORPD
+        | VPADDB | VPADDW | VPADDD | VPADDQ | VPMULLW | VPMULLD | VPAND | VPOR | VPXOR
+        | VPCMPEQB | VPCMPEQW | VPCMPEQD | VPCMPEQQ => {
+            if let (Some(Operand::Register(s)), Some(Operand::Register(t))) =
+                (ins.src(), ins.ssrc())
+            {
+                if !ins.needs_evex() && t.ebits()[1] && !s.ebits()[1] {
+                    ins.set(1, OperandOwned::Register(t));
+                    ins.set(2, OperandOwned::Register(s));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// returns shorter `GenAPI` for `ins` (or `api` if there is none)
+pub fn genapi(ins: &Instruction, bits: u8, api: GenAPI) -> GenAPI {
+    use Mnemonic::*;
+    let opt = match ins.mnemonic {
+        ADD => alu_imm(ins, 0),
+        OR => alu_imm(ins, 1),
+        ADC => alu_imm(ins, 2),
+        SBB => alu_imm(ins, 3),
+        AND => alu_imm(ins, 4),
+        SUB => alu_imm(ins, 5),
+        XOR => alu_imm(ins, 6),
+        CMP => alu_imm(ins, 7),
+        MOV => mov_imm(ins, bits),
+        PUSH => push_imm(ins, bits),
+        IMUL => imul_imm(ins),
+        AADD | AOR | AADC | ASBB | AAND | ASUB | AXOR => return apx_legacy(ins, bits, api, 2),
+        AINC | ADEC | ANEG | ANOT => return apx_legacy(ins, bits, api, 1),
+        _ => None,
+    };
+    opt.unwrap_or(api)
+}
+
+// replaces immediate at `idx` with its value truncated to size of destination,
+// so `add ecx, -1` is treated like `add ecx, 0xFFFF_FFFF` (and not like 64-bit operation)
+fn truncate_imm(ins: &mut Instruction, idx: usize) {
+    let sz = match ins.dst() {
+        Some(Operand::Register(r)) if r.purpose().is_gpr() => r.size(),
+        Some(Operand::Mem(m)) => m.size(),
+        _ => return,
+    };
+    if let Some(Operand::Imm(n)) = ins.get(idx) {
+        if let Some(v) = imm_in(n, sz) {
+            ins.set(idx, OperandOwned::Imm(Number::new(v)));
+        }
+    }
+}
+
+// value of `n` truncated to `sz`, if `n` can be represented in `sz` (as signed or unsigned number)
+fn imm_in(n: Number, sz: Size) -> Option<u64> {
+    let bits = <Size as Into<u8>>::into(sz) as u32 * 8;
+    if !(8..=64).contains(&bits) {
+        return None;
+    }
+    let raw = n.get_raw();
+    let mask = u64::MAX >> (64 - bits);
+    let min = -(1i128 << (bits - 1));
+    if raw <= mask || ((raw as i64) < 0 && (raw as i64 as i128) >= min) {
+        Some(raw & mask)
+    } else {
+        None
+    }
+}
+
+// returns true, if `v` (in `sz`) is equal to sign-extended imm8
+fn fits_i8(v: u64, sz: Size) -> bool {
+    match imm_in(Number::new(v as i8 as i64 as u64), sz) {
+        Some(s) => s == v,
+        None => false,
+    }
+}
+
+// returns true, if `v` (in `sz`) is equal to sign-extended imm32
+fn fits_i32(v: u64, sz: Size) -> bool {
+    sz != Size::Qword || i32::try_from(v as i64).is_ok()
+}
+
+// opcodes:
+//  - 04+8*ext ib (AL, imm8)
+//  - 05+8*ext iw/id (AX/EAX/RAX, imm16/32)
+//  - 80 /ext ib
+//  - 81 /ext iw/id
+//  - 83 /ext ib
+fn alu_imm(ins: &Instruction, ext: u8) -> Option<GenAPI> {
+    let (dst, src) = (ins.dst()?, ins.src()?);
+    let v = match src {
+        Operand::Imm(n) => n.get_raw(),
+        _ => return None,
+    };
+    let acc = match dst {
+        Operand::Register(r) if r.purpose().is_gpr() => r.to_byte() == 0 && r.ebits() == [false; 2],
+        Operand::Mem(_) => false,
+        _ => return None,
+    };
+    let sz = dst.size();
+    let isz = match sz {
+        Size::Byte => 1,
+        Size::Word => 2,
+        Size::Dword | Size::Qword => 4,
+        _ => return None,
+    };
+    if imm_in(Number::new(v), sz) != Some(v) || !fits_i32(v, sz) {
+        return None;
+    }
+    let api = if sz != Size::Byte && fits_i8(v, sz) {
+        GenAPI::new()
+            .opcode(&[0x83])
+            .modrm(true, Some(ext))
+            .rex()
+            .imm_atindex(1, 1)
+    } else if acc {
+        let opc = 0x04 + 8 * ext + (sz != Size::Byte) as u8;
+        GenAPI::new().opcode(&[opc]).rex().imm_atindex(1, isz)
+    } else {
+        let opc = 0x80 + (sz != Size::Byte) as u8;
+        GenAPI::new()
+            .opcode(&[opc])
+            .modrm(true, Some(ext))
+            .rex()
+            .imm_atindex(1, isz)
+    };
+    Some(api)
+}
+
+// `mov r64, imm` with negative imm32 uses `C7 /0 id` (sign-extended) instead of `movabs`
+fn mov_imm(ins: &Instruction, bits: u8) -> Option<GenAPI> {
+    match (ins.dst()?, ins.src()?) {
+        (Operand::Register(r), Operand::Imm(n))
+            if bits == 64
+                && r.purpose().is_gpr()
+                && r.size() == Size::Qword
+                && n.get_raw() > u32::MAX as u64
+                && fits_i32(n.get_raw(), Size::Qword) =>
+        {
+            Some(
+                GenAPI::new()
+                    .opcode(&[0xC7])
+                    .modrm(true, Some(0))
+                    .rex()
+                    .imm_atindex(1, 4),
+            )
+        }
+        _ => None,
+    }
+}
+
+// `push imm8` (6A) and `push imm32` (68) (imm is already truncated to 32 bits by `optimize`)
+fn push_imm(ins: &Instruction, bits: u8) -> Option<GenAPI> {
+    let v = match ins.dst()? {
+        Operand::Imm(n) if bits != 16 && n.get_raw() <= u32::MAX as u64 => n.get_raw() as u32,
+        _ => return None,
+    };
+    if i8::try_from(v as i32).is_ok() {
+        Some(
+            GenAPI::new()
+                .opcode(&[0x6A])
+                .imm_atindex(0, 1)
+                .fixed_size(Size::Byte),
+        )
+    } else {
+        Some(
+            GenAPI::new()
+                .opcode(&[0x68])
+                .imm_atindex(0, 4)
+                .fixed_size(Size::Dword),
+        )
+    }
+}
+
+// `imul r, r/m, imm`: 6B ib or 69 iw/id
+fn imul_imm(ins: &Instruction) -> Option<GenAPI> {
+    let (dst, v) = match (ins.dst()?, ins.get(2)?) {
+        (Operand::Register(r), Operand::Imm(n)) => (r, n.get_raw()),
+        _ => return None,
+    };
+    let sz = dst.size();
+    if !matches!(sz, Size::Word | Size::Dword | Size::Qword)
+        || imm_in(Number::new(v), sz) != Some(v)
+        || !fits_i32(v, sz)
+    {
+        return None;
+    }
+    let (opc, isz) = if fits_i8(v, sz) {
+        (0x6B, 1)
+    } else if sz == Size::Word {
+        (0x69, 2)
+    } else {
+        (0x69, 4)
+    };
+    Some(
+        GenAPI::new()
+            .opcode(&[opc])
+            .modrm(true, None)
+            .ord(&[MODRM_REG, MODRM_RM])
+            .rex()
+            .imm_atindex(2, isz),
+    )
+}
+
+// APX: legacy instructions promoted to EVEX map 4 have same opcodes as in legacy map 0,
+// so if they don't use new data destination or {nf}, they are same as their legacy
+// counterparts and can use REX (or 2-byte REX2 if r16-r31 are used) instead of 4-byte extended EVEX.
+fn apx_legacy(ins: &Instruction, bits: u8, api: GenAPI, operands: usize) -> GenAPI {
+    if bits != 64
+        || ins.len() != operands
+        || ins.is_apx()
+        || api.get_fpfx() != PREFIX_APX
+        || api.get_apx_eevex_version() != Some(APXVariant::LegacyExtension)
+        || api.get_apx_eevex_map_select() != MAP4
+        || api.get_apx_eevex_pp() != 0
+    {
+        return api;
+    }
+    let egpr = ins.iter().any(|o| o.ebits().iter().any(|[e4, _]| *e4));
+    // 0x66 is not generated for APX prefixes
+    let sz = ins.size();
+    if egpr && !matches!(sz, Size::Byte | Size::Dword | Size::Qword) {
+        return api;
+    }
+    let ext = match ins.mnemonic {
+        Mnemonic::AADD => 0,
+        Mnemonic::AOR => 1,
+        Mnemonic::AADC => 2,
+        Mnemonic::ASBB => 3,
+        Mnemonic::AAND => 4,
+        Mnemonic::ASUB => 5,
+        Mnemonic::AXOR => 6,
+        _ => 0,
+    };
+    let api = match ins.src() {
+        Some(Operand::Imm(_)) => match alu_imm(ins, ext) {
+            Some(a) => a,
+            None => return api,
+        },
+        _ => api.opcode_prefix(0),
+    };
+    if !egpr {
+        api.rex()
+    } else {
+        api.apx(
+            APXVariant::Rex2,
+            VexDetails::new().vex_we(sz == Size::Qword),
+            false,
+        )
+    }
+}
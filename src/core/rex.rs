@@ -26,6 +26,11 @@ pub fn needs_rex(ins: &Instruction, dst: &Option<Operand>, src: &Option<Operand>
             return true;
         }
     }
+    if let Some(m) = ins.get_mem() {
+        if m.base_rex() || m.index_rex() {
+            return true;
+        }
+    }
     match (size_d, size_s) {
         (Size::Qword, Size::Qword) | (Size::Qword, _) | (_, Size::Qword) => {}
         _ => return false,
@@ -137,6 +142,13 @@ pub fn needs_rex(ins: &Instruction, dst: &Option<Operand>, src: &Option<Operand>
             if let (Some(Operand::Mem(_)), _) | (_, Some(Operand::Mem(_))) = (&dst, &src) {
                 return true;
             }
+            // dereferenced symbol is RIP-relative memory operand
+            // and TLS offsets are sign-extended immediates (8-byte ones are `movabs`)
+            if let (Some(Operand::Symbol(s)), _) | (_, Some(Operand::Symbol(s))) = (&dst, &src) {
+                if s.is_deref() || s.reltype().is_some_and(|t| t.is_tls() || t.size() == 8) {
+                    return true;
+                }
+            }
             if let Some(Operand::Imm(i)) = &src {
                 if i.signed_size() == Size::Qword {
                     return true;
@@ -158,7 +170,18 @@ pub fn needs_rex(ins: &Instruction, dst: &Option<Operand>, src: &Option<Operand>
         | Mnemonic::ADC
         | Mnemonic::SBB
         | Mnemonic::XCHG
-        | Mnemonic::XOR => {
+        | Mnemonic::XOR
+        | Mnemonic::AADD
+        | Mnemonic::AOR
+        | Mnemonic::AADC
+        | Mnemonic::ASBB
+        | Mnemonic::AAND
+        | Mnemonic::ASUB
+        | Mnemonic::AXOR
+        | Mnemonic::AINC
+        | Mnemonic::ADEC
+        | Mnemonic::ANEG
+        | Mnemonic::ANOT => {
             matches!(
                 (dst, src),
                 (_, Some(Operand::Register(_)))
@@ -192,14 +215,10 @@ fn get_wb(op: &Option<Operand>) -> bool {
 }
 
 fn fix_rev(r: &mut bool, ins: &Instruction) {
-    #[allow(clippy::single_match)]
-    match ins.dst() {
-        Some(Operand::Register(reg)) => {
-            if reg.size() == Size::Xword {
-                *r = true;
-            }
+    if let Some(Operand::Register(reg)) = ins.dst() {
+        if reg.size() == Size::Xword {
+            *r = true;
         }
-        _ => {}
     }
     if matches!(ins.mnemonic, Mnemonic::UD1 | Mnemonic::UD2) {
         *r = true;
@@ -28,10 +28,22 @@ pub fn gen_sib(mem: &Mem) -> Option<u8> {
         Some(sib(scale, index.to_byte(), base))
     } else if mem.is_riprel() {
         None
-    } else if let Some(Register::RSP | Register::ESP | Register::SP) = mem.base() {
-        Some(sib(0, Register::SP.to_byte(), Register::SP.to_byte()))
     } else {
-        None
+        // [rsp] and [r12] can be encoded only with SIB
+        mem.base()
+            .filter(|b| b.to_byte() == Register::SP.to_byte())
+            .map(|b| sib(0, Register::SP.to_byte(), b.to_byte()))
+    }
+}
+
+// sibmem operands (AMX) are always encoded with SIB, even if they don't use index
+pub fn gen_sib_forced(dst: &Option<Operand>) -> Option<u8> {
+    match dst {
+        Some(Operand::Mem(mem)) if !mem.is_sib() && !mem.is_riprel() => {
+            let base = mem.base().map(|b| b.to_byte()).unwrap_or(0b101);
+            Some(sib(0, Register::SP.to_byte(), base))
+        }
+        _ => gen_sib_ins(dst),
     }
 }
 
@@ -43,7 +43,11 @@ pub fn vex(ctx: &api::GenAPI, ins: &Instruction) -> StackVec<u8, 3> {
     let vex_r = needs_vex3(&modrm_reg).0;
 
     let mut pfx = StackVec::new();
-    if (vex_b.0 || vex_b.1) || (map_select == 0b00011 || map_select == 0b00010) || vex_we {
+    if (vex_b.0 || vex_b.1)
+        || (map_select == 0b00011 || map_select == 0b00010)
+        || vex_we
+        || ins.vex3()
+    {
         pfx.push(THREE_BYTE_PFX);
         pfx.push(
             ((!vex_r) as u8) << 7
@@ -58,14 +62,10 @@ pub fn vex(ctx: &api::GenAPI, ins: &Instruction) -> StackVec<u8, 3> {
     pfx
 }
 
-fn needs_vex3(op: &Option<Operand>) -> (bool, bool) {
+pub fn needs_vex3(op: &Option<Operand>) -> (bool, bool) {
     if let Some(op) = op {
         match op {
-            Operand::Register(r) => {
-                if r.ebits()[1] {
-                    return (true, false);
-                }
-            }
+            Operand::Register(r) if r.ebits()[1] => return (true, false),
             Operand::Mem(m) => {
                 let rr = m.needs_rex();
                 if rr.0 || rr.1 {
@@ -79,7 +79,7 @@ fn needs_vex3(op: &Option<Operand>) -> (bool, bool) {
 }
 
 // VEX.vvvv field
-fn gen_vex4v(op: &Option<Operand>) -> u8 {
+pub fn gen_vex4v(op: &Option<Operand>) -> u8 {
     if let Some(Operand::Register(r)) = op {
         andn((r.ebits()[1] as u8) << 3 | r.to_byte(), 0b0000_1111)
     } else {
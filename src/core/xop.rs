@@ -0,0 +1,56 @@
+// pasm - src/core/xop.rs
+// ----------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+use crate::core::{api, vex};
+use crate::shr::instruction::{IVariant, Instruction};
+use crate::shr::stackvec::StackVec;
+
+// XOP prefix uses 0x8F escape (POP r/m), so it is distinguished from POP by map_select >= 8
+const XOP_PFX: u8 = 0x8F;
+
+pub fn xop(ctx: &api::GenAPI, ins: &Instruction) -> StackVec<u8, 3> {
+    let [modrm_rm, modrm_reg, xop_opr] = ctx.get_ord_oprs(ins);
+
+    let vvvv = vex::gen_vex4v(&xop_opr);
+    let pp = ctx.get_pp().unwrap();
+    let map_select = ctx.get_map_select().unwrap();
+    let xop_we = ctx.get_vex_we().unwrap();
+
+    let vlength = match ctx.get_vex_vlength().and_then(|v| v.get()) {
+        Some(l) => l as u8,
+        None => (ins.which_variant() == IVariant::YMM) as u8,
+    };
+
+    let (xop_b, xop_x) = vex::needs_vex3(&modrm_rm);
+    let xop_r = vex::needs_vex3(&modrm_reg).0;
+
+    let mut pfx = StackVec::new();
+    pfx.push(XOP_PFX);
+    pfx.push((!xop_r as u8) << 7 | (!xop_x as u8) << 6 | (!xop_b as u8) << 5 | map_select);
+    pfx.push((xop_we as u8) << 7 | vvvv << 3 | vlength << 2 | pp);
+    pfx
+}
+
+#[cfg(all(test, not(feature = "refresh")))]
+mod tests {
+    use crate::core::assemble_lines;
+    #[test]
+    fn txop_0() {
+        // XOP (map 8, 9), TBM (map 9, 0xA) and system instructions without XOP prefix
+        let bin = assemble_lines(
+            "vpcmov xmm0, xmm1, xmm2, xword [rax]\nvprotb xmm9, xmm10, 7
+blcfill rax, qword [rbx]\nbextr eax, ecx, 0x1234\nrdpru",
+            64,
+        );
+        assert_eq!(
+            bin,
+            [
+                0x8F, 0xE8, 0xF0, 0xA2, 0x00, 0x20, 0x8F, 0x48, 0x78, 0xC0, 0xCA, 0x07, 0x8F, 0xE9,
+                0xF8, 0x01, 0x0B, 0x8F, 0xEA, 0x78, 0x10, 0xC1, 0x34, 0x12, 0x00, 0x00, 0x0F, 0x01,
+                0xFD
+            ]
+        );
+    }
+}
@@ -18,6 +18,8 @@ pub fn help() -> String {
     help_string.push_str(
         "\t-o=[PATH] / --output=[PATH]       ; specifies output file, by default a.out\n",
     );
+    help_string
+        .push_str("\t-I=[PATH] / --include=[PATH]      ; adds directory to include search paths\n");
     help_string.push_str("\t-v / --version                    ; prints version\n");
     #[cfg(feature = "iinfo")]
     help_string.push_str("\t-s / --supported-instructions     ; prints supported instructions\n");
@@ -32,6 +34,12 @@ pub fn help() -> String {
     help_string.push_str(
         "\t-t / --time                       ; measures time requried to assemble source code\n",
     );
+    help_string.push_str(
+        "\t-O / --optimize                   ; uses shortest encoding of every instruction\n",
+    );
+    help_string.push_str(
+        "\t--cpu=[SPEC]                      ; rejects instructions not supported by CPU (like `cpu` directive)\n",
+    );
 
     help_string.push_str("made by matissoss\nlicensed under MPL 2.0");
     help_string.push_str("\nsource code: https://github.com/Matissoss/pasm");
@@ -4,51 +4,206 @@
 // licensed under MPL 2.0
 
 use std::{
+    collections::{HashMap, HashSet},
     fs::OpenOptions,
-    io::{Read, Write},
+    io::Write,
     path::Path,
 };
 
 use crate::{
-    core::{api::AssembleResult, comp},
-    obj::Elf,
+    cli::CLI,
+    core::{api::AssembleResult, comp, nop::nops, opt},
+    obj::{Elf, ElfKind},
     pre::{
-        chk,
+        chk, cpu,
         par::{par, LineResult},
+        prp::Source,
+        tls, x32,
     },
     shr::{
         error::Error as PasmError,
-        reloc::{relocate_addresses, RelType, Relocation},
+        expr,
+        isa::IsaSet,
+        mnemonic::Mnemonic,
+        reloc::{relocate_addresses, resolve_differences, write_addends, RelType, Relocation},
         section::{Section, SectionAttributes},
-        symbol::{Symbol, SymbolType},
+        symbol::{numeric_label, numeric_ref, Symbol, SymbolType},
         visibility::Visibility,
     },
-    utils::LineIter,
+    utils::{split_once_intelligent, LineIter},
 };
 
 pub fn assemble(ipath: &Path, opath: &Path) -> Result<(), PasmError> {
-    // fetch input file
-    let ifile = OpenOptions::new()
-        .read(true)
-        .write(false)
-        .create(false)
-        .open(ipath);
-    let mut ifile = match ifile {
+    // fetch input file (with all of its includes)
+    let src = Source::new(ipath, CLI.include_paths())?;
+
+    // source is assembled again, until sizes of branches stop changing
+    let mut short = HashSet::new();
+    let mut long = HashSet::new();
+    let mut out = pass(&src, &short)?;
+    while relax(&out, &mut short, &mut long) {
+        out = pass(&src, &short)?;
+    }
+    let Pass {
+        mut obuf,
+        mut rels,
+        symbols,
+        sections,
+        target,
+        ..
+    } = out;
+
+    for s in &symbols {
+        if !s.valid {
+            return Err(PasmError::new(
+                format!(
+                    "you tried to use directive on invalid symbol named \"{}\"",
+                    s.name
+                ),
+                8,
+            ));
+        }
+    }
+
+    // errors of relocations have line in preprocessed source, so it has to be located
+    let locate = |mut e: PasmError| {
+        if let Some(lnum) = e.get_line() {
+            src.locate(&mut e, lnum);
+        }
+        e
+    };
+    let target = target.unwrap_or("bin");
+    resolve_differences(&mut obuf, &mut rels, &symbols, target == "bin").map_err(locate)?;
+
+    match target {
+        #[cfg(feature = "target_elf")]
+        "elf64" | "ELF64" => {
+            let elf = Elf::new(&sections, opath, &obuf, rels, &symbols, ElfKind::Elf64)
+                .map_err(locate)?;
+            obuf = elf.compile(ElfKind::Elf64);
+        }
+        #[cfg(feature = "target_elf")]
+        "elf32" | "ELF32" => {
+            write_addends(&mut obuf, &mut rels).map_err(locate)?;
+            let elf = Elf::new(&sections, opath, &obuf, rels, &symbols, ElfKind::Elf32)
+                .map_err(locate)?;
+            obuf = elf.compile(ElfKind::Elf32);
+        }
+        // x32 ABI: 64-bit code in ELFCLASS32 file
+        #[cfg(feature = "target_elf")]
+        t if is_x32(t) => {
+            let elf =
+                Elf::new(&sections, opath, &obuf, rels, &symbols, ElfKind::X32).map_err(locate)?;
+            obuf = elf.compile(ElfKind::X32);
+        }
+        "bin" => {
+            relocate_addresses(&mut obuf, rels, &symbols).map_err(locate)?;
+        }
+        t => return Err(PasmError::new(format!("unknown target {t}"), 7)),
+    }
+
+    // now write content to a file
+    let ofile = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(opath);
+    let mut ofile = match ofile {
         Ok(f) => f,
         Err(e) => {
             return Err(PasmError::new(e.to_string(), 6));
         }
     };
-    let mut ibuf = Vec::new();
-    if let Err(e) = ifile.read_to_end(&mut ibuf) {
-        return Err(PasmError::new(e.to_string(), 6));
+    if let Err(err) = ofile.write_all(&obuf) {
+        return Err(PasmError::new(err.to_string(), 6));
     }
+    Ok(())
+}
+
+fn is_x32(target: &str) -> bool {
+    matches!(target, "elfx32" | "elf32x" | "ELFX32" | "ELF32X")
+}
+
+// output of single assembling pass
+struct Pass<'a> {
+    obuf: Vec<u8>,
+    rels: Vec<Relocation<'a>>,
+    symbols: Vec<Symbol<'a>>,
+    sections: Vec<Section<'a>>,
+    target: Option<&'a str>,
+    branches: Vec<Branch>,
+}
+
+// `jmp`/`jcc` to symbol, which relocation type was not provided
+struct Branch {
+    lnum: usize,
+    // index of its relocation
+    rel: usize,
+    // how many bytes are saved if rel8 is used instead of rel32
+    shrink: usize,
+}
+
+// decides which branches use rel8 in next pass (`short`): branch is shrinked if its target is
+// in the same section and displacement fits into rel8, but if displacement stops fitting
+// (because of layout changes), then branch stays rel32 forever (`long`), so passes always end.
+// returns true if anything changed.
+fn relax(out: &Pass, short: &mut HashSet<usize>, long: &mut HashSet<usize>) -> bool {
+    let mut changed = false;
+    for b in &out.branches {
+        let rel = &out.rels[b.rel];
+        let symbol = out.symbols.iter().find(|s| {
+            s.name == rel.symbol && !matches!(s.visibility, Visibility::Extern | Visibility::Weak)
+        });
+        let symbol = match symbol {
+            Some(s) if s.sindex == rel.shidx => s,
+            _ => continue,
+        };
+        let target = symbol.offset as i64 + rel.addend as i64 + rel.reltype.pc_adjust() as i64;
+        let end = (rel.offset + rel.size()) as i64;
+        if short.contains(&b.lnum) {
+            if !(-128..=127).contains(&(target - end)) {
+                short.remove(&b.lnum);
+                long.insert(b.lnum);
+                changed = true;
+            }
+        } else if !long.contains(&b.lnum) {
+            let shrink = b.shrink as i64;
+            // target after branch is moved too
+            let target = if target >= end {
+                target - shrink
+            } else {
+                target
+            };
+            if (-128..=127).contains(&(target - (end - shrink))) {
+                short.insert(b.lnum);
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+// how many bytes are saved if branch uses rel8 instead of rel32 (`None` if it cannot be relaxed)
+fn branch_shrink(mnemonic: Mnemonic) -> Option<usize> {
+    use Mnemonic::*;
+    match mnemonic {
+        JMP => Some(3),
+        JA | JB | JC | JO | JP | JS | JL | JG | JE | JZ | JAE | JBE | JNA | JNB | JNC | JNG
+        | JNL | JNO | JNP | JNS | JPE | JPO | JNE | JNZ | JLE | JGE | JNAE | JNBE | JNGE | JNLE => {
+            Some(4)
+        }
+        _ => None,
+    }
+}
 
+// assembles whole source; branches with line numbers in `short` use rel8
+fn pass<'a>(src: &'a Source, short: &HashSet<usize>) -> Result<Pass<'a>, PasmError> {
     // get output from assembler
     let mut obuf: Vec<u8> = Vec::new();
     let mut rels: Vec<Relocation> = Vec::new();
     let mut symbols: Vec<Symbol> = Vec::new();
-    let mut line_iter = LineIter::new(&ibuf);
+    let mut branches: Vec<Branch> = Vec::new();
+    let mut line_iter = LineIter::new(src.buf.as_bytes());
 
     let mut sections: Vec<Section> = Vec::new();
     let mut current_section = Section {
@@ -59,45 +214,141 @@ pub fn assemble(ipath: &Path, opath: &Path) -> Result<(), PasmError> {
         attributes: SectionAttributes::new(),
         bits: 16,
     };
+    // true, if current section was declared with `section` (and is not implicit `.text`)
+    let mut declared = false;
     let mut current_label = 0usize;
     let mut sindex: u16 = 0u16;
 
+    // last declarations of numeric labels (section name, section offset, label offset)
+    let mut numeric: HashMap<u32, (&str, usize, usize)> = HashMap::new();
+    // references to numeric labels declared after them (relocation index, label, is subtracted, line)
+    let mut pending: Vec<(usize, u32, bool, usize)> = Vec::new();
+
     let mut target: Option<&str> = None;
     let mut bits: u8 = 16u8;
+    let mut optimize = CLI.optimize();
+    let mut isa = IsaSet::all();
+    if let Some(c) = CLI.cpu() {
+        isa.apply(c)?;
+    }
+    // line of `lea rdi, qword @[SYMBOL, tlsgd]`, that is not yet followed by `call __tls_get_addr`
+    let mut tlsgd: Option<usize> = None;
 
     while let Some((lnum, line)) = line_iter.next() {
         let line = line.trim();
-        match par(line) {
+        let res = par(line);
+        if let Some(l) = tlsgd {
+            if matches!(
+                res,
+                LineResult::Section(_) | LineResult::Directive("align", _)
+            ) {
+                let mut e = tls::check_tlsgd_call(None).unwrap_err();
+                src.locate(&mut e, l);
+                return Err(e);
+            }
+        }
+        match res {
             LineResult::Error(mut e) => {
-                e.set_line(lnum + 1);
+                src.locate(&mut e, lnum);
                 return Err(e);
             }
             LineResult::Instruction(mut i) => {
+                if optimize {
+                    opt::optimize(&mut i, bits);
+                }
                 let e = if bits == 64 {
                     chk::check_ins64bit(&i)
                 } else {
                     chk::check_ins32bit(&i)
                 };
-                if let Err(mut e) = e {
-                    e.set_line(lnum + 1);
+                if let Err(mut e) =
+                    e.and_then(|_| tls::check_tls(&i, bits))
+                        .and_then(|_| match target {
+                            Some(t) if is_x32(t) => x32::check_x32(&i),
+                            _ => Ok(()),
+                        })
+                {
+                    src.locate(&mut e, lnum);
                     return Err(e);
                 }
+                // general-dynamic sequence is padded to length expected by linker
+                if let Some(l) = tlsgd.take() {
+                    if let Err(mut e) = tls::check_tlsgd_call(Some(&i)) {
+                        src.locate(&mut e, l);
+                        return Err(e);
+                    }
+                    obuf.extend(tls::TLSGD_CALL_PADDING);
+                } else if tls::is_tlsgd(&i) {
+                    tlsgd = Some(lnum);
+                    obuf.extend(tls::TLSGD_LEA_PADDING);
+                }
+                // `jmp`/`jcc` without relocation type can be relaxed
+                let shrink = branch_shrink(i.mnemonic).filter(|_| bits != 16);
+                let branch = match (shrink, i.symbol_mut(0)) {
+                    (Some(shrink), Some(s)) if s.reltype().is_none() && s.sub().is_none() => {
+                        if short.contains(&lnum) {
+                            s.set_reltype(RelType::REL8);
+                        }
+                        Some(Branch {
+                            lnum,
+                            rel: rels.len(),
+                            shrink,
+                        })
+                    }
+                    _ => None,
+                };
                 // i hate Rust's borrow checker sometimes tbh
                 let ins_ptr = std::ptr::from_mut(&mut i);
                 std::mem::forget(i);
-                let (res, mut rel_a) = comp::get_genapi(unsafe { &*ins_ptr }, bits).assemble(
-                    unsafe { &*ins_ptr },
-                    bits,
-                    RelType::REL32,
-                );
+                let mut api = comp::get_genapi(unsafe { &*ins_ptr }, bits);
+                if optimize {
+                    api = opt::genapi(unsafe { &*ins_ptr }, bits, api);
+                }
+                let ins = unsafe { &*ins_ptr };
+                if let Err(mut e) = api
+                    .check_relocs(ins, bits)
+                    .and_then(|_| cpu::check_cpu(ins, bits, api.uses_evex(ins), &isa))
+                {
+                    src.locate(&mut e, lnum);
+                    return Err(e);
+                }
+                let (res, mut rel_a) = api.assemble(unsafe { &*ins_ptr }, bits, RelType::REL32);
                 for r in rel_a.iter_mut() {
                     r.offset += obuf.len();
+                    r.shidx = sindex;
+                    r.line = lnum;
                 }
                 match res {
-                    AssembleResult::WLargeImm(d) => obuf.extend(d.into_iter()),
+                    AssembleResult::WLargeImm(d) => obuf.extend(d),
                     AssembleResult::NoLargeImm(d) => obuf.extend(d.iter()),
                 }
+                let base = rels.len();
+                if rel_a.len() == 1 {
+                    branches.extend(branch);
+                }
                 rels.extend(rel_a.into_iter());
+                // references to numeric labels are turned into references to their sections
+                for (idx, r) in rels.iter_mut().enumerate().skip(base) {
+                    for sub in [false, true] {
+                        let name = if sub { r.sub } else { Some(r.symbol) };
+                        let (n, forward) = match name.and_then(numeric_ref) {
+                            Some(n) => n,
+                            None => continue,
+                        };
+                        if forward {
+                            pending.push((idx, n, sub, lnum));
+                        } else if let Some(&(section, start, offset)) = numeric.get(&n) {
+                            r.retarget(sub, section, start, offset);
+                        } else {
+                            let mut e = PasmError::new(
+                                format!("there is no numeric label `{n}:` before `{n}b`"),
+                                8,
+                            );
+                            src.locate(&mut e, lnum);
+                            return Err(e);
+                        }
+                    }
+                }
                 unsafe {
                     std::ptr::drop_in_place(ins_ptr);
                 }
@@ -119,7 +370,8 @@ pub fn assemble(ipath: &Path, opath: &Path) -> Result<(), PasmError> {
                     }
                 }
                 current_section.size = obuf.len() - current_section.offset;
-                if sindex != 0 {
+                // implicit `.text` section is omitted if nothing was put into it
+                if declared || current_section.size != 0 {
                     symbols.push(Symbol {
                         name: current_section.name,
                         offset: current_section.offset,
@@ -129,8 +381,10 @@ pub fn assemble(ipath: &Path, opath: &Path) -> Result<(), PasmError> {
                         stype: SymbolType::Section,
                         valid: true,
                     });
-                    sindex += 1;
+                    sections.push(current_section);
+                    sindex = sections.len() as u16;
                 }
+                declared = true;
                 current_section = Section {
                     name: s,
                     size: 0,
@@ -141,6 +395,17 @@ pub fn assemble(ipath: &Path, opath: &Path) -> Result<(), PasmError> {
                 };
             }
 
+            LineResult::Label(l) if numeric_label(l).is_some() => {
+                let n = numeric_label(l).unwrap_or_default();
+                let label = (current_section.name, current_section.offset, obuf.len());
+                pending.retain(|&(idx, m, sub, _)| {
+                    if m == n {
+                        rels[idx].retarget(sub, label.0, label.1, label.2);
+                    }
+                    m != n
+                });
+                numeric.insert(n, label);
+            }
             LineResult::Label(l) => {
                 let mut found = None;
                 for (i, s) in symbols.iter().enumerate() {
@@ -158,6 +423,8 @@ pub fn assemble(ipath: &Path, opath: &Path) -> Result<(), PasmError> {
                 }
                 if let Some(i) = found {
                     symbols[i].valid = true;
+                    symbols[i].offset = obuf.len();
+                    symbols[i].sindex = sindex;
                     symbols[current_label].size = obuf.len() - symbols[current_label].offset;
                     current_label = i;
                 } else {
@@ -317,72 +584,477 @@ pub fn assemble(ipath: &Path, opath: &Path) -> Result<(), PasmError> {
                     }
                 }
             }
+            LineResult::Directive("optimize", o) => {
+                optimize = match o.trim() {
+                    "" | "on" => true,
+                    "off" => false,
+                    o => {
+                        let mut e = PasmError::new(
+                            format!("expected `on` or `off` after `optimize`, found `{o}`"),
+                            3,
+                        );
+                        src.locate(&mut e, lnum);
+                        return Err(e);
+                    }
+                };
+            }
+            LineResult::Directive("cpu", c) => {
+                if let Err(mut e) = isa.apply(c) {
+                    src.locate(&mut e, lnum);
+                    return Err(e);
+                }
+            }
             LineResult::Directive("nobits", _) => current_section.attributes.set_nobits(true),
             LineResult::Directive("writeable", _) => current_section.attributes.set_write(true),
+            LineResult::Directive("executable", _) => current_section.attributes.set_exec(true),
+            LineResult::Directive("tls", _) => current_section.attributes.set_tls(true),
             LineResult::Directive("align", c) => {
-                if let Ok(c) = c.parse::<u16>() {
-                    current_section.align = c;
+                // align N[, FILL]
+                let (n, fill) = match split_once_intelligent(c, ',') {
+                    Some((n, fill)) => (n.trim(), Some(fill.trim())),
+                    None => (c, None),
+                };
+                let n = match expr::eval(n) {
+                    Ok(n)
+                        if n.get_as_u64() <= u16::MAX as u64
+                            && n.get_as_u64().is_power_of_two() =>
+                    {
+                        n.get_as_u64() as usize
+                    }
+                    Ok(_) => {
+                        let mut e = PasmError::new(
+                            format!("alignment `{n}` has to be power of two and fit into 16 bits"),
+                            15,
+                        );
+                        src.locate(&mut e, lnum);
+                        return Err(e);
+                    }
+                    Err(mut e) => {
+                        src.locate(&mut e, lnum);
+                        return Err(e);
+                    }
+                };
+                let fill = match fill.map(expr::eval) {
+                    Some(Ok(f)) if f.get_as_u64() <= u8::MAX as u64 => Some(f.get_as_u64() as u8),
+                    Some(Ok(_)) => {
+                        let mut e = PasmError::new("alignment fill has to fit into byte", 15);
+                        src.locate(&mut e, lnum);
+                        return Err(e);
+                    }
+                    Some(Err(mut e)) => {
+                        src.locate(&mut e, lnum);
+                        return Err(e);
+                    }
+                    None => None,
+                };
+                current_section.align = current_section.align.max(n as u16);
+                // in `bin` target offsets are aligned from start of file, otherwise from start of section
+                let start = if target.unwrap_or("bin") == "bin" {
+                    0
+                } else {
+                    current_section.offset
+                };
+                let pad = (n - (obuf.len() - start) % n) % n;
+                match fill {
+                    Some(f) => obuf.resize(obuf.len() + pad, f),
+                    // implicit `.text` section is treated as executable
+                    None if current_section.attributes.exec() || !declared => {
+                        nops(&mut obuf, pad, bits)
+                    }
+                    None => obuf.resize(obuf.len() + pad, 0),
                 }
             }
             LineResult::Directive("alloc", _) => current_section.attributes.set_alloc(true),
             _ => {}
         }
     }
+    if let Some(l) = tlsgd {
+        let mut e = tls::check_tlsgd_call(None).unwrap_err();
+        src.locate(&mut e, l);
+        return Err(e);
+    }
+    if let Some(&(_, n, _, lnum)) = pending.first() {
+        let mut e = PasmError::new(format!("there is no numeric label `{n}:` after `{n}f`"), 8);
+        src.locate(&mut e, lnum);
+        return Err(e);
+    }
     current_section.size = obuf.len() - current_section.offset;
-    symbols.push(Symbol {
-        name: current_section.name,
-        offset: current_section.offset,
-        size: current_section.size,
-        sindex,
-        visibility: Visibility::Local,
-        stype: SymbolType::Section,
-        valid: true,
-    });
-    sections.push(current_section);
+    if declared || current_section.size != 0 || sections.is_empty() {
+        symbols.push(Symbol {
+            name: current_section.name,
+            offset: current_section.offset,
+            size: current_section.size,
+            sindex,
+            visibility: Visibility::Local,
+            stype: SymbolType::Section,
+            valid: true,
+        });
+        sections.push(current_section);
+    }
 
-    for s in &symbols {
-        if !s.valid {
-            return Err(PasmError::new(
-                format!(
-                    "you tried to use directive on invalid symbol named \"{}\"",
-                    s.name
-                ),
-                8,
-            ));
+    Ok(Pass {
+        obuf,
+        rels,
+        symbols,
+        sections,
+        target,
+        branches,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    // preprocesses `content` as if it was input file (without touching filesystem)
+    fn source(content: &str) -> Source {
+        Source::from_content(Path::new("test.asm"), content.as_bytes(), &[]).unwrap()
+    }
+    // assembles `content` like `bin` target: with relaxation, label differences and relocations
+    fn assemble_bin(content: &str) -> Vec<u8> {
+        let src = source(content);
+        let mut short = HashSet::new();
+        let mut long = HashSet::new();
+        let mut out = pass(&src, &short).unwrap();
+        while relax(&out, &mut short, &mut long) {
+            out = pass(&src, &short).unwrap();
         }
+        resolve_differences(&mut out.obuf, &mut out.rels, &out.symbols, true).unwrap();
+        relocate_addresses(&mut out.obuf, out.rels, &out.symbols).unwrap();
+        out.obuf
     }
-
-    match target.unwrap_or("bin") {
-        #[cfg(feature = "target_elf")]
-        "elf64" | "ELF64" => {
-            let elf = Elf::new(&sections, opath, &obuf, rels, &symbols, true)?;
-            obuf = elf.compile(true);
+    fn assemble_err(content: &str) -> PasmError {
+        pass(&source(content), &HashSet::new()).err().unwrap()
+    }
+    #[test]
+    fn tlocate_0() {
+        // relocation errors on first line of source have location too
+        let src = source("jmp @[l, rel8]\nalign 256\nl:\n");
+        let mut out = pass(&src, &HashSet::new()).unwrap();
+        let mut e = relocate_addresses(&mut out.obuf, out.rels, &out.symbols).unwrap_err();
+        assert_eq!(e.get_line(), Some(0));
+        src.locate(&mut e, 0);
+        assert_eq!(e.get_line(), Some(1));
+    }
+    #[test]
+    fn trelax_0() {
+        // backward and forward branches in range are shrinked
+        let bin = assemble_bin("bits 64\nl0:\nnop\njne @[l1]\njmp @[l0]\nl1:\njmp @[l1, rel32]\n");
+        assert_eq!(
+            bin,
+            [0x90, 0x75, 0x02, 0xEB, 0xFB, 0xE9, 0xFB, 0xFF, 0xFF, 0xFF]
+        );
+        // first jump fits into rel8 only after second one is shrinked
+        let bin = assemble_bin(
+            "bits 64\njmp @[l1]\ntimes 124 nop\njmp @[l2]\nl1:\nnop\nl2:\njmp @[l3]\ntimes 200 nop\nl3:\n",
+        );
+        assert_eq!(&bin[..2], &[0xEB, 126]);
+        assert_eq!(&bin[126..128], &[0xEB, 1]);
+        // target is too far
+        assert_eq!(&bin[129..134], &[0xE9, 200, 0, 0, 0]);
+    }
+    #[test]
+    fn talign_0() {
+        // padding grows, when jump before it is shrinked
+        let bin = assemble_bin("bits 64\njmp @[l]\nalign 16\nl:\nalign 4, 0xCC\n");
+        assert_eq!(bin.len(), 16);
+        assert_eq!(&bin[..2], &[0xEB, 14]);
+        assert_eq!(&bin[2..4], &[0x66, 0x0F]);
+        let bin =
+            assemble_bin("bits 64\nsection .data\nbytele 1\nalign 4\nbytele 2\nalign 2, 0xFF\n");
+        assert_eq!(bin, [1, 0, 0, 0, 2, 0xFF]);
+    }
+    #[test]
+    fn tpseudo_pfx_0() {
+        let bin = assemble_bin(
+            "bits 64\nmov eax, dword [rbx + 8], {disp32}\nmov eax, dword [rbx], {disp8}\nmov eax, dword [rbp]\n",
+        );
+        assert_eq!(bin, [0x8B, 0x83, 8, 0, 0, 0, 0x8B, 0x43, 0, 0x8B, 0x45, 0]);
+        let bin = assemble_bin(
+            "bits 64\nadd rax, rcx\nadd rax, rcx, {load}\ncmp al, cl, {store}\nmov rax, rcx, {load}\n",
+        );
+        assert_eq!(
+            bin,
+            [0x48, 0x01, 0xC8, 0x48, 0x03, 0xC1, 0x38, 0xC8, 0x48, 0x8B, 0xC1]
+        );
+        let bin = assemble_bin("bits 64\nvaddps xmm0, xmm1, xmm2, {vex3}\n");
+        assert_eq!(bin, [0xC4, 0xE1, 0x70, 0x58, 0xC2]);
+    }
+    #[test]
+    fn topt_0() {
+        let src =
+            "bits 64\nadd ecx, -1\nadd eax, 1000\nmov rax, 1\nmov rax, -1\nimul eax, ecx, 3\n";
+        let bin = assemble_bin(&format!("optimize\n{src}"));
+        assert_eq!(
+            bin,
+            [
+                0x83, 0xC1, 0xFF, 0x05, 0xE8, 0x03, 0x00, 0x00, 0xB8, 1, 0, 0, 0, 0x48, 0xC7, 0xC0,
+                0xFF, 0xFF, 0xFF, 0xFF, 0x6B, 0xC1, 3
+            ]
+        );
+        // output does not depend on number of passes
+        assert_eq!(bin, assemble_bin(&format!("optimize\n{src}")));
+        let bin = assemble_bin(
+            "bits 64\noptimize\npush -1\nvmovaps xmm0, xmm8\nvaddps ymm0, ymm1, ymm9\naadd r17, rcx\nainc rax\noptimize off\nvmovaps xmm0, xmm8\n",
+        );
+        assert_eq!(
+            bin,
+            [
+                0x6A, 0xFF, 0xC5, 0x78, 0x29, 0xC0, 0xC5, 0xB4, 0x58, 0xC1, 0xD5, 0x48, 0x03, 0xC9,
+                0x48, 0xFF, 0xC0, 0xC4, 0xC1, 0x78, 0x28, 0xC0
+            ]
+        );
+    }
+    #[test]
+    fn tcpu_0() {
+        let bin = assemble_bin(
+            "bits 64\ncpu x86-64-v2\npopcnt eax, ecx\ncpu +avx2\nvpaddb ymm0, ymm1, ymm2\n",
+        );
+        assert_eq!(bin, [0xF3, 0x0F, 0xB8, 0xC1, 0xC5, 0xF5, 0xFC, 0xC2]);
+        let e = PasmError::new("", 19);
+        for src in [
+            "bits 64\ncpu x86-64-v2\nvpaddb ymm0, ymm1, ymm2\n",
+            "bits 64\ncpu x86-64-v3\nvaddps zmm0, zmm1, zmm2\n",
+            "bits 64\ncpu x86-64-v4\nadd r16, rax\n",
+            "bits 64\ncpu x86-64-v4,-avx512bw\nkmovd k1, k2\n",
+            "bits 64\ncpu +avx1024\n",
+        ] {
+            assert_eq!(assemble_err(src), e);
         }
-        #[cfg(feature = "target_elf")]
-        "elf32" | "ELF32" => {
-            let elf = Elf::new(&sections, opath, &obuf, rels, &symbols, false)?;
-            obuf = elf.compile(false);
+    }
+    #[test]
+    fn tcpu_1() {
+        // extensions are chosen by prefix, that instruction is encoded with
+        for (src, bin) in [
+            (
+                "bits 64\ncpu x86-64-v4,+avx512bf16\nvcvtne2ps2bf16 xmm0, xmm1, xmm2\n",
+                &[0x62, 0xF2, 0x77, 0x08, 0x72, 0xC2][..],
+            ),
+            (
+                "bits 64\ncpu x86-64-v4,+avx512bf16\nvdpbf16ps xmm0, xmm1, xmm2\n",
+                &[0x62, 0xF2, 0x76, 0x08, 0x52, 0xC2],
+            ),
+            (
+                "bits 64\ncpu x86-64-v4,+avx512ifma\nvpmadd52luq xmm0, xmm1, xmm2\n",
+                &[0x62, 0xF2, 0xF5, 0x08, 0xB4, 0xC2],
+            ),
+            (
+                "bits 64\ncpu x86-64-v3,+avxneconvert\nvcvtneps2bf16 xmm0, xmm1\n",
+                &[0xC4, 0xE2, 0x7A, 0x72, 0xC1],
+            ),
+            (
+                "bits 64\ncpu x86-64-v3,+avxvnni\nvpdpbusd xmm0, xmm1, xmm2\n",
+                &[0xC4, 0xE2, 0x71, 0x50, 0xC2],
+            ),
+        ] {
+            assert_eq!(assemble_bin(src), bin);
         }
-        "bin" => {
-            relocate_addresses(&mut obuf, rels, &symbols)?;
+        let e = PasmError::new("", 19);
+        for src in [
+            "bits 64\ncpu x86-64-v4,+avxneconvert\nvcvtne2ps2bf16 xmm0, xmm1, xmm2\n",
+            "bits 64\ncpu x86-64-v4,+avxneconvert\nvdpbf16ps xmm0, xmm1, xmm2\n",
+            "bits 64\ncpu x86-64-v4,+avxifma\nvpmadd52luq xmm0, xmm1, xmm2\n",
+            "bits 64\ncpu x86-64-v4,+avxvnni\nvpdpbusd xmm16, xmm1, xmm2\n",
+            "bits 64\ncpu x86-64-v4\npbndkb\n",
+        ] {
+            assert_eq!(assemble_err(src), e);
         }
-        t => return Err(PasmError::new(format!("unknown target {t}"), 7)),
     }
-
-    // now write content to a file
-    let ofile = OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(opath);
-    let mut ofile = match ofile {
-        Ok(f) => f,
-        Err(e) => {
-            return Err(PasmError::new(e.to_string(), 6));
+    #[test]
+    fn tpic_0() {
+        let src = source(
+            "bits 64\nextern f\nmov rax, qword @[f, gotpcrel]\nmov eax, dword @[f, gotpcrel]\n\
+            lea rax, qword @[f, gotpcrel]\ncall @[f, plt32]\nmov qword @[f, gotpcrel], rax\n\
+            add qword @[f, gotpcrel], rax\n",
+        );
+        let out = pass(&src, &HashSet::new()).unwrap();
+        assert_eq!(&out.obuf[..3], &[0x48, 0x8B, 0x05]);
+        assert_eq!(&out.obuf[7..9], &[0x8B, 0x05]);
+        let types: Vec<RelType> = out.rels.iter().map(|r| r.reltype).collect();
+        assert_eq!(
+            types,
+            [
+                RelType::REX_GOTPCRELX,
+                RelType::GOTPCRELX,
+                RelType::GOTPCREL,
+                RelType::PLT32,
+                // stores cannot be relaxed
+                RelType::GOTPCREL,
+                RelType::GOTPCREL
+            ]
+        );
+        assert!(out.rels.iter().all(|r| r.addend == -4));
+    }
+    #[test]
+    fn tlea_0() {
+        let bin = assemble_bin(
+            "bits 64\nlea rax, qword [rbx]\nlea r9, qword [rbx]\nlea r9d, dword [r10]\n",
+        );
+        assert_eq!(bin, [0x48, 0x8D, 0x03, 0x4C, 0x8D, 0x0B, 0x45, 0x8D, 0x0A]);
+    }
+    #[test]
+    fn ttls_0() {
+        let src = source(
+            "bits 64\nextern e\nmov rax, @[v, tpoff32]\nmov rcx, qword @[e, gottpoff]\n\
+            lea rdi, qword @[e, tlsgd]\ncall @[__tls_get_addr, plt32]\nlea rdi, qword @[v, tlsld]\n\
+            add rax, @[v, dtpoff32]\nsection .tdata\ntls\nv:\ndwordle 1\n",
+        );
+        let out = pass(&src, &HashSet::new()).unwrap();
+        assert_eq!(&out.obuf[..3], &[0x48, 0xC7, 0xC0]);
+        assert_eq!(&out.obuf[7..10], &[0x48, 0x8B, 0x0D]);
+        // general-dynamic sequence is padded
+        assert_eq!(&out.obuf[14..18], &[0x66, 0x48, 0x8D, 0x3D]);
+        assert_eq!(&out.obuf[22..26], &[0x66, 0x66, 0x48, 0xE8]);
+        assert_eq!(&out.obuf[30..33], &[0x48, 0x8D, 0x3D]);
+        let rels: Vec<(RelType, i32)> = out.rels.iter().map(|r| (r.reltype, r.addend)).collect();
+        assert_eq!(
+            rels,
+            [
+                (RelType::TPOFF32, 0),
+                (RelType::GOTTPOFF, -4),
+                (RelType::TLSGD, -4),
+                (RelType::PLT32, -4),
+                (RelType::TLSLD, -4),
+                (RelType::DTPOFF32, 0)
+            ]
+        );
+        assert!(out.sections[1].attributes.tls());
+        let e = PasmError::new("", 20);
+        for src in [
+            "bits 64\nlea rax, qword @[v, tlsgd]\nv:\n",
+            "bits 64\nmov eax, dword @[v, gottpoff]\nv:\n",
+            "bits 64\nmov eax, dword @[v, tpoff32]\nv:\n",
+            "bits 32\nmov eax, @[v, tpoff32]\nv:\n",
+            "bits 64\nlea rdi, qword @[v, tlsgd]\nv:\n",
+            "bits 64\nlea rdi, qword @[v, tlsgd]\ncall @[v]\nv:\n",
+            "bits 64\nlea rdi, qword @[v, tlsgd]\nalign 8\ncall @[__tls_get_addr, plt32]\nv:\n",
+        ] {
+            assert_eq!(assemble_err(src), e);
+        }
+    }
+    #[test]
+    fn tabs_0() {
+        // symbols in data directives are absolute, `abs64` in `mov` selects `movabs`
+        let bin = assemble_bin(
+            "bits 64\nqwordle @[e]\nwordle @[e, 1]\nbytele @[e]\nmov rax, @[e, abs64]\n\
+            qwordle @[e, rel64]\ne:\n",
+        );
+        assert_eq!(
+            bin,
+            [
+                0x1D, 0, 0, 0, 0, 0, 0, 0, 0x1E, 0, 0x1D, 0x48, 0xB8, 0x1D, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0
+            ]
+        );
+    }
+    #[test]
+    fn tabs_1() {
+        // relocation type has to match field, that it is written into
+        let e = PasmError::new("", 20);
+        for ins in [
+            "dwordle @[x, abs64]",
+            "add rax, @[x, abs64]",
+            "push @[x, abs64]",
+            "mov qword [rax], @[x, abs64]",
+            "call @[x, abs64]",
+            "mov eax, @[x, abs64]",
+            "mov ax, @[x, abs8]",
+            "mov eax, dword @[x, rel8]",
+        ] {
+            assert_eq!(assemble_err(&format!("bits 64\n{ins}\nx:\n")), e);
+        }
+    }
+    #[test]
+    fn talign_2() {
+        // 16-bit padding decodes as NOPs with 16-bit ModRM
+        let bin = assemble_bin("bits 16\nnop\nalign 8\n");
+        assert_eq!(bin, [0x90, 0x66, 0x0F, 0x1F, 0x80, 0x00, 0x00, 0x90]);
+    }
+    #[test]
+    fn tdiff_0() {
+        // difference fills only its field
+        let bin = assemble_bin(
+            "bits 64\ns:\nwordle @[e] - @[s]\nwordle 0xBEEF\nbytele @[e] - @[s]\nbytele 0x11\ne:\n\
+            wordle @[s] - @[e]\nbytele @[e] - @[s]\n",
+        );
+        assert_eq!(bin, [0x06, 0x00, 0xEF, 0xBE, 0x06, 0x11, 0xFA, 0xFF, 0x06]);
+    }
+    #[test]
+    fn tdisp_0() {
+        // 32-bit addressing accepts unsigned displacement, 64-bit one only signed
+        let bin = assemble_bin(
+            "bits 32\nmov eax, dword [0xFEE00000]\nmov eax, dword [ebx + 0xFFFF_FFFC]\n",
+        );
+        assert_eq!(bin, [0x8B, 0x05, 0x00, 0x00, 0xE0, 0xFE, 0x8B, 0x43, 0xFC]);
+        let bin = assemble_bin("bits 64\nmov eax, dword [ebx + 0x8000_0000]\n");
+        assert_eq!(bin, [0x67, 0x8B, 0x83, 0x00, 0x00, 0x00, 0x80]);
+        for ins in [
+            "mov eax, dword [0xFEE00000]",
+            "mov eax, dword [rax + 0x8000_0000]",
+        ] {
+            assert_eq!(
+                assemble_err(&format!("bits 64\n{ins}\n")),
+                PasmError::new("", 11)
+            );
+        }
+    }
+    #[test]
+    fn tdefine_0() {
+        // `define` expression keeps its precedence in immediates and displacements
+        let bin =
+            assemble_bin("bits 64\ndefine X 1 + 2\nmov rax, X * 3\nmov rcx, qword [rax + X * 2]\n");
+        assert_eq!(bin, [0xB8, 0x09, 0x00, 0x00, 0x00, 0x48, 0x8B, 0x48, 0x06]);
+    }
+    #[test]
+    fn tx32_0() {
+        let src = source("bits 64\nmov eax, dword @[v]\nv:\nqwordle @[v]\n");
+        let out = pass(&src, &HashSet::new()).unwrap();
+        let kind = ElfKind::X32;
+        let bin = Elf::new(
+            &out.sections,
+            Path::new("x32.o"),
+            &out.obuf,
+            out.rels,
+            &out.symbols,
+            kind,
+        )
+        .unwrap()
+        .compile(kind);
+        // ELFCLASS32, EM_X86_64
+        assert_eq!(bin[4], 1);
+        assert_eq!(&bin[18..20], &[62, 0]);
+        // Elf32_Rela entries: offset, info (symbol << 8 | R_X86_64_PC32/R_X86_64_64), addend
+        let rela = [2, 0, 0, 0, 0x02, 2, 0, 0, 0xFC, 0xFF, 0xFF, 0xFF];
+        assert!(bin.windows(12).any(|w| w == rela));
+        let rela = [6, 0, 0, 0, 0x01, 2, 0, 0, 0, 0, 0, 0];
+        assert!(bin.ends_with(&rela));
+        // 32-bit relocations are accepted in data and instructions, 64-bit ones in neither
+        let src = source(
+            "target elfx32\nbits 64\nmov rsi, @[v, abs32u]\nmov rsi, @[v, abs32]\nv:\n\
+            dwordle @[v]\ndwordle @[v, abs32]\nqwordle @[v] - @[w]\nw:\n",
+        );
+        let out = pass(&src, &HashSet::new()).unwrap();
+        let rels: Vec<RelType> = out.rels.iter().map(|r| r.reltype).collect();
+        assert_eq!(
+            &rels[..4],
+            [
+                RelType::ABS32U,
+                RelType::ABS32,
+                RelType::ABS32U,
+                RelType::ABS32
+            ]
+        );
+        let e = PasmError::new("", 20);
+        for ins in [
+            "mov rsi, @[v, abs64]",
+            "qwordle @[v, abs64]",
+            "qwordle @[v]",
+            "mov rsi, @[v, rel64]",
+            "qwordle @[v, rel64]",
+        ] {
+            assert_eq!(
+                assemble_err(&format!("target elfx32\nbits 64\n{ins}\nv:\n")),
+                e
+            );
         }
-    };
-    if let Err(err) = ofile.write_all(&obuf) {
-        return Err(PasmError::new(err.to_string(), 6));
     }
-    Ok(())
 }
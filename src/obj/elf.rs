@@ -22,9 +22,12 @@ const SHT_SYMTAB: u32 = 2;
 const SHT_STRTAB: u32 = 3;
 const SHT_RELA: u32 = 4;
 const SHT_NOBITS: u32 = 8;
+const SHT_REL: u32 = 9;
 
 const RELA_SIZE_64: usize = 24;
 const RELA_SIZE_32: usize = 12;
+// elf32 uses relocations with implicit addends (stored in relocated field)
+const REL_SIZE_32: usize = 8;
 
 const SYM_SIZE_64: usize = 24;
 const SYM_SIZE_32: usize = 16;
@@ -39,6 +42,7 @@ const SHDR_SIZE_32: usize = 40;
 const SHF_WRITE: u32 = 0x01;
 const SHF_ALLOC: u32 = 0x02;
 const SHF_EXECINSTR: u32 = 0x04;
+const SHF_TLS: u32 = 0x400;
 
 const EM_I386: u8 = 3;
 const EM_X86_64: u8 = 62;
@@ -124,6 +128,35 @@ fn mk_ident(is_64bit: bool, is_le: bool) -> [u8; 16] {
 
 type Sections<'a> = &'a [Section<'a>];
 
+/// class and machine of ELF file (they are independent in x32 ABI)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ElfKind {
+    // ELFCLASS64, EM_X86_64, RELA
+    Elf64,
+    // ELFCLASS32, EM_386, REL
+    Elf32,
+    // ELFCLASS32, EM_X86_64, RELA
+    X32,
+}
+
+impl ElfKind {
+    /// returns true for ELFCLASS64
+    pub const fn is_64bit(&self) -> bool {
+        matches!(self, Self::Elf64)
+    }
+    /// returns true if file uses EM_X86_64 (and `R_X86_64_*` relocations with explicit addends)
+    pub const fn is_x86_64(&self) -> bool {
+        !matches!(self, Self::Elf32)
+    }
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Elf64 => "elf64",
+            Self::Elf32 => "elf32",
+            Self::X32 => "elfx32",
+        }
+    }
+}
+
 impl<'a> Elf<'a> {
     pub fn new(
         sections: Sections<'a>,
@@ -131,12 +164,12 @@ impl<'a> Elf<'a> {
         code: &'a [u8],
         relocs: Vec<Relocation>,
         symbols: &'a [Symbol],
-        is_64bit: bool,
+        kind: ElfKind,
     ) -> Result<Self, Error> {
-        make_elf(sections, opath, code, relocs, symbols, is_64bit)
+        make_elf(sections, opath, code, relocs, symbols, kind)
     }
-    pub fn compile(self, is_64bit: bool) -> Vec<u8> {
-        compile(self, is_64bit)
+    pub fn compile(self, kind: ElfKind) -> Vec<u8> {
+        compile(self, kind)
     }
     fn get_local_symbol_count(&self) -> usize {
         self.symbols.len() - self.get_global_count()
@@ -151,12 +184,12 @@ impl<'a> Elf<'a> {
         }
         None
     }
-    fn push_reloc(&mut self, reloc: &TmpRelocation, is_64bit: bool) {
+    fn push_reloc(&mut self, reloc: &TmpRelocation, kind: ElfKind) {
         let symb = reloc.symbol as u64;
         self.relocations.push(ElfRelocation {
             offset: reloc.offset as u64,
             info: symb << 32
-                | if is_64bit {
+                | if kind.is_x86_64() {
                     (reloc.reltype.to_elf64_rtype() & 0xFF) as u32
                 } else {
                     reloc.reltype.to_elf32_rtype() & 0xFF
@@ -181,13 +214,19 @@ impl<'a> Elf<'a> {
     }
     fn push_symbol(&mut self, symbol: &Symbol) {
         let name = self.push_strtab(symbol.name);
+        let is_extern = symbol.visibility == Visibility::Extern;
         self.symbols.push(ElfSymbol {
             name,
             value: symbol.offset,
             size: symbol.size,
-            section_index: symbol.sindex as u32 + 4,
+            // extern symbols are undefined (SHN_UNDEF)
+            section_index: if is_extern {
+                0
+            } else {
+                symbol.sindex as u32 + 4
+            },
             info: (match symbol.visibility {
-                Visibility::Public => 1,
+                Visibility::Public | Visibility::Extern => 1,
                 Visibility::Local => 0,
                 Visibility::Weak => 2,
                 _ => 0,
@@ -242,7 +281,7 @@ fn make_elf<'a>(
     code: &'a [u8],
     relocs: Vec<Relocation>,
     symbols: &'a [Symbol],
-    is_64bit: bool,
+    kind: ElfKind,
 ) -> Result<Elf<'a>, Error> {
     let mut elf = Elf::default();
     elf.shstrtab.push(0);
@@ -292,7 +331,8 @@ fn make_elf<'a>(
                 } else {
                     0
                 };
-                w + a + e
+                let t = if section.attributes.tls() { SHF_TLS } else { 0 };
+                w + a + e + t
             },
             entry_count: 0,
 
@@ -304,8 +344,51 @@ fn make_elf<'a>(
         });
     }
     elf.code = code;
-    elf.push_symbols(symbols);
-    for reloc in relocs {
+    // offsets of symbols and relocations are relative to start of code,
+    // but in ELF they have to be relative to start of their section
+    let symbols: Vec<Symbol> = symbols
+        .iter()
+        .map(|s| {
+            let mut s = s.clone();
+            if s.visibility != Visibility::Extern {
+                if let Some(sc) = sections.get(s.sindex as usize) {
+                    s.offset -= sc.offset;
+                    // symbols defined in TLS sections have to be STT_TLS
+                    if sc.attributes.tls() && s.stype != SymbolType::Section {
+                        s.stype = SymbolType::Tls;
+                    }
+                }
+            } else if relocs
+                .iter()
+                .any(|r| r.symbol == s.name && r.reltype.is_tls())
+            {
+                s.stype = SymbolType::Tls;
+            }
+            s
+        })
+        .collect();
+    elf.push_symbols(&symbols);
+    for mut reloc in relocs {
+        if let Some(sc) = sections.get(reloc.shidx as usize) {
+            reloc.offset -= sc.offset;
+        }
+        let rtype = if kind.is_x86_64() {
+            reloc.reltype.to_elf64_rtype()
+        } else {
+            reloc.reltype.to_elf32_rtype() as u64
+        };
+        if rtype == 0 {
+            return Err(Error::new_wline(
+                format!(
+                    "relocation {} against symbol \"{}\" is not supported in {} target",
+                    reloc.reltype.name(),
+                    reloc.symbol,
+                    kind.name()
+                ),
+                20,
+                reloc.line,
+            ));
+        }
         if let Some(idx) = elf.find_symbol(reloc.symbol) {
             elf.push_reloc(
                 &TmpRelocation {
@@ -315,12 +398,13 @@ fn make_elf<'a>(
                     reltype: reloc.reltype,
                     sindex: reloc.shidx,
                 },
-                is_64bit,
+                kind,
             );
         } else {
-            return Err(Error::new(
+            return Err(Error::new_wline(
                 format!("usage of undefined symbol \"{}\"", reloc.symbol),
                 4,
+                reloc.line,
             ));
         }
     }
@@ -389,9 +473,9 @@ fn ehdr_collect(e: ElfHeader, is_64bit: bool) -> Vec<u8> {
     b
 }
 
-fn reloc_collect(rel: ElfRelocation, is_64bit: bool) -> Vec<u8> {
-    let mut b = Vec::with_capacity(RELA_SIZE_32);
-    if is_64bit {
+fn reloc_collect(rel: ElfRelocation, kind: ElfKind) -> Vec<u8> {
+    let mut b = Vec::with_capacity(RELA_SIZE_64);
+    if kind.is_64bit() {
         b.extend(rel.offset.to_le_bytes());
         b.extend(rel.info.to_le_bytes());
         b.extend(rel.addend.to_le_bytes());
@@ -400,7 +484,11 @@ fn reloc_collect(rel: ElfRelocation, is_64bit: bool) -> Vec<u8> {
         let rel_info_high = rel.info & 0xFFFF_FFFF_0000_0000;
         let rel_info_new = (rel_info_high >> 24) as u32 | rel.info as u32;
         b.extend(rel_info_new.to_le_bytes());
-        b.extend((rel.addend.try_into().unwrap_or(0i32)).to_le_bytes());
+        if kind.is_x86_64() {
+            b.extend((rel.addend as i32).to_le_bytes());
+        } else {
+            // Elf32_Rel: addend is already in code
+        }
     }
     b
 }
@@ -433,13 +521,13 @@ fn sym_collect(symb: ElfSymbol, is_64bit: bool) -> Vec<u8> {
 //      - .symtab
 //      - other sections:
 //          - x
-//          - .rela.x
+//          - .rela.x (.rel.x in elf32)
 // - Content:
 //      - .shstrtab
 //      - .strtab
 //      - .symtab
 //      - code
-//      - .rela.*
+//      - .rela.* (.rel.* in elf32)
 //
 const NULL_SHDR: ElfSection = ElfSection {
     name: 0,
@@ -453,8 +541,13 @@ const NULL_SHDR: ElfSection = ElfSection {
     size: 0,
     entry_size: 0,
 };
-fn compile(mut elf: Elf, is_64bit: bool) -> Vec<u8> {
-    let rela_size = if is_64bit { RELA_SIZE_64 } else { RELA_SIZE_32 };
+fn compile(mut elf: Elf, kind: ElfKind) -> Vec<u8> {
+    let is_64bit = kind.is_64bit();
+    let (rela_size, rela_type, rela_prefix) = match kind {
+        ElfKind::Elf64 => (RELA_SIZE_64, SHT_RELA, ".rela"),
+        ElfKind::X32 => (RELA_SIZE_32, SHT_RELA, ".rela"),
+        ElfKind::Elf32 => (REL_SIZE_32, SHT_REL, ".rel"),
+    };
     let sym_size = if is_64bit { SYM_SIZE_64 } else { SYM_SIZE_32 };
     let shdr_size = if is_64bit { SHDR_SIZE_64 } else { SHDR_SIZE_32 };
     let ehdr_size = if is_64bit { EHDR_SIZE_64 } else { EHDR_SIZE_32 };
@@ -466,31 +559,22 @@ fn compile(mut elf: Elf, is_64bit: bool) -> Vec<u8> {
     // we add .shstrtab, .strtab, .symtab and NULL section
     elf.header.section_count += elf.sections.len() + 4;
     elf.header.shstrtab_index = 1;
-    elf.header.machine = if is_64bit { EM_X86_64 } else { EM_I386 };
+    elf.header.machine = if kind.is_x86_64() { EM_X86_64 } else { EM_I386 };
     elf.header.section_offset = ehdr_size;
 
-    let mut rela_info: Vec<RelInfo> = Vec::new();
-    let mut rela_shidx = 0;
-    let mut rela_count = 0;
-    if !elf.relocations.is_empty() {
-        for reloc in &elf.relocations {
-            if reloc.sindex == rela_shidx {
-                rela_count += 1;
-            } else {
-                rela_info.push(RelInfo {
-                    name: 0,
-                    relcount: rela_count,
-                });
-                rela_count = 1;
-                rela_shidx += 1;
-            }
-        }
-    }
-    if rela_count != 0 {
-        rela_info.push(RelInfo {
+    // relocations are grouped by section, which they are applied to
+    elf.relocations.sort_by_key(|r| r.sindex);
+    let mut rela_info = vec![
+        RelInfo {
             name: 0,
-            relcount: rela_count,
-        });
+            relcount: 0
+        };
+        elf.sections.len()
+    ];
+    for reloc in &elf.relocations {
+        if let Some(r) = rela_info.get_mut(reloc.sindex as usize) {
+            r.relcount += 1;
+        }
     }
 
     for r in &rela_info {
@@ -507,7 +591,7 @@ fn compile(mut elf: Elf, is_64bit: bool) -> Vec<u8> {
 
     for idx in 0..rela_info.len() {
         if rela_info[idx].relcount != 0 {
-            let cstr = format!(".rela{}", unsafe {
+            let cstr = format!("{rela_prefix}{}", unsafe {
                 utils::cstring(elf.shstrtab.as_ptr().add(elf.sections[idx].name))
             });
             rela_info[idx].name = elf.push_shstrtab(&cstr);
@@ -583,7 +667,7 @@ fn compile(mut elf: Elf, is_64bit: bool) -> Vec<u8> {
                 bytes.extend(shdr_collect(
                     ElfSection {
                         name: relc.name,
-                        stype: SHT_RELA,
+                        stype: rela_type,
                         info: 4 + idx as u32,
                         link: 3,
                         size: relc.relcount * rela_size,
@@ -609,7 +693,7 @@ fn compile(mut elf: Elf, is_64bit: bool) -> Vec<u8> {
     bytes.extend(elf.code);
 
     for rel in elf.relocations {
-        bytes.extend(reloc_collect(rel, is_64bit));
+        bytes.extend(reloc_collect(rel, kind));
     }
     bytes
 }
@@ -270,6 +270,14 @@ pub fn check_ins32bit(ins: &Instruction) -> Result<(), Error> {
             &[],
         ),
         BSWAP => ot_chk(ins, &[(&[R32], Optional::Needed)], &[], &[]),
+        BEXTR if matches!(ins.ssrc(), Some(Operand::Imm(_))) => {
+            use chkn::*;
+            CheckAPI::<3>::new()
+                .push(&[R32], true)
+                .push(&[R32, M32], true)
+                .push(&[I8, I16, I32], true)
+                .check(ins)
+        }
         ANDN | BZHI | BEXTR => ot_chk(
             ins,
             &[
@@ -280,6 +288,13 @@ pub fn check_ins32bit(ins: &Instruction) -> Result<(), Error> {
             &[],
             &[],
         ),
+        BLCFILL | BLCI | BLCIC | BLCMSK | BLCS | BLSFILL | BLSIC | T1MSKC | TZMSK => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[R32], true)
+                .push(&[R32, M32], true)
+                .check(ins)
+        }
         ARPL => ot_chk(
             ins,
             &[(&[R16, M16], Optional::Needed), (&[R16], Optional::Needed)],
@@ -416,7 +431,31 @@ pub fn check_ins32bit(ins: &Instruction) -> Result<(), Error> {
             &[(M32, M32), (R32, R32), (MMX, MMX), (XMM, MMX), (MMX, XMM)],
             &[],
         ),
-        Mnemonic::MOVQ | Mnemonic::PUSHAQ | MOVSTRQ | SCASQ | STOSQ | Mnemonic::POPAQ | INCSSPQ => {
+        Mnemonic::MOVQ
+        | Mnemonic::PUSHAQ
+        | MOVSTRQ
+        | SCASQ
+        | STOSQ
+        | Mnemonic::POPAQ
+        | INCSSPQ
+        | LDTILECFG
+        | STTILECFG
+        | TILELOADD
+        | TILELOADDT1
+        | TILESTORED
+        | TILEZERO
+        | TILERELEASE
+        | TDPBSSD
+        | TDPBSUD
+        | TDPBUSD
+        | TDPBUUD
+        | TDPBF16PS
+        | TDPFP16PS
+        | ALDTILECFG
+        | ASTTILECFG
+        | ATILELOADD
+        | ATILELOADDT1
+        | ATILESTORED => {
             let er = Error::new(
                 "you tried to use instruction that is invalid when bits != 64",
                 10,
@@ -445,6 +484,18 @@ pub fn check_ins32bit(ins: &Instruction) -> Result<(), Error> {
 
 pub fn check_ins64bit(ins: &Instruction) -> Result<(), Error> {
     use Mnemonic::*;
+    // 64-bit addressing sign-extends displacement, so it cannot wrap like in 32-bit one
+    if let Some(m) = ins.get_mem() {
+        if m.is_u32_offset() && m.addrsize() != Size::Dword {
+            return Err(Error::new(
+                format!(
+                    "displacement {:#X} does not fit into signed 32 bits, that are allowed in 64-bit addressing",
+                    m.offset().unwrap_or(0) as u32
+                ),
+                11,
+            ));
+        }
+    }
     match ins.mnemonic {
         LCALL | LJMP => {
             use chkn::*;
@@ -1064,6 +1115,15 @@ pub fn check_ins64bit(ins: &Instruction) -> Result<(), Error> {
             &[],
         ),
         BSWAP => ot_chk(ins, &[(&[R32, R64], Optional::Needed)], &[], &[]),
+        BEXTR if matches!(ins.ssrc(), Some(Operand::Imm(_))) => {
+            use chkn::*;
+            CheckAPI::<3>::new()
+                .push(&[R32, R64], true)
+                .push(&[R32, R64, M32, M64], true)
+                .push(&[I8, I16, I32], true)
+                .set_mode(CheckMode::X86)
+                .check(ins)
+        }
         ANDN | BZHI | BEXTR => ot_chk(
             ins,
             &[
@@ -1074,6 +1134,14 @@ pub fn check_ins64bit(ins: &Instruction) -> Result<(), Error> {
             &[],
             &[],
         ),
+        BLCFILL | BLCI | BLCIC | BLCMSK | BLCS | BLSFILL | BLSIC | T1MSKC | TZMSK => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[R32, R64], true)
+                .push(&[R32, R64, M32, M64], true)
+                .set_mode(CheckMode::X86)
+                .check(ins)
+        }
         // part c
         CMPSTRB | CMPSTRW | CMPSTRD | CMPSTRQ | SCASB | SCASW | SCASD | SCASQ => {
             ot_chk(ins, &[], &[], &[REPE, REPZ, REPNE, REPNZ])
@@ -1206,6 +1274,80 @@ pub fn check_ins64bit(ins: &Instruction) -> Result<(), Error> {
             use chkn::*;
             CheckAPI::<1>::new().push(&[R64], true).check(ins)
         }
+
+        // AMX
+        LDTILECFG | STTILECFG => {
+            use chkn::*;
+            CheckAPI::<1>::new().push(&[M512], true).check(ins)
+        }
+        ALDTILECFG | ASTTILECFG => {
+            use chkn::*;
+            CheckAPI::<1>::new()
+                .push(&[M512], true)
+                .apx(APXVariant::VexExtension, false)
+                .check(ins)
+        }
+        TILELOADD | TILELOADDT1 => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[TMM], true)
+                .push(&[MA], true)
+                .check(ins)?;
+            sibmem_chk(ins, 1)
+        }
+        ATILELOADD | ATILELOADDT1 => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[TMM], true)
+                .push(&[MA], true)
+                .apx(APXVariant::VexExtension, false)
+                .check(ins)?;
+            sibmem_chk(ins, 1)
+        }
+        TILESTORED => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[MA], true)
+                .push(&[TMM], true)
+                .check(ins)?;
+            sibmem_chk(ins, 0)
+        }
+        ATILESTORED => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[MA], true)
+                .push(&[TMM], true)
+                .apx(APXVariant::VexExtension, false)
+                .check(ins)?;
+            sibmem_chk(ins, 0)
+        }
+        TILEZERO => {
+            use chkn::*;
+            CheckAPI::<1>::new().push(&[TMM], true).check(ins)
+        }
+        TILERELEASE => {
+            use chkn::*;
+            CheckAPI::<0>::new().check(ins)
+        }
+        TDPBSSD | TDPBSUD | TDPBUSD | TDPBUUD | TDPBF16PS | TDPFP16PS => {
+            use chkn::*;
+            CheckAPI::<3>::new()
+                .push(&[TMM], true)
+                .push(&[TMM], true)
+                .push(&[TMM], true)
+                .check(ins)?;
+            let [d, s, t] = [ins.dst(), ins.src(), ins.ssrc()].map(|o| match o {
+                Some(Operand::Register(r)) => r.to_byte(),
+                _ => 0,
+            });
+            if d == s || d == t || s == t {
+                return Err(Error::new(
+                    "all tile registers used by this instruction have to be different",
+                    8,
+                ));
+            }
+            Ok(())
+        }
         _ => shr_chk(ins),
     }
 }
@@ -1220,10 +1362,120 @@ pub fn shr_chk(ins: &Instruction) -> Result<(), Error> {
         | FLDLG2 | FLDLN2 | FCOMPP | FCHS | FABS | FTST | FXAM | FXTRACT | FRNDINT | FPREM
         | FSQRT | FSCALE | F2XM1 | FYL2X | FPTAN | FYL2XP1 | FDECSTP | FINCSTP | FNSETPM
         | FSETPM | FNSTSWAX | FSTSWAX | FUCOMPP | FPREM1 | FSINCOS | FSIN | FCOS | ENCLV
-        | ENCLS | ENCLU | SAVEPREVSSP | FPATAN => {
+        | ENCLS | ENCLU | SAVEPREVSSP | FPATAN | MONITORX | MWAITX | RDPRU | INVLPGB | TLBSYNC => {
             use chkn::*;
             CheckAPI::<0>::new().check(ins)
         }
+
+        // AMD XOP
+        VPCMOV | VFMADDPS | VFMADDPD | VFMSUBPS | VFMSUBPD | VFNMADDPS | VFNMADDPD | VFNMSUBPS
+        | VFNMSUBPD | VFMADDSUBPS | VFMADDSUBPD | VFMSUBADDPS | VFMSUBADDPD => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM, YMM], true)
+                .push(&[XMM, YMM], true)
+                .push(&[XMM, YMM, M128, M256], true)
+                .push(&[XMM, YMM, M128, M256], true)
+                .check(ins)?;
+            is4_chk(ins)
+        }
+        VPPERM => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM], true)
+                .push(&[XMM], true)
+                .push(&[XMM, M128], true)
+                .push(&[XMM, M128], true)
+                .check(ins)?;
+            is4_chk(ins)
+        }
+        VFMADDSS | VFMSUBSS | VFNMADDSS | VFNMSUBSS => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM], true)
+                .push(&[XMM], true)
+                .push(&[XMM, M32], true)
+                .push(&[XMM, M32], true)
+                .check(ins)?;
+            is4_chk(ins)
+        }
+        VFMADDSD | VFMSUBSD | VFNMADDSD | VFNMSUBSD => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM], true)
+                .push(&[XMM], true)
+                .push(&[XMM, M64], true)
+                .push(&[XMM, M64], true)
+                .check(ins)?;
+            is4_chk(ins)
+        }
+        VPROTB | VPROTW | VPROTD | VPROTQ => {
+            use chkn::*;
+            CheckAPI::<3>::new()
+                .push(&[XMM], true)
+                .push(&[XMM, M128], true)
+                .push(&[XMM, M128, I8], true)
+                .forbidden(&[[XMM, M128, M128]])
+                .check(ins)
+        }
+        VPSHAB | VPSHAW | VPSHAD | VPSHAQ | VPSHLB | VPSHLW | VPSHLD | VPSHLQ => {
+            use chkn::*;
+            CheckAPI::<3>::new()
+                .push(&[XMM], true)
+                .push(&[XMM, M128], true)
+                .push(&[XMM, M128], true)
+                .forbidden(&[[XMM, M128, M128]])
+                .check(ins)
+        }
+        VPCOMB | VPCOMW | VPCOMD | VPCOMQ | VPCOMUB | VPCOMUW | VPCOMUD | VPCOMUQ => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM], true)
+                .push(&[XMM], true)
+                .push(&[XMM, M128], true)
+                .push(&[I8], true)
+                .check(ins)
+        }
+        VPMACSWW | VPMACSSWW | VPMACSWD | VPMACSSWD | VPMACSDD | VPMACSSDD | VPMACSDQL
+        | VPMACSSDQL | VPMACSDQH | VPMACSSDQH | VPMADCSWD | VPMADCSSWD => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM], true)
+                .push(&[XMM], true)
+                .push(&[XMM, M128], true)
+                .push(&[XMM], true)
+                .check(ins)
+        }
+        VFRCZPS | VFRCZPD => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM, YMM], true)
+                .push(&[XMM, YMM, M128, M256], true)
+                .forbidden(&[[XMM, YMM], [XMM, M256], [YMM, XMM], [YMM, M128]])
+                .check(ins)
+        }
+        VFRCZSS => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM], true)
+                .push(&[XMM, M32], true)
+                .check(ins)
+        }
+        VFRCZSD => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM], true)
+                .push(&[XMM, M64], true)
+                .check(ins)
+        }
+        VPHADDBW | VPHADDBD | VPHADDBQ | VPHADDWD | VPHADDWQ | VPHADDDQ | VPHADDUBW | VPHADDUBD
+        | VPHADDUBQ | VPHADDUWD | VPHADDUWQ | VPHADDUDQ | VPHSUBBW | VPHSUBWD | VPHSUBDQ => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM], true)
+                .push(&[XMM, M128], true)
+                .check(ins)
+        }
         FCMOVB | FCMOVE | FCMOVBE | FCMOVU | FCMOVNB | FCMOVNE | FCMOVNBE | FCMOVNU | FADDP
         | FMULP | FSUBRP | FFREE | FSUBP | FDIVP | FDIVRP | FCOMP | FCOMI | FCOMIP | FUCOMI
         | FUCOMIP => {
@@ -2727,7 +2979,7 @@ pub fn shr_chk(ins: &Instruction) -> Result<(), Error> {
                 (&[XMM, R32, M32], Optional::Needed),
                 (&[XMM, R32, M32], Optional::Needed),
             ],
-            &[(M32, M32), (R32, R32), (XMM, XMM)],
+            &[(M32, M32), (R32, R32)],
             &[],
         ),
         Mnemonic::VMOVQ => ot_chk(
@@ -3108,6 +3360,222 @@ pub fn shr_chk(ins: &Instruction) -> Result<(), Error> {
             &[],
             &[],
         ),
+        // GFNI
+        Mnemonic::GF2P8AFFINEQB | Mnemonic::GF2P8AFFINEINVQB => {
+            use chkn::*;
+            CheckAPI::<3>::new()
+                .push(&[XMM], true)
+                .push(&[XMM, M128], true)
+                .push(&[I8], true)
+                .check(ins)
+        }
+        Mnemonic::GF2P8MULB => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM], true)
+                .push(&[XMM, M128], true)
+                .check(ins)
+        }
+        Mnemonic::VGF2P8AFFINEQB | Mnemonic::VGF2P8AFFINEINVQB => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST64], true)
+                .push(&[I8], true)
+                .set_evex()
+                .allow_masks()
+                .check(ins)
+        }
+        Mnemonic::VGF2P8MULB => {
+            use chkn::*;
+            CheckAPI::<3>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512], true)
+                .set_evex()
+                .allow_masks()
+                .check(ins)
+        }
+        // AVX10.2
+        VMINMAXBF16 => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST16], true)
+                .push(&[I8], true)
+                .set_evex()
+                .allow_masks()
+                .check(ins)
+        }
+        VMINMAXPH => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST16], true)
+                .push(&[I8], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::SAE, Size::Zword)
+                .allow_masks()
+                .check(ins)
+        }
+        VMINMAXPS => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST32], true)
+                .push(&[I8], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::SAE, Size::Zword)
+                .allow_masks()
+                .check(ins)
+        }
+        VMINMAXPD => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST64], true)
+                .push(&[I8], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::SAE, Size::Zword)
+                .allow_masks()
+                .check(ins)
+        }
+        VMINMAXSH => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM], true)
+                .push(&[XMM], true)
+                .push(&[XMM, M16], true)
+                .push(&[I8], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::SAE, Size::Xword)
+                .allow_masks()
+                .check(ins)
+        }
+        VMINMAXSS => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM], true)
+                .push(&[XMM], true)
+                .push(&[XMM, M32], true)
+                .push(&[I8], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::SAE, Size::Xword)
+                .allow_masks()
+                .check(ins)
+        }
+        VMINMAXSD => {
+            use chkn::*;
+            CheckAPI::<4>::new()
+                .push(&[XMM], true)
+                .push(&[XMM], true)
+                .push(&[XMM, M64], true)
+                .push(&[I8], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::SAE, Size::Xword)
+                .allow_masks()
+                .check(ins)
+        }
+        VCVTBF162IBS | VCVTBF162IUBS | VCVTTBF162IBS | VCVTTBF162IUBS => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST16], true)
+                .set_evex()
+                .allow_masks()
+                .check(ins)
+        }
+        VCVTPH2IBS | VCVTPH2IUBS => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST16], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::ER, Size::Zword)
+                .allow_masks()
+                .check(ins)
+        }
+        VCVTTPH2IBS | VCVTTPH2IUBS => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST16], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::SAE, Size::Zword)
+                .allow_masks()
+                .check(ins)
+        }
+        VCVTPS2IBS | VCVTPS2IUBS => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST32], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::ER, Size::Zword)
+                .allow_masks()
+                .check(ins)
+        }
+        VCVTTPS2IBS | VCVTTPS2IUBS => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST32], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::SAE, Size::Zword)
+                .allow_masks()
+                .check(ins)
+        }
+        VADDBF16 | VSUBBF16 | VMULBF16 | VDIVBF16 | VMAXBF16 | VMINBF16 | VSCALEFBF16 => {
+            use chkn::*;
+            CheckAPI::<3>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST16], true)
+                .set_evex()
+                .allow_masks()
+                .check(ins)
+        }
+        VSQRTBF16 | VRCPBF16 | VRSQRTBF16 => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM, YMM, ZMM], true)
+                .push(&[XMM, YMM, ZMM, M128, M256, M512, MBCST16], true)
+                .set_evex()
+                .allow_masks()
+                .check(ins)
+        }
+        VCOMXSH | VUCOMXSH => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM], true)
+                .push(&[XMM, M16], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::SAE, Size::Xword)
+                .check(ins)
+        }
+        VCOMXSS | VUCOMXSS => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM], true)
+                .push(&[XMM, M32], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::SAE, Size::Xword)
+                .check(ins)
+        }
+        VCOMXSD | VUCOMXSD => {
+            use chkn::*;
+            CheckAPI::<2>::new()
+                .push(&[XMM], true)
+                .push(&[XMM, M64], true)
+                .set_evex()
+                .avx10_modifier(AVX10Modifier::SAE, Size::Xword)
+                .check(ins)
+        }
         // cvt-part1
         Mnemonic::CVTPD2PI => ot_chk(
             ins,
@@ -5733,6 +6201,31 @@ pub fn shr_chk(ins: &Instruction) -> Result<(), Error> {
 
 // Utils
 
+// FMA4 and XOP instructions with 4 operands: only one of them can be memory operand
+fn is4_chk(ins: &Instruction) -> Result<(), Error> {
+    if let (Some(Operand::Mem(_)), Some(Operand::Mem(_))) = (ins.get(2), ins.get(3)) {
+        return Err(Error::new(
+            "you tried to use forbidden operand combination",
+            7,
+        ));
+    }
+    Ok(())
+}
+
+// AMX sibmem: memory operand encoded with SIB (base + index * scale as stride);
+// it cannot be RIP-relative or use VSIB
+fn sibmem_chk(ins: &Instruction, idx: usize) -> Result<(), Error> {
+    if let Some(Operand::Mem(m)) = ins.get(idx) {
+        if m.is_riprel() || m.is_vsib() {
+            return Err(Error::new(
+                format!("operand at index {idx} has to be sibmem (it cannot be RIP-relative or use VSIB)"),
+                8,
+            ));
+        }
+    }
+    Ok(())
+}
+
 // Legacy check API
 #[derive(PartialEq, Debug)]
 enum Optional {
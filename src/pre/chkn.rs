@@ -498,7 +498,7 @@ impl<'a, const OPERAND_COUNT: usize> CheckAPI<'a, OPERAND_COUNT> {
             match modf {
                 AVX10Modifier::None => {}
                 AVX10Modifier::ER => {
-                    if ins.evex_er().is_some() && ins.size_full_gt() != size {
+                    if ins.evex_er().is_some() && !avx10_size(ins.size_full_gt(), size) {
                         return Err(Error::new("you tried to use {er} subexpression on instruction using wrong variant", 16));
                     } else if let Some(true) = ins.evex_sae() {
                         return Err(Error::new("you tried to use {sae} subexpression on instruction that does not allow it", 16));
@@ -506,7 +506,7 @@ impl<'a, const OPERAND_COUNT: usize> CheckAPI<'a, OPERAND_COUNT> {
                 }
                 AVX10Modifier::SAE => {
                     if let Some(true) = ins.evex_sae() {
-                        if ins.size_full_gt() != size {
+                        if !avx10_size(ins.size_full_gt(), size) {
                             return Err(Error::new("you tried to use {sae} subexpression on instruction using wrong variant", 16));
                         }
                     } else if ins.evex_er().is_some() {
@@ -551,27 +551,24 @@ impl<'a, const OPERAND_COUNT: usize> CheckAPI<'a, OPERAND_COUNT> {
                         return Err(er);
                     }
                     match o {
-                        Operand::Register(r) => {
+                        Operand::Register(r)
                             if sz != r.size()
                                 && !r.is_dbg_reg()
                                 && !r.is_ctrl_reg()
-                                && !r.is_sgmnt()
-                            {
-                                let er = Error::new(
-                                    "you tried to use invalid operand size in this instruction",
-                                    8,
-                                );
-                                return Err(er);
-                            }
+                                && !r.is_sgmnt() =>
+                        {
+                            let er = Error::new(
+                                "you tried to use invalid operand size in this instruction",
+                                8,
+                            );
+                            return Err(er);
                         }
-                        Operand::Mem(m) => {
-                            if sz != m.size() {
-                                let er = Error::new(
-                                    "you tried to use invalid operand size in this instruction",
-                                    8,
-                                );
-                                return Err(er);
-                            }
+                        Operand::Mem(m) if sz != m.size() => {
+                            let er = Error::new(
+                                "you tried to use invalid operand size in this instruction",
+                                8,
+                            );
+                            return Err(er);
                         }
                         _ => {}
                     }
@@ -581,6 +578,13 @@ impl<'a, const OPERAND_COUNT: usize> CheckAPI<'a, OPERAND_COUNT> {
         Ok(())
     }
 }
+
+// AVX10.2 allows {er} and {sae} on 256-bit variants of instructions,
+// that allow them on 512-bit variants
+fn avx10_size(ins_size: Size, for_size: Size) -> bool {
+    ins_size == for_size || (ins_size == Size::Yword && for_size == Size::Zword)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
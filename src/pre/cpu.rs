@@ -0,0 +1,118 @@
+// pasm - src/pre/cpu.rs
+// ---------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+// checks if instruction is supported by CPU selected with `cpu` directive (or `--cpu` flag)
+
+use crate::shr::{
+    error::Error,
+    instruction::{Instruction, Operand},
+    isa::{Isa, IsaSet},
+    mnemonic::Mnemonic,
+    size::Size,
+};
+
+// `evex` tells if instruction is encoded with EVEX prefix (see `GenAPI::uses_evex`)
+pub fn check_cpu(ins: &Instruction, bits: u8, evex: bool, set: &IsaSet) -> Result<(), Error> {
+    if *set == IsaSet::all() {
+        return Ok(());
+    }
+    for isa in required(ins, bits, evex) {
+        if !set.has(isa) {
+            return Err(Error::new(
+                format!(
+                    "instruction requires `{}` extension, which is disabled by `cpu` directive",
+                    isa.name()
+                ),
+                19,
+            ));
+        }
+    }
+    Ok(())
+}
+
+// returns extensions required by `ins`
+fn required(ins: &Instruction, bits: u8, evex: bool) -> impl Iterator<Item = Isa> {
+    use Mnemonic::*;
+    let sz = ins.size_full_gt();
+    let mut isa = match ins.mnemonic {
+        // lahf/sahf are always valid outside of 64-bit mode
+        LAHF | SAHF if bits != 64 => Isa::Base,
+        // XOP form of bextr
+        BEXTR if matches!(ins.ssrc(), Some(Operand::Imm(_))) => Isa::Tbm,
+        // AVX10.2 zero-extending moves
+        VMOVD | VMOVW if is_xmm_xmm(ins) => Isa::Avx10_2,
+        m => m.isa(),
+    };
+    // VEX forms of AVX-512 instructions
+    isa = match isa {
+        Isa::Mmx if has_xmm(ins) => Isa::Sse2,
+        Isa::Avx if sz == Size::Yword && is_avx_int(ins.mnemonic) => Isa::Avx2,
+        Isa::Aes if !evex && sz == Size::Yword => Isa::Vaes,
+        Isa::Pclmul if !evex && sz == Size::Yword => Isa::Vpclmulqdq,
+        Isa::Avx512vnni if !evex => Isa::AvxVnni,
+        Isa::Avx512bf16 if !evex && ins.mnemonic == VCVTNEPS2BF16 => Isa::AvxNeConvert,
+        i => i,
+    };
+    // EVEX forms of VEX instructions
+    let evex = if evex {
+        match isa {
+            Isa::Avx | Isa::Avx2 | Isa::Fma | Isa::F16c => {
+                isa = Isa::Avx512f;
+                None
+            }
+            Isa::Aes => {
+                isa = Isa::Vaes;
+                Some(Isa::Avx512f)
+            }
+            Isa::Pclmul => {
+                isa = Isa::Vpclmulqdq;
+                Some(Isa::Avx512f)
+            }
+            Isa::Gfni => Some(Isa::Avx512f),
+            _ => None,
+        }
+    } else {
+        None
+    };
+    // r16-r31, {nf} and new data destination
+    let apx = (isa != Isa::ApxF && ins.needs_apx_extension()).then_some(Isa::ApxF);
+    // 256-bit {er}/{sae}
+    let avx10 = (sz == Size::Yword && (ins.evex_er().is_some() || ins.evex_sae() == Some(true)))
+        .then_some(Isa::Avx10_2);
+    [Some(isa), evex, apx, avx10].into_iter().flatten()
+}
+
+fn has_xmm(ins: &Instruction) -> bool {
+    ins.iter()
+        .any(|o| matches!(o, Operand::Register(r) if r.size() == Size::Xword))
+}
+
+fn is_xmm_xmm(ins: &Instruction) -> bool {
+    ins.len() == 2
+        && ins
+            .iter()
+            .all(|o| matches!(o, Operand::Register(r) if r.size() == Size::Xword))
+}
+
+// AVX instructions, that operate on integers (256-bit forms were introduced in AVX2)
+#[rustfmt::skip]
+fn is_avx_int(m: Mnemonic) -> bool {
+    use Mnemonic::*;
+    !matches!(
+        m,
+        VMOVAPS | VMOVUPS | VADDPS | VADDSS | VSUBPS | VSUBSS | VMULPS | VMULSS | VDIVPS
+        | VDIVSS | VRCPPS | VRCPSS | VSQRTPS | VSQRTSS | VRSQRTPS | VRSQRTSS | VMINPS
+        | VMINSS | VMAXPS | VMAXSS | VORPS | VANDPS | VANDNPS | VXORPS | VCMPPS | VCMPSS
+        | VSHUFPS | VUNPCKLPS | VUNPCKHPS | VADDPD | VSUBPD | VMULPD | VDIVPD | VSQRTPD
+        | VMINPD | VMAXPD | VORPD | VANDPD | VANDNPD | VXORPD | VCMPPD | VMOVAPD | VMOVUPD
+        | VMOVMSKPD | VMOVDQA | VADDSUBPS | VADDSUBPD | VHADDPS | VHSUBPS | VHADDPD | VHSUBPD
+        | VMOVSLDUP | VMOVSHDUP | VMOVDDUP | VLDDQU | VDPPS | VPTEST | VBLENDPS | VBLENDPD
+        | VROUNDPD | VROUNDPS | VBLENDVPS | VBLENDVPD | VZEROALL | VZEROUPPER | VINSERTF128
+        | VEXTRACTF128 | VBROADCASTSS | VBROADCASTSD | VBROADCASTF128 | VMOVMSKPS
+        | VPERMILPD | VPERMILPS | VPERM2F128 | VTESTPD | VTESTPS | VMASKMOVPS | VMASKMOVPD
+        | VCVTPD2DQ | VCVTPD2PS | VCVTPS2DQ | VCVTPS2PD | VCVTDQ2PD | VCVTDQ2PS | VCVTTPD2DQ
+        | VCVTTPS2DQ
+    )
+}
@@ -1,4 +1,9 @@
 pub mod chk;
 pub mod chkn;
+pub mod cpu;
 #[cfg(not(feature = "refresh"))]
 pub mod par;
+#[cfg(not(feature = "refresh"))]
+pub mod prp;
+pub mod tls;
+pub mod x32;
@@ -5,6 +5,7 @@
 
 use crate::shr::{
     error::Error,
+    expr,
     instruction::{Instruction, OperandOwned},
     mem::Mem,
     mnemonic::Mnemonic,
@@ -131,6 +132,24 @@ pub fn par<'a>(mut line: &'a str) -> LineResult<'a> {
                             "z" => ins.set_evex_z(),
                             "evex" => ins.set_evex(),
                             "vex" => ins.set_vex(),
+                            // encoding-selection pseudo-prefixes
+                            "vex3" => ins.set_vex3(),
+                            "disp8" | "disp32" if ins.forced_disp().is_some() => {
+                                return LineResult::Error(Error::new(
+                                    "displacement width can be forced only once per instruction",
+                                    4,
+                                ))
+                            }
+                            "disp8" => ins.set_disp8(),
+                            "disp32" => ins.set_disp32(),
+                            "load" | "store" if ins.load() || ins.store() => {
+                                return LineResult::Error(Error::new(
+                                    "you cannot use both {load} and {store} on one instruction",
+                                    4,
+                                ))
+                            }
+                            "load" => ins.set_load(),
+                            "store" => ins.set_store(),
                             _ => {
                                 return LineResult::Error(Error::new(
                                     format!(
@@ -143,6 +162,18 @@ pub fn par<'a>(mut line: &'a str) -> LineResult<'a> {
                     }
                 }
             }
+            if let (Some(Size::Byte), Some(off)) =
+                (ins.forced_disp(), ins.get_mem().and_then(|m| m.offset()))
+            {
+                if i8::try_from(off).is_err() {
+                    return LineResult::Error(Error::new(
+                        format!(
+                            "{{disp8}} was used, but displacement {off} does not fit into 8 bits"
+                        ),
+                        4,
+                    ));
+                }
+            }
 
             LineResult::Instruction(ins)
         } else if mnem == "section" {
@@ -159,13 +190,18 @@ pub fn par<'a>(mut line: &'a str) -> LineResult<'a> {
     }
 }
 
-/// splits line more intelligently (so mov rax, ',' will work)          
+/// splits line more intelligently (so mov rax, ',' and call @[sym, rel32] will work)
 fn split_once_parser(line: &str) -> Option<(&str, &str)> {
     let mut str_closure = false;
+    let mut sref_closure = false;
     for (i, b) in line.as_bytes().iter().enumerate() {
         if b == &b'"' || b == &b'\'' {
             str_closure = !str_closure;
-        } else if b == &b',' && !str_closure {
+        } else if b == &b'@' && line.as_bytes().get(i + 1) == Some(&b'[') && !str_closure {
+            sref_closure = true;
+        } else if b == &b']' && sref_closure {
+            sref_closure = false;
+        } else if b == &b',' && !str_closure && !sref_closure {
             return Some((&line[0..i], &line[i + 1..]));
         } else if b == &b';' && !str_closure {
             return Some((&line[0..i], ""));
@@ -196,6 +232,8 @@ fn par_operand<'a>(slice: &'a str) -> Result<ParserOperand<'a>, Error> {
     } else if let Ok(mut symbolref) = SymbolRef::from_str(slice) {
         symbolref.deref(false);
         Ok(ParserOperand::SymbolRef(symbolref))
+    } else if expr::is_expr(slice) {
+        Ok(ParserOperand::Imm(expr::eval(slice)?))
     } else if let Some((sz, slice)) = slice.split_once(' ') {
         if sz.starts_with("q")
             || sz.starts_with("d")
@@ -214,18 +252,20 @@ fn par_operand<'a>(slice: &'a str) -> Result<ParserOperand<'a>, Error> {
                 ));
             };
 
-            if let Ok(mut m) = Mem::from_str(slice) {
-                m.set_size(sz);
-                Ok(ParserOperand::Mem(m))
-            } else if let Ok(mut s) = SymbolRef::from_str(slice) {
-                s.set_size(sz);
-                s.deref(true);
-                Ok(ParserOperand::SymbolRef(s))
-            } else {
-                Err(Error::new(
-                    "failed to parse an operand: expected to find memory addressing",
-                    5,
-                ))
+            match Mem::from_str(slice) {
+                Ok(mut m) => {
+                    m.set_size(sz);
+                    Ok(ParserOperand::Mem(m))
+                }
+                Err(e) => {
+                    if let Ok(mut s) = SymbolRef::from_str(slice) {
+                        s.set_size(sz);
+                        s.deref(true);
+                        Ok(ParserOperand::SymbolRef(s))
+                    } else {
+                        Err(e)
+                    }
+                }
             }
         } else {
             Err(Error::new(
@@ -255,6 +295,8 @@ mod tests {
         assert_eq!(split_once_parser(line), Some(("mov \",\"", " rax")));
         let line = "rax, rcx";
         assert_eq!(split_once_parser(line), Some(("rax", " rcx")));
+        let line = "@[sym, abs32], rax";
+        assert_eq!(split_once_parser(line), Some(("@[sym, abs32]", " rax")));
         let line = "this should be parsed; this SHOULD NOT BE!";
         assert_eq!(split_once_parser(line), Some(("this should be parsed", "")))
     }
@@ -277,6 +319,13 @@ mod tests {
             par_operand(slice),
             Ok(ParserOperand::SubExpression("subexpr"))
         );
+        let slice = "4096 * (1 << 2)";
+        assert_eq!(
+            par_operand(slice),
+            Ok(ParserOperand::Imm(Number::uint64(16384)))
+        );
+        let slice = "1 / 0";
+        assert_eq!(par_operand(slice), Err(Error::new("", 15)));
     }
     #[test]
     fn tparser_2() {
@@ -287,4 +336,17 @@ mod tests {
         expected.mnemonic = Mnemonic::MOV;
         assert_eq!(par(islice), LineResult::Instruction(expected));
     }
+    #[test]
+    fn tpseudo_pfx_0() {
+        if let LineResult::Instruction(ins) = par("mov rax, qword [rbx + 1], {disp32}, {load}") {
+            assert_eq!(ins.forced_disp(), Some(Size::Dword));
+            assert!(ins.load() && !ins.store() && !ins.vex3());
+        } else {
+            panic!("didn't parse into instruction");
+        }
+        let err = LineResult::Error(Error::new("", 4));
+        assert_eq!(par("mov rax, qword [rbx + 128], {disp8}"), err);
+        assert_eq!(par("mov rax, qword [rbx], {disp8}, {disp32}"), err);
+        assert_eq!(par("add rax, rcx, {load}, {store}"), err);
+    }
 }
@@ -0,0 +1,1609 @@
+// pasm - src/pre/prp.rs
+// ---------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+// preprocessor: runs before `par` and produces single source buffer,
+// where every line remembers from which file (and line) it came from.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    shr::{error::Error, expr, mnemonic::Mnemonic, reg::Register, size::Size},
+    utils::LineIter,
+};
+
+// deeper includes/expansions are (most probably) result of some kind of recursion
+const MAX_INCLUDE_DEPTH: usize = 64;
+const MAX_MACRO_DEPTH: usize = 64;
+
+// directives, which arguments are symbol names (constants are not substituted there)
+const SYMBOL_DIRECTIVES: &[&str] = &[
+    "section",
+    "public",
+    "private",
+    "weak",
+    "protected",
+    "function",
+    "object",
+    "extern",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Origin {
+    // index in `Source.files`
+    pub file: u16,
+    pub line: u32,
+    // 0 if line does not come from macro expansion, otherwise index + 1 in `Source.exps`
+    pub exp: u32,
+}
+
+#[derive(Debug)]
+struct Expansion {
+    // what is expanded (like "macro `name`")
+    name: Box<str>,
+    // call site
+    at: Origin,
+}
+
+#[derive(Debug, Default)]
+pub struct Source {
+    pub buf: String,
+    files: Vec<Box<str>>,
+    origins: Vec<Origin>,
+    exps: Vec<Expansion>,
+}
+
+#[derive(Debug)]
+struct Param {
+    name: Box<str>,
+    default: Option<Box<str>>,
+    variadic: bool,
+}
+
+#[derive(Debug)]
+struct Macro {
+    params: Vec<Param>,
+    body: Vec<(Box<str>, Origin)>,
+    // where `macro` line is
+    origin: Origin,
+}
+
+#[derive(Debug)]
+struct Field {
+    name: Box<str>,
+    offset: u64,
+    // size of single element
+    size: u64,
+    count: u64,
+}
+
+#[derive(Debug)]
+struct Struct {
+    fields: Vec<Field>,
+    size: u64,
+    // alignment of the largest field (like in C)
+    align: u64,
+    // where `struct` line is
+    origin: Origin,
+}
+
+#[derive(Debug)]
+struct Rept {
+    count: u64,
+    counter: Option<Param>,
+    body: Vec<(Box<str>, Origin)>,
+    // how many `rept` blocks inside of body are currently open
+    nested: usize,
+    // where `rept` line is
+    origin: Origin,
+}
+
+#[derive(Debug)]
+struct Const {
+    val: Box<str>,
+    origin: Origin,
+}
+
+impl Source {
+    pub fn new(ipath: &Path, ipaths: &[PathBuf]) -> Result<Self, Error> {
+        let content = match fs::read(ipath) {
+            Ok(c) => c,
+            Err(e) => return Err(Error::new(e.to_string(), 6)),
+        };
+        Self::from_content(ipath, &content, ipaths)
+    }
+    /// same as `new`, but `content` of input file is already read (`ipath` is used for errors
+    /// and to resolve includes)
+    pub fn from_content(ipath: &Path, content: &[u8], ipaths: &[PathBuf]) -> Result<Self, Error> {
+        let mut prp = Prp {
+            src: Source::default(),
+            ipaths,
+            stack: Vec::new(),
+            macros: HashMap::new(),
+            consts: HashMap::new(),
+            defining: None,
+            repeating: None,
+            structs: HashMap::new(),
+            structure: None,
+            depth: 0,
+            conds: Vec::new(),
+            bits: 16,
+            target: "bin".into(),
+            scope: None,
+        };
+        prp.file(ipath, content)?;
+        Ok(prp.src)
+    }
+    pub fn origin(&self, lnum: usize) -> Option<Origin> {
+        self.origins.get(lnum).copied()
+    }
+    pub fn file(&self, idx: u16) -> &str {
+        &self.files[idx as usize]
+    }
+    /// sets file and line of error, so it points into file, from which line `lnum` came
+    pub fn locate(&self, e: &mut Error, lnum: usize) {
+        if let Some(o) = self.origin(lnum) {
+            self.locate_origin(e, o);
+        }
+    }
+    /// same as `locate`, but uses `Origin` directly; if line comes from macro expansion,
+    /// then every call site is added as note to error
+    pub fn locate_origin(&self, e: &mut Error, o: Origin) {
+        e.set_file(self.file(o.file));
+        e.set_line(o.line as usize);
+        let mut exp = o.exp;
+        while exp != 0 {
+            let x = &self.exps[exp as usize - 1];
+            e.add_note(format!(
+                "in expansion of {} at {}:{}",
+                x.name,
+                self.file(x.at.file),
+                x.at.line
+            ));
+            exp = x.at.exp;
+        }
+    }
+    fn push(&mut self, line: &str, origin: Origin) {
+        self.buf.push_str(line);
+        self.buf.push('\n');
+        self.origins.push(origin);
+    }
+}
+
+struct Prp<'a> {
+    src: Source,
+    ipaths: &'a [PathBuf],
+    // canonical paths of files that are currently being included
+    stack: Vec<PathBuf>,
+    macros: HashMap<Box<str>, Macro>,
+    consts: HashMap<Box<str>, Const>,
+    // macro, which body is currently being collected
+    defining: Option<(Box<str>, Macro)>,
+    // `rept` block, which body is currently being collected
+    repeating: Option<Rept>,
+    structs: HashMap<Box<str>, Struct>,
+    // struct, which fields are currently being declared
+    structure: Option<(Box<str>, Struct)>,
+    // current macro expansion depth
+    depth: usize,
+    // conditional blocks (`if`/`ifdef`/`ifndef` ... `endif`) that are currently open
+    conds: Vec<Cond>,
+    // values of `bits` and `target` directives (used in conditions)
+    bits: u8,
+    target: Box<str>,
+    // last non-local label (scope of local labels)
+    scope: Option<Box<str>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CondState {
+    // lines are emitted
+    Active,
+    // no branch was taken yet (lines are skipped)
+    Pending,
+    // some branch was already taken (lines are skipped)
+    Done,
+    // whole block is inside of skipped block
+    Skip,
+}
+
+#[derive(Debug)]
+struct Cond {
+    state: CondState,
+    // true, if `else` was already seen
+    else_seen: bool,
+    origin: Origin,
+}
+
+impl Prp<'_> {
+    fn file(&mut self, path: &Path, content: &[u8]) -> Result<(), Error> {
+        let fidx = self.src.files.len() as u16;
+        self.src.files.push(path.to_string_lossy().into());
+        self.stack
+            .push(path.canonicalize().unwrap_or_else(|_| path.to_path_buf()));
+
+        let conds = self.conds.len();
+        let mut line_iter = LineIter::new(content);
+        while let Some((lnum, line)) = line_iter.next() {
+            let origin = Origin {
+                file: fidx,
+                line: lnum as u32 + 1,
+                exp: 0,
+            };
+            if let Some(l) = line.trim().strip_suffix(':').filter(|l| l.contains('$')) {
+                let mut e = Error::new(format!("label `{l}` cannot contain `$`"), 8);
+                self.src.locate_origin(&mut e, origin);
+                return Err(e);
+            }
+            self.line(line, origin)?;
+        }
+        self.check_conds(conds)?;
+        if let Some(r) = &self.repeating {
+            if r.origin.file == fidx {
+                let mut e = Error::new("`rept` block is not closed with `endrept`", 13);
+                self.src.locate_origin(&mut e, r.origin);
+                return Err(e);
+            }
+        }
+        if let Some((name, st)) = &self.structure {
+            if st.origin.file == fidx {
+                let mut e = Error::new(
+                    format!("struct `{name}` is not closed with `endstruct`"),
+                    17,
+                );
+                self.src.locate_origin(&mut e, st.origin);
+                return Err(e);
+            }
+        }
+        if let Some((name, m)) = &self.defining {
+            if m.origin.file == fidx {
+                let mut e = Error::new(format!("macro `{name}` is not closed with `endmacro`"), 13);
+                self.src.locate_origin(&mut e, m.origin);
+                return Err(e);
+            }
+        }
+        self.stack.pop();
+        Ok(())
+    }
+    fn line(&mut self, line: &str, origin: Origin) -> Result<(), Error> {
+        if let Err(mut e) = self.line_inner(line, origin) {
+            if e.get_line().is_none() {
+                self.src.locate_origin(&mut e, origin);
+            }
+            return Err(e);
+        }
+        Ok(())
+    }
+    fn line_inner(&mut self, line: &str, origin: Origin) -> Result<(), Error> {
+        let trimmed = line.trim();
+        if self.defining.is_some() {
+            if directive(trimmed, "endmacro").is_some() {
+                let (name, m) = self.defining.take().unwrap();
+                self.macros.insert(name, m);
+            } else if directive(trimmed, "macro").is_some() {
+                return Err(Error::new(
+                    "macros cannot be defined inside other macros",
+                    13,
+                ));
+            } else if let Some((_, m)) = &mut self.defining {
+                m.body.push((line.into(), origin));
+            }
+            return Ok(());
+        }
+        let (word, args) = trimmed.split_once([' ', '\t']).unwrap_or((trimmed, ""));
+        if let Some(r) = &mut self.repeating {
+            if directive(trimmed, "endrept").is_some() {
+                if r.nested == 0 {
+                    let r = self.repeating.take().unwrap();
+                    return self.repeat(r);
+                }
+                r.nested -= 1;
+            } else if directive(trimmed, "rept").is_some() {
+                r.nested += 1;
+            }
+            r.body.push((line.into(), origin));
+            return Ok(());
+        }
+        if matches!(word, "if" | "ifdef" | "ifndef" | "elif" | "else" | "endif") {
+            return self.cond(word, args.trim(), origin);
+        }
+        if self
+            .conds
+            .last()
+            .is_some_and(|c| c.state != CondState::Active)
+        {
+            return Ok(());
+        }
+        if self.structure.is_some() {
+            return if directive(trimmed, "endstruct").is_some() {
+                self.endstruct()
+            } else if directive(trimmed, "struct").is_some() {
+                Err(Error::new(
+                    "structs cannot be declared inside other structs",
+                    17,
+                ))
+            } else {
+                self.field(trimmed)
+            };
+        }
+        if let Some(ipath) = directive(trimmed, "include") {
+            let from = PathBuf::from(self.src.file(origin.file));
+            self.include(&from, ipath)
+        } else if let Some(args) = directive(trimmed, "macro") {
+            self.define(args, origin)
+        } else if directive(trimmed, "endmacro").is_some() {
+            Err(Error::new("`endmacro` without matching `macro`", 13))
+        } else if let Some(args) = directive(trimmed, "rept") {
+            self.rept(args, origin)
+        } else if directive(trimmed, "endrept").is_some() {
+            Err(Error::new("`endrept` without matching `rept`", 13))
+        } else if let Some(args) = directive(trimmed, "times") {
+            self.times(args, origin)
+        } else if let Some(name) = directive(trimmed, "struct") {
+            self.structure(name, origin)
+        } else if directive(trimmed, "endstruct").is_some() {
+            Err(Error::new("`endstruct` without matching `struct`", 17))
+        } else if let Some(args) = directive(trimmed, "define") {
+            let (name, val) = args.split_once([' ', '\t']).unwrap_or((args, ""));
+            self.constant(name, val, origin)
+        } else {
+            let (name, args) = trimmed.split_once([' ', '\t']).unwrap_or((trimmed, ""));
+            if self.macros.contains_key(name) {
+                self.expand(name, args.trim(), origin)
+            } else if self.structs.contains_key(name) {
+                self.instance(name, args.trim(), origin)
+            } else if let Some(val) = directive(args.trim(), "equ") {
+                if val.is_empty() {
+                    return Err(Error::new(format!("constant `{name}` requires value"), 14));
+                }
+                // unlike `define`, `equ` evaluates its value (if it is an expression)
+                let val = split_args(val).join(", ");
+                let val = self.replace(&val).unwrap_or(val);
+                if expr::is_expr(&val) {
+                    let n = expr::eval_raw(&val)?;
+                    self.constant(name, &n.to_string(), origin)
+                } else {
+                    self.constant(name, &val, origin)
+                }
+            } else if let Some(l) = self.substitute(trimmed) {
+                self.emit(&l, origin)
+            } else {
+                self.emit(line, origin)
+            }
+        }
+    }
+    fn emit(&mut self, line: &str, origin: Origin) -> Result<(), Error> {
+        let trimmed = line.trim();
+        if let Some(b) = directive(trimmed, "bits") {
+            if let Ok(b) = b.parse() {
+                self.bits = b;
+            }
+        } else if let Some(t) = directive(trimmed, "target") {
+            self.target = t.into();
+        }
+        match self.locals(trimmed)? {
+            Some(l) => self.src.push(&l, origin),
+            None => self.src.push(line, origin),
+        }
+        Ok(())
+    }
+    // prefixes local labels (`.NAME`) with name of last non-local label (`parent.NAME`);
+    // returns `None` if line did not change
+    fn locals(&mut self, line: &str) -> Result<Option<String>, Error> {
+        if let Some(name) = line.strip_suffix(':') {
+            if name.starts_with('.') {
+                return Ok(Some(format!("{}:", self.local(name)?)));
+            }
+            // labels generated by `%%LABEL` (starting with `$`) and numeric labels do not change scope
+            if name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+                self.scope = Some(name.into());
+            }
+            return Ok(None);
+        }
+        if !line.contains("@[") {
+            return Ok(None);
+        }
+        let mut out = String::with_capacity(line.len());
+        let mut changed = false;
+        let bytes = line.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let start = i;
+            match bytes[i] {
+                q @ (b'"' | b'\'') => {
+                    i += 1;
+                    while i < bytes.len() && bytes[i] != q {
+                        i += 1;
+                    }
+                    i = (i + 1).min(bytes.len());
+                }
+                b'@' if bytes.get(i + 1) == Some(&b'[') => {
+                    i += 2;
+                    let sym = line[i..].trim_start();
+                    if sym.starts_with('.') {
+                        let len = sym.find([',', ']', ' ', '\t']).unwrap_or(sym.len());
+                        out.push_str(&line[start..line.len() - sym.len()]);
+                        out.push_str(&self.local(&sym[..len])?);
+                        i = line.len() - sym.len() + len;
+                        changed = true;
+                        continue;
+                    }
+                }
+                b';' => i = bytes.len(),
+                b'/' if bytes.get(i + 1) == Some(&b'/') => i = bytes.len(),
+                _ => i += 1,
+            }
+            out.push_str(&line[start..i]);
+        }
+        Ok(if changed { Some(out) } else { None })
+    }
+    fn local(&self, name: &str) -> Result<String, Error> {
+        if !is_ident(&name[1..]) {
+            return Err(Error::new(format!("invalid local label `{name}`"), 8));
+        }
+        match &self.scope {
+            Some(scope) => Ok(format!("{scope}{name}")),
+            None => Err(Error::new(
+                format!("local label `{name}` is used before any non-local label"),
+                8,
+            )),
+        }
+    }
+    // if EXPR / ifdef NAME / ifndef NAME / elif EXPR / else / endif
+    fn cond(&mut self, word: &str, args: &str, origin: Origin) -> Result<(), Error> {
+        match word {
+            "if" | "ifdef" | "ifndef" => {
+                let state = if self
+                    .conds
+                    .last()
+                    .is_some_and(|c| c.state != CondState::Active)
+                {
+                    CondState::Skip
+                } else if self.cond_eval(word, args)? {
+                    CondState::Active
+                } else {
+                    CondState::Pending
+                };
+                self.conds.push(Cond {
+                    state,
+                    else_seen: false,
+                    origin,
+                });
+            }
+            "elif" | "else" => {
+                let c = match self.conds.last() {
+                    Some(c) => c,
+                    None => return Err(Error::new(format!("`{word}` without matching `if`"), 16)),
+                };
+                if c.else_seen {
+                    return Err(Error::new(format!("`{word}` after `else`"), 16));
+                }
+                let state = match c.state {
+                    CondState::Active => CondState::Done,
+                    CondState::Pending if word == "else" => CondState::Active,
+                    CondState::Pending => {
+                        if self.cond_eval("if", args)? {
+                            CondState::Active
+                        } else {
+                            CondState::Pending
+                        }
+                    }
+                    s => s,
+                };
+                let c = self.conds.last_mut().unwrap();
+                c.state = state;
+                c.else_seen = word == "else";
+            }
+            _ => {
+                if self.conds.pop().is_none() {
+                    return Err(Error::new("`endif` without matching `if`", 16));
+                }
+            }
+        }
+        Ok(())
+    }
+    fn cond_eval(&self, word: &str, args: &str) -> Result<bool, Error> {
+        let args = split_args(args).join(", ");
+        if word != "if" {
+            if !is_const_ident(&args) {
+                return Err(Error::new(
+                    format!("`{word}` requires name, found `{args}`"),
+                    16,
+                ));
+            }
+            return Ok(self.is_defined(&args) == (word == "ifdef"));
+        }
+        if args.is_empty() {
+            return Err(Error::new("condition cannot be empty", 16));
+        }
+        // builtin functions: defined(NAME), target(NAME)
+        let mut cond = String::with_capacity(args.len());
+        let mut rest = args.as_str();
+        while let Some(i) = rest.find(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            cond.push_str(&rest[..i]);
+            rest = &rest[i..];
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+                .unwrap_or(rest.len());
+            let (name, after) = rest.split_at(len);
+            let arg = after
+                .trim_start()
+                .strip_prefix('(')
+                .and_then(|a| a.split_once(')'));
+            match (name, arg) {
+                ("defined", Some((arg, after))) => {
+                    cond.push(if self.is_defined(arg.trim()) {
+                        '1'
+                    } else {
+                        '0'
+                    });
+                    rest = after;
+                }
+                ("target", Some((arg, after))) => {
+                    let t = arg.trim().eq_ignore_ascii_case(&self.target);
+                    cond.push(if t { '1' } else { '0' });
+                    rest = after;
+                }
+                _ => {
+                    cond.push_str(name);
+                    rest = after;
+                }
+            }
+        }
+        cond.push_str(rest);
+        let cond = self.replace(&cond).unwrap_or(cond);
+        Ok(expr::eval_raw(&cond)? != 0)
+    }
+    fn is_defined(&self, name: &str) -> bool {
+        name == "__BITS__"
+            || self.consts.contains_key(name)
+            || self.macros.contains_key(name)
+            || self.structs.contains_key(name)
+    }
+    // checks if every conditional block opened after `conds`-th was closed
+    // (blocks cannot span across files and macro expansions)
+    fn check_conds(&self, conds: usize) -> Result<(), Error> {
+        if let Some(c) = self.conds.get(conds) {
+            let mut e = Error::new("conditional block is not closed with `endif`", 16);
+            self.src.locate_origin(&mut e, c.origin);
+            return Err(e);
+        }
+        Ok(())
+    }
+    // define NAME [VALUE] / NAME equ VALUE
+    fn constant(&mut self, name: &str, val: &str, origin: Origin) -> Result<(), Error> {
+        if !is_const_ident(name) {
+            return Err(Error::new(format!("invalid constant name `{name}`"), 14));
+        }
+        if Mnemonic::from_str(name).is_ok()
+            || Register::from_str(name).is_ok()
+            || Size::from_str(name).is_ok()
+        {
+            return Err(Error::new(
+                format!("constant name `{name}` collides with mnemonic, register or size"),
+                14,
+            ));
+        }
+        if let Some(c) = self.consts.get(name) {
+            return Err(Error::new(
+                format!(
+                    "constant `{name}` is already defined at {}:{}",
+                    self.src.file(c.origin.file),
+                    c.origin.line
+                ),
+                14,
+            ));
+        }
+        let val = split_args(val).join(", ");
+        let val = self.replace(&val).unwrap_or(val);
+        // keep precedence of substituted expression: `define X 1 + 2` and `X * 3` give 9
+        let val = if expr::is_expr(&val) {
+            format!("({val})")
+        } else {
+            val
+        };
+        self.consts.insert(
+            name.into(),
+            Const {
+                val: val.into(),
+                origin,
+            },
+        );
+        Ok(())
+    }
+    // replaces constants in line with their values; returns `None` if line did not change.
+    // first word (mnemonic/directive), labels, strings, symbol references (`@[...]`),
+    // subexpressions and comments are left untouched
+    fn substitute(&self, line: &str) -> Option<String> {
+        if (self.consts.is_empty() && !line.contains("__BITS__")) || line.ends_with(':') {
+            return None;
+        }
+        let (first, rest) = match line.split_once([' ', '\t']) {
+            Some(s) => s,
+            None => (line, ""),
+        };
+        if SYMBOL_DIRECTIVES.contains(&first) {
+            return None;
+        }
+        let replaced = self.replace(rest)?;
+        Some(format!("{}{replaced}", &line[..line.len() - rest.len()]))
+    }
+    // replaces every constant in `line` (see `substitute`)
+    fn replace(&self, line: &str) -> Option<String> {
+        let mut out = String::with_capacity(line.len());
+        let mut changed = false;
+        let bytes = line.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let start = i;
+            match bytes[i] {
+                q @ (b'"' | b'\'') => {
+                    i += 1;
+                    while i < bytes.len() && bytes[i] != q {
+                        i += 1;
+                    }
+                    i = (i + 1).min(bytes.len());
+                }
+                b'@' if bytes.get(i + 1) == Some(&b'[') => {
+                    i += line[i..].find(']').map(|e| e + 1).unwrap_or(line.len() - i);
+                }
+                b'{' => {
+                    i += line[i..].find('}').map(|e| e + 1).unwrap_or(line.len() - i);
+                }
+                b';' => i = bytes.len(),
+                b'/' if bytes.get(i + 1) == Some(&b'/') => i = bytes.len(),
+                b if b.is_ascii_alphanumeric() || b == b'_' || b == b'.' => {
+                    while i < bytes.len()
+                        && (bytes[i].is_ascii_alphanumeric()
+                            || bytes[i] == b'_'
+                            || bytes[i] == b'.')
+                    {
+                        i += 1;
+                    }
+                    if is_const_ident(&line[start..i]) {
+                        if let Some(c) = self.consts.get(&line[start..i]) {
+                            out.push_str(&c.val);
+                            changed = true;
+                            continue;
+                        } else if &line[start..i] == "__BITS__" {
+                            out.push_str(&self.bits.to_string());
+                            changed = true;
+                            continue;
+                        }
+                    }
+                }
+                _ => i += 1,
+            }
+            out.push_str(&line[start..i]);
+        }
+        if changed {
+            Some(out)
+        } else {
+            None
+        }
+    }
+    // struct NAME
+    fn structure(&mut self, name: &str, origin: Origin) -> Result<(), Error> {
+        if !is_ident(name) {
+            return Err(Error::new(format!("invalid struct name `{name}`"), 17));
+        }
+        if Mnemonic::from_str(name).is_ok()
+            || Register::from_str(name).is_ok()
+            || Size::from_str(name).is_ok()
+            || self.macros.contains_key(name)
+        {
+            return Err(Error::new(
+                format!("struct name `{name}` collides with mnemonic, register, size or macro"),
+                17,
+            ));
+        }
+        if let Some(st) = self.structs.get(name) {
+            return Err(Error::new(
+                format!(
+                    "struct `{name}` is already defined at {}:{}",
+                    self.src.file(st.origin.file),
+                    st.origin.line
+                ),
+                17,
+            ));
+        }
+        self.structure = Some((
+            name.into(),
+            Struct {
+                fields: Vec::new(),
+                size: 0,
+                align: 1,
+                origin,
+            },
+        ));
+        Ok(())
+    }
+    // SIZE NAME[, COUNT] (SIZE can also be name of other struct)
+    fn field(&mut self, line: &str) -> Result<(), Error> {
+        let args = split_args(line);
+        let (decl, count) = match args.as_slice() {
+            [] => return Ok(()),
+            [decl] => (decl, 1),
+            [decl, count] => (decl, self.count(count)?),
+            _ => return Err(Error::new(format!("invalid field `{line}`"), 17)),
+        };
+        let (ty, name) = match decl.split_once([' ', '\t']) {
+            Some((ty, name)) => (ty, name.trim()),
+            None => {
+                return Err(Error::new(
+                    format!("field `{decl}` requires size and name"),
+                    17,
+                ))
+            }
+        };
+        // field is aligned to its size (or to alignment of struct), so layout matches C
+        let (size, align) = match (Size::from_str(ty), self.structs.get(ty)) {
+            (Ok(sz), _) if u8::from(sz) != 0 => {
+                let sz = u8::from(sz) as u64;
+                (sz, 1 << sz.trailing_zeros())
+            }
+            (_, Some(st)) => (st.size, st.align),
+            _ => {
+                return Err(Error::new(
+                    format!("unknown size or struct `{ty}` of field `{name}`"),
+                    17,
+                ))
+            }
+        };
+        let st = match &mut self.structure {
+            Some((_, st)) => st,
+            None => return Ok(()),
+        };
+        if !is_ident(name) || name == "size" {
+            return Err(Error::new(format!("invalid field name `{name}`"), 17));
+        }
+        if st.fields.iter().any(|f| &*f.name == name) {
+            return Err(Error::new(format!("duplicate field `{name}`"), 17));
+        }
+        let offset = st.size.next_multiple_of(align);
+        st.fields.push(Field {
+            name: name.into(),
+            offset,
+            size,
+            count,
+        });
+        st.size = offset + size * count;
+        st.align = st.align.max(align);
+        Ok(())
+    }
+    // defines `NAME.FIELD` and `NAME.size` constants
+    fn endstruct(&mut self) -> Result<(), Error> {
+        let (name, mut st) = match self.structure.take() {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+        // trailing padding, so elements of array are aligned too
+        st.size = st.size.next_multiple_of(st.align);
+        for f in &st.fields {
+            self.constant(
+                &format!("{name}.{}", f.name),
+                &f.offset.to_string(),
+                st.origin,
+            )?;
+        }
+        self.constant(&format!("{name}.size"), &st.size.to_string(), st.origin)?;
+        self.structs.insert(name, st);
+        Ok(())
+    }
+    // NAME [VALUE, ...] [FIELD=VALUE, ...]
+    fn instance(&mut self, name: &str, args: &str, origin: Origin) -> Result<(), Error> {
+        let st = &self.structs[name];
+        let mut vals: Vec<Option<&str>> = vec![None; st.fields.len()];
+        for (i, arg) in split_args(args).into_iter().enumerate() {
+            let named = match arg.split_once('=') {
+                Some((f, v)) if is_ident(f.trim()) => {
+                    match st.fields.iter().position(|x| &*x.name == f.trim()) {
+                        Some(idx) => Some((idx, v.trim())),
+                        None => {
+                            return Err(Error::new(
+                                format!("struct `{name}` has no field `{}`", f.trim()),
+                                17,
+                            ))
+                        }
+                    }
+                }
+                _ => None,
+            };
+            let (idx, val) = match named {
+                Some(n) => n,
+                None if i < vals.len() => (i, arg),
+                None => {
+                    return Err(Error::new(
+                        format!("too many values for struct `{name}`"),
+                        17,
+                    ))
+                }
+            };
+            if vals[idx].is_some() {
+                return Err(Error::new(
+                    format!("field `{}` is initialized twice", st.fields[idx].name),
+                    17,
+                ));
+            }
+            vals[idx] = Some(val);
+        }
+        let mut lines: Vec<String> = Vec::new();
+        // padding and uninitialized fields are zeroed
+        let mut zeros = 0;
+        let mut end = 0;
+        for (f, v) in st.fields.iter().zip(vals) {
+            zeros += f.offset - end;
+            end = f.offset + f.size * f.count;
+            let v = match v {
+                Some(v) => v,
+                None => {
+                    zeros += f.size * f.count;
+                    continue;
+                }
+            };
+            let data = match (f.size, f.count) {
+                (1, 1) => "bytele",
+                (2, 1) => "wordle",
+                (4, 1) => "dwordle",
+                (8, 1) => "qwordle",
+                _ => {
+                    return Err(Error::new(
+                        format!(
+                            "field `{}` cannot be initialized (only single byte, word, dword or qword can)",
+                            f.name
+                        ),
+                        17,
+                    ))
+                }
+            };
+            empty(&mut lines, zeros);
+            zeros = 0;
+            lines.push(format!("{data} {v}"));
+        }
+        empty(&mut lines, zeros + st.size - end);
+        for l in lines {
+            self.line_inner(&l, origin)?;
+        }
+        Ok(())
+    }
+    // macro NAME [PARAM[=DEFAULT], ...] [PARAM...]
+    fn define(&mut self, args: &str, origin: Origin) -> Result<(), Error> {
+        let (name, args) = args.split_once([' ', '\t']).unwrap_or((args, ""));
+        if !is_ident(name) {
+            return Err(Error::new(format!("invalid macro name `{name}`"), 13));
+        }
+        if Mnemonic::from_str(name).is_ok() || matches!(name, "include" | "macro" | "endmacro") {
+            return Err(Error::new(
+                format!("macro name `{name}` collides with mnemonic or directive"),
+                13,
+            ));
+        }
+        if self.structs.contains_key(name) {
+            return Err(Error::new(
+                format!("macro name `{name}` collides with struct"),
+                13,
+            ));
+        }
+        if let Some(m) = self.macros.get(name) {
+            return Err(Error::new(
+                format!(
+                    "macro `{name}` is already defined at {}:{}",
+                    self.src.file(m.origin.file),
+                    m.origin.line
+                ),
+                13,
+            ));
+        }
+        let mut params: Vec<Param> = Vec::new();
+        for p in split_args(args) {
+            if params.last().is_some_and(|p| p.variadic) {
+                return Err(Error::new(
+                    format!("variadic parameter must be last parameter of macro `{name}`"),
+                    13,
+                ));
+            }
+            let (pname, default, variadic) = if let Some(p) = p.strip_suffix("...") {
+                (p.trim(), None, true)
+            } else if let Some((p, d)) = p.split_once('=') {
+                (p.trim(), Some(d.trim().into()), false)
+            } else {
+                (p, None, false)
+            };
+            if !is_ident(pname) {
+                return Err(Error::new(
+                    format!("invalid parameter name `{pname}` in macro `{name}`"),
+                    13,
+                ));
+            }
+            if params.iter().any(|p| &*p.name == pname) {
+                return Err(Error::new(
+                    format!("duplicate parameter `{pname}` in macro `{name}`"),
+                    13,
+                ));
+            }
+            params.push(Param {
+                name: pname.into(),
+                default,
+                variadic,
+            });
+        }
+        self.defining = Some((
+            name.into(),
+            Macro {
+                params,
+                body: Vec::new(),
+                origin,
+            },
+        ));
+        Ok(())
+    }
+    fn expand(&mut self, name: &str, args: &str, origin: Origin) -> Result<(), Error> {
+        let exp = self.expansion(format!("macro `{name}`"), origin)?;
+        let m = &self.macros[name];
+        let vals = bind(name, m, args)?;
+        let body: Vec<(String, Origin)> = m
+            .body
+            .iter()
+            .map(|(l, o)| (subst(l, &m.params, &vals, exp), Origin { exp, ..*o }))
+            .collect();
+        self.run(body)
+    }
+    // rept COUNT[, COUNTER]
+    fn rept(&mut self, args: &str, origin: Origin) -> Result<(), Error> {
+        let args = split_args(args);
+        let (count, counter) = match args.as_slice() {
+            [count] => (count, None),
+            [count, counter] => (count, Some(*counter)),
+            _ => {
+                return Err(Error::new(
+                    "`rept` requires count and optionally name of counter",
+                    13,
+                ))
+            }
+        };
+        if counter.is_some_and(|c| !is_ident(c)) {
+            return Err(Error::new(
+                format!("invalid counter name `{}`", counter.unwrap_or_default()),
+                13,
+            ));
+        }
+        let count = self.count(count)?;
+        self.repeating = Some(Rept {
+            count,
+            counter: counter.map(|c| Param {
+                name: c.into(),
+                default: None,
+                variadic: false,
+            }),
+            body: Vec::new(),
+            nested: 0,
+            origin,
+        });
+        Ok(())
+    }
+    fn repeat(&mut self, r: Rept) -> Result<(), Error> {
+        let params = match r.counter {
+            Some(c) => vec![c],
+            None => Vec::new(),
+        };
+        for i in 0..r.count {
+            let exp = self.expansion("`rept` block".into(), r.origin)?;
+            let vals = [i.to_string()];
+            let body: Vec<(String, Origin)> = r
+                .body
+                .iter()
+                .map(|(l, o)| (subst(l, &params, &vals, exp), Origin { exp, ..*o }))
+                .collect();
+            self.run(body)?;
+        }
+        Ok(())
+    }
+    // times COUNT LINE
+    fn times(&mut self, args: &str, origin: Origin) -> Result<(), Error> {
+        // count is either in parentheses or ends at first whitespace
+        let end = if args.starts_with('(') {
+            let mut depth = 0usize;
+            args.find(|c| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })
+            .map(|i| i + 1)
+            .unwrap_or(args.len())
+        } else {
+            args.find([' ', '\t']).unwrap_or(args.len())
+        };
+        let (count, line) = args.split_at(end);
+        let line = line.trim();
+        if line.is_empty() {
+            return Err(Error::new("`times` requires count and line to repeat", 13));
+        }
+        for _ in 0..self.count(count)? {
+            self.line_inner(line, origin)?;
+        }
+        Ok(())
+    }
+    // evaluates repetition count
+    fn count(&self, count: &str) -> Result<u64, Error> {
+        let count = self.replace(count).unwrap_or(count.to_string());
+        let n = expr::eval_raw(&count)?;
+        if n < 0 || n > u32::MAX as i128 {
+            return Err(Error::new(format!("invalid repetition count {n}"), 13));
+        }
+        Ok(n as u64)
+    }
+    // registers new expansion and returns its index (for `Origin.exp`)
+    fn expansion(&mut self, name: String, at: Origin) -> Result<u32, Error> {
+        if self.depth >= MAX_MACRO_DEPTH {
+            return Err(Error::new(
+                format!("macro expansions are nested deeper than {MAX_MACRO_DEPTH} levels"),
+                13,
+            ));
+        }
+        self.src.exps.push(Expansion {
+            name: name.into(),
+            at,
+        });
+        Ok(self.src.exps.len() as u32)
+    }
+    // assembles lines of expansion
+    fn run(&mut self, body: Vec<(String, Origin)>) -> Result<(), Error> {
+        self.depth += 1;
+        let conds = self.conds.len();
+        let repeating = self.repeating.is_some();
+        for (l, o) in body {
+            self.line(&l, o)?;
+        }
+        self.check_conds(conds)?;
+        if !repeating {
+            if let Some(r) = &self.repeating {
+                let mut e = Error::new("`rept` block is not closed with `endrept`", 13);
+                self.src.locate_origin(&mut e, r.origin);
+                return Err(e);
+            }
+        }
+        self.depth -= 1;
+        Ok(())
+    }
+    fn include(&mut self, from: &Path, ipath: &str) -> Result<(), Error> {
+        let ipath = unquote(ipath);
+        if ipath.is_empty() {
+            return Err(Error::new("include directive requires path to a file", 12));
+        }
+        let path = if let Some(p) = resolve(from, ipath, self.ipaths) {
+            p
+        } else {
+            return Err(Error::new(
+                format!("could not find file \"{ipath}\" in include search paths"),
+                12,
+            ));
+        };
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+        if self.stack.contains(&canonical) {
+            let mut cycle = String::new();
+            for p in &self.stack {
+                cycle.push_str(&p.to_string_lossy());
+                cycle.push_str(" -> ");
+            }
+            cycle.push_str(&canonical.to_string_lossy());
+            return Err(Error::new(format!("include cycle detected: {cycle}"), 12));
+        }
+        if self.stack.len() >= MAX_INCLUDE_DEPTH {
+            return Err(Error::new(
+                format!("includes are nested deeper than {MAX_INCLUDE_DEPTH} levels"),
+                12,
+            ));
+        }
+        let content = match fs::read(&path) {
+            Ok(c) => c,
+            Err(e) => {
+                return Err(Error::new(
+                    format!("could not read file \"{}\": {e}", path.to_string_lossy()),
+                    6,
+                ))
+            }
+        };
+        self.file(&path, &content)
+    }
+}
+
+// searches for included file in (in order):
+// - directory of file that includes it
+// - current working directory
+// - include search paths (`-I` flag)
+fn resolve(from: &Path, ipath: &str, ipaths: &[PathBuf]) -> Option<PathBuf> {
+    let ipath = Path::new(ipath);
+    if ipath.is_absolute() {
+        return if ipath.is_file() {
+            Some(ipath.to_path_buf())
+        } else {
+            None
+        };
+    }
+    let dir = from.parent().unwrap_or(Path::new(""));
+    let path = dir.join(ipath);
+    if path.is_file() {
+        return Some(path);
+    }
+    if ipath.is_file() {
+        return Some(ipath.to_path_buf());
+    }
+    for dir in ipaths {
+        let path = dir.join(ipath);
+        if path.is_file() {
+            return Some(path);
+        }
+    }
+    None
+}
+
+// assigns arguments to macro parameters:
+// - named arguments (`PARAM=VALUE`) first,
+// - then positional ones (in order, skipping already assigned parameters),
+// - remaining positional arguments go to variadic parameter (separated with `, `)
+fn bind(name: &str, m: &Macro, args: &str) -> Result<Vec<String>, Error> {
+    let mut vals: Vec<Option<String>> = vec![None; m.params.len()];
+    let mut positional = Vec::new();
+    for a in split_args(args) {
+        let named = a
+            .split_once('=')
+            .filter(|(_, v)| !v.starts_with('='))
+            .and_then(|(n, v)| Some((m.params.iter().position(|p| &*p.name == n.trim())?, v)));
+        if let Some((i, v)) = named {
+            if vals[i].is_some() {
+                return Err(Error::new(
+                    format!(
+                        "parameter `{}` of macro `{name}` was provided more than once",
+                        m.params[i].name
+                    ),
+                    13,
+                ));
+            }
+            vals[i] = Some(v.trim().to_string());
+        } else {
+            positional.push(a);
+        }
+    }
+    let mut positional = positional.into_iter();
+    for (i, p) in m.params.iter().enumerate() {
+        if vals[i].is_some() {
+            continue;
+        }
+        if p.variadic {
+            vals[i] = Some(positional.by_ref().collect::<Vec<_>>().join(", "));
+        } else if let Some(a) = positional.next() {
+            vals[i] = Some(a.to_string());
+        } else if let Some(d) = &p.default {
+            vals[i] = Some(d.to_string());
+        } else {
+            return Err(Error::new(
+                format!(
+                    "macro `{name}` requires argument for parameter `{}`",
+                    p.name
+                ),
+                13,
+            ));
+        }
+    }
+    if positional.next().is_some() {
+        return Err(Error::new(
+            format!(
+                "too many arguments for macro `{name}` (expected at most {})",
+                m.params.len()
+            ),
+            13,
+        ));
+    }
+    Ok(vals.into_iter().map(|v| v.unwrap_or_default()).collect())
+}
+
+// replaces `%PARAM` with its value and `%%LABEL` with label unique for expansion
+fn subst(line: &str, params: &[Param], vals: &[String], exp: u32) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(i) = rest.find('%') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i + 1..];
+        let (local, r) = match rest.strip_prefix('%') {
+            Some(r) => (true, r),
+            None => (false, rest),
+        };
+        let len = r
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(r.len());
+        let ident = &r[..len];
+        if local && is_ident(ident) {
+            // `$` cannot be used in labels written in source, so it does not collide with them
+            out.push_str(&format!("${ident}_{exp}"));
+            rest = &r[len..];
+        } else if let Some(p) = params
+            .iter()
+            .position(|p| &*p.name == ident)
+            .filter(|_| !local)
+        {
+            out.push_str(&vals[p]);
+            rest = &r[len..];
+        } else {
+            out.push('%');
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// splits arguments on `,`, but not when inside of quotes or brackets;
+// stops at comment
+fn split_args(s: &str) -> Vec<&str> {
+    let mut args = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+    let mut end = s.len();
+    let bytes = s.as_bytes();
+    for (i, &b) in bytes.iter().enumerate() {
+        if let Some(q) = quote {
+            if b == q {
+                quote = None;
+            }
+            continue;
+        }
+        match b {
+            b'"' | b'\'' => quote = Some(b),
+            b'[' | b'(' | b'{' => depth += 1,
+            b']' | b')' | b'}' => depth = depth.saturating_sub(1),
+            b',' if depth == 0 => {
+                args.push(s[start..i].trim());
+                start = i + 1;
+            }
+            b';' => {
+                end = i;
+                break;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                end = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    let last = s[start..end].trim();
+    if !last.is_empty() || !args.is_empty() {
+        args.push(last);
+    }
+    args
+}
+
+fn is_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// identifier, that can also contain `.` (but not at start or end), like `Struct.field`
+fn is_const_ident(s: &str) -> bool {
+    !s.ends_with('.') && s.split('.').all(is_ident)
+}
+
+// pushes `empty` lines, that emit `n` zeroed bytes
+fn empty(lines: &mut Vec<String>, mut n: u64) {
+    while n > 0 {
+        let len = n.min(u16::MAX as u64);
+        lines.push(format!("empty {len}"));
+        n -= len;
+    }
+}
+
+// returns arguments of directive if line starts with it
+fn directive<'a>(line: &'a str, name: &str) -> Option<&'a str> {
+    let rest = line.strip_prefix(name)?;
+    if rest.is_empty() {
+        Some(rest)
+    } else if rest.starts_with([' ', '\t']) {
+        Some(rest.trim())
+    } else {
+        None
+    }
+}
+
+// strips quotes (and everything after closing quote, like comments)
+fn unquote(s: &str) -> &str {
+    if let Some(s) = s.strip_prefix('"') {
+        s.split_once('"').map(|(s, _)| s).unwrap_or(s)
+    } else {
+        s.split_whitespace().next().unwrap_or("")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn source(content: &str) -> Result<Source, Error> {
+        Source::from_content(Path::new("test.asm"), content.as_bytes(), &[])
+    }
+    fn tmp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("pasm-prp-{name}-{}", std::process::id()));
+        let _ = fs::create_dir_all(&dir);
+        dir
+    }
+    #[test]
+    fn tinclude_0() {
+        let dir = tmp_dir("include");
+        let idir = dir.join("inc");
+        let _ = fs::create_dir_all(&idir);
+        fs::write(dir.join("main.asm"), "bits 64\ninclude \"consts.asm\"\nret").unwrap();
+        fs::write(idir.join("consts.asm"), "nop\n\tinclude sub.asm\n").unwrap();
+        fs::write(idir.join("sub.asm"), "syscall").unwrap();
+        let src = Source::new(&dir.join("main.asm"), std::slice::from_ref(&idir)).unwrap();
+        assert_eq!(src.buf, "bits 64\nnop\nsyscall\nret\n");
+        assert_eq!(
+            src.origin(0),
+            Some(Origin {
+                file: 0,
+                line: 1,
+                exp: 0
+            })
+        );
+        assert_eq!(
+            src.origin(1),
+            Some(Origin {
+                file: 1,
+                line: 1,
+                exp: 0
+            })
+        );
+        assert_eq!(
+            src.origin(2),
+            Some(Origin {
+                file: 2,
+                line: 1,
+                exp: 0
+            })
+        );
+        assert_eq!(
+            src.origin(3),
+            Some(Origin {
+                file: 0,
+                line: 3,
+                exp: 0
+            })
+        );
+        assert!(src.file(2).ends_with("sub.asm"));
+        let _ = fs::remove_dir_all(dir);
+    }
+    #[test]
+    fn tinclude_1() {
+        let dir = tmp_dir("cycle");
+        fs::write(dir.join("a.asm"), "nop\ninclude \"b.asm\"").unwrap();
+        fs::write(dir.join("b.asm"), "\n\ninclude \"a.asm\"").unwrap();
+        let e = Source::new(&dir.join("a.asm"), &[]).unwrap_err();
+        assert!(e.msg().starts_with("include cycle detected"));
+        assert_eq!(e.get_line(), Some(3));
+        assert!(e.get_file().unwrap().ends_with("b.asm"));
+        let e = Source::new(&dir.join("b.asm"), &[]).unwrap_err();
+        assert!(e.get_file().unwrap().ends_with("a.asm"));
+        fs::write(dir.join("c.asm"), "include \"none.asm\"").unwrap();
+        let e = Source::new(&dir.join("c.asm"), &[]).unwrap_err();
+        assert_eq!(e, Error::new("", 12));
+        assert_eq!(e.get_line(), Some(1));
+        let _ = fs::remove_dir_all(dir);
+    }
+    #[test]
+    fn tmacro_0() {
+        let src = source(
+            "macro sys nr, a=0, rest...\n\tmov rax, %nr\n\tmov rdi, %a\n\tdb %rest\n%%l:\nendmacro\n\
+            macro exit code\n\tsys 60, %code ; exit\nendmacro\n\
+            sys 1, 2, 3, [rax, 4]\nsys a=5, nr=2\nexit 7\n",
+        )
+        .unwrap();
+        assert_eq!(
+            src.buf,
+            "\tmov rax, 1\n\tmov rdi, 2\n\tdb 3, [rax, 4]\n$l_1:\n\
+            \tmov rax, 2\n\tmov rdi, 5\n\tdb \n$l_2:\n\
+            \tmov rax, 60\n\tmov rdi, 7\n\tdb \n$l_4:\n"
+        );
+        assert_eq!(
+            src.origin(0),
+            Some(Origin {
+                file: 0,
+                line: 2,
+                exp: 1
+            })
+        );
+        let mut e = Error::new("", 0);
+        src.locate(&mut e, 8);
+        assert_eq!(e.get_line(), Some(2));
+        let notes: Vec<&str> = e.get_notes().collect();
+        assert_eq!(notes.len(), 2);
+        assert!(notes[0].starts_with("in expansion of macro `sys` at") && notes[0].ends_with(":8"));
+        assert!(
+            notes[1].starts_with("in expansion of macro `exit` at") && notes[1].ends_with(":12")
+        );
+    }
+    #[test]
+    fn tmacro_1() {
+        let err = |content: &str| source(content).unwrap_err();
+        let e = err("macro m a\nnop\nendmacro\nm\n");
+        assert_eq!(e, Error::new("", 13));
+        assert_eq!(e.get_line(), Some(4));
+        assert_eq!(err("macro m a\nendmacro\nm 1, 2\n").get_line(), Some(3));
+        assert_eq!(err("nop\nmacro m\nnop\n").get_line(), Some(2));
+        assert_eq!(err("endmacro\n").get_line(), Some(1));
+        assert_eq!(err("macro mov\nendmacro\n").get_line(), Some(1));
+        assert_eq!(err("macro m a..., b\nendmacro\n").get_line(), Some(1));
+        assert_eq!(
+            err("macro m\nendmacro\nmacro m\nendmacro\n").get_line(),
+            Some(3)
+        );
+        let e = err("macro m\nm\nendmacro\nm\n");
+        assert_eq!(e.get_line(), Some(2));
+        assert_eq!(e.get_notes().count(), MAX_MACRO_DEPTH);
+    }
+    #[test]
+    fn tconst_0() {
+        let src = source(
+            "define A 1 ; one\nB equ A\ndefine S.len 16\nmacro m x\n\tmov %x, B\nendmacro\n\
+            A:\npublic A\nm rax\nmov rcx, qword [rax + S.len]\nstring \"A\"\ncall @[A]\n\
+            mov rdx, 0xA\nalign S.len\n",
+        )
+        .unwrap();
+        assert_eq!(
+            src.buf,
+            "A:\npublic A\nmov rax, 1\nmov rcx, qword [rax + 16]\nstring \"A\"\n\
+            call @[A]\nmov rdx, 0xA\nalign 16\n"
+        );
+        let src = source("define X 1 + 2\ndefine Y X * 2\nmov rax, X * 3\nmov rax, Y\n").unwrap();
+        assert_eq!(src.buf, "mov rax, (1 + 2) * 3\nmov rax, ((1 + 2) * 2)\n");
+        for (content, line) in [
+            ("define A 1\nA equ 2\n", 2),
+            ("nop\ndefine rax 1\n", 2),
+            ("define qword 1\n", 1),
+            ("define 1A 1\n", 1),
+            ("A equ\n", 1),
+        ] {
+            let e = source(content).unwrap_err();
+            assert_eq!(e, Error::new("", 14));
+            assert_eq!(e.get_line(), Some(line));
+        }
+    }
+    #[test]
+    fn tcond_0() {
+        let src = source(
+            "define A 2\nbits 64\ntarget elf64\n\
+            if A == 1\nnop\nelif A == 2 && __BITS__ == 64\n\
+            if defined(B) || !target(elf64)\nl0:\nelse\nl1:\nendif\n\
+            else\nl2:\nendif\n\
+            ifndef B\nmacro m\nifdef A\nl3:\nendif\nendmacro\nendif\n\
+            ifdef B\nmacro m\nendmacro\nendif\nm\n",
+        )
+        .unwrap();
+        assert_eq!(src.buf, "bits 64\ntarget elf64\nl1:\nl3:\n");
+        for (content, line) in [
+            ("nop\nendif\n", 2),
+            ("if 1\nelse\nelse\nendif\n", 3),
+            ("if 1\nnop\n", 1),
+            ("if\nendif\n", 1),
+            ("if UNKNOWN\nendif\n", 1),
+            ("macro m\nif 1\nendmacro\nm\n", 2),
+        ] {
+            let e = source(content).unwrap_err();
+            assert!(e == Error::new("", 16) || e == Error::new("", 15));
+            assert_eq!(e.get_line(), Some(line));
+        }
+    }
+    #[test]
+    fn trept_0() {
+        let src = source(
+            "define N 2\nrept N + 1, i\nrept 2, j\nbytele %i * 2 + %j\nendrept\n%%l:\nendrept\n\
+            times (N * 2) nop\ntimes 0 nop\n",
+        )
+        .unwrap();
+        assert_eq!(
+            src.buf,
+            "bytele 0 * 2 + 0\nbytele 0 * 2 + 1\n$l_1:\n\
+            bytele 1 * 2 + 0\nbytele 1 * 2 + 1\n$l_4:\n\
+            bytele 2 * 2 + 0\nbytele 2 * 2 + 1\n$l_7:\n\
+            nop\nnop\nnop\nnop\n"
+        );
+        for (content, line) in [
+            ("rept 2\nnop\n", 1),
+            ("endrept\n", 1),
+            ("rept -1\nendrept\n", 1),
+            ("times 2\n", 1),
+            ("rept 2\nnop\nendrept\nendrept\n", 4),
+        ] {
+            let e = source(content).unwrap_err();
+            assert_eq!(e, Error::new("", 13));
+            assert_eq!(e.get_line(), Some(line));
+        }
+    }
+    #[test]
+    fn tlocal_0() {
+        let src = source(
+            "macro m\n%%skip:\njmp @[.loop]\nendmacro\n\
+            a:\n.loop:\nm\njmp @[ .loop, rel8]\nb:\n.loop:\n\
+            dwordle @[.end] - @[.loop]\nstring \"@[.loop]\"\n.end:\n",
+        )
+        .unwrap();
+        assert_eq!(
+            src.buf,
+            "a:\na.loop:\n$skip_1:\njmp @[a.loop]\njmp @[ a.loop, rel8]\nb:\nb.loop:\n\
+            dwordle @[b.end] - @[b.loop]\nstring \"@[.loop]\"\nb.end:\n"
+        );
+        // generated labels do not collide with labels written in source
+        let src = source("macro m\n%%l:\nendmacro\nm\n__l_1:\nl_1:\n").unwrap();
+        assert_eq!(src.buf, "$l_1:\n__l_1:\nl_1:\n");
+        for content in [".loop:\n", "jmp @[.loop]\n", "a:\n.1:\n", "a:\n$l_1:\n"] {
+            let e = source(content).unwrap_err();
+            assert_eq!(e, Error::new("", 8));
+            assert_eq!(e.get_line(), Some(1 + content.starts_with("a:") as usize));
+        }
+    }
+    #[test]
+    fn tstruct_0() {
+        let src = source(
+            "define N 2\nstruct P\ndword x\ndword y ; comment\nendstruct\n\
+            struct S\nqword id\nP pos, N\nbyte tag\nendstruct\n\
+            mov rax, qword [rdi + S.tag]\nmov rax, S.size\n\
+            S 1, tag='a'\nS\n",
+        )
+        .unwrap();
+        assert_eq!(
+            src.buf,
+            "mov rax, qword [rdi + 24]\nmov rax, 32\n\
+            qwordle 1\nempty 16\nbytele 'a'\nempty 7\nempty 32\n"
+        );
+        for (content, line) in [
+            ("struct S\nqword a\n", 1),
+            ("struct S\nqword a\nqword a\nendstruct\n", 3),
+            ("struct S\nfoo a\nendstruct\n", 2),
+            ("struct S\nqword a, 2\nendstruct\nS 1\n", 4),
+            ("struct S\nqword a\nendstruct\nS b=1\n", 4),
+            ("struct S\nqword a\nendstruct\nS 1, 2\n", 4),
+            ("endstruct\n", 1),
+        ] {
+            let e = source(content).unwrap_err();
+            assert_eq!(e, Error::new("", 17));
+            assert_eq!(e.get_line(), Some(line));
+        }
+    }
+    #[test]
+    fn tstruct_1() {
+        // fields are aligned like in C
+        let src = source(
+            "struct stat
+qword st_dev
+qword st_ino
+dword st_mode
+byte st_flag
+            qword st_size
+word st_uid
+endstruct
+            struct T
+byte b
+stat st
+endstruct
+            mov rax, stat.st_size
+mov rax, stat.st_uid
+mov rax, stat.size
+            mov rax, T.st
+mov rax, T.size
+stat st_flag=1, st_uid=2
+",
+        )
+        .unwrap();
+        assert_eq!(
+            src.buf,
+            "mov rax, 24\nmov rax, 32\nmov rax, 40\nmov rax, 8\nmov rax, 48\n\
+            empty 20\nbytele 1\nempty 11\nwordle 2\nempty 6\n"
+        );
+    }
+}
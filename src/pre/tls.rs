@@ -0,0 +1,105 @@
+// pasm - src/pre/tls.rs
+// ---------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+// checks if TLS relocations are used in instructions, that linker expects for their access model
+// (linker rewrites these instruction sequences, when it relaxes TLS models)
+
+// general-dynamic sequence has to be 16 bytes long, so that linker can rewrite it:
+// `66 48 8D 3D` + rel32 (`lea rdi`) and `66 66 48 E8` + rel32 (`call __tls_get_addr`)
+pub const TLSGD_LEA_PADDING: [u8; 1] = [0x66];
+pub const TLSGD_CALL_PADDING: [u8; 3] = [0x66, 0x66, 0x48];
+
+use crate::shr::{
+    error::Error,
+    instruction::{Instruction, Operand},
+    mnemonic::Mnemonic,
+    reg::{Purpose, Register},
+    reloc::RelType,
+    size::Size,
+};
+
+pub fn check_tls(ins: &Instruction, bits: u8) -> Result<(), Error> {
+    for (s, idx) in ins.get_symbs().into_iter() {
+        let reltype = match s.reltype() {
+            Some(r) if r.is_tls() => r,
+            _ => continue,
+        };
+        if bits != 64 {
+            return Err(Error::new(
+                format!(
+                    "relocation `{}` can only be used in 64-bit mode",
+                    reltype.name()
+                ),
+                20,
+            ));
+        }
+        let ok = s.sub().is_none()
+            && match reltype {
+                RelType::TLSGD | RelType::TLSLD => {
+                    ins.mnemonic == Mnemonic::LEA
+                        && idx == 1
+                        && s.is_deref()
+                        && matches!(ins.dst(), Some(Operand::Register(Register::RDI)))
+                }
+                RelType::GOTTPOFF => {
+                    matches!(ins.mnemonic, Mnemonic::MOV | Mnemonic::ADD)
+                        && idx == 1
+                        && s.is_deref()
+                        && matches!(ins.dst(), Some(Operand::Register(r))
+                            if r.purpose() == Purpose::General && r.size() == Size::Qword)
+                }
+                // offsets are absolute values, so they cannot be RIP-relative
+                _ => !s.is_deref(),
+            };
+        if !ok {
+            return Err(Error::new(
+                format!(
+                    "relocation `{}` cannot be used in this instruction, expected: {}",
+                    reltype.name(),
+                    shape(reltype)
+                ),
+                20,
+            ));
+        }
+    }
+    Ok(())
+}
+
+// returns true if instruction begins general-dynamic sequence
+pub fn is_tlsgd(ins: &Instruction) -> bool {
+    ins.get_symbs()
+        .into_iter()
+        .any(|(s, _)| s.reltype() == Some(RelType::TLSGD))
+}
+
+// checks if `ins` (instruction directly after `lea rdi, qword @[SYMBOL, tlsgd]`) calls `__tls_get_addr`
+pub fn check_tlsgd_call(ins: Option<&Instruction>) -> Result<(), Error> {
+    let ok = ins.is_some_and(|ins| {
+        ins.mnemonic == Mnemonic::CALL
+            && matches!(ins.get_symbs().first(), Some((s, 0))
+                if s.symbol == "__tls_get_addr"
+                    && !s.is_deref()
+                    && s.sub().is_none()
+                    && matches!(s.reltype(), None | Some(RelType::PLT32 | RelType::REL32)))
+    });
+    if ok {
+        Ok(())
+    } else {
+        Err(Error::new(
+            "`lea rdi, qword @[SYMBOL, tlsgd]` has to be directly followed by `call @[__tls_get_addr, plt32]`",
+            20,
+        ))
+    }
+}
+
+fn shape(reltype: RelType) -> &'static str {
+    match reltype {
+        RelType::TLSGD => "`lea rdi, qword @[SYMBOL, tlsgd]`",
+        RelType::TLSLD => "`lea rdi, qword @[SYMBOL, tlsld]`",
+        RelType::GOTTPOFF => "`mov/add REG64, qword @[SYMBOL, gottpoff]`",
+        RelType::TPOFF32 => "immediate `@[SYMBOL, tpoff32]`",
+        _ => "immediate `@[SYMBOL, dtpoff32]`",
+    }
+}
@@ -0,0 +1,35 @@
+// pasm - src/pre/x32.rs
+// ---------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+// checks if relocations can be used in x32 ABI (`elfx32` target), where pointers are 32-bit:
+// 32-bit absolute relocations are accepted in data and instructions, while 64-bit ones
+// (also implicit `abs64` of `qwordle`) are rejected everywhere
+
+use crate::shr::{error::Error, instruction::Instruction, mnemonic::Mnemonic, reloc::RelType};
+
+pub fn check_x32(ins: &Instruction) -> Result<(), Error> {
+    let qword = matches!(ins.mnemonic, Mnemonic::QWORDLE | Mnemonic::QWORDBE);
+    for (s, _) in ins.get_symbs().into_iter() {
+        // label difference is not address, so it can fill 64-bit field
+        if s.sub().is_some() {
+            continue;
+        }
+        let reltype = match s.reltype() {
+            Some(r) => r,
+            None if qword => RelType::ABS64,
+            None => continue,
+        };
+        if matches!(reltype, RelType::ABS64 | RelType::REL64) {
+            return Err(Error::new(
+                format!(
+                    "relocation `{}` cannot be used in x32 ABI, where pointers are 32-bit (use `abs32u` or `abs32` instead)",
+                    reltype.name()
+                ),
+                20,
+            ));
+        }
+    }
+    Ok(())
+}
@@ -45,6 +45,7 @@ pub const XMM: AType = AType::Register(Register::XMM0, false);
 pub const YMM: AType = AType::Register(Register::YMM0, false);
 pub const ZMM: AType = AType::Register(Register::ZMM0, false);
 pub const K: AType = AType::Register(Register::K0, false);
+pub const TMM: AType = AType::Register(Register::TMM0, false);
 
 pub const MA: AType = AType::Memory(Size::Any, Size::Any, BoolTable8::new());
 pub const M8: AType = AType::Memory(Size::Byte, Size::Any, BoolTable8::new());
@@ -128,6 +129,7 @@ impl std::fmt::Display for AType {
         match self {
             Self::Register(r, true) => write!(f, "{}", r.to_string())?,
             Self::Register(r, false) => match (r.size(), r.purpose()) {
+                (_, Purpose::Tile) => write!(f, "tmm0")?,
                 (Size::Byte, _) => write!(f, "r8")?,
                 (Size::Word, Purpose::General) => write!(f, "r16")?,
                 (Size::Word, Purpose::Sgmnt) => write!(f, "sreg")?,
@@ -4,13 +4,18 @@
 // licensed under MPL 2.0
 
 use crate::cli::CLI;
-use std::fmt::Display;
+use std::{fmt::Display, path::Path};
 
 #[derive(Debug, Clone)]
 pub struct Error {
-    line: u64,
+    // line in file, in which error occured (if it is `None`, then it is unknown)
+    line: Option<u64>,
     msg: Box<str>,
     error_code: u64,
+    // file in which error occured (if it is `None`, then it is input file)
+    file: Option<Box<str>>,
+    // additional lines printed after error (like macro call sites); separated with `\n`
+    notes: Option<Box<str>>,
 }
 
 impl Display for Error {
@@ -24,19 +29,27 @@ impl Display for Error {
     // help: go to `{SOURCE_CODE_REPO}/docs/error-spec.md#e[{ERROR_CODE}]` for more info
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         writeln!(f, "error[a{:04}]: {}", self.error_code, self.msg)?;
-        if self.line != 0 {
-            writeln!(f, "---> at line {}", self.line)?;
-            if let Some(pth) = CLI.infile() {
+        if let Some(line) = self.line {
+            if let Some(file) = &self.file {
+                writeln!(f, "---> at {file}:{line}")?;
+            } else {
+                writeln!(f, "---> at line {line}")?;
+            }
+            let pth = match &self.file {
+                Some(file) => Some(Path::new(&**file)),
+                None => CLI.infile().as_deref(),
+            };
+            if let Some(pth) = pth {
                 // pls don't cancel me for this horrible code, i'll try to optimize this better
                 // later if Rust allows me to use global scope variables, i promise
                 let file_content: Vec<String> = std::fs::read_to_string(pth)
-                    .unwrap()
+                    .unwrap_or_default()
                     .lines()
                     .map(|s| s.to_string())
                     .collect();
-                for i in (self.line - 1)..=(self.line + 1) {
-                    if let Some(l) = file_content.get((i as usize) - 1) {
-                        if i == self.line {
+                for i in line.saturating_sub(1)..=(line + 1) {
+                    if let Some(l) = file_content.get((i as usize).wrapping_sub(1)) {
+                        if i == line {
                             writeln!(f, "\t->| {l}")?;
                         } else {
                             writeln!(f, "\t  | {l}")?;
@@ -45,6 +58,11 @@ impl Display for Error {
                 }
             }
         }
+        if let Some(notes) = &self.notes {
+            for n in notes.lines() {
+                writeln!(f, "---> {n}")?;
+            }
+        }
         Ok(())
     }
 }
@@ -63,18 +81,37 @@ impl Error {
     }
     pub fn new(msg: impl ToString, ecd: u64) -> Self {
         Self {
-            line: 0,
+            line: None,
             msg: msg.to_string().into(),
             error_code: ecd,
+            file: None,
+            notes: None,
         }
     }
     pub fn msg(&self) -> &str {
         &self.msg
     }
     pub fn set_line(&mut self, line: usize) {
-        self.line = line as u64;
+        self.line = Some(line as u64);
+    }
+    pub fn get_line(&self) -> Option<usize> {
+        self.line.map(|l| l as usize)
+    }
+    pub fn set_file(&mut self, file: &str) {
+        self.file = Some(file.into());
+    }
+    pub fn get_file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+    pub fn add_note(&mut self, note: impl ToString) {
+        let mut notes = self.notes.take().map(String::from).unwrap_or_default();
+        if !notes.is_empty() {
+            notes.push('\n');
+        }
+        notes.push_str(&note.to_string());
+        self.notes = Some(notes.into());
     }
-    pub fn get_line(&self) -> usize {
-        self.line as usize
+    pub fn get_notes(&self) -> impl Iterator<Item = &str> {
+        self.notes.as_deref().unwrap_or("").lines()
     }
 }
@@ -0,0 +1,324 @@
+// pasm - src/shr/expr.rs
+// ----------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+// constant expression evaluator (used for immediates, displacements and directive arguments).
+//
+// operators (from lowest to highest precedence):
+//  - `||`
+//  - `&&`
+//  - `|`
+//  - `^`
+//  - `&`
+//  - `==`, `!=`
+//  - `<`, `<=`, `>`, `>=`
+//  - `<<`, `>>`
+//  - `+`, `-`
+//  - `*`, `/`, `%`
+//  - unary `-`, `+`, `~`, `!`
+//
+// values are computed in range of i64::MIN..=u64::MAX, everything outside of it
+// is reported as overflow.
+
+use crate::shr::{error::Error, num::Number};
+use std::str::FromStr;
+
+const MIN: i128 = i64::MIN as i128;
+const MAX: i128 = u64::MAX as i128;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tok {
+    Num(i128),
+    LParen,
+    RParen,
+    // binary operator (or unary if it is `-`, `+`)
+    Op(Op),
+    // `~`
+    Not,
+    // `!`
+    LNot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    LOr,
+    LAnd,
+    Or,
+    Xor,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Shl,
+    Shr,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl Op {
+    const fn prec(&self) -> u8 {
+        match self {
+            Self::LOr => 1,
+            Self::LAnd => 2,
+            Self::Or => 3,
+            Self::Xor => 4,
+            Self::And => 5,
+            Self::Eq | Self::Ne => 6,
+            Self::Lt | Self::Le | Self::Gt | Self::Ge => 7,
+            Self::Shl | Self::Shr => 8,
+            Self::Add | Self::Sub => 9,
+            Self::Mul | Self::Div | Self::Rem => 10,
+        }
+    }
+}
+
+/// returns true, if `s` consists only of numbers, operators and parentheses
+/// and has at least one operator or parenthesis (so it is not a plain number).
+pub fn is_expr(s: &str) -> bool {
+    match tokenize(s) {
+        Some(t) => t.iter().any(|t| !matches!(t, Tok::Num(_))),
+        None => false,
+    }
+}
+
+/// returns true, if `s` consists only of numbers, operators and parentheses
+pub fn is_const(s: &str) -> bool {
+    tokenize(s).is_some()
+}
+
+/// evaluates constant expression
+pub fn eval(s: &str) -> Result<Number, Error> {
+    let n = eval_raw(s)?;
+    if n < 0 {
+        Ok(Number::int64(n as i64))
+    } else {
+        Ok(Number::uint64(n as u64))
+    }
+}
+
+/// evaluates constant expression without converting it into `Number`
+pub fn eval_raw(s: &str) -> Result<i128, Error> {
+    let toks = match tokenize(s) {
+        Some(t) => t,
+        None => {
+            return Err(Error::new(
+                format!("expression `{s}` contains something, that is not a number or operator"),
+                15,
+            ))
+        }
+    };
+    let mut p = Parser {
+        toks: &toks,
+        idx: 0,
+        src: s,
+    };
+    let n = p.expr(0)?;
+    if p.idx != toks.len() {
+        return Err(p.err("unexpected token"));
+    }
+    Ok(n)
+}
+
+fn tokenize(s: &str) -> Option<Vec<Tok>> {
+    let mut toks = Vec::new();
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let next = bytes.get(i + 1).copied();
+        let (tok, len) = match (b, next) {
+            (b' ' | b'\t', _) => {
+                i += 1;
+                continue;
+            }
+            (b'0'..=b'9', _) => {
+                let len = s[i..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(s.len() - i);
+                let n = Number::from_str(&s[i..i + len]).ok()?;
+                (Tok::Num(n.get_raw() as i128), len)
+            }
+            (b'\'', _) => {
+                let len = s[i + 1..].find('\'')? + 2;
+                let n = Number::from_str(&s[i..i + len]).ok()?;
+                (Tok::Num(n.get_raw() as i128), len)
+            }
+            (b'(', _) => (Tok::LParen, 1),
+            (b')', _) => (Tok::RParen, 1),
+            (b'~', _) => (Tok::Not, 1),
+            (b'|', Some(b'|')) => (Tok::Op(Op::LOr), 2),
+            (b'&', Some(b'&')) => (Tok::Op(Op::LAnd), 2),
+            (b'=', Some(b'=')) => (Tok::Op(Op::Eq), 2),
+            (b'!', Some(b'=')) => (Tok::Op(Op::Ne), 2),
+            (b'<', Some(b'=')) => (Tok::Op(Op::Le), 2),
+            (b'>', Some(b'=')) => (Tok::Op(Op::Ge), 2),
+            (b'<', Some(b'<')) => (Tok::Op(Op::Shl), 2),
+            (b'>', Some(b'>')) => (Tok::Op(Op::Shr), 2),
+            (b'!', _) => (Tok::LNot, 1),
+            (b'|', _) => (Tok::Op(Op::Or), 1),
+            (b'&', _) => (Tok::Op(Op::And), 1),
+            (b'^', _) => (Tok::Op(Op::Xor), 1),
+            (b'<', _) => (Tok::Op(Op::Lt), 1),
+            (b'>', _) => (Tok::Op(Op::Gt), 1),
+            (b'+', _) => (Tok::Op(Op::Add), 1),
+            (b'-', _) => (Tok::Op(Op::Sub), 1),
+            (b'*', _) => (Tok::Op(Op::Mul), 1),
+            (b'/', _) => (Tok::Op(Op::Div), 1),
+            (b'%', _) => (Tok::Op(Op::Rem), 1),
+            _ => return None,
+        };
+        toks.push(tok);
+        i += len;
+    }
+    if toks.is_empty() {
+        None
+    } else {
+        Some(toks)
+    }
+}
+
+struct Parser<'a> {
+    toks: &'a [Tok],
+    idx: usize,
+    src: &'a str,
+}
+
+impl Parser<'_> {
+    fn err(&self, msg: &str) -> Error {
+        Error::new(format!("{msg} in expression `{}`", self.src), 15)
+    }
+    fn check(&self, n: Option<i128>) -> Result<i128, Error> {
+        match n {
+            Some(n) if (MIN..=MAX).contains(&n) => Ok(n),
+            _ => Err(self.err("integer overflow")),
+        }
+    }
+    // precedence climbing
+    fn expr(&mut self, min_prec: u8) -> Result<i128, Error> {
+        let mut lhs = self.unary()?;
+        while let Some(Tok::Op(op)) = self.toks.get(self.idx).copied() {
+            if op.prec() <= min_prec {
+                break;
+            }
+            self.idx += 1;
+            let rhs = self.expr(op.prec())?;
+            lhs = self.binary(op, lhs, rhs)?;
+        }
+        Ok(lhs)
+    }
+    fn unary(&mut self) -> Result<i128, Error> {
+        let tok = match self.toks.get(self.idx) {
+            Some(t) => *t,
+            None => return Err(self.err("unexpected end")),
+        };
+        self.idx += 1;
+        match tok {
+            Tok::Num(n) => Ok(n),
+            Tok::LParen => {
+                let n = self.expr(0)?;
+                if self.toks.get(self.idx) != Some(&Tok::RParen) {
+                    return Err(self.err("unclosed parenthesis"));
+                }
+                self.idx += 1;
+                Ok(n)
+            }
+            Tok::Op(Op::Sub) => {
+                let n = self.unary()?;
+                self.check(n.checked_neg())
+            }
+            Tok::Op(Op::Add) => self.unary(),
+            Tok::Not => {
+                let n = self.unary()?;
+                // values above i64::MAX are treated as unsigned
+                if n > i64::MAX as i128 {
+                    Ok(!(n as u64) as i128)
+                } else {
+                    Ok(!n)
+                }
+            }
+            Tok::LNot => Ok((self.unary()? == 0) as i128),
+            Tok::RParen | Tok::Op(_) => Err(self.err("unexpected token")),
+        }
+    }
+    fn binary(&self, op: Op, lhs: i128, rhs: i128) -> Result<i128, Error> {
+        match op {
+            Op::LOr => Ok((lhs != 0 || rhs != 0) as i128),
+            Op::LAnd => Ok((lhs != 0 && rhs != 0) as i128),
+            Op::Or => Ok(lhs | rhs),
+            Op::Xor => Ok(lhs ^ rhs),
+            Op::And => Ok(lhs & rhs),
+            Op::Eq => Ok((lhs == rhs) as i128),
+            Op::Ne => Ok((lhs != rhs) as i128),
+            Op::Lt => Ok((lhs < rhs) as i128),
+            Op::Le => Ok((lhs <= rhs) as i128),
+            Op::Gt => Ok((lhs > rhs) as i128),
+            Op::Ge => Ok((lhs >= rhs) as i128),
+            Op::Shl | Op::Shr => {
+                if !(0..64).contains(&rhs) {
+                    return Err(self.err("shift amount out of range (0..64)"));
+                }
+                if op == Op::Shl {
+                    self.check(lhs.checked_shl(rhs as u32))
+                } else {
+                    Ok(lhs >> rhs)
+                }
+            }
+            Op::Add => self.check(lhs.checked_add(rhs)),
+            Op::Sub => self.check(lhs.checked_sub(rhs)),
+            Op::Mul => self.check(lhs.checked_mul(rhs)),
+            Op::Div | Op::Rem => {
+                if rhs == 0 {
+                    return Err(self.err("division by zero"));
+                }
+                if op == Op::Div {
+                    self.check(lhs.checked_div(rhs))
+                } else {
+                    self.check(lhs.checked_rem(rhs))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn teval_0() {
+        assert_eq!(eval_raw("4096*4"), Ok(16384));
+        assert_eq!(eval_raw("1 + 2 * 3"), Ok(7));
+        assert_eq!(eval_raw("(1 + 2) * 3"), Ok(9));
+        assert_eq!(eval_raw("-8*3"), Ok(-24));
+        assert_eq!(eval_raw("10 - 2 - 3"), Ok(5));
+        assert_eq!(eval_raw("1 << 4 | 0x0F"), Ok(31));
+        assert_eq!(eval_raw("0xFF & ~0x0F ^ 1"), Ok(0xF1));
+        assert_eq!(eval_raw("7 % 4 == 3"), Ok(1));
+        assert_eq!(eval_raw("1 < 2 && 2 >= 3 || !0"), Ok(1));
+        assert_eq!(eval_raw("'a' + 1"), Ok(98));
+        assert_eq!(eval_raw("0xFFFF_FFFF_FFFF_FFFF & 0xFF"), Ok(0xFF));
+        assert_eq!(eval_raw("~0xFFFF_FFFF_FFFF_FFFF"), Ok(0));
+        assert_eq!(eval("-1").unwrap().get_raw(), u64::MAX);
+    }
+    #[test]
+    fn teval_1() {
+        let err = Error::new("", 15);
+        assert_eq!(eval_raw("1 / 0"), Err(err.clone()));
+        assert_eq!(eval_raw("1 % (2 - 2)"), Err(err.clone()));
+        assert_eq!(eval_raw("0xFFFF_FFFF_FFFF_FFFF + 1"), Err(err.clone()));
+        assert_eq!(eval_raw("1 << 64"), Err(err.clone()));
+        assert_eq!(eval_raw("(1 + 2"), Err(err.clone()));
+        assert_eq!(eval_raw("1 +"), Err(err.clone()));
+        assert_eq!(eval_raw("rax + 1"), Err(err));
+        assert!(is_expr("4096 * 4"));
+        assert!(!is_expr("4096"));
+        assert!(!is_expr("rax"));
+        assert!(!is_expr("qword [rax]"));
+    }
+}
@@ -24,6 +24,6 @@ pub fn mnem_fromstr(str: &str) -> Option<Mnemonic> {
 	use Mnemonic::*;
 	let r = str.as_bytes();
 	match r.len() {
-		2=>match r[0]{b'b'=>match r[1]{b't'=>s(BT),_=>N}b'i'=>match r[1]{b'n'=>s(IN),_=>N}b'o'=>match r[1]{b'r'=>s(OR),_=>N}b'j' => match r[1]{b'a'=>s(JA),b'b'=>s(JB),b'c'=>s(JC),b'e'=>s(JE),b'g'=>s(JG),b'l'=>s(JL),b'o'=>s(JO),b'p'=>s(JP),b's'=>s(JS),b'z'=>s(JZ),_=>N}_=>N}3=>match r[0]{b'h'=>match r[1]{b'l'=>match r[2]{b't'=>s(HLT),_=>N}_=>N}b'i'=>match r[1]{b'n' => match r[2]{b'c'=>s(INC),b't'=>s(INT),_=>N}_=>N}b'o'=>match r[1]{b'u'=>match r[2]{b't'=>s(OUT),_=>N}_=>N}b'p'=>match r[1]{b'o' => match r[2]{b'p'=>s(POP),b'r'=>s(POR),_=>N}_=>N}b'u'=>match r[1]{b'd' => match r[2]{b'0'=>s(UD0),b'1'=>s(UD1),b'2'=>s(UD2),_=>N}_=>N}b'x'=>match r[1]{b'o'=>match r[2]{b'r'=>s(XOR),_=>N}_=>N}b'a' => match r[1]{b'n'=>match r[2]{b'd'=>s(AND),_=>N}b'o'=>match r[2]{b'r'=>s(AOR),_=>N}b'a' => match r[2]{b'a'=>s(AAA),b'd'=>s(AAD),b'm'=>s(AAM),b's'=>s(AAS),_=>N}b'd' => match r[2]{b'c'=>s(ADC),b'd'=>s(ADD),_=>N}_=>N}b'b' => match r[1]{b's' => match r[2]{b'f'=>s(BSF),b'r'=>s(BSR),_=>N}b't' => match r[2]{b'c'=>s(BTC),b'r'=>s(BTR),b's'=>s(BTS),_=>N}_=>N}b'c' => match r[1]{b'b'=>match r[2]{b'w'=>s(CBW),_=>N}b'd'=>match r[2]{b'q'=>s(CDQ),_=>N}b'q'=>match r[2]{b'o'=>s(CQO),_=>N}b'w'=>match r[2]{b'd'=>s(CWD),_=>N}b'l' => match r[2]{b'c'=>s(CLC),b'd'=>s(CLD),b'i'=>s(CLI),_=>N}b'm' => match r[2]{b'c'=>s(CMC),b'p'=>s(CMP),_=>N}_=>N}b'd' => match r[1]{b'e'=>match r[2]{b'c'=>s(DEC),_=>N}b'i'=>match r[2]{b'v'=>s(DIV),_=>N}b'a' => match r[2]{b'a'=>s(DAA),b's'=>s(DAS),_=>N}_=>N}b'f' => match r[1]{b'l'=>match r[2]{b'd'=>s(FLD),_=>N}b's'=>match r[2]{b't'=>s(FST),_=>N}_=>N}b'j' => match r[1]{b'a'=>match r[2]{b'e'=>s(JAE),_=>N}b'b'=>match r[2]{b'e'=>s(JBE),_=>N}b'g'=>match r[2]{b'e'=>s(JGE),_=>N}b'l'=>match r[2]{b'e'=>s(JLE),_=>N}b'm'=>match r[2]{b'p'=>s(JMP),_=>N}b'n' => match r[2]{b'a'=>s(JNA),b'b'=>s(JNB),b'c'=>s(JNC),b'e'=>s(JNE),b'g'=>s(JNG),b'l'=>s(JNL),b'o'=>s(JNO),b'p'=>s(JNP),b's'=>s(JNS),b'z'=>s(JNZ),_=>N}b'p' => match r[2]{b'e'=>s(JPE),b'o'=>s(JPO),_=>N}_=>N}b'l' => match r[1]{b'a'=>match r[2]{b'r'=>s(LAR),_=>N}b'e'=>match r[2]{b'a'=>s(LEA),_=>N}b'f'=>match r[2]{b's'=>s(LFS),_=>N}b'g'=>match r[2]{b's'=>s(LGS),_=>N}b's'=>match r[2]{b'l'=>s(LSL),_=>N}b't'=>match r[2]{b'r'=>s(LTR),_=>N}_=>N}b'm' => match r[1]{b'o'=>match r[2]{b'v'=>s(MOV),_=>N}b'u'=>match r[2]{b'l'=>s(MUL),_=>N}_=>N}b'n' => match r[1]{b'e'=>match r[2]{b'g'=>s(NEG),_=>N}b'o' => match r[2]{b'p'=>s(NOP),b't'=>s(NOT),_=>N}_=>N}b'r' => match r[1]{b's'=>match r[2]{b'm'=>s(RSM),_=>N}b'c' => match r[2]{b'l'=>s(RCL),b'r'=>s(RCR),_=>N}b'e' => match r[2]{b'p'=>s(REP),b't'=>s(RET),_=>N}b'o' => match r[2]{b'l'=>s(ROL),b'r'=>s(ROR),_=>N}_=>N}b's' => match r[1]{b'b'=>match r[2]{b'b'=>s(SBB),_=>N}b'u'=>match r[2]{b'b'=>s(SUB),_=>N}b'a' => match r[2]{b'l'=>s(SAL),b'r'=>s(SAR),_=>N}b'h' => match r[2]{b'l'=>s(SHL),b'r'=>s(SHR),_=>N}b't' => match r[2]{b'c'=>s(STC),b'd'=>s(STD),b'i'=>s(STI),b'r'=>s(STR),_=>N}_=>N}_=>N}4=>match r[0]{b'd'=>match r[1]{b'p'=>match r[2]{b'p' => match r[3]{b'd'=>s(DPPD),b's'=>s(DPPS),_=>N}_=>N}_=>N}b'e'=>match r[1]{b'm'=>match r[2]{b'm'=>match r[3]{b's'=>s(EMMS),_=>N}_=>N}_=>N}b'k'=>match r[1]{b'o'=>match r[2]{b'r' => match r[3]{b'b'=>s(KORB),b'd'=>s(KORD),b'q'=>s(KORQ),b'w'=>s(KORW),_=>N}_=>N}_=>N}b'n'=>match r[1]{b'o'=>match r[2]{b'p'=>match r[3]{b'l'=>s(NOPL),_=>N}_=>N}_=>N}b'o'=>match r[1]{b'r'=>match r[2]{b'p' => match r[3]{b'd'=>s(ORPD),b's'=>s(ORPS),_=>N}_=>N}_=>N}b't'=>match r[1]{b'e'=>match r[2]{b's'=>match r[3]{b't'=>s(TEST),_=>N}_=>N}_=>N}b'w'=>match r[1]{b'a'=>match r[2]{b'i'=>match r[3]{b't'=>s(WAIT),_=>N}_=>N}_=>N}b'a' => match r[1]{b'i'=>match r[2]{b'n'=>match r[3]{b'c'=>s(AINC),_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>s(AMUL),_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r'=>s(AXOR),_=>N}_=>N}b'a' => match r[2]{b'n'=>match r[3]{b'd'=>s(AAND),_=>N}b'o'=>match r[3]{b'r'=>s(AAOR),_=>N}b'd' => match r[3]{b'c'=>s(AADC),b'd'=>s(AADD),_=>N}_=>N}b'd' => match r[2]{b'c'=>match r[3]{b'x'=>s(ADCX),_=>N}b'e'=>match r[3]{b'c'=>s(ADEC),_=>N}b'i'=>match r[3]{b'v'=>s(ADIV),_=>N}b'o'=>match r[3]{b'x'=>s(ADOX),_=>N}_=>N}b'n' => match r[2]{b'd'=>match r[3]{b'n'=>s(ANDN),_=>N}b'e'=>match r[3]{b'g'=>s(ANEG),_=>N}b'o'=>match r[3]{b't'=>s(ANOT),_=>N}_=>N}b'r' => match r[2]{b'p'=>match r[3]{b'l'=>s(ARPL),_=>N}b'c' => match r[3]{b'l'=>s(ARCL),b'r'=>s(ARCR),_=>N}b'o' => match r[3]{b'l'=>s(AROL),b'r'=>s(AROR),_=>N}_=>N}b's' => match r[2]{b'a'=>match r[3]{b'r'=>s(ASAR),_=>N}b'b'=>match r[3]{b'b'=>s(ASBB),_=>N}b'u'=>match r[3]{b'b'=>s(ASUB),_=>N}b'h' => match r[3]{b'l'=>s(ASHL),b'r'=>s(ASHR),_=>N}_=>N}_=>N}b'b' => match r[1]{b'l'=>match r[2]{b's' => match r[3]{b'i'=>s(BLSI),b'r'=>s(BLSR),_=>N}_=>N}b'z'=>match r[2]{b'h'=>match r[3]{b'i'=>s(BZHI),_=>N}_=>N}_=>N}b'c' => match r[1]{b'a'=>match r[2]{b'l'=>match r[3]{b'l'=>s(CALL),_=>N}_=>N}b'd'=>match r[2]{b'q'=>match r[3]{b'e'=>s(CDQE),_=>N}_=>N}b'w'=>match r[2]{b'd'=>match r[3]{b'e'=>s(CWDE),_=>N}_=>N}b'l' => match r[2]{b'a'=>match r[3]{b'c'=>s(CLAC),_=>N}b'g'=>match r[3]{b'i'=>s(CLGI),_=>N}b't'=>match r[3]{b's'=>s(CLTS),_=>N}b'u'=>match r[3]{b'i'=>s(CLUI),_=>N}b'w'=>match r[3]{b'b'=>s(CLWB),_=>N}_=>N}_=>N}b'f' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b'd'=>s(FBLD),_=>N}_=>N}b'd'=>match r[2]{b'i'=>match r[3]{b'v'=>s(FDIV),_=>N}_=>N}b'e'=>match r[2]{b'n'=>match r[3]{b'i'=>s(FENI),_=>N}_=>N}b'l'=>match r[2]{b'd' => match r[3]{b'1'=>s(FLD1),b'z'=>s(FLDZ),_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>s(FMUL),_=>N}_=>N}b'n'=>match r[2]{b'o'=>match r[3]{b'p'=>s(FNOP),_=>N}_=>N}b't'=>match r[2]{b's'=>match r[3]{b't'=>s(FTST),_=>N}_=>N}b'a' => match r[2]{b'b'=>match r[3]{b's'=>s(FABS),_=>N}b'd'=>match r[3]{b'd'=>s(FADD),_=>N}_=>N}b'c' => match r[2]{b'h'=>match r[3]{b's'=>s(FCHS),_=>N}b'o' => match r[3]{b'm'=>s(FCOM),b's'=>s(FCOS),_=>N}_=>N}b'i' => match r[2]{b'l'=>match r[3]{b'd'=>s(FILD),_=>N}b's'=>match r[3]{b't'=>s(FIST),_=>N}_=>N}b's' => match r[2]{b'i'=>match r[3]{b'n'=>s(FSIN),_=>N}b't'=>match r[3]{b'p'=>s(FSTP),_=>N}b'u'=>match r[3]{b'b'=>s(FSUB),_=>N}_=>N}b'x' => match r[2]{b'a'=>match r[3]{b'm'=>s(FXAM),_=>N}b'c'=>match r[3]{b'h'=>s(FXCH),_=>N}_=>N}_=>N}b'i' => match r[1]{b'd'=>match r[2]{b'i'=>match r[3]{b'v'=>s(IDIV),_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>s(IMUL),_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b't'=>s(IRET),_=>N}_=>N}b'n' => match r[2]{b'v'=>match r[3]{b'd'=>s(INVD),_=>N}b's' => match r[3]{b'b'=>s(INSB),b'd'=>s(INSD),b'w'=>s(INSW),_=>N}b't' => match r[3]{b'1'=>s(INT1),b'3'=>s(INT3),b'o'=>s(INTO),_=>N}_=>N}_=>N}b'j' => match r[1]{b'c'=>match r[2]{b'x'=>match r[3]{b'z'=>s(JCXZ),_=>N}_=>N}b'n' => match r[2]{b'a'=>match r[3]{b'e'=>s(JNAE),_=>N}b'b'=>match r[3]{b'e'=>s(JNBE),_=>N}b'g'=>match r[3]{b'e'=>s(JNGE),_=>N}b'l'=>match r[3]{b'e'=>s(JNLE),_=>N}_=>N}_=>N}b'l' => match r[1]{b'a'=>match r[2]{b'h'=>match r[3]{b'f'=>s(LAHF),_=>N}_=>N}b'g'=>match r[2]{b'd'=>match r[3]{b't'=>s(LGDT),_=>N}_=>N}b'i'=>match r[2]{b'd'=>match r[3]{b't'=>s(LIDT),_=>N}_=>N}b'j'=>match r[2]{b'm'=>match r[3]{b'p'=>s(LJMP),_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b't'=>s(LLDT),_=>N}_=>N}b'm'=>match r[2]{b's'=>match r[3]{b'w'=>s(LMSW),_=>N}_=>N}b'o' => match r[2]{b'c'=>match r[3]{b'k'=>s(LOCK),_=>N}b'o'=>match r[3]{b'p'=>s(LOOP),_=>N}_=>N}_=>N}b'm' => match r[1]{b'o'=>match r[2]{b'v' => match r[3]{b'd'=>s(MOVD),b'q'=>s(MOVQ),_=>N}_=>N}b'u'=>match r[2]{b'l'=>match r[3]{b'x'=>s(MULX),_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'n'=>match r[3]{b'd'=>s(PAND),_=>N}_=>N}b'd'=>match r[2]{b'e'=>match r[3]{b'p'=>s(PDEP),_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>s(PEXT),_=>N}_=>N}b'o'=>match r[2]{b'p' => match r[3]{b'2'=>s(POP2),b'f'=>s(POPF),b'p'=>s(POPP),_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h'=>s(PUSH),_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r'=>s(PXOR),_=>N}_=>N}_=>N}b'r' => match r[1]{b'e'=>match r[2]{b'p' => match r[3]{b'e'=>s(REPE),b'z'=>s(REPZ),_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'x'=>s(RORX),_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b't' => match r[3]{b'a'=>s(SETA),b'b'=>s(SETB),b'c'=>s(SETC),b'e'=>s(SETE),b'g'=>s(SETG),b'l'=>s(SETL),b'o'=>s(SETO),b'p'=>s(SETP),b's'=>s(SETS),b'z'=>s(SETZ),_=>N}_=>N}b'g'=>match r[2]{b'd'=>match r[3]{b't'=>s(SGDT),_=>N}_=>N}b'i'=>match r[2]{b'd'=>match r[3]{b't'=>s(SIDT),_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b't'=>s(SLDT),_=>N}_=>N}b'm'=>match r[2]{b's'=>match r[3]{b'w'=>s(SMSW),_=>N}_=>N}b'a' => match r[2]{b'h'=>match r[3]{b'f'=>s(SAHF),_=>N}b'r'=>match r[3]{b'x'=>s(SARX),_=>N}_=>N}b'h' => match r[2]{b'l' => match r[3]{b'd'=>s(SHLD),b'x'=>s(SHLX),_=>N}b'r' => match r[3]{b'd'=>s(SHRD),b'x'=>s(SHRX),_=>N}_=>N}b't' => match r[2]{b'a'=>match r[3]{b'c'=>s(STAC),_=>N}b'g'=>match r[3]{b'i'=>s(STGI),_=>N}b'u'=>match r[3]{b'i'=>s(STUI),_=>N}_=>N}_=>N}b'v' => match r[1]{b'e'=>match r[2]{b'r' => match r[3]{b'r'=>s(VERR),b'w'=>s(VERW),_=>N}_=>N}b'p'=>match r[2]{b'o'=>match r[3]{b'r'=>s(VPOR),_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>s(XADD),_=>N}_=>N}b'c'=>match r[2]{b'h'=>match r[3]{b'g'=>s(XCHG),_=>N}_=>N}b'e'=>match r[2]{b'n'=>match r[3]{b'd'=>s(XEND),_=>N}_=>N}b'l'=>match r[2]{b'a'=>match r[3]{b't'=>s(XLAT),_=>N}_=>N}_=>N}_=>N}5=>match r[0]{b'd'=>match r[1]{b'i'=>match r[2]{b'v' => match r[3]{b'p' => match r[4]{b'd'=>s(DIVPD),b's'=>s(DIVPS),_=>N}b's' => match r[4]{b'd'=>s(DIVSD),b's'=>s(DIVSS),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'r'=>match r[2]{b'e'=>match r[3]{b't' => match r[4]{b'd'=>s(IRETD),b'q'=>s(IRETQ),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[1]{b'u'=>match r[2]{b't'=>match r[3]{b's' => match r[4]{b'b'=>s(OUTSB),b'd'=>s(OUTSD),b'w'=>s(OUTSW),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'i'=>match r[2]{b'r'=>match r[3]{b'e'=>match r[4]{b't'=>s(UIRET),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[1]{b'r' => match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>s(WRMSR),_=>N}_=>N}b's'=>match r[3]{b's' => match r[4]{b'd'=>s(WRSSD),b'q'=>s(WRSSQ),_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'd'=>match r[2]{b'd' => match r[3]{b'p' => match r[4]{b'd'=>s(ADDPD),b's'=>s(ADDPS),_=>N}b's' => match r[4]{b'd'=>s(ADDSD),b's'=>s(ADDSS),_=>N}_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>match r[4]{b'x'=>s(AMULX),_=>N}_=>N}_=>N}b'n'=>match r[2]{b'd'=>match r[3]{b'p' => match r[4]{b'd'=>s(ANDPD),b's'=>s(ANDPS),_=>N}_=>N}_=>N}b'r'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'x'=>s(ARORX),_=>N}_=>N}_=>N}b'a' => match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b'n'=>s(AANDN),_=>N}_=>N}b'x'=>match r[3]{b'o'=>match r[4]{b'r'=>s(AAXOR),_=>N}_=>N}b'a' => match r[3]{b'd'=>match r[4]{b'd'=>s(AAADD),_=>N}b'n'=>match r[4]{b'd'=>s(AAAND),_=>N}_=>N}b'd' => match r[3]{b'c'=>match r[4]{b'x'=>s(AADCX),_=>N}b'o'=>match r[4]{b'x'=>s(AADOX),_=>N}_=>N}_=>N}b'b' => match r[2]{b'l'=>match r[3]{b's' => match r[4]{b'i'=>s(ABLSI),b'r'=>s(ABLSR),_=>N}_=>N}b'z'=>match r[3]{b'h'=>match r[4]{b'i'=>s(ABZHI),_=>N}_=>N}_=>N}b'i' => match r[2]{b'd'=>match r[3]{b'i'=>match r[4]{b'v'=>s(AIDIV),_=>N}_=>N}b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>s(AIMUL),_=>N}_=>N}_=>N}b'p' => match r[2]{b'd'=>match r[3]{b'e'=>match r[4]{b'p'=>s(APDEP),_=>N}_=>N}b'e'=>match r[3]{b'x'=>match r[4]{b't'=>s(APEXT),_=>N}_=>N}_=>N}b's' => match r[2]{b'a'=>match r[3]{b'r'=>match r[4]{b'x'=>s(ASARX),_=>N}_=>N}b'c'=>match r[3]{b'i'=>match r[4]{b'i'=>s(ASCII),_=>N}_=>N}b'h' => match r[3]{b'l' => match r[4]{b'd'=>s(ASHLD),b'x'=>s(ASHLX),_=>N}b'r' => match r[4]{b'd'=>s(ASHRD),b'x'=>s(ASHRX),_=>N}_=>N}_=>N}_=>N}b'b' => match r[1]{b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>s(BEXTR),_=>N}_=>N}_=>N}b's'=>match r[2]{b'w'=>match r[3]{b'a'=>match r[4]{b'p'=>s(BSWAP),_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'a'=>s(CCMPA),b'b'=>s(CCMPB),b'c'=>s(CCMPC),b'e'=>s(CCMPE),b'f'=>s(CCMPF),b'g'=>s(CCMPG),b'l'=>s(CCMPL),b'o'=>s(CCMPO),b's'=>s(CCMPS),b't'=>s(CCMPT),b'z'=>s(CCMPZ),_=>N}_=>N}_=>N}b'p'=>match r[2]{b'u'=>match r[3]{b'i'=>match r[4]{b'd'=>s(CPUID),_=>N}_=>N}_=>N}b'r'=>match r[2]{b'c'=>match r[3]{b'3'=>match r[4]{b'2'=>s(CRC32),_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'a'=>s(CMOVA),b'b'=>s(CMOVB),b'c'=>s(CMOVC),b'e'=>s(CMOVE),b'g'=>s(CMOVG),b'l'=>s(CMOVL),b'o'=>s(CMOVO),b'p'=>s(CMOVP),b's'=>s(CMOVS),b'z'=>s(CMOVZ),_=>N}_=>N}b'p' => match r[3]{b'p' => match r[4]{b'd'=>s(CMPPD),b's'=>s(CMPPS),_=>N}b's' => match r[4]{b'd'=>s(CMPSD),b's'=>s(CMPSS),_=>N}_=>N}_=>N}_=>N}b'e' => match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b't'=>match r[4]{b'y'=>s(EMPTY),_=>N}_=>N}_=>N}b'n' => match r[2]{b'c'=>match r[3]{b'l' => match r[4]{b's'=>s(ENCLS),b'u'=>s(ENCLU),b'v'=>s(ENCLV),_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b'r'=>s(ENTER),_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'2'=>match r[2]{b'x'=>match r[3]{b'm'=>match r[4]{b'1'=>s(F2XM1),_=>N}_=>N}_=>N}b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'p'=>s(FADDP),_=>N}_=>N}_=>N}b'b'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'p'=>s(FBSTP),_=>N}_=>N}_=>N}b'd'=>match r[2]{b'i' => match r[3]{b's'=>match r[4]{b'i'=>s(FDISI),_=>N}b'v' => match r[4]{b'p'=>s(FDIVP),b'r'=>s(FDIVR),_=>N}_=>N}_=>N}b'f'=>match r[2]{b'r'=>match r[3]{b'e'=>match r[4]{b'e'=>s(FFREE),_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd' => match r[3]{b'c'=>match r[4]{b'w'=>s(FLDCW),_=>N}b'p'=>match r[4]{b'i'=>s(FLDPI),_=>N}_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>match r[4]{b'p'=>s(FMULP),_=>N}_=>N}_=>N}b'n'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'i'=>s(FNENI),_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>s(FUCOM),_=>N}_=>N}_=>N}b'w'=>match r[2]{b'a'=>match r[3]{b'i'=>match r[4]{b't'=>s(FWAIT),_=>N}_=>N}_=>N}b'y'=>match r[2]{b'l'=>match r[3]{b'2'=>match r[4]{b'x'=>s(FYL2X),_=>N}_=>N}_=>N}b'c' => match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'x'=>s(FCLEX),_=>N}_=>N}b'o'=>match r[3]{b'm' => match r[4]{b'i'=>s(FCOMI),b'p'=>s(FCOMP),_=>N}_=>N}_=>N}b'i' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>s(FIADD),_=>N}_=>N}b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>s(FICOM),_=>N}_=>N}b'd'=>match r[3]{b'i'=>match r[4]{b'v'=>s(FIDIV),_=>N}_=>N}b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>s(FIMUL),_=>N}_=>N}b'n'=>match r[3]{b'i'=>match r[4]{b't'=>s(FINIT),_=>N}_=>N}b's' => match r[3]{b't'=>match r[4]{b'p'=>s(FISTP),_=>N}b'u'=>match r[4]{b'b'=>s(FISUB),_=>N}_=>N}_=>N}b'p' => match r[2]{b'r'=>match r[3]{b'e'=>match r[4]{b'm'=>s(FPREM),_=>N}_=>N}b't'=>match r[3]{b'a'=>match r[4]{b'n'=>s(FPTAN),_=>N}_=>N}_=>N}b's' => match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>s(FSAVE),_=>N}_=>N}b'q'=>match r[3]{b'r'=>match r[4]{b't'=>s(FSQRT),_=>N}_=>N}b'u'=>match r[3]{b'b' => match r[4]{b'p'=>s(FSUBP),b'r'=>s(FSUBR),_=>N}_=>N}b't' => match r[3]{b'c'=>match r[4]{b'w'=>s(FSTCW),_=>N}b's'=>match r[4]{b'w'=>s(FSTSW),_=>N}_=>N}_=>N}_=>N}b'j' => match r[1]{b'e'=>match r[2]{b'c'=>match r[3]{b'x'=>match r[4]{b'z'=>s(JECXZ),_=>N}_=>N}_=>N}b'r'=>match r[2]{b'c'=>match r[3]{b'x'=>match r[4]{b'z'=>s(JRCXZ),_=>N}_=>N}_=>N}_=>N}b'k' => match r[1]{b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'b'=>s(KMOVB),b'd'=>s(KMOVD),b'q'=>s(KMOVQ),b'w'=>s(KMOVW),_=>N}_=>N}_=>N}b'n'=>match r[2]{b'o'=>match r[3]{b't' => match r[4]{b'b'=>s(KNOTB),b'd'=>s(KNOTD),b'q'=>s(KNOTQ),b'w'=>s(KNOTW),_=>N}_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r' => match r[4]{b'b'=>s(KXORB),b'd'=>s(KXORD),b'q'=>s(KXORQ),b'w'=>s(KXORW),_=>N}_=>N}_=>N}b'a' => match r[2]{b'd'=>match r[3]{b'd' => match r[4]{b'b'=>s(KADDB),b'd'=>s(KADDD),b'q'=>s(KADDQ),b'w'=>s(KADDW),_=>N}_=>N}b'n'=>match r[3]{b'd' => match r[4]{b'b'=>s(KANDB),b'd'=>s(KANDD),b'q'=>s(KANDQ),b'w'=>s(KANDW),_=>N}_=>N}_=>N}_=>N}b'l' => match r[1]{b'c'=>match r[2]{b'a'=>match r[3]{b'l'=>match r[4]{b'l'=>s(LCALL),_=>N}_=>N}_=>N}b'd'=>match r[2]{b'd'=>match r[3]{b'q'=>match r[4]{b'u'=>s(LDDQU),_=>N}_=>N}_=>N}b'e'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>s(LEAVE),_=>N}_=>N}_=>N}b'z'=>match r[2]{b'c'=>match r[3]{b'n'=>match r[4]{b't'=>s(LZCNT),_=>N}_=>N}_=>N}b'o' => match r[2]{b'd'=>match r[3]{b's' => match r[4]{b'b'=>s(LODSB),b'd'=>s(LODSD),b'q'=>s(LODSQ),b'w'=>s(LODSW),_=>N}_=>N}b'o'=>match r[3]{b'p'=>match r[4]{b'e'=>s(LOOPE),_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'a'=>match r[2]{b'x' => match r[3]{b'p' => match r[4]{b'd'=>s(MAXPD),b's'=>s(MAXPS),_=>N}b's' => match r[4]{b'd'=>s(MAXSD),b's'=>s(MAXSS),_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n' => match r[3]{b'p' => match r[4]{b'd'=>s(MINPD),b's'=>s(MINPS),_=>N}b's' => match r[4]{b'd'=>s(MINSD),b's'=>s(MINSS),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'v' => match r[3]{b'b'=>match r[4]{b'e'=>s(MOVBE),_=>N}b'z'=>match r[4]{b'x'=>s(MOVZX),_=>N}b's' => match r[4]{b'd'=>s(MOVSD),b's'=>s(MOVSS),b'x'=>s(MOVSX),_=>N}_=>N}_=>N}b'u'=>match r[2]{b'l' => match r[3]{b'p' => match r[4]{b'd'=>s(MULPD),b's'=>s(MULPS),_=>N}b's' => match r[4]{b'd'=>s(MULSD),b's'=>s(MULSS),_=>N}_=>N}_=>N}b'w'=>match r[2]{b'a'=>match r[3]{b'i'=>match r[4]{b't'=>s(MWAIT),_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'o'=>match r[2]{b'p' => match r[3]{b'2'=>match r[4]{b'p'=>s(POP2P),_=>N}b'a' => match r[4]{b'd'=>s(POPAD),b'q'=>s(POPAQ),b'w'=>s(POPAW),_=>N}b'f' => match r[4]{b'd'=>s(POPFD),b'q'=>s(POPFQ),_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>s(PTEST),_=>N}_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h' => match r[4]{b'2'=>s(PUSH2),b'f'=>s(PUSHF),b'p'=>s(PUSHP),_=>N}_=>N}_=>N}b'a' => match r[2]{b'b'=>match r[3]{b's' => match r[4]{b'b'=>s(PABSB),b'd'=>s(PABSD),b'w'=>s(PABSW),_=>N}_=>N}b'd'=>match r[3]{b'd' => match r[4]{b'b'=>s(PADDB),b'd'=>s(PADDD),b'q'=>s(PADDQ),b'w'=>s(PADDW),_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'n'=>s(PANDN),_=>N}_=>N}b'u'=>match r[3]{b's'=>match r[4]{b'e'=>s(PAUSE),_=>N}_=>N}b'v'=>match r[3]{b'g' => match r[4]{b'b'=>s(PAVGB),b'w'=>s(PAVGW),_=>N}_=>N}_=>N}b's' => match r[2]{b'l'=>match r[3]{b'l' => match r[4]{b'd'=>s(PSLLD),b'q'=>s(PSLLQ),b'w'=>s(PSLLW),_=>N}_=>N}b'u'=>match r[3]{b'b' => match r[4]{b'b'=>s(PSUBB),b'd'=>s(PSUBD),b'q'=>s(PSUBQ),b'w'=>s(PSUBW),_=>N}_=>N}b'r' => match r[3]{b'a' => match r[4]{b'd'=>s(PSRAD),b'w'=>s(PSRAW),_=>N}b'l' => match r[4]{b'd'=>s(PSRLD),b'q'=>s(PSRLQ),b'w'=>s(PSRLW),_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'c'=>match r[2]{b'p' => match r[3]{b'p'=>match r[4]{b's'=>s(RCPPS),_=>N}b's'=>match r[4]{b's'=>s(RCPSS),_=>N}_=>N}_=>N}b'e'=>match r[2]{b'p'=>match r[3]{b'n' => match r[4]{b'e'=>s(REPNE),b'z'=>s(REPNZ),_=>N}_=>N}_=>N}b'd' => match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>s(RDMSR),_=>N}_=>N}b't'=>match r[3]{b's'=>match r[4]{b'c'=>s(RDTSC),_=>N}_=>N}b'p' => match r[3]{b'i'=>match r[4]{b'd'=>s(RDPID),_=>N}b'm'=>match r[4]{b'c'=>s(RDPMC),_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'c'=>match r[2]{b'a'=>match r[3]{b's' => match r[4]{b'b'=>s(SCASB),b'd'=>s(SCASD),b'q'=>s(SCASQ),b'w'=>s(SCASW),_=>N}_=>N}_=>N}b'e'=>match r[2]{b't' => match r[3]{b'a'=>match r[4]{b'e'=>s(SETAE),_=>N}b'b'=>match r[4]{b'e'=>s(SETBE),_=>N}b'g'=>match r[4]{b'e'=>s(SETGE),_=>N}b'l'=>match r[4]{b'e'=>s(SETLE),_=>N}b'n' => match r[4]{b'a'=>s(SETNA),b'b'=>s(SETNB),b'c'=>s(SETNC),b'e'=>s(SETNE),b'g'=>s(SETNG),b'l'=>s(SETNL),b'o'=>s(SETNO),b'p'=>s(SETNP),b's'=>s(SETNS),b'z'=>s(SETNZ),_=>N}b'p' => match r[4]{b'e'=>s(SETPE),b'o'=>s(SETPO),_=>N}_=>N}_=>N}b't'=>match r[2]{b'o'=>match r[3]{b's' => match r[4]{b'b'=>s(STOSB),b'd'=>s(STOSD),b'q'=>s(STOSQ),b'w'=>s(STOSW),_=>N}_=>N}_=>N}b'u'=>match r[2]{b'b' => match r[3]{b'p' => match r[4]{b'd'=>s(SUBPD),b's'=>s(SUBPS),_=>N}b's' => match r[4]{b'd'=>s(SUBSD),b's'=>s(SUBSS),_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'd'=>match r[2]{b'p'=>match r[3]{b'p' => match r[4]{b'd'=>s(VDPPD),b's'=>s(VDPPS),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'p' => match r[4]{b'd'=>s(VORPD),b's'=>s(VORPS),_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'd'=>s(VMOVD),b'q'=>s(VMOVQ),b'w'=>s(VMOVW),_=>N}_=>N}b'r'=>match r[3]{b'u'=>match r[4]{b'n'=>s(VMRUN),_=>N}_=>N}b'x'=>match r[3]{b'o'=>match r[4]{b'n'=>s(VMXON),_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b'n'=>match r[4]{b'd'=>s(VPAND),_=>N}_=>N}b'x'=>match r[3]{b'o'=>match r[4]{b'r'=>s(VPXOR),_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'l'=>match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'b'=>s(XLATB),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'p' => match r[4]{b'd'=>s(XORPD),b's'=>s(XORPS),_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>s(XSAVE),_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>s(XTEST),_=>N}_=>N}_=>N}_=>N}_=>N}6=>match r[0]{b'e'=>match r[1]{b'n'=>match r[2]{b'q'=>match r[3]{b'c'=>match r[4]{b'm'=>match r[5]{b'd'=>s(ENQCMD),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[1]{b'e'=>match r[2]{b't'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'c'=>s(GETSEC),_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'n'=>match r[2]{b'v' => match r[3]{b'e'=>match r[4]{b'p'=>match r[5]{b't'=>s(INVEPT),_=>N}_=>N}b'l'=>match r[4]{b'p'=>match r[5]{b'g'=>s(INVLPG),_=>N}_=>N}_=>N}_=>N}_=>N}b'j'=>match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b'a'=>match r[4]{b'b'=>match r[5]{b's'=>s(JMPABS),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[1]{b'd' => match r[2]{b'p'=>match r[3]{b'k'=>match r[4]{b'r'=>match r[5]{b'u'=>s(RDPKRU),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'a'=>match r[4]{b'n'=>match r[5]{b'd'=>s(RDRAND),_=>N}_=>N}_=>N}b't'=>match r[3]{b's'=>match r[4]{b'c'=>match r[5]{b'p'=>s(RDTSCP),_=>N}_=>N}_=>N}b's' => match r[3]{b'e'=>match r[4]{b'e'=>match r[5]{b'd'=>s(RDSEED),_=>N}_=>N}b's'=>match r[4]{b'p' => match r[5]{b'd'=>s(RDSSPD),b'q'=>s(RDSSPQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'm'=>match r[2]{b'w'=>match r[3]{b'a'=>match r[4]{b'i'=>match r[5]{b't'=>s(UMWAIT),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'b'=>match r[2]{b'e'=>match r[3]{b'x'=>match r[4]{b't'=>match r[5]{b'r'=>s(ABEXTR),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c'=>s(AESDEC),_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c'=>s(AESENC),_=>N}_=>N}b'i'=>match r[4]{b'm'=>match r[5]{b'c'=>s(AESIMC),_=>N}_=>N}_=>N}_=>N}b'k'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'b'=>s(AKMOVB),b'd'=>s(AKMOVD),b'q'=>s(AKMOVQ),b'w'=>s(AKMOVW),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'z'=>match r[3]{b'c'=>match r[4]{b'n'=>match r[5]{b't'=>s(ALZCNT),_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'b'=>match r[5]{b'e'=>s(AMOVBE),_=>N}b'r'=>match r[5]{b's'=>s(AMOVRS),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b'd'=>match r[3]{b'n'=>match r[4]{b'p' => match r[5]{b'd'=>s(ANDNPD),b's'=>s(ANDNPS),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'd'=>match r[3]{b'm'=>match r[4]{b's'=>match r[5]{b'r'=>s(ARDMSR),_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'z'=>match r[3]{b'c'=>match r[4]{b'n'=>match r[5]{b't'=>s(ATZCNT),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[2]{b'r'=>match r[3]{b's'=>match r[4]{b's' => match r[5]{b'd'=>s(AWRSSD),b'q'=>s(AWRSSQ),_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'a'=>s(ACMOVA),b'b'=>s(ACMOVB),b'c'=>s(ACMOVC),b'e'=>s(ACMOVE),b'g'=>s(ACMOVG),b'l'=>s(ACMOVL),b'o'=>s(ACMOVO),b'p'=>s(ACMOVP),b's'=>s(ACMOVS),b'z'=>s(ACMOVZ),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'c'=>match r[4]{b'3'=>match r[5]{b'2'=>s(ACRC32),_=>N}_=>N}_=>N}_=>N}_=>N}b'b' => match r[1]{b'l'=>match r[2]{b's'=>match r[3]{b'm'=>match r[4]{b's'=>match r[5]{b'k'=>s(BLSMSK),_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b't'=>match r[3]{b'e' => match r[4]{b'b'=>match r[5]{b'e'=>s(BYTEBE),_=>N}b'l'=>match r[5]{b'e'=>s(BYTELE),_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'a'=>match r[5]{b'e'=>s(CCMPAE),_=>N}b'b'=>match r[5]{b'e'=>s(CCMPBE),_=>N}b'g'=>match r[5]{b'e'=>s(CCMPGE),_=>N}b'l'=>match r[5]{b'e'=>s(CCMPLE),_=>N}b'n' => match r[5]{b'a'=>s(CCMPNA),b'b'=>s(CCMPNB),b'c'=>s(CCMPNC),b'e'=>s(CCMPNE),b'g'=>s(CCMPNG),b'l'=>s(CCMPNL),b'o'=>s(CCMPNO),b's'=>s(CCMPNS),b'z'=>s(CCMPNZ),_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'a'=>match r[5]{b'e'=>s(CMOVAE),_=>N}b'b'=>match r[5]{b'e'=>s(CMOVBE),_=>N}b'g'=>match r[5]{b'e'=>s(CMOVGE),_=>N}b'l'=>match r[5]{b'e'=>s(CMOVLE),_=>N}b'n' => match r[5]{b'a'=>s(CMOVNA),b'b'=>s(CMOVNB),b'c'=>s(CMOVNC),b'e'=>s(CMOVNE),b'g'=>s(CMOVNG),b'l'=>s(CMOVNL),b'o'=>s(CMOVNO),b'p'=>s(CMOVNP),b's'=>s(CMOVNS),b'z'=>s(CMOVNZ),_=>N}b'p' => match r[5]{b'e'=>s(CMOVPE),b'o'=>s(CMOVPO),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'm'=>match r[3]{b'i'=>match r[4]{b's' => match r[5]{b'd'=>s(COMISD),b's'=>s(COMISS),_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't' => match r[5]{b'a'=>s(CTESTA),b'b'=>s(CTESTB),b'c'=>s(CTESTC),b'e'=>s(CTESTE),b'f'=>s(CTESTF),b'g'=>s(CTESTG),b'l'=>s(CTESTL),b'o'=>s(CTESTO),b's'=>s(CTESTS),b't'=>s(CTESTT),b'z'=>s(CTESTZ),_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'd'=>match r[2]{b'i'=>match r[3]{b'v'=>match r[4]{b'r'=>match r[5]{b'p'=>s(FDIVRP),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd' => match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'v'=>s(FLDENV),_=>N}_=>N}b'l' => match r[4]{b'g'=>match r[5]{b'2'=>s(FLDLG2),_=>N}b'n'=>match r[5]{b'2'=>s(FLDLN2),_=>N}b'2' => match r[5]{b'e'=>s(FLDL2E),b't'=>s(FLDL2T),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>s(FRSTOR),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm' => match r[5]{b'i'=>s(FUCOMI),b'p'=>s(FUCOMP),_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b's'=>match r[3]{b'a'=>match r[4]{b'v'=>match r[5]{b'e'=>s(FXSAVE),_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'b'=>s(FCMOVB),b'e'=>s(FCMOVE),b'u'=>s(FCMOVU),_=>N}_=>N}_=>N}b'o'=>match r[3]{b'm' => match r[4]{b'i'=>match r[5]{b'p'=>s(FCOMIP),_=>N}b'p'=>match r[5]{b'p'=>s(FCOMPP),_=>N}_=>N}_=>N}_=>N}b'i' => match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>match r[5]{b'p'=>s(FICOMP),_=>N}_=>N}_=>N}b'd'=>match r[3]{b'i'=>match r[4]{b'v'=>match r[5]{b'r'=>s(FIDIVR),_=>N}_=>N}_=>N}b's' => match r[3]{b't'=>match r[4]{b't'=>match r[5]{b'p'=>s(FISTTP),_=>N}_=>N}b'u'=>match r[4]{b'b'=>match r[5]{b'r'=>s(FISUBR),_=>N}_=>N}_=>N}_=>N}b'n' => match r[2]{b'c'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'x'=>s(FNCLEX),_=>N}_=>N}_=>N}b'd'=>match r[3]{b'i'=>match r[4]{b's'=>match r[5]{b'i'=>s(FNDISI),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n'=>match r[4]{b'i'=>match r[5]{b't'=>s(FNINIT),_=>N}_=>N}_=>N}b's' => match r[3]{b'a'=>match r[4]{b'v'=>match r[5]{b'e'=>s(FNSAVE),_=>N}_=>N}b't' => match r[4]{b'c'=>match r[5]{b'w'=>s(FNSTCW),_=>N}b's'=>match r[5]{b'w'=>s(FNSTSW),_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'a'=>match r[5]{b'n'=>s(FPATAN),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'e'=>match r[4]{b'm'=>match r[5]{b'1'=>s(FPREM1),_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'c'=>match r[3]{b'a'=>match r[4]{b'l'=>match r[5]{b'e'=>s(FSCALE),_=>N}_=>N}_=>N}b'e'=>match r[3]{b't'=>match r[4]{b'p'=>match r[5]{b'm'=>s(FSETPM),_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'v'=>s(FSTENV),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b'r'=>match r[5]{b'p'=>s(FSUBRP),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'p' => match r[5]{b'd'=>s(HADDPD),b's'=>s(HADDPS),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b't'=>s(HRESET),_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b'b'=>match r[4]{b'p' => match r[5]{b'd'=>s(HSUBPD),b's'=>s(HSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'k' => match r[1]{b'a'=>match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b'n' => match r[5]{b'b'=>s(KANDNB),b'd'=>s(KANDND),b'q'=>s(KANDNQ),b'w'=>s(KANDNW),_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't' => match r[5]{b'b'=>s(KTESTB),b'd'=>s(KTESTD),b'q'=>s(KTESTQ),b'w'=>s(KTESTW),_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b'n'=>match r[3]{b'o'=>match r[4]{b'r' => match r[5]{b'b'=>s(KXNORB),b'd'=>s(KXNORD),b'q'=>s(KXNORQ),b'w'=>s(KXNORW),_=>N}_=>N}_=>N}_=>N}_=>N}b'l' => match r[1]{b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(LFENCE),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'o'=>match r[3]{b'p'=>match r[4]{b'n'=>match r[5]{b'e'=>s(LOOPNE),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(MFENCE),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'v' => match r[3]{b'a'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVAPD),b's'=>s(MOVAPS),_=>N}_=>N}b'd'=>match r[4]{b'q'=>match r[5]{b'a'=>s(MOVDQA),_=>N}_=>N}b'h'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVHPD),b's'=>s(MOVHPS),_=>N}_=>N}b'l'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVLPD),b's'=>s(MOVLPS),_=>N}_=>N}b'n'=>match r[4]{b't'=>match r[5]{b'i'=>s(MOVNTI),_=>N}_=>N}b's'=>match r[4]{b'x'=>match r[5]{b'd'=>s(MOVSXD),_=>N}_=>N}b'u'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVUPD),b's'=>s(MOVUPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b's' => match r[5]{b'b'=>s(PADDSB),b'w'=>s(PADDSW),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b'k'=>match r[5]{b'b'=>s(PBNDKB),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r' => match r[5]{b'b'=>s(PEXTRB),b'd'=>s(PEXTRD),b'q'=>s(PEXTRQ),b'w'=>s(PEXTRW),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'r' => match r[5]{b'b'=>s(PINSRB),b'd'=>s(PINSRD),b'q'=>s(PINSRQ),b'w'=>s(PINSRW),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'p'=>match r[3]{b'c'=>match r[4]{b'n'=>match r[5]{b't'=>s(POPCNT),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h' => match r[4]{b'2'=>match r[5]{b'p'=>s(PUSH2P),_=>N}b'a' => match r[5]{b'd'=>s(PUSHAD),b'q'=>s(PUSHAQ),b'w'=>s(PUSHAW),_=>N}b'f' => match r[5]{b'd'=>s(PUSHFD),b'q'=>s(PUSHFQ),_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd' => match r[5]{b'd'=>s(PHADDD),b'w'=>s(PHADDW),_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b' => match r[5]{b'd'=>s(PHSUBD),b'w'=>s(PHSUBW),_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'x' => match r[4]{b's' => match r[5]{b'b'=>s(PMAXSB),b'd'=>s(PMAXSD),b'w'=>s(PMAXSW),_=>N}b'u' => match r[5]{b'd'=>s(PMAXUD),b'w'=>s(PMAXUW),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n' => match r[4]{b's' => match r[5]{b'b'=>s(PMINSB),b'd'=>s(PMINSD),b'w'=>s(PMINSW),_=>N}b'u'=>match r[5]{b'w'=>s(PMINUW),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'd'=>match r[5]{b'q'=>s(PMULDQ),_=>N}b'h'=>match r[5]{b'w'=>s(PMULHW),_=>N}b'l' => match r[5]{b'd'=>s(PMULLD),b'w'=>s(PMULLW),_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f' => match r[5]{b'b'=>s(PSHUFB),b'd'=>s(PSHUFD),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'g'=>match r[4]{b'n' => match r[5]{b'b'=>s(PSIGNB),b'd'=>s(PSIGND),b'w'=>s(PSIGNW),_=>N}_=>N}_=>N}b'l'=>match r[3]{b'l'=>match r[4]{b'd'=>match r[5]{b'q'=>s(PSLLDQ),_=>N}_=>N}_=>N}b'm'=>match r[3]{b'a'=>match r[4]{b's'=>match r[5]{b'h'=>s(PSMASH),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'l'=>match r[4]{b'd'=>match r[5]{b'q'=>s(PSRLDQ),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b's' => match r[5]{b'b'=>s(PSUBSB),b'w'=>s(PSUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b't' => match r[3]{b'a'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETAZU),_=>N}_=>N}b'b'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETBZU),_=>N}_=>N}b'c'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETCZU),_=>N}_=>N}b'e'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETEZU),_=>N}_=>N}b'g'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETGZU),_=>N}_=>N}b'l'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETLZU),_=>N}_=>N}b'o'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETOZU),_=>N}_=>N}b'p'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETPZU),_=>N}_=>N}b's'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETSZU),_=>N}_=>N}b'z'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETZZU),_=>N}_=>N}b'n' => match r[4]{b'a'=>match r[5]{b'e'=>s(SETNAE),_=>N}b'b'=>match r[5]{b'e'=>s(SETNBE),_=>N}b'g'=>match r[5]{b'e'=>s(SETNGE),_=>N}b'l'=>match r[5]{b'e'=>s(SETNLE),_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(SFENCE),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'u'=>match r[3]{b'f'=>match r[4]{b'p'=>match r[5]{b's'=>s(SHUFPS),_=>N}_=>N}_=>N}_=>N}b'k'=>match r[2]{b'i'=>match r[3]{b'n'=>match r[4]{b'i'=>match r[5]{b't'=>s(SKINIT),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[2]{b'r'=>match r[3]{b't' => match r[4]{b'p' => match r[5]{b'd'=>s(SQRTPD),b's'=>s(SQRTPS),_=>N}b's' => match r[5]{b'd'=>s(SQRTSD),b's'=>s(SQRTSS),_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'r'=>match r[3]{b'i'=>match r[4]{b'n'=>match r[5]{b'g'=>s(STRING),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[2]{b'a'=>match r[3]{b'p'=>match r[4]{b'g'=>match r[5]{b's'=>s(SWAPGS),_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's'=>match r[3]{b'r'=>match r[4]{b'e'=>match r[5]{b't'=>s(SYSRET),_=>N}_=>N}_=>N}_=>N}_=>N}b't' => match r[1]{b'd'=>match r[2]{b'c'=>match r[3]{b'a'=>match r[4]{b'l'=>match r[5]{b'l'=>s(TDCALL),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'u'=>match r[5]{b'i'=>s(TESTUI),_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'a'=>match r[3]{b'u'=>match r[4]{b's'=>match r[5]{b'e'=>s(TPAUSE),_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'p' => match r[5]{b'd'=>s(VCMPPD),b'h'=>s(VCMPPH),b's'=>s(VCMPPS),_=>N}b's' => match r[5]{b'd'=>s(VCMPSD),b'h'=>s(VCMPSH),b's'=>s(VCMPSS),_=>N}_=>N}_=>N}_=>N}b'd'=>match r[2]{b'i'=>match r[3]{b'v' => match r[4]{b'p' => match r[5]{b'd'=>s(VDIVPD),b'h'=>s(VDIVPH),b's'=>s(VDIVPS),_=>N}b's' => match r[5]{b'd'=>s(VDIVSD),b'h'=>s(VDIVSH),b's'=>s(VDIVSS),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'q'=>match r[5]{b'u'=>s(VLDDQU),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'c'=>match r[3]{b'p' => match r[4]{b'p' => match r[5]{b'h'=>s(VRCPPH),b's'=>s(VRCPPS),_=>N}b's' => match r[5]{b'h'=>s(VRCPSH),b's'=>s(VRCPSS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b'b' => match r[4]{b'p' => match r[5]{b'd'=>s(VSUBPD),b'h'=>s(VSUBPH),b's'=>s(VSUBPS),_=>N}b's' => match r[5]{b'd'=>s(VSUBSD),b'h'=>s(VSUBSH),b's'=>s(VSUBSS),_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'p' => match r[5]{b'd'=>s(VXORPD),b's'=>s(VXORPS),_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'd'=>match r[3]{b'd' => match r[4]{b'p' => match r[5]{b'd'=>s(VADDPD),b'h'=>s(VADDPH),b's'=>s(VADDPS),_=>N}b's' => match r[5]{b'd'=>s(VADDSD),b'h'=>s(VADDSH),b's'=>s(VADDSS),_=>N}_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'p' => match r[5]{b'd'=>s(VANDPD),b's'=>s(VANDPS),_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'x' => match r[4]{b'p' => match r[5]{b'd'=>s(VMAXPD),b'h'=>s(VMAXPH),b's'=>s(VMAXPS),_=>N}b's' => match r[5]{b'd'=>s(VMAXSD),b'h'=>s(VMAXSH),b's'=>s(VMAXSS),_=>N}_=>N}_=>N}b'c'=>match r[3]{b'a'=>match r[4]{b'l'=>match r[5]{b'l'=>s(VMCALL),_=>N}_=>N}_=>N}b'f'=>match r[3]{b'u'=>match r[4]{b'n'=>match r[5]{b'c'=>s(VMFUNC),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n' => match r[4]{b'p' => match r[5]{b'd'=>s(VMINPD),b'h'=>s(VMINPH),b's'=>s(VMINPS),_=>N}b's' => match r[5]{b'd'=>s(VMINSD),b'h'=>s(VMINSH),b's'=>s(VMINSS),_=>N}_=>N}_=>N}b'l'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>s(VMLOAD),_=>N}_=>N}_=>N}b'o'=>match r[3]{b'v'=>match r[4]{b's' => match r[5]{b'd'=>s(VMOVSD),b'h'=>s(VMOVSH),b's'=>s(VMOVSS),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'e'=>match r[4]{b'a'=>match r[5]{b'd'=>s(VMREAD),_=>N}_=>N}_=>N}b's'=>match r[3]{b'a'=>match r[4]{b'v'=>match r[5]{b'e'=>s(VMSAVE),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'p' => match r[5]{b'd'=>s(VMULPD),b'h'=>s(VMULPH),b's'=>s(VMULPS),_=>N}b's' => match r[5]{b'd'=>s(VMULSD),b'h'=>s(VMULSH),b's'=>s(VMULSS),_=>N}_=>N}_=>N}b'x'=>match r[3]{b'o'=>match r[4]{b'f'=>match r[5]{b'f'=>s(VMXOFF),_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'p' => match r[5]{b'b'=>s(VPCMPB),b'd'=>s(VPCMPD),b'q'=>s(VPCMPQ),b'w'=>s(VPCMPW),_=>N}_=>N}_=>N}b'e'=>match r[3]{b'r'=>match r[4]{b'm' => match r[5]{b'b'=>s(VPERMB),b'd'=>s(VPERMD),b'q'=>s(VPERMQ),b'w'=>s(VPERMW),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'o' => match r[4]{b'l' => match r[5]{b'd'=>s(VPROLD),b'q'=>s(VPROLQ),_=>N}b'r' => match r[5]{b'd'=>s(VPRORD),b'q'=>s(VPRORQ),_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b's'=>match r[5]{b't'=>s(VPTEST),_=>N}_=>N}_=>N}b'a' => match r[3]{b'd'=>match r[4]{b'd' => match r[5]{b'b'=>s(VPADDB),b'd'=>s(VPADDD),b'q'=>s(VPADDQ),b'w'=>s(VPADDW),_=>N}_=>N}b'n'=>match r[4]{b'd'=>match r[5]{b'n'=>s(VPANDN),_=>N}_=>N}b'v'=>match r[4]{b'g' => match r[5]{b'b'=>s(VPAVGB),b'w'=>s(VPAVGW),_=>N}_=>N}_=>N}b's' => match r[3]{b'l'=>match r[4]{b'l' => match r[5]{b'd'=>s(VPSLLD),b'q'=>s(VPSLLQ),b'w'=>s(VPSLLW),_=>N}_=>N}b'u'=>match r[4]{b'b' => match r[5]{b'b'=>s(VPSUBB),b'd'=>s(VPSUBD),b'q'=>s(VPSUBQ),b'w'=>s(VPSUBW),_=>N}_=>N}b'r' => match r[4]{b'a' => match r[5]{b'd'=>s(VPSRAD),b'w'=>s(VPSRAW),_=>N}b'l' => match r[5]{b'd'=>s(VPSRLD),b'q'=>s(VPSRLQ),b'w'=>s(VPSRLW),_=>N}_=>N}_=>N}_=>N}_=>N}b'w' => match r[1]{b'b'=>match r[2]{b'i'=>match r[3]{b'n'=>match r[4]{b'v'=>match r[5]{b'd'=>s(WBINVD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'd' => match r[4]{b'b'=>match r[5]{b'e'=>s(WORDBE),_=>N}b'l'=>match r[5]{b'e'=>s(WORDLE),_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'p'=>match r[3]{b'k'=>match r[4]{b'r'=>match r[5]{b'u'=>s(WRPKRU),_=>N}_=>N}_=>N}b'u'=>match r[3]{b's'=>match r[4]{b's' => match r[5]{b'd'=>s(WRUSSD),b'q'=>s(WRUSSQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'b'=>match r[3]{b'o'=>match r[4]{b'r'=>match r[5]{b't'=>s(XABORT),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'e'=>match r[3]{b'g'=>match r[4]{b'i'=>match r[5]{b'n'=>s(XBEGIN),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[2]{b'e'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'v'=>s(XGETBV),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>s(XRSTOR),_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e' => match r[5]{b'c'=>s(XSAVEC),b's'=>s(XSAVES),_=>N}_=>N}_=>N}b'e'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'v'=>s(XSETBV),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}7=>match r[0]{b'b'=>match r[1]{b'l'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'd'=>match r[5]{b'p' => match r[6]{b'd'=>s(BLENDPD),b's'=>s(BLENDPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[1]{b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd' => match r[5]{b'b'=>match r[6]{b'e'=>s(DWORDBE),_=>N}b'l'=>match r[6]{b'e'=>s(DWORDLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[1]{b'n' => match r[2]{b'd'=>match r[3]{b'b'=>match r[4]{b'r' => match r[5]{b'3'=>match r[6]{b'2'=>s(ENDBR32),_=>N}b'6'=>match r[6]{b'4'=>s(ENDBR64),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[3]{b'c'=>match r[4]{b'm'=>match r[5]{b'd'=>match r[6]{b's'=>s(ENQCMDS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'n' => match r[2]{b'c'=>match r[3]{b's'=>match r[4]{b's'=>match r[5]{b'p' => match r[6]{b'd'=>s(INCSSPD),b'q'=>s(INCSSPQ),_=>N}_=>N}_=>N}_=>N}b'v' => match r[3]{b'l'=>match r[4]{b'p'=>match r[5]{b'g'=>match r[6]{b'a'=>s(INVLPGA),_=>N}_=>N}_=>N}b'p'=>match r[4]{b'c'=>match r[5]{b'i'=>match r[6]{b'd'=>s(INVPCID),_=>N}_=>N}_=>N}b'v'=>match r[4]{b'p'=>match r[5]{b'i'=>match r[6]{b'd'=>s(INVVPID),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[1]{b'd'=>match r[2]{b'm'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b's'=>match r[6]{b'r'=>s(LDMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'q'=>match r[1]{b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd' => match r[5]{b'b'=>match r[6]{b'e'=>s(QWORDBE),_=>N}b'l'=>match r[6]{b'e'=>s(QWORDLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'c'=>match r[2]{b'o'=>match r[3]{b'm'=>match r[4]{b'i'=>match r[5]{b's' => match r[6]{b'd'=>s(UCOMISD),b's'=>s(UCOMISS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b's'=>match r[4]{b'm'=>match r[5]{b's'=>match r[6]{b'k'=>s(ABLSMSK),_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'a'=>match r[6]{b'e'=>s(ACMOVAE),_=>N}b'b'=>match r[6]{b'e'=>s(ACMOVBE),_=>N}b'g'=>match r[6]{b'e'=>s(ACMOVGE),_=>N}b'l'=>match r[6]{b'e'=>s(ACMOVLE),_=>N}b'n' => match r[6]{b'a'=>s(ACMOVNA),b'b'=>s(ACMOVNB),b'c'=>s(ACMOVNC),b'e'=>s(ACMOVNE),b'g'=>s(ACMOVNG),b'l'=>s(ACMOVNL),b'o'=>s(ACMOVNO),b'p'=>s(ACMOVNP),b's'=>s(ACMOVNS),b'z'=>s(ACMOVNZ),_=>N}b'p' => match r[6]{b'e'=>s(ACMOVPE),b'o'=>s(ACMOVPO),_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'n'=>match r[3]{b'q'=>match r[4]{b'c'=>match r[5]{b'm'=>match r[6]{b'd'=>s(AENQCMD),_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'o'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'n'=>match r[6]{b't'=>s(APOPCNT),_=>N}_=>N}_=>N}_=>N}_=>N}b'w'=>match r[2]{b'r'=>match r[3]{b'u'=>match r[4]{b's'=>match r[5]{b's' => match r[6]{b'd'=>s(AWRUSSD),b'q'=>s(AWRUSSQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'i' => match r[2]{b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>match r[5]{b'z'=>match r[6]{b'u'=>s(AIMULZU),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[3]{b'v'=>match r[4]{b'e'=>match r[5]{b'p'=>match r[6]{b't'=>s(AINVEPT),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p'=>match r[4]{b'n' => match r[5]{b'a'=>match r[6]{b'e'=>s(CCMPNAE),_=>N}b'b'=>match r[6]{b'e'=>s(CCMPNBE),_=>N}b'g'=>match r[6]{b'e'=>s(CCMPNGE),_=>N}b'l'=>match r[6]{b'e'=>s(CCMPNLE),_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'o'=>match r[5]{b'v' => match r[6]{b'a'=>s(CFCMOVA),b'b'=>s(CFCMOVB),b'c'=>s(CFCMOVC),b'e'=>s(CFCMOVE),b'g'=>s(CFCMOVG),b'l'=>s(CFCMOVL),b'o'=>s(CFCMOVO),b'p'=>s(CFCMOVP),b's'=>s(CFCMOVS),b'z'=>s(CFCMOVZ),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'f'=>match r[3]{b'l'=>match r[4]{b'u'=>match r[5]{b's'=>match r[6]{b'h'=>s(CLFLUSH),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't' => match r[5]{b'a'=>match r[6]{b'e'=>s(CTESTAE),_=>N}b'b'=>match r[6]{b'e'=>s(CTESTBE),_=>N}b'g'=>match r[6]{b'e'=>s(CTESTGE),_=>N}b'l'=>match r[6]{b'e'=>s(CTESTLE),_=>N}b'n' => match r[6]{b'a'=>s(CTESTNA),b'b'=>s(CTESTNB),b'c'=>s(CTESTNC),b'e'=>s(CTESTNE),b'g'=>s(CTESTNG),b'l'=>s(CTESTNL),b'o'=>s(CTESTNO),b's'=>s(CTESTNS),b'z'=>s(CTESTNZ),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v'=>match r[4]{b'n' => match r[5]{b'a'=>match r[6]{b'e'=>s(CMOVNAE),_=>N}b'b'=>match r[6]{b'e'=>s(CMOVNBE),_=>N}b'g'=>match r[6]{b'e'=>s(CMOVNGE),_=>N}b'l'=>match r[6]{b'e'=>s(CMOVNLE),_=>N}_=>N}_=>N}_=>N}b'p' => match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(CMPSTRB),b'd'=>s(CMPSTRD),b'q'=>s(CMPSTRQ),b'w'=>s(CMPSTRW),_=>N}_=>N}_=>N}b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>s(CMPXCHG),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'b'=>match r[6]{b'e'=>s(FCMOVBE),_=>N}b'n' => match r[6]{b'b'=>s(FCMOVNB),b'e'=>s(FCMOVNE),b'u'=>s(FCMOVNU),_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[2]{b'e'=>match r[3]{b'c'=>match r[4]{b's'=>match r[5]{b't'=>match r[6]{b'p'=>s(FDECSTP),_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b'c'=>match r[4]{b's'=>match r[5]{b't'=>match r[6]{b'p'=>s(FINCSTP),_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b's' => match r[3]{b'e'=>match r[4]{b't'=>match r[5]{b'p'=>match r[6]{b'm'=>s(FNSETPM),_=>N}_=>N}_=>N}b't'=>match r[4]{b'e'=>match r[5]{b'n'=>match r[6]{b'v'=>s(FNSTENV),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b'i'=>match r[5]{b'n'=>match r[6]{b't'=>s(FRNDINT),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm' => match r[5]{b'i'=>match r[6]{b'p'=>s(FUCOMIP),_=>N}b'p'=>match r[6]{b'p'=>s(FUCOMPP),_=>N}_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b'l'=>match r[3]{b'2'=>match r[4]{b'x'=>match r[5]{b'p'=>match r[6]{b'1'=>s(FYL2XP1),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'i'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'o'=>match r[6]{b's'=>s(FSINCOS),_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b's'=>match r[4]{b'w'=>match r[5]{b'a'=>match r[6]{b'x'=>s(FSTSWAX),_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[2]{b'r'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'o'=>match r[6]{b'r'=>s(FXRSTOR),_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'r'=>match r[4]{b'a'=>match r[5]{b'c'=>match r[6]{b't'=>s(FXTRACT),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'o' => match r[2]{b'n'=>match r[3]{b'i'=>match r[4]{b't'=>match r[5]{b'o'=>match r[6]{b'r'=>s(MONITOR),_=>N}_=>N}_=>N}_=>N}b'v' => match r[3]{b'h'=>match r[4]{b'l'=>match r[5]{b'p'=>match r[6]{b's'=>s(MOVHLPS),_=>N}_=>N}_=>N}b'l'=>match r[4]{b'h'=>match r[5]{b'p'=>match r[6]{b's'=>s(MOVLHPS),_=>N}_=>N}_=>N}b'n'=>match r[4]{b't' => match r[5]{b'd'=>match r[6]{b'q'=>s(MOVNTDQ),_=>N}b'p'=>match r[6]{b'd'=>s(MOVNTPD),_=>N}_=>N}_=>N}b'q'=>match r[4]{b'2'=>match r[5]{b'd'=>match r[6]{b'q'=>s(MOVQ2DQ),_=>N}_=>N}_=>N}b's'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(MOVSTRB),b'd'=>s(MOVSTRD),b'q'=>s(MOVSTRQ),b'w'=>s(MOVSTRW),_=>N}_=>N}_=>N}b'd' => match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b'p'=>s(MOVDDUP),_=>N}_=>N}b'i'=>match r[5]{b'r'=>match r[6]{b'i'=>s(MOVDIRI),_=>N}_=>N}b'q'=>match r[5]{b'2'=>match r[6]{b'q'=>s(MOVDQ2Q),_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b's'=>match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'b'=>match r[6]{b'w'=>s(MPSADBW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'w'=>s(PBLENDW),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'w'=>match r[3]{b'r'=>match r[4]{b'i'=>match r[5]{b't'=>match r[6]{b'e'=>s(PTWRITE),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'u'=>match r[5]{b's' => match r[6]{b'b'=>s(PADDUSB),b'w'=>s(PADDUSW),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[3]{b'i'=>match r[4]{b'g'=>match r[5]{b'n'=>match r[6]{b'r'=>s(PALIGNR),_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'e'=>match r[5]{b'q' => match r[6]{b'b'=>s(PCMPEQB),b'd'=>s(PCMPEQD),b'q'=>s(PCMPEQQ),b'w'=>s(PCMPEQW),_=>N}_=>N}b'g'=>match r[5]{b't' => match r[6]{b'b'=>s(PCMPGTB),b'd'=>s(PCMPGTD),b'q'=>s(PCMPGTQ),b'w'=>s(PCMPGTW),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[3]{b'n'=>match r[4]{b'f'=>match r[5]{b'i'=>match r[6]{b'g'=>s(PCONFIG),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b's'=>match r[6]{b'w'=>s(PHADDSW),_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b's'=>match r[6]{b'w'=>s(PHSUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'w'=>match r[6]{b'd'=>s(PMADDWD),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'h'=>match r[5]{b'u'=>match r[6]{b'w'=>s(PMULHUW),_=>N}_=>N}b'u'=>match r[5]{b'd'=>match r[6]{b'q'=>s(PMULUDQ),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f' => match r[5]{b'h'=>match r[6]{b'w'=>s(PSHUFHW),_=>N}b'l'=>match r[6]{b'w'=>s(PSHUFLW),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b'u'=>match r[5]{b's' => match r[6]{b'b'=>s(PSUBUSB),b'w'=>s(PSUBUSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b'r'=>match r[4]{b'e'=>match r[5]{b'a'=>match r[6]{b'd'=>s(RMPREAD),_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'u'=>match r[3]{b'n'=>match r[4]{b'd' => match r[5]{b'p' => match r[6]{b'd'=>s(ROUNDPD),b's'=>s(ROUNDPS),_=>N}b's' => match r[6]{b'd'=>s(ROUNDSD),b's'=>s(ROUNDSS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'q'=>match r[3]{b'r'=>match r[4]{b't' => match r[5]{b'p'=>match r[6]{b's'=>s(RSQRTPS),_=>N}b's'=>match r[6]{b's'=>s(RSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b't'=>match r[2]{b'm'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b's'=>match r[6]{b'r'=>s(STMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's' => match r[3]{b'c'=>match r[4]{b'a'=>match r[5]{b'l'=>match r[6]{b'l'=>s(SYSCALL),_=>N}_=>N}_=>N}b'e'=>match r[4]{b'x'=>match r[5]{b'i'=>match r[6]{b't'=>s(SYSEXIT),_=>N}_=>N}_=>N}_=>N}_=>N}b'e' => match r[2]{b'a'=>match r[3]{b'm' => match r[4]{b'o'=>match r[5]{b'p'=>match r[6]{b's'=>s(SEAMOPS),_=>N}_=>N}b'r'=>match r[5]{b'e'=>match r[6]{b't'=>s(SEAMRET),_=>N}_=>N}_=>N}_=>N}b't' => match r[3]{b'a'=>match r[4]{b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETAEZU),_=>N}_=>N}_=>N}b'b'=>match r[4]{b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETBEZU),_=>N}_=>N}_=>N}b'g'=>match r[4]{b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETGEZU),_=>N}_=>N}_=>N}b'l'=>match r[4]{b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETLEZU),_=>N}_=>N}_=>N}b's'=>match r[4]{b's'=>match r[5]{b'b'=>match r[6]{b'y'=>s(SETSSBY),_=>N}_=>N}_=>N}b'n' => match r[4]{b'a'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNAZU),_=>N}_=>N}b'b'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNBZU),_=>N}_=>N}b'c'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNCZU),_=>N}_=>N}b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNEZU),_=>N}_=>N}b'g'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNGZU),_=>N}_=>N}b'l'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNLZU),_=>N}_=>N}b'o'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNOZU),_=>N}_=>N}b'p'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNPZU),_=>N}_=>N}b's'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNSZU),_=>N}_=>N}b'z'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNZZU),_=>N}_=>N}_=>N}b'p' => match r[4]{b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETPEZU),_=>N}_=>N}b'o'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETPOZU),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'o'=>match r[3]{b'm'=>match r[4]{b'i'=>match r[5]{b's' => match r[6]{b'd'=>s(VCOMISD),b'h'=>s(VCOMISH),b's'=>s(VCOMISS),_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b'p'=>match r[4]{b'2'=>match r[5]{b'p' => match r[6]{b'd'=>s(VEXP2PD),b's'=>s(VEXP2PS),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'p' => match r[6]{b'd'=>s(VTESTPD),b's'=>s(VTESTPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'e'=>match r[3]{b's' => match r[4]{b'd'=>match r[5]{b'e'=>match r[6]{b'c'=>s(VAESDEC),_=>N}_=>N}b'e'=>match r[5]{b'n'=>match r[6]{b'c'=>s(VAESENC),_=>N}_=>N}b'i'=>match r[5]{b'm'=>match r[6]{b'c'=>s(VAESIMC),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[3]{b'i'=>match r[4]{b'g'=>match r[5]{b'n' => match r[6]{b'd'=>s(VALIGND),b'q'=>s(VALIGNQ),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'n'=>match r[5]{b'p' => match r[6]{b'd'=>s(VANDNPD),b's'=>s(VANDNPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'p' => match r[6]{b'd'=>s(VHADDPD),b's'=>s(VHADDPS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b'p' => match r[6]{b'd'=>s(VHSUBPD),b's'=>s(VHSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'c'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'a'=>match r[6]{b'r'=>s(VMCLEAR),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b'e'=>match r[4]{b'x'=>match r[5]{b'i'=>match r[6]{b't'=>s(VMGEXIT),_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'c'=>match r[4]{b'a'=>match r[5]{b'l'=>match r[6]{b'l'=>s(VMMCALL),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[3]{b'v' => match r[4]{b'a'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVAPD),b's'=>s(VMOVAPS),_=>N}_=>N}b'd'=>match r[5]{b'q'=>match r[6]{b'a'=>s(VMOVDQA),_=>N}_=>N}b'h'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVHPD),b's'=>s(VMOVHPS),_=>N}_=>N}b'l'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVLPD),b's'=>s(VMOVLPS),_=>N}_=>N}b'u'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVUPD),b's'=>s(VMOVUPS),_=>N}_=>N}_=>N}_=>N}b'p'=>match r[3]{b't'=>match r[4]{b'r' => match r[5]{b'l'=>match r[6]{b'd'=>s(VMPTRLD),_=>N}b's'=>match r[6]{b't'=>s(VMPTRST),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[3]{b'r'=>match r[4]{b'i'=>match r[5]{b't'=>match r[6]{b'e'=>s(VMWRITE),_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b's' => match r[6]{b'b'=>s(VPADDSB),b'w'=>s(VPADDSW),_=>N}_=>N}_=>N}_=>N}b'c'=>match r[3]{b'm'=>match r[4]{b'p'=>match r[5]{b'u' => match r[6]{b'b'=>s(VPCMPUB),b'd'=>s(VPCMPUD),b'q'=>s(VPCMPUQ),b'w'=>s(VPCMPUW),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n'=>match r[4]{b's'=>match r[5]{b'r' => match r[6]{b'b'=>s(VPINSRB),b'd'=>s(VPINSRD),b'q'=>s(VPINSRQ),b'w'=>s(VPINSRW),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b'o' => match r[4]{b'l'=>match r[5]{b'v' => match r[6]{b'd'=>s(VPROLVD),b'q'=>s(VPROLVQ),_=>N}_=>N}b'r'=>match r[5]{b'v' => match r[6]{b'd'=>s(VPRORVD),b'q'=>s(VPRORVQ),_=>N}_=>N}_=>N}_=>N}b'e' => match r[3]{b'r'=>match r[4]{b'm'=>match r[5]{b'p' => match r[6]{b'd'=>s(VPERMPD),b's'=>s(VPERMPS),_=>N}_=>N}_=>N}b'x'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(VPEXTRB),b'd'=>s(VPEXTRD),b'q'=>s(VPEXTRQ),b'w'=>s(VPEXTRW),_=>N}_=>N}_=>N}_=>N}b'h' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd' => match r[6]{b'd'=>s(VPHADDD),b'w'=>s(VPHADDW),_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b' => match r[6]{b'd'=>s(VPHSUBD),b'w'=>s(VPHSUBW),_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'a'=>match r[4]{b'x' => match r[5]{b's' => match r[6]{b'b'=>s(VPMAXSB),b'd'=>s(VPMAXSD),b'w'=>s(VPMAXSW),_=>N}b'u' => match r[6]{b'b'=>s(VPMAXUB),b'd'=>s(VPMAXUD),b'w'=>s(VPMAXUW),_=>N}_=>N}_=>N}b'i'=>match r[4]{b'n' => match r[5]{b's' => match r[6]{b'b'=>s(VPMINSB),b'd'=>s(VPMINSD),b'w'=>s(VPMINSW),_=>N}b'u' => match r[6]{b'b'=>s(VPMINUB),b'w'=>s(VPMINUW),_=>N}_=>N}_=>N}b'o'=>match r[4]{b'v' => match r[5]{b'w'=>match r[6]{b'b'=>s(VPMOVWB),_=>N}b'd' => match r[6]{b'b'=>s(VPMOVDB),b'w'=>s(VPMOVDW),_=>N}b'q' => match r[6]{b'b'=>s(VPMOVQB),b'd'=>s(VPMOVQD),b'w'=>s(VPMOVQW),_=>N}_=>N}_=>N}b'u'=>match r[4]{b'l' => match r[5]{b'd'=>match r[6]{b'q'=>s(VPMULDQ),_=>N}b'h'=>match r[6]{b'w'=>s(VPMULHW),_=>N}b'l' => match r[6]{b'd'=>s(VPMULLD),b'w'=>s(VPMULLW),_=>N}_=>N}_=>N}_=>N}b's' => match r[3]{b'i'=>match r[4]{b'g'=>match r[5]{b'n' => match r[6]{b'b'=>s(VPSIGNB),b'd'=>s(VPSIGND),b'w'=>s(VPSIGNW),_=>N}_=>N}_=>N}b'l'=>match r[4]{b'l'=>match r[5]{b'v' => match r[6]{b'd'=>s(VPSLLVD),b'q'=>s(VPSLLVQ),b'w'=>s(VPSLLVW),_=>N}_=>N}_=>N}b'u'=>match r[4]{b'b'=>match r[5]{b's' => match r[6]{b'b'=>s(VPSUBSB),b'w'=>s(VPSUBSW),_=>N}_=>N}_=>N}b'h' => match r[4]{b'l'=>match r[5]{b'd' => match r[6]{b'd'=>s(VPSHLDD),b'q'=>s(VPSHLDQ),b'w'=>s(VPSHLDW),_=>N}_=>N}b'r'=>match r[5]{b'd' => match r[6]{b'd'=>s(VPSHRDD),b'q'=>s(VPSHRDQ),b'w'=>s(VPSHRDW),_=>N}_=>N}_=>N}b'r' => match r[4]{b'a'=>match r[5]{b'v' => match r[6]{b'd'=>s(VPSRAVD),b'q'=>s(VPSRAVQ),b'w'=>s(VPSRAVW),_=>N}_=>N}b'l' => match r[5]{b'd'=>match r[6]{b'q'=>s(VPSRLDQ),_=>N}b'v' => match r[6]{b'd'=>s(VPSRLVD),b'q'=>s(VPSRLVQ),b'w'=>s(VPSRLVW),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f'=>match r[5]{b'p'=>match r[6]{b's'=>s(VSHUFPS),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[3]{b'r'=>match r[4]{b't' => match r[5]{b'p' => match r[6]{b'd'=>s(VSQRTPD),b'h'=>s(VSQRTPH),b's'=>s(VSQRTPS),_=>N}b's' => match r[6]{b'd'=>s(VSQRTSD),b'h'=>s(VSQRTSH),b's'=>s(VSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'l'=>match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'6'=>match r[6]{b'4'=>s(XLATB64),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b's'=>s(XRSTORS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>match r[5]{b'6'=>match r[6]{b'4'=>s(XSAVE64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}8=>match r[0]{b'b'=>match r[1]{b'l'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'd'=>match r[5]{b'v'=>match r[6]{b'p' => match r[7]{b'd'=>s(BLENDVPD),b's'=>s(BLENDVPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'n'=>match r[2]{b's'=>match r[3]{b'e'=>match r[4]{b'r'=>match r[5]{b't'=>match r[6]{b'p'=>match r[7]{b's'=>s(INSERTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[1]{b'o'=>match r[2]{b'v' => match r[3]{b'm'=>match r[4]{b's'=>match r[5]{b'k'=>match r[6]{b'p'=>match r[7]{b'd'=>s(MOVMSKPD),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[4]{b't'=>match r[5]{b'd'=>match r[6]{b'q'=>match r[7]{b'a'=>s(MOVNTDQA),_=>N}_=>N}_=>N}_=>N}b's' => match r[4]{b'h'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(MOVSHDUP),_=>N}_=>N}_=>N}b'l'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(MOVSLDUP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v'=>match r[5]{b'n' => match r[6]{b'a'=>match r[7]{b'e'=>s(ACMOVNAE),_=>N}b'b'=>match r[7]{b'e'=>s(ACMOVNBE),_=>N}b'g'=>match r[7]{b'e'=>s(ACMOVNGE),_=>N}b'l'=>match r[7]{b'e'=>s(ACMOVNLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[2]{b'd'=>match r[3]{b's'=>match r[4]{b'u'=>match r[5]{b'b'=>match r[6]{b'p' => match r[7]{b'd'=>s(ADDSUBPD),b's'=>s(ADDSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'n'=>match r[3]{b'q'=>match r[4]{b'c'=>match r[5]{b'm'=>match r[6]{b'd'=>match r[7]{b's'=>s(AENQCMDS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b'v' => match r[4]{b'p'=>match r[5]{b'c'=>match r[6]{b'i'=>match r[7]{b'd'=>s(AINVPCID),_=>N}_=>N}_=>N}b'v'=>match r[5]{b'p'=>match r[6]{b'i'=>match r[7]{b'd'=>s(AINVVPID),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v'=>match r[4]{b'd'=>match r[5]{b'i'=>match r[6]{b'r'=>match r[7]{b'i'=>s(AMOVDIRI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'f'=>match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'o'=>match r[5]{b'v' => match r[6]{b'a'=>match r[7]{b'e'=>s(CFCMOVAE),_=>N}b'b'=>match r[7]{b'e'=>s(CFCMOVBE),_=>N}b'g'=>match r[7]{b'e'=>s(CFCMOVGE),_=>N}b'l'=>match r[7]{b'e'=>s(CFCMOVLE),_=>N}b'n' => match r[7]{b'a'=>s(CFCMOVNA),b'b'=>s(CFCMOVNB),b'c'=>s(CFCMOVNC),b'e'=>s(CFCMOVNE),b'g'=>s(CFCMOVNG),b'l'=>s(CFCMOVNL),b'o'=>s(CFCMOVNO),b'p'=>s(CFCMOVNP),b's'=>s(CFCMOVNS),b'z'=>s(CFCMOVNZ),_=>N}b'p' => match r[7]{b'e'=>s(CFCMOVPE),b'o'=>s(CFCMOVPO),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'p' => match r[3]{b'a'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPAXADD),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPBXADD),_=>N}_=>N}_=>N}_=>N}b'c'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPCXADD),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPEXADD),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPGXADD),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPLXADD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPOXADD),_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPSXADD),_=>N}_=>N}_=>N}_=>N}b'z'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPZXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'n' => match r[6]{b'a'=>match r[7]{b'e'=>s(CTESTNAE),_=>N}b'b'=>match r[7]{b'e'=>s(CTESTNBE),_=>N}b'g'=>match r[7]{b'e'=>s(CTESTNGE),_=>N}b'l'=>match r[7]{b'e'=>s(CTESTNLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v'=>match r[2]{b't' => match r[3]{b'd'=>match r[4]{b'q'=>match r[5]{b'2'=>match r[6]{b'p' => match r[7]{b'd'=>s(CVTDQ2PD),b's'=>s(CVTDQ2PS),_=>N}_=>N}_=>N}_=>N}b'p' => match r[4]{b'd'=>match r[5]{b'2' => match r[6]{b'd'=>match r[7]{b'q'=>s(CVTPD2DQ),_=>N}b'p' => match r[7]{b'i'=>s(CVTPD2PI),b's'=>s(CVTPD2PS),_=>N}_=>N}_=>N}b'i'=>match r[5]{b'2'=>match r[6]{b'p' => match r[7]{b'd'=>s(CVTPI2PD),b's'=>s(CVTPI2PS),_=>N}_=>N}_=>N}b's'=>match r[5]{b'2' => match r[6]{b'd'=>match r[7]{b'q'=>s(CVTPS2DQ),_=>N}b'p' => match r[7]{b'd'=>s(CVTPS2PD),b'i'=>s(CVTPS2PI),_=>N}_=>N}_=>N}_=>N}b's' => match r[4]{b'd'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'i'=>s(CVTSD2SI),b's'=>s(CVTSD2SS),_=>N}_=>N}_=>N}b'i'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'd'=>s(CVTSI2SD),b's'=>s(CVTSI2SS),_=>N}_=>N}_=>N}b's'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'd'=>s(CVTSS2SD),b'i'=>s(CVTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l' => match r[2]{b'd'=>match r[3]{b'e'=>match r[4]{b'm'=>match r[5]{b'o'=>match r[6]{b't'=>match r[7]{b'e'=>s(CLDEMOTE),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b's'=>match r[4]{b's'=>match r[5]{b'b'=>match r[6]{b's'=>match r[7]{b'y'=>s(CLRSSBSY),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v'=>match r[5]{b'n'=>match r[6]{b'b'=>match r[7]{b'e'=>s(FCMOVNBE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b's'=>match r[5]{b'w'=>match r[6]{b'a'=>match r[7]{b'x'=>s(FNSTSWAX),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b's'=>match r[3]{b'a'=>match r[4]{b'v'=>match r[5]{b'e'=>match r[6]{b'6'=>match r[7]{b'4'=>s(FXSAVE64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'k' => match r[1]{b'o'=>match r[2]{b'r'=>match r[3]{b't'=>match r[4]{b'e'=>match r[5]{b's'=>match r[6]{b't' => match r[7]{b'b'=>s(KORTESTB),b'd'=>s(KORTESTD),b'q'=>s(KORTESTQ),b'w'=>s(KORTESTW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'h'=>match r[3]{b'i'=>match r[4]{b'f'=>match r[5]{b't' => match r[6]{b'l' => match r[7]{b'b'=>s(KSHIFTLB),b'd'=>s(KSHIFTLD),b'q'=>s(KSHIFTLQ),b'w'=>s(KSHIFTLW),_=>N}b'r' => match r[7]{b'b'=>s(KSHIFTRB),b'd'=>s(KSHIFTRD),b'q'=>s(KSHIFTRQ),b'w'=>s(KSHIFTRW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'b'=>match r[7]{b'w'=>s(KUNPCKBW),_=>N}b'd'=>match r[7]{b'q'=>s(KUNPCKDQ),_=>N}b'w'=>match r[7]{b'd'=>s(KUNPCKWD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'c'=>match r[3]{b'k' => match r[4]{b's'=>match r[5]{b's' => match r[6]{b'd'=>match r[7]{b'w'=>s(PACKSSDW),_=>N}b'w'=>match r[7]{b'b'=>s(PACKSSWB),_=>N}_=>N}_=>N}b'u'=>match r[5]{b's' => match r[6]{b'd'=>match r[7]{b'w'=>s(PACKUSDW),_=>N}b'w'=>match r[7]{b'b'=>s(PACKUSWB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'v'=>match r[7]{b'b'=>s(PBLENDVB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b's'=>match r[5]{b'x' => match r[6]{b'd'=>match r[7]{b'q'=>s(PMOVSXDQ),_=>N}b'b' => match r[7]{b'd'=>s(PMOVSXBD),b'q'=>s(PMOVSXBQ),b'w'=>s(PMOVSXBW),_=>N}b'w' => match r[7]{b'd'=>s(PMOVSXWD),b'q'=>s(PMOVSXWQ),_=>N}_=>N}_=>N}b'z'=>match r[5]{b'x' => match r[6]{b'd'=>match r[7]{b'q'=>s(PMOVZXDQ),_=>N}b'b' => match r[7]{b'd'=>s(PMOVZXBD),b'q'=>s(PMOVZXBQ),b'w'=>s(PMOVZXBW),_=>N}b'w' => match r[7]{b'd'=>s(PMOVZXWD),b'q'=>s(PMOVZXWQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l'=>match r[4]{b'h'=>match r[5]{b'r'=>match r[6]{b's'=>match r[7]{b'w'=>s(PMULHRSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b'q'=>match r[4]{b'u'=>match r[5]{b'e'=>match r[6]{b'r'=>match r[7]{b'y'=>s(RMPQUERY),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b't'=>match r[3]{b'o'=>match r[4]{b'r'=>match r[5]{b's'=>match r[6]{b's'=>match r[7]{b'p'=>s(RSTORSSP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd' => match r[2]{b'f'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(RDFSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(RDGSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'h'=>match r[2]{b'a'=>match r[3]{b'1'=>match r[4]{b'm'=>match r[5]{b's'=>match r[6]{b'g' => match r[7]{b'1'=>s(SHA1MSG1),b'2'=>s(SHA1MSG2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b't'=>match r[6]{b'e'=>match r[7]{b'r'=>s(SYSENTER),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e' => match r[2]{b'a'=>match r[3]{b'm'=>match r[4]{b'c'=>match r[5]{b'a'=>match r[6]{b'l'=>match r[7]{b'l'=>s(SEAMCALL),_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'u'=>match r[5]{b'i'=>match r[6]{b'p'=>match r[7]{b'i'=>s(SENDUIPI),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'n' => match r[4]{b'a'=>match r[5]{b'e'=>match r[6]{b'z'=>match r[7]{b'u'=>s(SETNAEZU),_=>N}_=>N}_=>N}b'b'=>match r[5]{b'e'=>match r[6]{b'z'=>match r[7]{b'u'=>s(SETNBEZU),_=>N}_=>N}_=>N}b'g'=>match r[5]{b'e'=>match r[6]{b'z'=>match r[7]{b'u'=>s(SETNGEZU),_=>N}_=>N}_=>N}b'l'=>match r[5]{b'e'=>match r[6]{b'z'=>match r[7]{b'u'=>s(SETNLEZU),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u' => match r[1]{b'm'=>match r[2]{b'o'=>match r[3]{b'n'=>match r[4]{b'i'=>match r[5]{b't'=>match r[6]{b'o'=>match r[7]{b'r'=>s(UMONITOR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b'p'=>match r[3]{b'c'=>match r[4]{b'k' => match r[5]{b'h'=>match r[6]{b'p'=>match r[7]{b's'=>s(UNPCKHPS),_=>N}_=>N}b'l'=>match r[6]{b'p'=>match r[7]{b's'=>s(UNPCKLPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'p' => match r[7]{b'd'=>s(VBLENDPD),b's'=>s(VBLENDPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'v'=>match r[3]{b't' => match r[4]{b'p'=>match r[5]{b'h'=>match r[6]{b'2'=>match r[7]{b'w'=>s(VCVTPH2W),_=>N}_=>N}_=>N}b'w'=>match r[5]{b'2'=>match r[6]{b'p'=>match r[7]{b'h'=>s(VCVTW2PH),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>match r[5]{b'c' => match r[6]{b'p'=>match r[7]{b'h'=>s(VFMULCPH),_=>N}b's'=>match r[7]{b'h'=>s(VFMULCSH),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b'm'=>match r[4]{b'x'=>match r[5]{b'c'=>match r[6]{b's'=>match r[7]{b'r'=>s(VLDMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>match r[5]{b'i'=>match r[6]{b's' => match r[7]{b'd'=>s(VUCOMISD),b'h'=>s(VUCOMISH),b's'=>s(VUCOMISS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'z'=>match r[2]{b'e'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'a'=>match r[6]{b'l'=>match r[7]{b'l'=>s(VZEROALL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'l'=>match r[3]{b'a'=>match r[4]{b'u'=>match r[5]{b'n'=>match r[6]{b'c'=>match r[7]{b'h'=>s(VMLAUNCH),_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[3]{b'v' => match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(VMOVDDUP),_=>N}_=>N}_=>N}b'h'=>match r[5]{b'l'=>match r[6]{b'p'=>match r[7]{b's'=>s(VMOVHLPS),_=>N}_=>N}_=>N}b'l'=>match r[5]{b'h'=>match r[6]{b'p'=>match r[7]{b's'=>s(VMOVLHPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[3]{b's'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'b'=>match r[7]{b'w'=>s(VMPSADBW),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b'e'=>match r[4]{b's'=>match r[5]{b'u'=>match r[6]{b'm'=>match r[7]{b'e'=>s(VMRESUME),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'b'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'n'=>match r[6]{b'd' => match r[7]{b'd'=>s(VPBLENDD),b'w'=>s(VPBLENDW),_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[3]{b'm'=>match r[4]{b'p' => match r[5]{b'e'=>match r[6]{b'q' => match r[7]{b'b'=>s(VPCMPEQB),b'd'=>s(VPCMPEQD),b'q'=>s(VPCMPEQQ),b'w'=>s(VPCMPEQW),_=>N}_=>N}b'g'=>match r[6]{b't' => match r[7]{b'b'=>s(VPCMPGTB),b'd'=>s(VPCMPGTD),b'q'=>s(VPCMPGTQ),b'w'=>s(VPCMPGTW),_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[3]{b'p' => match r[4]{b'b' => match r[5]{b's' => match r[6]{b's'=>match r[7]{b'd'=>s(VPDPBSSD),_=>N}b'u'=>match r[7]{b'd'=>s(VPDPBSUD),_=>N}_=>N}b'u' => match r[6]{b's'=>match r[7]{b'd'=>s(VPDPBUSD),_=>N}b'u'=>match r[7]{b'd'=>s(VPDPBUUD),_=>N}_=>N}_=>N}b'w' => match r[5]{b's' => match r[6]{b's'=>match r[7]{b'd'=>s(VPDPWSSD),_=>N}b'u'=>match r[7]{b'd'=>s(VPDPWSUD),_=>N}_=>N}b'u' => match r[6]{b's'=>match r[7]{b'd'=>s(VPDPWUSD),_=>N}b'u'=>match r[7]{b'd'=>s(VPDPWUUD),_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[3]{b'r'=>match r[4]{b'm' => match r[5]{b'i'=>match r[6]{b'2' => match r[7]{b'b'=>s(VPERMI2B),b'd'=>s(VPERMI2D),b'q'=>s(VPERMI2Q),b'w'=>s(VPERMI2W),_=>N}_=>N}b't'=>match r[6]{b'2' => match r[7]{b'b'=>s(VPERMT2B),b'd'=>s(VPERMT2D),b'q'=>s(VPERMT2Q),b'w'=>s(VPERMT2W),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[3]{b'z'=>match r[4]{b'c'=>match r[5]{b'n'=>match r[6]{b't' => match r[7]{b'd'=>s(VPLZCNTD),b'q'=>s(VPLZCNTQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'n'=>match r[6]{b't' => match r[7]{b'b'=>s(VPOPCNTB),b'd'=>s(VPOPCNTD),b'q'=>s(VPOPCNTQ),b'w'=>s(VPOPCNTW),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b's'=>match r[5]{b't'=>match r[6]{b'm' => match r[7]{b'b'=>s(VPTESTMB),b'd'=>s(VPTESTMD),b'q'=>s(VPTESTMQ),b'w'=>s(VPTESTMW),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b's' => match r[7]{b'b'=>s(VPADDUSB),b'w'=>s(VPADDUSW),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[4]{b'i'=>match r[5]{b'g'=>match r[6]{b'n'=>match r[7]{b'r'=>s(VPALIGNR),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'w'=>match r[7]{b'd'=>s(VPMADDWD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[4]{b'v' => match r[5]{b'b'=>match r[6]{b'2'=>match r[7]{b'm'=>s(VPMOVB2M),_=>N}_=>N}b'd'=>match r[6]{b'2'=>match r[7]{b'm'=>s(VPMOVD2M),_=>N}_=>N}b'm'=>match r[6]{b'2' => match r[7]{b'b'=>s(VPMOVM2B),b'd'=>s(VPMOVM2D),b'q'=>s(VPMOVM2Q),b'w'=>s(VPMOVM2W),_=>N}_=>N}b'q'=>match r[6]{b'2'=>match r[7]{b'm'=>s(VPMOVQ2M),_=>N}_=>N}b'w'=>match r[6]{b'2'=>match r[7]{b'm'=>s(VPMOVW2M),_=>N}_=>N}b's' => match r[6]{b'w'=>match r[7]{b'b'=>s(VPMOVSWB),_=>N}b'd' => match r[7]{b'b'=>s(VPMOVSDB),b'w'=>s(VPMOVSDW),_=>N}b'q' => match r[7]{b'b'=>s(VPMOVSQB),b'd'=>s(VPMOVSQD),b'w'=>s(VPMOVSQW),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[4]{b'l' => match r[5]{b'h'=>match r[6]{b'u'=>match r[7]{b'w'=>s(VPMULHUW),_=>N}_=>N}b'u'=>match r[6]{b'd'=>match r[7]{b'q'=>s(VPMULUDQ),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b'u'=>match r[6]{b's' => match r[7]{b'b'=>s(VPSUBUSB),b'w'=>s(VPSUBUSW),_=>N}_=>N}_=>N}_=>N}b'h' => match r[4]{b'l'=>match r[5]{b'd'=>match r[6]{b'v' => match r[7]{b'd'=>s(VPSHLDVD),b'q'=>s(VPSHLDVQ),b'w'=>s(VPSHLDVW),_=>N}_=>N}_=>N}b'r'=>match r[5]{b'd'=>match r[6]{b'v' => match r[7]{b'd'=>s(VPSHRDVD),b'q'=>s(VPSHRDVQ),b'w'=>s(VPSHRDVW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'a'=>match r[3]{b'n'=>match r[4]{b'g'=>match r[5]{b'e' => match r[6]{b'p' => match r[7]{b'd'=>s(VRANGEPD),b's'=>s(VRANGEPS),_=>N}b's' => match r[7]{b'd'=>s(VRANGESD),b's'=>s(VRANGESS),_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[3]{b'p' => match r[4]{b'1'=>match r[5]{b'4' => match r[6]{b'p' => match r[7]{b'd'=>s(VRCP14PD),b's'=>s(VRCP14PS),_=>N}b's' => match r[7]{b'd'=>s(VRCP14SD),b's'=>s(VRCP14SS),_=>N}_=>N}_=>N}b'2'=>match r[5]{b'8' => match r[6]{b'p' => match r[7]{b'd'=>s(VRCP28PD),b's'=>s(VRCP28PS),_=>N}b's' => match r[7]{b'd'=>s(VRCP28SD),b's'=>s(VRCP28SS),_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[3]{b'u'=>match r[4]{b'n'=>match r[5]{b'd' => match r[6]{b'p' => match r[7]{b'd'=>s(VROUNDPD),b's'=>s(VROUNDPS),_=>N}b's' => match r[7]{b'd'=>s(VROUNDSD),b's'=>s(VROUNDSS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'q'=>match r[4]{b'r'=>match r[5]{b't' => match r[6]{b'p' => match r[7]{b'h'=>s(VRSQRTPH),b's'=>s(VRSQRTPS),_=>N}b's' => match r[7]{b'h'=>s(VRSQRTSH),b's'=>s(VRSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'm' => match r[3]{b'3'=>match r[4]{b'm'=>match r[5]{b's'=>match r[6]{b'g' => match r[7]{b'1'=>s(VSM3MSG1),b'2'=>s(VSM3MSG2),_=>N}_=>N}_=>N}_=>N}b'4'=>match r[4]{b'k'=>match r[5]{b'e'=>match r[6]{b'y'=>match r[7]{b'4'=>s(VSM4KEY4),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'm'=>match r[4]{b'x'=>match r[5]{b'c'=>match r[6]{b's'=>match r[7]{b'r'=>s(VSTMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'w' => match r[1]{b'b'=>match r[2]{b'n'=>match r[3]{b'o'=>match r[4]{b'i'=>match r[5]{b'n'=>match r[6]{b'v'=>match r[7]{b'd'=>s(WBNOINVD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'f'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(WRFSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(WRGSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'c'=>match r[3]{b'q'=>match r[4]{b'u'=>match r[5]{b'i'=>match r[6]{b'r'=>match r[7]{b'e'=>s(XACQUIRE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e' => match r[5]{b'c'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XSAVEC64),_=>N}_=>N}b'o'=>match r[6]{b'p'=>match r[7]{b't'=>s(XSAVEOPT),_=>N}_=>N}b's'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XSAVES64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'e'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(XRELEASE),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XRSTOR64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}9=>match r[0]{b'a'=>match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'a'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPAXADD),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPBXADD),_=>N}_=>N}_=>N}_=>N}b'c'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPCXADD),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPEXADD),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPGXADD),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPLXADD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPOXADD),_=>N}_=>N}_=>N}_=>N}b's'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPSXADD),_=>N}_=>N}_=>N}_=>N}b'z'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPZXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[1]{b'x'=>match r[2]{b't'=>match r[3]{b'r'=>match r[4]{b'a'=>match r[5]{b'c'=>match r[6]{b't'=>match r[7]{b'p'=>match r[8]{b's'=>s(EXTRACTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[1]{b'x'=>match r[2]{b'r'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'o'=>match r[6]{b'r'=>match r[7]{b'6'=>match r[8]{b'4'=>s(FXRSTOR64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[1]{b'o'=>match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'i'=>match r[5]{b'w'=>match r[6]{b'k'=>match r[7]{b'e'=>match r[8]{b'y'=>s(LOADIWKEY),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[1]{b'o'=>match r[2]{b'v'=>match r[3]{b'd'=>match r[4]{b'i'=>match r[5]{b'r'=>match r[6]{b'6'=>match r[7]{b'4'=>match r[8]{b'b'=>s(MOVDIR64B),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'w'=>match r[1]{b'r'=>match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>match r[5]{b'l'=>match r[6]{b'i'=>match r[7]{b's'=>match r[8]{b't'=>s(WRMSRLIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'f'=>match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'o'=>match r[5]{b'v'=>match r[6]{b'n' => match r[7]{b'a'=>match r[8]{b'e'=>s(CFCMOVNAE),_=>N}b'b'=>match r[8]{b'e'=>s(CFCMOVNBE),_=>N}b'g'=>match r[8]{b'e'=>s(CFCMOVNGE),_=>N}b'l'=>match r[8]{b'e'=>s(CFCMOVNLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'p' => match r[3]{b'a'=>match r[4]{b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPAEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[4]{b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPBEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[4]{b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPGEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[4]{b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPLEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>match r[7]{b'8'=>match r[8]{b'b'=>s(CMPXCHG8B),_=>N}_=>N}_=>N}_=>N}_=>N}b'n' => match r[4]{b'a'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNAXADD),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNBXADD),_=>N}_=>N}_=>N}_=>N}b'c'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNCXADD),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNEXADD),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNGXADD),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNLXADD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNOXADD),_=>N}_=>N}_=>N}_=>N}b's'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNSXADD),_=>N}_=>N}_=>N}_=>N}b'z'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNZXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v'=>match r[2]{b't'=>match r[3]{b't' => match r[4]{b'p' => match r[5]{b'd'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(CVTTPD2DQ),_=>N}b'p'=>match r[8]{b'i'=>s(CVTTPD2PI),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(CVTTPS2DQ),_=>N}b'p'=>match r[8]{b'i'=>s(CVTTPS2PI),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b's'=>match r[8]{b'i'=>s(CVTTSD2SI),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2'=>match r[7]{b's'=>match r[8]{b'i'=>s(CVTTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'm'=>match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b'b'=>match r[7]{b's'=>match r[8]{b'w'=>s(PMADDUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b'f'=>match r[4]{b'e'=>match r[5]{b't'=>match r[6]{b'c'=>match r[7]{b'h' => match r[8]{b'0'=>s(PREFETCH0),b'1'=>s(PREFETCH1),b'2'=>s(PREFETCH2),b'a'=>s(PREFETCHA),b'w'=>s(PREFETCHW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h' => match r[7]{b'b'=>match r[8]{b'w'=>s(PUNPCKHBW),_=>N}b'd'=>match r[8]{b'q'=>s(PUNPCKHDQ),_=>N}b'w'=>match r[8]{b'd'=>s(PUNPCKHWD),_=>N}_=>N}b'l' => match r[7]{b'b'=>match r[8]{b'w'=>s(PUNPCKLBW),_=>N}b'd'=>match r[8]{b'q'=>s(PUNPCKLDQ),_=>N}b'w'=>match r[8]{b'd'=>s(PUNPCKLWD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v'=>match r[2]{b'a'=>match r[3]{b'l'=>match r[4]{b'i'=>match r[5]{b'd'=>match r[6]{b'a'=>match r[7]{b't'=>match r[8]{b'e'=>s(PVALIDATE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'l'=>match r[3]{b'm'=>match r[4]{b'u'=>match r[5]{b'l'=>match r[6]{b'q'=>match r[7]{b'd'=>match r[8]{b'q'=>s(PCLMULQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'p' => match r[4]{b'e'=>match r[5]{b's'=>match r[6]{b't'=>match r[7]{b'r' => match r[8]{b'i'=>s(PCMPESTRI),b'm'=>s(PCMPESTRM),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[5]{b's'=>match r[6]{b't'=>match r[7]{b'r' => match r[8]{b'i'=>s(PCMPISTRI),b'm'=>s(PCMPISTRM),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'd'=>match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>match r[5]{b'l'=>match r[6]{b'i'=>match r[7]{b's'=>match r[8]{b't'=>s(RDMSRLIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'p' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'j'=>match r[6]{b'u'=>match r[7]{b's'=>match r[8]{b't'=>s(RMPADJUST),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[4]{b'p'=>match r[5]{b'd'=>match r[6]{b'a'=>match r[7]{b't'=>match r[8]{b'e'=>s(RMPUPDATE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b'r'=>match r[3]{b'i'=>match r[4]{b'a'=>match r[5]{b'l'=>match r[6]{b'i'=>match r[7]{b'z'=>match r[8]{b'e'=>s(SERIALIZE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'a'=>match r[3]{b'1' => match r[4]{b'n'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b't'=>match r[8]{b'e'=>s(SHA1NEXTE),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b's'=>match r[8]{b'4'=>s(SHA1RNDS4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'4'=>match r[2]{b'f'=>match r[3]{b'm'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'd' => match r[7]{b'p'=>match r[8]{b's'=>s(V4FMADDPS),_=>N}b's'=>match r[8]{b's'=>s(V4FMADDSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b's'=>match r[5]{b'u'=>match r[6]{b'b'=>match r[7]{b'p' => match r[8]{b'd'=>s(VADDSUBPD),b's'=>s(VADDSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd' => match r[6]{b'm'=>match r[7]{b'p' => match r[8]{b'd'=>s(VBLENDMPD),b's'=>s(VBLENDMPS),_=>N}_=>N}b'v'=>match r[7]{b'p' => match r[8]{b'd'=>s(VBLENDVPD),b's'=>s(VBLENDVPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'v'=>match r[3]{b't' => match r[4]{b'd'=>match r[5]{b'q'=>match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VCVTDQ2PD),b'h'=>s(VCVTDQ2PH),b's'=>s(VCVTDQ2PS),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[5]{b'q'=>match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VCVTQQ2PD),b'h'=>s(VCVTQQ2PH),b's'=>s(VCVTQQ2PS),_=>N}_=>N}_=>N}_=>N}b't'=>match r[5]{b'p'=>match r[6]{b'h'=>match r[7]{b'2'=>match r[8]{b'w'=>s(VCVTTPH2W),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[5]{b'w'=>match r[6]{b'2'=>match r[7]{b'p'=>match r[8]{b'h'=>s(VCVTUW2PH),_=>N}_=>N}_=>N}_=>N}b'p' => match r[5]{b'd'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(VCVTPD2DQ),_=>N}b'q'=>match r[8]{b'q'=>s(VCVTPD2QQ),_=>N}b'p' => match r[8]{b'h'=>s(VCVTPD2PH),b's'=>s(VCVTPD2PS),_=>N}_=>N}_=>N}b'h'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(VCVTPH2DQ),_=>N}b'q'=>match r[8]{b'q'=>s(VCVTPH2QQ),_=>N}b'u'=>match r[8]{b'w'=>s(VCVTPH2UW),_=>N}b'p' => match r[8]{b'd'=>s(VCVTPH2PD),b's'=>s(VCVTPH2PS),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(VCVTPS2DQ),_=>N}b'q'=>match r[8]{b'q'=>s(VCVTPS2QQ),_=>N}b'p' => match r[8]{b'd'=>s(VCVTPS2PD),b'h'=>s(VCVTPS2PH),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'h'=>s(VCVTSD2SH),b'i'=>s(VCVTSD2SI),b's'=>s(VCVTSD2SS),_=>N}_=>N}_=>N}b'h'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'd'=>s(VCVTSH2SD),b'i'=>s(VCVTSH2SI),b's'=>s(VCVTSH2SS),_=>N}_=>N}_=>N}b'i'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'd'=>s(VCVTSI2SD),b'h'=>s(VCVTSI2SH),b's'=>s(VCVTSI2SS),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'd'=>s(VCVTSS2SD),b'h'=>s(VCVTSS2SH),b'i'=>s(VCVTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b'p'=>match r[4]{b'a'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b'p' => match r[8]{b'd'=>s(VEXPANDPD),b's'=>s(VEXPANDPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[2]{b'e'=>match r[3]{b't'=>match r[4]{b'e'=>match r[5]{b'x'=>match r[6]{b'p' => match r[7]{b'p' => match r[8]{b'd'=>s(VGETEXPPD),b'h'=>s(VGETEXPPH),b's'=>s(VGETEXPPS),_=>N}b's' => match r[8]{b'd'=>s(VGETEXPSD),b'h'=>s(VGETEXPSH),b's'=>s(VGETEXPSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'r'=>match r[6]{b't'=>match r[7]{b'p'=>match r[8]{b's'=>s(VINSERTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'm'=>match r[5]{b's'=>match r[6]{b'k'=>match r[7]{b'p' => match r[8]{b'd'=>s(VMOVMSKPD),b's'=>s(VMOVMSKPS),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[5]{b't'=>match r[6]{b'd'=>match r[7]{b'q'=>match r[8]{b'a'=>s(VMOVNTDQA),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'h'=>match r[6]{b'd'=>match r[7]{b'u'=>match r[8]{b'p'=>s(VMOVSHDUP),_=>N}_=>N}_=>N}b'l'=>match r[6]{b'd'=>match r[7]{b'u'=>match r[8]{b'p'=>s(VMOVSLDUP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b'd'=>match r[4]{b'u'=>match r[5]{b'c'=>match r[6]{b'e' => match r[7]{b'p' => match r[8]{b'd'=>s(VREDUCEPD),b'h'=>s(VREDUCEPH),b's'=>s(VREDUCEPS),_=>N}b's' => match r[8]{b'd'=>s(VREDUCESD),b'h'=>s(VREDUCESH),b's'=>s(VREDUCESS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h'=>match r[7]{b'p'=>match r[8]{b's'=>s(VUNPCKHPS),_=>N}_=>N}b'l'=>match r[7]{b'p'=>match r[8]{b's'=>s(VUNPCKLPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd' => match r[2]{b'b'=>match r[3]{b'p'=>match r[4]{b's'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'b'=>match r[8]{b'w'=>s(VDBPSADBW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[3]{b'b'=>match r[4]{b'f'=>match r[5]{b'1'=>match r[6]{b'6'=>match r[7]{b'p'=>match r[8]{b's'=>s(VDPBF16PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'u'=>match r[5]{b'l'=>match r[6]{b'c' => match r[7]{b'p'=>match r[8]{b'h'=>s(VFCMULCPH),_=>N}b's'=>match r[8]{b'h'=>s(VFCMULCSH),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'c' => match r[7]{b'p'=>match r[8]{b'h'=>s(VFMADDCPH),_=>N}b's'=>match r[8]{b'h'=>s(VFMADDCSH),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'4'=>match r[3]{b'd'=>match r[4]{b'p'=>match r[5]{b'w'=>match r[6]{b's'=>match r[7]{b's'=>match r[8]{b'd'=>s(VP4DPWSSD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a'=>match r[3]{b'c'=>match r[4]{b'k' => match r[5]{b's'=>match r[6]{b's' => match r[7]{b'd'=>match r[8]{b'w'=>s(VPACKSSDW),_=>N}b'w'=>match r[8]{b'b'=>s(VPACKSSWB),_=>N}_=>N}_=>N}b'u'=>match r[6]{b's' => match r[7]{b'd'=>match r[8]{b'w'=>s(VPACKUSDW),_=>N}b'w'=>match r[8]{b'b'=>s(VPACKUSWB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'n'=>match r[6]{b'd' => match r[7]{b'm' => match r[8]{b'b'=>s(VPBLENDMB),b'd'=>s(VPBLENDMD),b'q'=>s(VPBLENDMQ),b'w'=>s(VPBLENDMW),_=>N}b'v'=>match r[8]{b'b'=>s(VPBLENDVB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[3]{b'p' => match r[4]{b'b' => match r[5]{b's' => match r[6]{b's'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPBSSDS),_=>N}_=>N}b'u'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPBSUDS),_=>N}_=>N}_=>N}b'u' => match r[6]{b's'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPBUSDS),_=>N}_=>N}b'u'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPBUUDS),_=>N}_=>N}_=>N}_=>N}b'w' => match r[5]{b's' => match r[6]{b's'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPWSSDS),_=>N}_=>N}b'u'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPWSUDS),_=>N}_=>N}_=>N}b'u' => match r[6]{b's'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPWUSDS),_=>N}_=>N}b'u'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPWUUDS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b's'=>match r[5]{b't'=>match r[6]{b'n'=>match r[7]{b'm' => match r[8]{b'b'=>s(VPTESTNMB),b'd'=>s(VPTESTNMD),b'q'=>s(VPTESTNMQ),b'w'=>s(VPTESTNMW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e' => match r[3]{b'r'=>match r[4]{b'm' => match r[5]{b't'=>match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VPERMT2PD),b's'=>s(VPERMT2PS),_=>N}_=>N}_=>N}b'i' => match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VPERMI2PD),b's'=>s(VPERMI2PS),_=>N}_=>N}b'l'=>match r[7]{b'p' => match r[8]{b'd'=>s(VPERMILPD),b's'=>s(VPERMILPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[4]{b'p'=>match r[5]{b'a'=>match r[6]{b'n'=>match r[7]{b'd' => match r[8]{b'b'=>s(VPEXPANDB),b'd'=>s(VPEXPANDD),b'q'=>s(VPEXPANDQ),b'w'=>s(VPEXPANDW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b's'=>match r[6]{b'x' => match r[7]{b'd'=>match r[8]{b'q'=>s(VPMOVSXDQ),_=>N}b'b' => match r[8]{b'd'=>s(VPMOVSXBD),b'q'=>s(VPMOVSXBQ),b'w'=>s(VPMOVSXBW),_=>N}b'w' => match r[8]{b'd'=>s(VPMOVSXWD),b'q'=>s(VPMOVSXWQ),_=>N}_=>N}_=>N}b'u'=>match r[6]{b's' => match r[7]{b'w'=>match r[8]{b'b'=>s(VPMOVUSWB),_=>N}b'd' => match r[8]{b'b'=>s(VPMOVUSDB),b'w'=>s(VPMOVUSDW),_=>N}b'q' => match r[8]{b'b'=>s(VPMOVUSQB),b'd'=>s(VPMOVUSQD),b'w'=>s(VPMOVUSQW),_=>N}_=>N}_=>N}b'z'=>match r[6]{b'x' => match r[7]{b'd'=>match r[8]{b'q'=>s(VPMOVZXDQ),_=>N}b'b' => match r[8]{b'd'=>s(VPMOVZXBD),b'q'=>s(VPMOVZXBQ),b'w'=>s(VPMOVZXBW),_=>N}b'w' => match r[8]{b'd'=>s(VPMOVZXWD),b'q'=>s(VPMOVZXWQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[4]{b'l'=>match r[5]{b'h'=>match r[6]{b'r'=>match r[7]{b's'=>match r[8]{b'w'=>s(VPMULHRSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'c'=>match r[3]{b'a'=>match r[4]{b'l'=>match r[5]{b'e'=>match r[6]{b'f' => match r[7]{b'p' => match r[8]{b'd'=>s(VSCALEFPD),b'h'=>s(VSCALEFPH),b's'=>s(VSCALEFPS),_=>N}b's' => match r[8]{b'd'=>s(VSCALEFSD),b'h'=>s(VSCALEFSH),b's'=>s(VSCALEFSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'3'=>match r[4]{b'r'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b's'=>match r[8]{b'2'=>s(VSM3RNDS2),_=>N}_=>N}_=>N}_=>N}_=>N}b'4'=>match r[4]{b'r'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b's'=>match r[8]{b'4'=>s(VSM4RNDS4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'r' => match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'l'=>match r[5]{b'd'=>match r[6]{b't'=>match r[7]{b'r'=>match r[8]{b'k'=>s(XRESLDTRK),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b's'=>match r[7]{b'6'=>match r[8]{b'4'=>s(XRSTORS64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b's'=>match r[4]{b'l'=>match r[5]{b'd'=>match r[6]{b't'=>match r[7]{b'r'=>match r[8]{b'k'=>s(XSUSLDTRK),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}10=>match r[0]{b'c'=>match r[1]{b'm'=>match r[2]{b'p' => match r[3]{b'n' => match r[4]{b'a'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(CMPNAEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(CMPNBEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(CMPNGEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(CMPNLEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>match r[7]{b'1'=>match r[8]{b'6'=>match r[9]{b'b'=>s(CMPXCHG16B),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[1]{b'a'=>match r[2]{b's'=>match r[3]{b'k'=>match r[4]{b'm'=>match r[5]{b'o'=>match r[6]{b'v'=>match r[7]{b'd'=>match r[8]{b'q'=>match r[9]{b'u'=>s(MASKMOVDQU),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[1]{b'h'=>match r[2]{b'a'=>match r[3]{b'2'=>match r[4]{b'5'=>match r[5]{b'6'=>match r[6]{b'm'=>match r[7]{b's'=>match r[8]{b'g' => match r[9]{b'1'=>s(SHA256MSG1),b'2'=>s(SHA256MSG2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[1]{b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>match r[5]{b'o'=>match r[6]{b'p'=>match r[7]{b't'=>match r[8]{b'6'=>match r[9]{b'4'=>s(XSAVEOPT64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'a'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPAEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPBEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPGEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPLEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'n' => match r[5]{b'a'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNAXADD),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNBXADD),_=>N}_=>N}_=>N}_=>N}b'c'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNCXADD),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNEXADD),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNGXADD),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNLXADD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNOXADD),_=>N}_=>N}_=>N}_=>N}b's'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNSXADD),_=>N}_=>N}_=>N}_=>N}b'z'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNZXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c'=>match r[6]{b'l'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>s(AESDECLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c'=>match r[6]{b'l'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>s(AESENCLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'h'=>match r[2]{b'm'=>match r[3]{b'i'=>match r[4]{b'n'=>match r[5]{b'p'=>match r[6]{b'o'=>match r[7]{b's'=>match r[8]{b'u'=>match r[9]{b'w'=>s(PHMINPOSUW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(PUNPCKHQDQ),_=>N}_=>N}_=>N}b'l'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(PUNPCKLQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'4'=>match r[2]{b'f'=>match r[3]{b'n'=>match r[4]{b'm'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd' => match r[8]{b'p'=>match r[9]{b's'=>s(V4FNMADDPS),_=>N}b's'=>match r[9]{b's'=>s(V4FNMADDSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'v'=>match r[3]{b't' => match r[4]{b'p' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b'u' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTPD2UDQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTPD2UQQ),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[6]{b'2' => match r[7]{b'p'=>match r[8]{b's'=>match r[9]{b'x'=>s(VCVTPH2PSX),_=>N}_=>N}b'u' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTPH2UDQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTPH2UQQ),_=>N}_=>N}_=>N}_=>N}b's'=>match r[6]{b'2' => match r[7]{b'p'=>match r[8]{b'h'=>match r[9]{b'x'=>s(VCVTPS2PHX),_=>N}_=>N}b'u' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTPS2UDQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTPS2UQQ),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b'u'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTSD2USI),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[6]{b'2'=>match r[7]{b'u'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTSH2USI),_=>N}_=>N}_=>N}_=>N}b's'=>match r[6]{b'2'=>match r[7]{b'u'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTSS2USI),_=>N}_=>N}_=>N}_=>N}_=>N}b't' => match r[5]{b'p' => match r[6]{b'd'=>match r[7]{b'2' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTTPD2DQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTTPD2QQ),_=>N}_=>N}_=>N}b'h'=>match r[7]{b'2' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTTPH2DQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTTPH2QQ),_=>N}b'u'=>match r[9]{b'w'=>s(VCVTTPH2UW),_=>N}_=>N}_=>N}b's'=>match r[7]{b'2' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTTPS2DQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTTPS2QQ),_=>N}_=>N}_=>N}_=>N}b's' => match r[6]{b'd'=>match r[7]{b'2'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTTSD2SI),_=>N}_=>N}_=>N}b'h'=>match r[7]{b'2'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTTSH2SI),_=>N}_=>N}_=>N}b's'=>match r[7]{b'2'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}b'u' => match r[5]{b'd'=>match r[6]{b'q'=>match r[7]{b'2'=>match r[8]{b'p' => match r[9]{b'd'=>s(VCVTUDQ2PD),b'h'=>s(VCVTUDQ2PH),b's'=>s(VCVTUDQ2PS),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[6]{b'q'=>match r[7]{b'2'=>match r[8]{b'p' => match r[9]{b'd'=>s(VCVTUQQ2PD),b'h'=>s(VCVTUQQ2PH),b's'=>s(VCVTUQQ2PS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[6]{b'i'=>match r[7]{b'2'=>match r[8]{b's' => match r[9]{b'd'=>s(VCVTUSI2SD),b'h'=>s(VCVTUSI2SH),b's'=>s(VCVTUSI2SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>match r[5]{b'a'=>match r[6]{b'c'=>match r[7]{b't'=>match r[8]{b'p'=>match r[9]{b's'=>s(VEXTRACTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'a'=>match r[3]{b's'=>match r[4]{b'k'=>match r[5]{b'm'=>match r[6]{b'o'=>match r[7]{b'v'=>match r[8]{b'p' => match r[9]{b'd'=>s(VMASKMOVPD),b's'=>s(VMASKMOVPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b'q'=>match r[4]{b'r'=>match r[5]{b't' => match r[6]{b'1'=>match r[7]{b'4' => match r[8]{b'p' => match r[9]{b'd'=>s(VRSQRT14PD),b's'=>s(VRSQRT14PS),_=>N}b's' => match r[9]{b'd'=>s(VRSQRT14SD),b's'=>s(VRSQRT14SS),_=>N}_=>N}_=>N}b'2'=>match r[7]{b'8' => match r[8]{b'p' => match r[9]{b'd'=>s(VRSQRT28PD),b's'=>s(VRSQRT28PS),_=>N}b's' => match r[9]{b'd'=>s(VRSQRT28SD),b's'=>s(VRSQRT28SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f' => match r[5]{b'f' => match r[6]{b'3'=>match r[7]{b'2'=>match r[8]{b'x'=>match r[9]{b'4'=>s(VSHUFF32X4),_=>N}_=>N}_=>N}b'6'=>match r[7]{b'4'=>match r[8]{b'x'=>match r[9]{b'2'=>s(VSHUFF64X2),_=>N}_=>N}_=>N}_=>N}b'i' => match r[6]{b'3'=>match r[7]{b'2'=>match r[8]{b'x'=>match r[9]{b'4'=>s(VSHUFI32X4),_=>N}_=>N}_=>N}b'6'=>match r[7]{b'4'=>match r[8]{b'x'=>match r[9]{b'2'=>s(VSHUFI64X2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'z'=>match r[2]{b'e'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'u'=>match r[6]{b'p'=>match r[7]{b'p'=>match r[8]{b'e'=>match r[9]{b'r'=>s(VZEROUPPER),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'd'=>match r[7]{b'c' => match r[8]{b'p'=>match r[9]{b'h'=>s(VFCMADDCPH),_=>N}b's'=>match r[9]{b'h'=>s(VFCMADDCSH),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[3]{b'c'=>match r[4]{b'l'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b's' => match r[8]{b'p' => match r[9]{b'd'=>s(VFPCLASSPD),b'h'=>s(VFPCLASSPH),b's'=>s(VFPCLASSPS),_=>N}b's' => match r[9]{b'd'=>s(VFPCLASSSD),b'h'=>s(VFPCLASSSH),b's'=>s(VFPCLASSSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g' => match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'h'=>match r[5]{b'e'=>match r[6]{b'r' => match r[7]{b'd'=>match r[8]{b'p' => match r[9]{b'd'=>s(VGATHERDPD),b's'=>s(VGATHERDPS),_=>N}_=>N}b'q'=>match r[8]{b'p' => match r[9]{b'd'=>s(VGATHERQPD),b's'=>s(VGATHERQPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[3]{b't'=>match r[4]{b'm'=>match r[5]{b'a'=>match r[6]{b'n'=>match r[7]{b't' => match r[8]{b'p' => match r[9]{b'd'=>s(VGETMANTPD),b'h'=>s(VGETMANTPH),b's'=>s(VGETMANTPS),_=>N}b's' => match r[9]{b'd'=>s(VGETMANTSD),b'h'=>s(VGETMANTSH),b's'=>s(VGETMANTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'4'=>match r[3]{b'd'=>match r[4]{b'p'=>match r[5]{b'w'=>match r[6]{b's'=>match r[7]{b's'=>match r[8]{b'd'=>match r[9]{b's'=>s(VP4DPWSSDS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[3]{b'r'=>match r[4]{b'm'=>match r[5]{b'2' => match r[6]{b'f'=>match r[7]{b'1'=>match r[8]{b'2'=>match r[9]{b'8'=>s(VPERM2F128),_=>N}_=>N}_=>N}b'i'=>match r[7]{b'1'=>match r[8]{b'2'=>match r[9]{b'8'=>s(VPERM2I128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b'a'=>match r[4]{b't'=>match r[5]{b'h'=>match r[6]{b'e'=>match r[7]{b'r' => match r[8]{b'd' => match r[9]{b'd'=>s(VPGATHERDD),b'q'=>s(VPGATHERDQ),_=>N}b'q' => match r[9]{b'd'=>s(VPGATHERQD),b'q'=>s(VPGATHERQQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'a'=>match r[4]{b's'=>match r[5]{b'k'=>match r[6]{b'm'=>match r[7]{b'o'=>match r[8]{b'v' => match r[9]{b'd'=>s(VPMASKMOVD),b'q'=>s(VPMASKMOVQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b'r'=>match r[5]{b'n'=>match r[6]{b'l'=>match r[7]{b'o'=>match r[8]{b'g' => match r[9]{b'd'=>s(VPTERNLOGD),b'q'=>s(VPTERNLOGQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'n'=>match r[4]{b'p'=>match r[5]{b'c'=>match r[6]{b'k' => match r[7]{b'h' => match r[8]{b'b'=>match r[9]{b'w'=>s(VPUNPCKHBW),_=>N}b'd'=>match r[9]{b'q'=>s(VPUNPCKHDQ),_=>N}b'w'=>match r[9]{b'd'=>s(VPUNPCKHWD),_=>N}_=>N}b'l' => match r[8]{b'b'=>match r[9]{b'w'=>s(VPUNPCKLBW),_=>N}b'd'=>match r[9]{b'q'=>s(VPUNPCKLDQ),_=>N}b'w'=>match r[9]{b'd'=>s(VPUNPCKLWD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[3]{b'l'=>match r[4]{b'm'=>match r[5]{b'u'=>match r[6]{b'l'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(VPCLMULQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[4]{b'p' => match r[5]{b'e'=>match r[6]{b's'=>match r[7]{b't'=>match r[8]{b'r' => match r[9]{b'i'=>s(VPCMPESTRI),b'm'=>s(VPCMPESTRM),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[6]{b's'=>match r[7]{b't'=>match r[8]{b'r' => match r[9]{b'i'=>s(VPCMPISTRI),b'm'=>s(VPCMPISTRM),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}11=>match r[0]{b'p'=>match r[1]{b'r'=>match r[2]{b'e'=>match r[3]{b'f'=>match r[4]{b'e'=>match r[5]{b't'=>match r[6]{b'c'=>match r[7]{b'h' => match r[8]{b'i'=>match r[9]{b't' => match r[10]{b'0'=>s(PREFETCHIT0),b'1'=>s(PREFETCHIT1),_=>N}_=>N}b'w'=>match r[9]{b't'=>match r[10]{b'1'=>s(PREFETCHWT1),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p'=>match r[4]{b'n' => match r[5]{b'a'=>match r[6]{b'e'=>match r[7]{b'x'=>match r[8]{b'a'=>match r[9]{b'd'=>match r[10]{b'd'=>s(ACMPNAEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[6]{b'e'=>match r[7]{b'x'=>match r[8]{b'a'=>match r[9]{b'd'=>match r[10]{b'd'=>s(ACMPNBEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[6]{b'e'=>match r[7]{b'x'=>match r[8]{b'a'=>match r[9]{b'd'=>match r[10]{b'd'=>s(ACMPNGEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[6]{b'e'=>match r[7]{b'x'=>match r[8]{b'a'=>match r[9]{b'd'=>match r[10]{b'd'=>s(ACMPNLEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c' => match r[6]{b'1'=>match r[7]{b'2'=>match r[8]{b'8'=>match r[9]{b'k'=>match r[10]{b'l'=>s(AESDEC128KL),_=>N}_=>N}_=>N}_=>N}b'2'=>match r[7]{b'5'=>match r[8]{b'6'=>match r[9]{b'k'=>match r[10]{b'l'=>s(AESDEC256KL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c' => match r[6]{b'1'=>match r[7]{b'2'=>match r[8]{b'8'=>match r[9]{b'k'=>match r[10]{b'l'=>s(AESENC128KL),_=>N}_=>N}_=>N}_=>N}b'2'=>match r[7]{b'5'=>match r[8]{b'6'=>match r[9]{b'k'=>match r[10]{b'l'=>s(AESENC256KL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'a'=>match r[2]{b'v'=>match r[3]{b'e'=>match r[4]{b'p'=>match r[5]{b'r'=>match r[6]{b'e'=>match r[7]{b'v'=>match r[8]{b's'=>match r[9]{b's'=>match r[10]{b'p'=>s(SAVEPREVSSP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'a'=>match r[3]{b'2'=>match r[4]{b'5'=>match r[5]{b'6'=>match r[6]{b'r'=>match r[7]{b'n'=>match r[8]{b'd'=>match r[9]{b's'=>match r[10]{b'2'=>s(SHA256RNDS2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'a'=>match r[2]{b'e'=>match r[3]{b's' => match r[4]{b'd'=>match r[5]{b'e'=>match r[6]{b'c'=>match r[7]{b'l'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't'=>s(VAESDECLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[5]{b'n'=>match r[6]{b'c'=>match r[7]{b'l'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't'=>s(VAESENCLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'r'=>match r[6]{b't' => match r[7]{b'f'=>match r[8]{b'1'=>match r[9]{b'2'=>match r[10]{b'8'=>s(VINSERTF128),_=>N}_=>N}_=>N}b'i'=>match r[8]{b'1'=>match r[9]{b'2'=>match r[10]{b'8'=>s(VINSERTI128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b's'=>match r[5]{b'c'=>match r[6]{b'a'=>match r[7]{b'l'=>match r[8]{b'e' => match r[9]{b'p' => match r[10]{b'd'=>s(VRNDSCALEPD),b'h'=>s(VRNDSCALEPH),b's'=>s(VRNDSCALEPS),_=>N}b's' => match r[10]{b'd'=>s(VRNDSCALESD),b'h'=>s(VRNDSCALESH),b's'=>s(VRNDSCALESS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'o'=>match r[3]{b'm'=>match r[4]{b'p'=>match r[5]{b'r'=>match r[6]{b'e'=>match r[7]{b's'=>match r[8]{b's'=>match r[9]{b'p' => match r[10]{b'd'=>s(VCOMPRESSPD),b's'=>s(VCOMPRESSPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v'=>match r[3]{b't'=>match r[4]{b't' => match r[5]{b'p' => match r[6]{b'd'=>match r[7]{b'2'=>match r[8]{b'u' => match r[9]{b'd'=>match r[10]{b'q'=>s(VCVTTPD2UDQ),_=>N}b'q'=>match r[10]{b'q'=>s(VCVTTPD2UQQ),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[7]{b'2'=>match r[8]{b'u' => match r[9]{b'd'=>match r[10]{b'q'=>s(VCVTTPH2UDQ),_=>N}b'q'=>match r[10]{b'q'=>s(VCVTTPH2UQQ),_=>N}_=>N}_=>N}_=>N}b's'=>match r[7]{b'2'=>match r[8]{b'u' => match r[9]{b'd'=>match r[10]{b'q'=>s(VCVTTPS2UDQ),_=>N}b'q'=>match r[10]{b'q'=>s(VCVTTPS2UQQ),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[6]{b'd'=>match r[7]{b'2'=>match r[8]{b'u'=>match r[9]{b's'=>match r[10]{b'i'=>s(VCVTTSD2USI),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[7]{b'2'=>match r[8]{b'u'=>match r[9]{b's'=>match r[10]{b'i'=>s(VCVTTSH2USI),_=>N}_=>N}_=>N}_=>N}b's'=>match r[7]{b'2'=>match r[8]{b'u'=>match r[9]{b's'=>match r[10]{b'i'=>s(VCVTTSS2USI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[2]{b'i'=>match r[3]{b'x'=>match r[4]{b'u'=>match r[5]{b'p'=>match r[6]{b'i'=>match r[7]{b'm'=>match r[8]{b'm' => match r[9]{b'p' => match r[10]{b'd'=>s(VFIXUPIMMPD),b's'=>s(VFIXUPIMMPS),_=>N}b's' => match r[10]{b'd'=>s(VFIXUPIMMSD),b's'=>s(VFIXUPIMMSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd' => match r[6]{b'1'=>match r[7]{b'3'=>match r[8]{b'2' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD132PD),b'h'=>s(VFMADD132PH),b's'=>s(VFMADD132PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD132SD),b'h'=>s(VFMADD132SH),b's'=>s(VFMADD132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[7]{b'1'=>match r[8]{b'3' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD213PD),b'h'=>s(VFMADD213PH),b's'=>s(VFMADD213PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD213SD),b'h'=>s(VFMADD213SH),b's'=>s(VFMADD213SS),_=>N}_=>N}_=>N}b'3'=>match r[8]{b'1' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD231PD),b'h'=>s(VFMADD231PH),b's'=>s(VFMADD231PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD231SD),b'h'=>s(VFMADD231SH),b's'=>s(VFMADD231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b' => match r[6]{b'1'=>match r[7]{b'3'=>match r[8]{b'2' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB132PD),b'h'=>s(VFMSUB132PH),b's'=>s(VFMSUB132PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB132SD),b'h'=>s(VFMSUB132SH),b's'=>s(VFMSUB132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[7]{b'1'=>match r[8]{b'3' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB213PD),b'h'=>s(VFMSUB213PH),b's'=>s(VFMSUB213PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB213SD),b'h'=>s(VFMSUB213SH),b's'=>s(VFMSUB213SS),_=>N}_=>N}_=>N}b'3'=>match r[8]{b'1' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB231PD),b'h'=>s(VFMSUB231PH),b's'=>s(VFMSUB231PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB231SD),b'h'=>s(VFMSUB231SH),b's'=>s(VFMSUB231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'c'=>match r[3]{b'o' => match r[4]{b'm'=>match r[5]{b'p'=>match r[6]{b'r'=>match r[7]{b'e'=>match r[8]{b's'=>match r[9]{b's' => match r[10]{b'b'=>s(VPCOMPRESSB),b'd'=>s(VPCOMPRESSD),b'q'=>s(VPCOMPRESSQ),b'w'=>s(VPCOMPRESSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[5]{b'f'=>match r[6]{b'l'=>match r[7]{b'i'=>match r[8]{b'c'=>match r[9]{b't' => match r[10]{b'd'=>s(VPCONFLICTD),b'q'=>s(VPCONFLICTQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[3]{b'm'=>match r[4]{b'i'=>match r[5]{b'n'=>match r[6]{b'p'=>match r[7]{b'o'=>match r[8]{b's'=>match r[9]{b'u'=>match r[10]{b'w'=>s(VPHMINPOSUW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'5'=>match r[7]{b'2' => match r[8]{b'h'=>match r[9]{b'u'=>match r[10]{b'q'=>s(VPMADD52HUQ),_=>N}_=>N}b'l'=>match r[9]{b'u'=>match r[10]{b'q'=>s(VPMADD52LUQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'c'=>match r[4]{b'a'=>match r[5]{b't'=>match r[6]{b't'=>match r[7]{b'e'=>match r[8]{b'r' => match r[9]{b'd' => match r[10]{b'd'=>s(VPSCATTERDD),b'q'=>s(VPSCATTERDQ),_=>N}b'q' => match r[10]{b'd'=>s(VPSCATTERQD),b'q'=>s(VPSCATTERQQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'c'=>match r[3]{b'a'=>match r[4]{b't'=>match r[5]{b't'=>match r[6]{b'e'=>match r[7]{b'r' => match r[8]{b'd'=>match r[9]{b'p' => match r[10]{b'd'=>s(VSCATTERDPD),b's'=>s(VSCATTERDPS),_=>N}_=>N}b'q'=>match r[9]{b'p' => match r[10]{b'd'=>s(VSCATTERQPD),b's'=>s(VSCATTERQPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[3]{b'a'=>match r[4]{b'5'=>match r[5]{b'1'=>match r[6]{b'2'=>match r[7]{b'm'=>match r[8]{b's'=>match r[9]{b'g' => match r[10]{b'1'=>s(VSHA512MSG1),b'2'=>s(VSHA512MSG2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}12=>match r[0]{b'e'=>match r[1]{b'n'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'd'=>match r[5]{b'e'=>match r[6]{b'k'=>match r[7]{b'e'=>match r[8]{b'y' => match r[9]{b'1'=>match r[10]{b'2'=>match r[11]{b'8'=>s(ENCODEKEY128),_=>N}_=>N}b'2'=>match r[10]{b'5'=>match r[11]{b'6'=>s(ENCODEKEY256),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'v'=>match r[3]{b't'=>match r[4]{b'n'=>match r[5]{b'e' => match r[6]{b'e'=>match r[7]{b'p'=>match r[8]{b'h'=>match r[9]{b'2'=>match r[10]{b'p'=>match r[11]{b's'=>s(VCVTNEEPH2PS),_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[7]{b'p'=>match r[8]{b'h'=>match r[9]{b'2'=>match r[10]{b'p'=>match r[11]{b's'=>s(VCVTNEOPH2PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>match r[5]{b'a'=>match r[6]{b'c'=>match r[7]{b't' => match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'2'=>match r[11]{b'8'=>s(VEXTRACTF128),_=>N}_=>N}_=>N}b'i'=>match r[9]{b'1'=>match r[10]{b'2'=>match r[11]{b'8'=>s(VEXTRACTI128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'n'=>match r[3]{b'm' => match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'd' => match r[7]{b'1'=>match r[8]{b'3'=>match r[9]{b'2' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD132PD),b'h'=>s(VFNMADD132PH),b's'=>s(VFNMADD132PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD132SD),b'h'=>s(VFNMADD132SH),b's'=>s(VFNMADD132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[8]{b'1'=>match r[9]{b'3' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD213PD),b'h'=>s(VFNMADD213PH),b's'=>s(VFNMADD213PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD213SD),b'h'=>s(VFNMADD213SH),b's'=>s(VFNMADD213SS),_=>N}_=>N}_=>N}b'3'=>match r[9]{b'1' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD231PD),b'h'=>s(VFNMADD231PH),b's'=>s(VFNMADD231PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD231SD),b'h'=>s(VFNMADD231SH),b's'=>s(VFNMADD231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[5]{b'u'=>match r[6]{b'b' => match r[7]{b'1'=>match r[8]{b'3'=>match r[9]{b'2' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB132PD),b'h'=>s(VFNMSUB132PH),b's'=>s(VFNMSUB132PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB132SD),b'h'=>s(VFNMSUB132SH),b's'=>s(VFNMSUB132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[8]{b'1'=>match r[9]{b'3' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB213PD),b'h'=>s(VFNMSUB213PH),b's'=>s(VFNMSUB213PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB213SD),b'h'=>s(VFNMSUB213SH),b's'=>s(VFNMSUB213SS),_=>N}_=>N}_=>N}b'3'=>match r[9]{b'1' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB231PD),b'h'=>s(VFNMSUB231PH),b's'=>s(VFNMSUB231PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB231SD),b'h'=>s(VFNMSUB231SH),b's'=>s(VFNMSUB231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'r'=>match r[6]{b't' => match r[7]{b'f' => match r[8]{b'3'=>match r[9]{b'2'=>match r[10]{b'x' => match r[11]{b'4'=>s(VINSERTF32X4),b'8'=>s(VINSERTF32X8),_=>N}_=>N}_=>N}b'6'=>match r[9]{b'4'=>match r[10]{b'x' => match r[11]{b'2'=>s(VINSERTF64X2),b'4'=>s(VINSERTF64X4),_=>N}_=>N}_=>N}_=>N}b'i' => match r[8]{b'3'=>match r[9]{b'2'=>match r[10]{b'x' => match r[11]{b'4'=>s(VINSERTI32X4),b'8'=>s(VINSERTI32X8),_=>N}_=>N}_=>N}b'6'=>match r[9]{b'4'=>match r[10]{b'x' => match r[11]{b'2'=>s(VINSERTI64X2),b'4'=>s(VINSERTI64X4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'h'=>match r[3]{b'a'=>match r[4]{b'5'=>match r[5]{b'1'=>match r[6]{b'2'=>match r[7]{b'r'=>match r[8]{b'n'=>match r[9]{b'd'=>match r[10]{b's'=>match r[11]{b'2'=>s(VSHA512RNDS2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b' => match r[2]{b'c'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'n'=>match r[6]{b'e'=>match r[7]{b's'=>match r[8]{b'h'=>match r[9]{b'2'=>match r[10]{b'p'=>match r[11]{b's'=>s(VBCSTNESH2PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'c'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>match r[10]{b's' => match r[11]{b'd'=>s(VBROADCASTSD),b's'=>s(VBROADCASTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'b'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'c'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't' => match r[11]{b'b'=>s(VPBROADCASTB),b'd'=>s(VPBROADCASTD),b'q'=>s(VPBROADCASTQ),b'w'=>s(VPBROADCASTW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'h'=>match r[4]{b'u'=>match r[5]{b'f'=>match r[6]{b'b'=>match r[7]{b'i'=>match r[8]{b't'=>match r[9]{b'q'=>match r[10]{b'm'=>match r[11]{b'b'=>s(VPSHUFBITQMB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}13=>match r[0]{b'v' => match r[1]{b'c'=>match r[2]{b'v'=>match r[3]{b't'=>match r[4]{b'n'=>match r[5]{b'e'=>match r[6]{b'p'=>match r[7]{b's'=>match r[8]{b'2'=>match r[9]{b'b'=>match r[10]{b'f'=>match r[11]{b'1'=>match r[12]{b'6'=>s(VCVTNEPS2BF16),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>match r[5]{b'a'=>match r[6]{b'c'=>match r[7]{b't' => match r[8]{b'f' => match r[9]{b'3'=>match r[10]{b'2'=>match r[11]{b'x' => match r[12]{b'4'=>s(VEXTRACTF32X4),b'8'=>s(VEXTRACTF32X8),_=>N}_=>N}_=>N}b'6'=>match r[10]{b'4'=>match r[11]{b'x' => match r[12]{b'2'=>s(VEXTRACTF64X2),b'4'=>s(VEXTRACTF64X4),_=>N}_=>N}_=>N}_=>N}b'i' => match r[9]{b'3'=>match r[10]{b'2'=>match r[11]{b'x' => match r[12]{b'4'=>s(VEXTRACTI32X4),b'8'=>s(VEXTRACTI32X8),_=>N}_=>N}_=>N}b'6'=>match r[10]{b'4'=>match r[11]{b'x' => match r[12]{b'2'=>s(VEXTRACTI64X2),b'4'=>s(VEXTRACTI64X4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'h'=>match r[5]{b'e'=>match r[6]{b'r'=>match r[7]{b'p'=>match r[8]{b'f' => match r[9]{b'0' => match r[10]{b'd'=>match r[11]{b'p' => match r[12]{b'd'=>s(VGATHERPF0DPD),b's'=>s(VGATHERPF0DPS),_=>N}_=>N}b'q'=>match r[11]{b'p' => match r[12]{b'd'=>s(VGATHERPF0QPD),b's'=>s(VGATHERPF0QPS),_=>N}_=>N}_=>N}b'1' => match r[10]{b'd'=>match r[11]{b'p' => match r[12]{b'd'=>s(VGATHERPF1DPD),b's'=>s(VGATHERPF1DPS),_=>N}_=>N}b'q'=>match r[11]{b'p' => match r[12]{b'd'=>s(VGATHERPF1QPD),b's'=>s(VGATHERPF1QPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'2'=>match r[3]{b'i'=>match r[4]{b'n'=>match r[5]{b't'=>match r[6]{b'e'=>match r[7]{b'r'=>match r[8]{b's'=>match r[9]{b'e'=>match r[10]{b'c'=>match r[11]{b't' => match r[12]{b'd'=>s(VP2INTERSECTD),b'q'=>s(VP2INTERSECTQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}14=>match r[0]{b'v' => match r[1]{b'c'=>match r[2]{b'v'=>match r[3]{b't'=>match r[4]{b'n'=>match r[5]{b'e' => match r[6]{b'2'=>match r[7]{b'p'=>match r[8]{b's'=>match r[9]{b'2'=>match r[10]{b'b'=>match r[11]{b'f'=>match r[12]{b'1'=>match r[13]{b'6'=>s(VCVTNE2PS2BF16),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[7]{b'b'=>match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'6'=>match r[11]{b'2'=>match r[12]{b'p'=>match r[13]{b's'=>s(VCVTNEEBF162PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[7]{b'b'=>match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'6'=>match r[11]{b'2'=>match r[12]{b'p'=>match r[13]{b's'=>s(VCVTNEOBF162PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b's'=>match r[7]{b'u'=>match r[8]{b'b' => match r[9]{b'1'=>match r[10]{b'3'=>match r[11]{b'2'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB132PD),b'h'=>s(VFMADDSUB132PH),b's'=>s(VFMADDSUB132PS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[10]{b'1'=>match r[11]{b'3'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB213PD),b'h'=>s(VFMADDSUB213PH),b's'=>s(VFMADDSUB213PS),_=>N}_=>N}_=>N}b'3'=>match r[11]{b'1'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB231PD),b'h'=>s(VFMADDSUB231PH),b's'=>s(VFMADDSUB231PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd' => match r[9]{b'1'=>match r[10]{b'3'=>match r[11]{b'2'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD132PD),b'h'=>s(VFMSUBADD132PH),b's'=>s(VFMSUBADD132PS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[10]{b'1'=>match r[11]{b'3'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD213PD),b'h'=>s(VFMSUBADD213PH),b's'=>s(VFMSUBADD213PS),_=>N}_=>N}_=>N}b'3'=>match r[11]{b'1'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD231PD),b'h'=>s(VFMSUBADD231PH),b's'=>s(VFMSUBADD231PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>match r[5]{b't'=>match r[6]{b'i'=>match r[7]{b's'=>match r[8]{b'h'=>match r[9]{b'i'=>match r[10]{b'f'=>match r[11]{b't'=>match r[12]{b'q'=>match r[13]{b'b'=>s(VPMULTISHIFTQB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'c'=>match r[3]{b'a'=>match r[4]{b't'=>match r[5]{b't'=>match r[6]{b'e'=>match r[7]{b'r'=>match r[8]{b'p'=>match r[9]{b'f' => match r[10]{b'0' => match r[11]{b'd'=>match r[12]{b'p' => match r[13]{b'd'=>s(VSCATTERPF0DPD),b's'=>s(VSCATTERPF0DPS),_=>N}_=>N}b'q'=>match r[12]{b'p' => match r[13]{b'd'=>s(VSCATTERPF0QPD),b's'=>s(VSCATTERPF0QPS),_=>N}_=>N}_=>N}b'1' => match r[11]{b'd'=>match r[12]{b'p' => match r[13]{b'd'=>s(VSCATTERPF1DPD),b's'=>s(VSCATTERPF1DPS),_=>N}_=>N}b'q'=>match r[12]{b'p' => match r[13]{b'd'=>s(VSCATTERPF1QPD),b's'=>s(VSCATTERPF1QPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b' => match r[2]{b'c'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'n'=>match r[6]{b'e'=>match r[7]{b'b'=>match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'6'=>match r[11]{b'2'=>match r[12]{b'p'=>match r[13]{b's'=>s(VBCSTNEBF162PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'c'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>match r[10]{b'f'=>match r[11]{b'1'=>match r[12]{b'2'=>match r[13]{b'8'=>s(VBROADCASTF128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}15=>match r[0]{b'a'=>match r[1]{b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c'=>match r[6]{b'w'=>match r[7]{b'i'=>match r[8]{b'd'=>match r[9]{b'e' => match r[10]{b'1'=>match r[11]{b'2'=>match r[12]{b'8'=>match r[13]{b'k'=>match r[14]{b'l'=>s(AESDECWIDE128KL),_=>N}_=>N}_=>N}_=>N}b'2'=>match r[11]{b'5'=>match r[12]{b'6'=>match r[13]{b'k'=>match r[14]{b'l'=>s(AESDECWIDE256KL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c'=>match r[6]{b'w'=>match r[7]{b'i'=>match r[8]{b'd'=>match r[9]{b'e' => match r[10]{b'1'=>match r[11]{b'2'=>match r[12]{b'8'=>match r[13]{b'k'=>match r[14]{b'l'=>s(AESENCWIDE128KL),_=>N}_=>N}_=>N}_=>N}b'2'=>match r[11]{b'5'=>match r[12]{b'6'=>match r[13]{b'k'=>match r[14]{b'l'=>s(AESENCWIDE256KL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'k'=>match r[4]{b'e'=>match r[5]{b'y'=>match r[6]{b'g'=>match r[7]{b'e'=>match r[8]{b'n'=>match r[9]{b'a'=>match r[10]{b's'=>match r[11]{b's'=>match r[12]{b'i'=>match r[13]{b's'=>match r[14]{b't'=>s(AESKEYGENASSIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'b'=>match r[2]{b'r'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'c'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>match r[10]{b'f' => match r[11]{b'3'=>match r[12]{b'2'=>match r[13]{b'x' => match r[14]{b'2'=>s(VBROADCASTF32X2),b'4'=>s(VBROADCASTF32X4),b'8'=>s(VBROADCASTF32X8),_=>N}_=>N}_=>N}b'6'=>match r[12]{b'4'=>match r[13]{b'x' => match r[14]{b'2'=>s(VBROADCASTF64X2),b'4'=>s(VBROADCASTF64X4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'b'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'c'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't' => match r[11]{b'i'=>match r[12]{b'1'=>match r[13]{b'2'=>match r[14]{b'8'=>s(VPBROADCASTI128),_=>N}_=>N}_=>N}b'm' => match r[12]{b'b'=>match r[13]{b'2'=>match r[14]{b'q'=>s(VPBROADCASTMB2Q),_=>N}_=>N}b'w'=>match r[13]{b'2'=>match r[14]{b'd'=>s(VPBROADCASTMW2D),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}16=>match r[0]{b'v' => match r[1]{b'a'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'k'=>match r[5]{b'e'=>match r[6]{b'y'=>match r[7]{b'g'=>match r[8]{b'e'=>match r[9]{b'n'=>match r[10]{b'a'=>match r[11]{b's'=>match r[12]{b's'=>match r[13]{b'i'=>match r[14]{b's'=>match r[15]{b't'=>s(VAESKEYGENASSIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'b'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'c'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't'=>match r[11]{b'i' => match r[12]{b'3'=>match r[13]{b'2'=>match r[14]{b'x' => match r[15]{b'2'=>s(VPBROADCASTI32X2),b'4'=>s(VPBROADCASTI32X4),b'8'=>s(VPBROADCASTI32X8),_=>N}_=>N}_=>N}b'6'=>match r[13]{b'4'=>match r[14]{b'x' => match r[15]{b'2'=>s(VPBROADCASTI64X2),b'4'=>s(VPBROADCASTI64X4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_ =>N,
+		2=>match r[0]{b'b'=>match r[1]{b't'=>s(BT),_=>N}b'i'=>match r[1]{b'n'=>s(IN),_=>N}b'o'=>match r[1]{b'r'=>s(OR),_=>N}b'j' => match r[1]{b'a'=>s(JA),b'b'=>s(JB),b'c'=>s(JC),b'e'=>s(JE),b'g'=>s(JG),b'l'=>s(JL),b'o'=>s(JO),b'p'=>s(JP),b's'=>s(JS),b'z'=>s(JZ),_=>N}_=>N}3=>match r[0]{b'h'=>match r[1]{b'l'=>match r[2]{b't'=>s(HLT),_=>N}_=>N}b'i'=>match r[1]{b'n' => match r[2]{b'c'=>s(INC),b't'=>s(INT),_=>N}_=>N}b'o'=>match r[1]{b'u'=>match r[2]{b't'=>s(OUT),_=>N}_=>N}b'p'=>match r[1]{b'o' => match r[2]{b'p'=>s(POP),b'r'=>s(POR),_=>N}_=>N}b'u'=>match r[1]{b'd' => match r[2]{b'0'=>s(UD0),b'1'=>s(UD1),b'2'=>s(UD2),_=>N}_=>N}b'x'=>match r[1]{b'o'=>match r[2]{b'r'=>s(XOR),_=>N}_=>N}b'a' => match r[1]{b'n'=>match r[2]{b'd'=>s(AND),_=>N}b'o'=>match r[2]{b'r'=>s(AOR),_=>N}b'a' => match r[2]{b'a'=>s(AAA),b'd'=>s(AAD),b'm'=>s(AAM),b's'=>s(AAS),_=>N}b'd' => match r[2]{b'c'=>s(ADC),b'd'=>s(ADD),_=>N}_=>N}b'b' => match r[1]{b's' => match r[2]{b'f'=>s(BSF),b'r'=>s(BSR),_=>N}b't' => match r[2]{b'c'=>s(BTC),b'r'=>s(BTR),b's'=>s(BTS),_=>N}_=>N}b'c' => match r[1]{b'b'=>match r[2]{b'w'=>s(CBW),_=>N}b'd'=>match r[2]{b'q'=>s(CDQ),_=>N}b'q'=>match r[2]{b'o'=>s(CQO),_=>N}b'w'=>match r[2]{b'd'=>s(CWD),_=>N}b'l' => match r[2]{b'c'=>s(CLC),b'd'=>s(CLD),b'i'=>s(CLI),_=>N}b'm' => match r[2]{b'c'=>s(CMC),b'p'=>s(CMP),_=>N}_=>N}b'd' => match r[1]{b'e'=>match r[2]{b'c'=>s(DEC),_=>N}b'i'=>match r[2]{b'v'=>s(DIV),_=>N}b'a' => match r[2]{b'a'=>s(DAA),b's'=>s(DAS),_=>N}_=>N}b'f' => match r[1]{b'l'=>match r[2]{b'd'=>s(FLD),_=>N}b's'=>match r[2]{b't'=>s(FST),_=>N}_=>N}b'j' => match r[1]{b'a'=>match r[2]{b'e'=>s(JAE),_=>N}b'b'=>match r[2]{b'e'=>s(JBE),_=>N}b'g'=>match r[2]{b'e'=>s(JGE),_=>N}b'l'=>match r[2]{b'e'=>s(JLE),_=>N}b'm'=>match r[2]{b'p'=>s(JMP),_=>N}b'n' => match r[2]{b'a'=>s(JNA),b'b'=>s(JNB),b'c'=>s(JNC),b'e'=>s(JNE),b'g'=>s(JNG),b'l'=>s(JNL),b'o'=>s(JNO),b'p'=>s(JNP),b's'=>s(JNS),b'z'=>s(JNZ),_=>N}b'p' => match r[2]{b'e'=>s(JPE),b'o'=>s(JPO),_=>N}_=>N}b'l' => match r[1]{b'a'=>match r[2]{b'r'=>s(LAR),_=>N}b'e'=>match r[2]{b'a'=>s(LEA),_=>N}b'f'=>match r[2]{b's'=>s(LFS),_=>N}b'g'=>match r[2]{b's'=>s(LGS),_=>N}b's'=>match r[2]{b'l'=>s(LSL),_=>N}b't'=>match r[2]{b'r'=>s(LTR),_=>N}_=>N}b'm' => match r[1]{b'o'=>match r[2]{b'v'=>s(MOV),_=>N}b'u'=>match r[2]{b'l'=>s(MUL),_=>N}_=>N}b'n' => match r[1]{b'e'=>match r[2]{b'g'=>s(NEG),_=>N}b'o' => match r[2]{b'p'=>s(NOP),b't'=>s(NOT),_=>N}_=>N}b'r' => match r[1]{b's'=>match r[2]{b'm'=>s(RSM),_=>N}b'c' => match r[2]{b'l'=>s(RCL),b'r'=>s(RCR),_=>N}b'e' => match r[2]{b'p'=>s(REP),b't'=>s(RET),_=>N}b'o' => match r[2]{b'l'=>s(ROL),b'r'=>s(ROR),_=>N}_=>N}b's' => match r[1]{b'b'=>match r[2]{b'b'=>s(SBB),_=>N}b'u'=>match r[2]{b'b'=>s(SUB),_=>N}b'a' => match r[2]{b'l'=>s(SAL),b'r'=>s(SAR),_=>N}b'h' => match r[2]{b'l'=>s(SHL),b'r'=>s(SHR),_=>N}b't' => match r[2]{b'c'=>s(STC),b'd'=>s(STD),b'i'=>s(STI),b'r'=>s(STR),_=>N}_=>N}_=>N}4=>match r[0]{b'd'=>match r[1]{b'p'=>match r[2]{b'p' => match r[3]{b'd'=>s(DPPD),b's'=>s(DPPS),_=>N}_=>N}_=>N}b'e'=>match r[1]{b'm'=>match r[2]{b'm'=>match r[3]{b's'=>s(EMMS),_=>N}_=>N}_=>N}b'k'=>match r[1]{b'o'=>match r[2]{b'r' => match r[3]{b'b'=>s(KORB),b'd'=>s(KORD),b'q'=>s(KORQ),b'w'=>s(KORW),_=>N}_=>N}_=>N}b'n'=>match r[1]{b'o'=>match r[2]{b'p'=>match r[3]{b'l'=>s(NOPL),_=>N}_=>N}_=>N}b'o'=>match r[1]{b'r'=>match r[2]{b'p' => match r[3]{b'd'=>s(ORPD),b's'=>s(ORPS),_=>N}_=>N}_=>N}b't'=>match r[1]{b'e'=>match r[2]{b's'=>match r[3]{b't'=>s(TEST),_=>N}_=>N}_=>N}b'w'=>match r[1]{b'a'=>match r[2]{b'i'=>match r[3]{b't'=>s(WAIT),_=>N}_=>N}_=>N}b'a' => match r[1]{b'i'=>match r[2]{b'n'=>match r[3]{b'c'=>s(AINC),_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>s(AMUL),_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r'=>s(AXOR),_=>N}_=>N}b'a' => match r[2]{b'n'=>match r[3]{b'd'=>s(AAND),_=>N}b'o'=>match r[3]{b'r'=>s(AAOR),_=>N}b'd' => match r[3]{b'c'=>s(AADC),b'd'=>s(AADD),_=>N}_=>N}b'd' => match r[2]{b'c'=>match r[3]{b'x'=>s(ADCX),_=>N}b'e'=>match r[3]{b'c'=>s(ADEC),_=>N}b'i'=>match r[3]{b'v'=>s(ADIV),_=>N}b'o'=>match r[3]{b'x'=>s(ADOX),_=>N}_=>N}b'n' => match r[2]{b'd'=>match r[3]{b'n'=>s(ANDN),_=>N}b'e'=>match r[3]{b'g'=>s(ANEG),_=>N}b'o'=>match r[3]{b't'=>s(ANOT),_=>N}_=>N}b'r' => match r[2]{b'p'=>match r[3]{b'l'=>s(ARPL),_=>N}b'c' => match r[3]{b'l'=>s(ARCL),b'r'=>s(ARCR),_=>N}b'o' => match r[3]{b'l'=>s(AROL),b'r'=>s(AROR),_=>N}_=>N}b's' => match r[2]{b'a'=>match r[3]{b'r'=>s(ASAR),_=>N}b'b'=>match r[3]{b'b'=>s(ASBB),_=>N}b'u'=>match r[3]{b'b'=>s(ASUB),_=>N}b'h' => match r[3]{b'l'=>s(ASHL),b'r'=>s(ASHR),_=>N}_=>N}_=>N}b'b' => match r[1]{b'l' => match r[2]{b'c' => match r[3]{b'i'=>s(BLCI),b's'=>s(BLCS),_=>N}b's' => match r[3]{b'i'=>s(BLSI),b'r'=>s(BLSR),_=>N}_=>N}b'z'=>match r[2]{b'h'=>match r[3]{b'i'=>s(BZHI),_=>N}_=>N}_=>N}b'c' => match r[1]{b'a'=>match r[2]{b'l'=>match r[3]{b'l'=>s(CALL),_=>N}_=>N}b'd'=>match r[2]{b'q'=>match r[3]{b'e'=>s(CDQE),_=>N}_=>N}b'w'=>match r[2]{b'd'=>match r[3]{b'e'=>s(CWDE),_=>N}_=>N}b'l' => match r[2]{b'a'=>match r[3]{b'c'=>s(CLAC),_=>N}b'g'=>match r[3]{b'i'=>s(CLGI),_=>N}b't'=>match r[3]{b's'=>s(CLTS),_=>N}b'u'=>match r[3]{b'i'=>s(CLUI),_=>N}b'w'=>match r[3]{b'b'=>s(CLWB),_=>N}_=>N}_=>N}b'f' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b'd'=>s(FBLD),_=>N}_=>N}b'd'=>match r[2]{b'i'=>match r[3]{b'v'=>s(FDIV),_=>N}_=>N}b'e'=>match r[2]{b'n'=>match r[3]{b'i'=>s(FENI),_=>N}_=>N}b'l'=>match r[2]{b'd' => match r[3]{b'1'=>s(FLD1),b'z'=>s(FLDZ),_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>s(FMUL),_=>N}_=>N}b'n'=>match r[2]{b'o'=>match r[3]{b'p'=>s(FNOP),_=>N}_=>N}b't'=>match r[2]{b's'=>match r[3]{b't'=>s(FTST),_=>N}_=>N}b'a' => match r[2]{b'b'=>match r[3]{b's'=>s(FABS),_=>N}b'd'=>match r[3]{b'd'=>s(FADD),_=>N}_=>N}b'c' => match r[2]{b'h'=>match r[3]{b's'=>s(FCHS),_=>N}b'o' => match r[3]{b'm'=>s(FCOM),b's'=>s(FCOS),_=>N}_=>N}b'i' => match r[2]{b'l'=>match r[3]{b'd'=>s(FILD),_=>N}b's'=>match r[3]{b't'=>s(FIST),_=>N}_=>N}b's' => match r[2]{b'i'=>match r[3]{b'n'=>s(FSIN),_=>N}b't'=>match r[3]{b'p'=>s(FSTP),_=>N}b'u'=>match r[3]{b'b'=>s(FSUB),_=>N}_=>N}b'x' => match r[2]{b'a'=>match r[3]{b'm'=>s(FXAM),_=>N}b'c'=>match r[3]{b'h'=>s(FXCH),_=>N}_=>N}_=>N}b'i' => match r[1]{b'd'=>match r[2]{b'i'=>match r[3]{b'v'=>s(IDIV),_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>s(IMUL),_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b't'=>s(IRET),_=>N}_=>N}b'n' => match r[2]{b'v'=>match r[3]{b'd'=>s(INVD),_=>N}b's' => match r[3]{b'b'=>s(INSB),b'd'=>s(INSD),b'w'=>s(INSW),_=>N}b't' => match r[3]{b'1'=>s(INT1),b'3'=>s(INT3),b'o'=>s(INTO),_=>N}_=>N}_=>N}b'j' => match r[1]{b'c'=>match r[2]{b'x'=>match r[3]{b'z'=>s(JCXZ),_=>N}_=>N}b'n' => match r[2]{b'a'=>match r[3]{b'e'=>s(JNAE),_=>N}b'b'=>match r[3]{b'e'=>s(JNBE),_=>N}b'g'=>match r[3]{b'e'=>s(JNGE),_=>N}b'l'=>match r[3]{b'e'=>s(JNLE),_=>N}_=>N}_=>N}b'l' => match r[1]{b'a'=>match r[2]{b'h'=>match r[3]{b'f'=>s(LAHF),_=>N}_=>N}b'g'=>match r[2]{b'd'=>match r[3]{b't'=>s(LGDT),_=>N}_=>N}b'i'=>match r[2]{b'd'=>match r[3]{b't'=>s(LIDT),_=>N}_=>N}b'j'=>match r[2]{b'm'=>match r[3]{b'p'=>s(LJMP),_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b't'=>s(LLDT),_=>N}_=>N}b'm'=>match r[2]{b's'=>match r[3]{b'w'=>s(LMSW),_=>N}_=>N}b'o' => match r[2]{b'c'=>match r[3]{b'k'=>s(LOCK),_=>N}b'o'=>match r[3]{b'p'=>s(LOOP),_=>N}_=>N}_=>N}b'm' => match r[1]{b'o'=>match r[2]{b'v' => match r[3]{b'd'=>s(MOVD),b'q'=>s(MOVQ),_=>N}_=>N}b'u'=>match r[2]{b'l'=>match r[3]{b'x'=>s(MULX),_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'n'=>match r[3]{b'd'=>s(PAND),_=>N}_=>N}b'd'=>match r[2]{b'e'=>match r[3]{b'p'=>s(PDEP),_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>s(PEXT),_=>N}_=>N}b'o'=>match r[2]{b'p' => match r[3]{b'2'=>s(POP2),b'f'=>s(POPF),b'p'=>s(POPP),_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h'=>s(PUSH),_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r'=>s(PXOR),_=>N}_=>N}_=>N}b'r' => match r[1]{b'e'=>match r[2]{b'p' => match r[3]{b'e'=>s(REPE),b'z'=>s(REPZ),_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'x'=>s(RORX),_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b't' => match r[3]{b'a'=>s(SETA),b'b'=>s(SETB),b'c'=>s(SETC),b'e'=>s(SETE),b'g'=>s(SETG),b'l'=>s(SETL),b'o'=>s(SETO),b'p'=>s(SETP),b's'=>s(SETS),b'z'=>s(SETZ),_=>N}_=>N}b'g'=>match r[2]{b'd'=>match r[3]{b't'=>s(SGDT),_=>N}_=>N}b'i'=>match r[2]{b'd'=>match r[3]{b't'=>s(SIDT),_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b't'=>s(SLDT),_=>N}_=>N}b'm'=>match r[2]{b's'=>match r[3]{b'w'=>s(SMSW),_=>N}_=>N}b'a' => match r[2]{b'h'=>match r[3]{b'f'=>s(SAHF),_=>N}b'r'=>match r[3]{b'x'=>s(SARX),_=>N}_=>N}b'h' => match r[2]{b'l' => match r[3]{b'd'=>s(SHLD),b'x'=>s(SHLX),_=>N}b'r' => match r[3]{b'd'=>s(SHRD),b'x'=>s(SHRX),_=>N}_=>N}b't' => match r[2]{b'a'=>match r[3]{b'c'=>s(STAC),_=>N}b'g'=>match r[3]{b'i'=>s(STGI),_=>N}b'u'=>match r[3]{b'i'=>s(STUI),_=>N}_=>N}_=>N}b'v' => match r[1]{b'e'=>match r[2]{b'r' => match r[3]{b'r'=>s(VERR),b'w'=>s(VERW),_=>N}_=>N}b'p'=>match r[2]{b'o'=>match r[3]{b'r'=>s(VPOR),_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>s(XADD),_=>N}_=>N}b'c'=>match r[2]{b'h'=>match r[3]{b'g'=>s(XCHG),_=>N}_=>N}b'e'=>match r[2]{b'n'=>match r[3]{b'd'=>s(XEND),_=>N}_=>N}b'l'=>match r[2]{b'a'=>match r[3]{b't'=>s(XLAT),_=>N}_=>N}_=>N}_=>N}5=>match r[0]{b'd'=>match r[1]{b'i'=>match r[2]{b'v' => match r[3]{b'p' => match r[4]{b'd'=>s(DIVPD),b's'=>s(DIVPS),_=>N}b's' => match r[4]{b'd'=>s(DIVSD),b's'=>s(DIVSS),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'r'=>match r[2]{b'e'=>match r[3]{b't' => match r[4]{b'd'=>s(IRETD),b'q'=>s(IRETQ),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[1]{b'u'=>match r[2]{b't'=>match r[3]{b's' => match r[4]{b'b'=>s(OUTSB),b'd'=>s(OUTSD),b'w'=>s(OUTSW),_=>N}_=>N}_=>N}_=>N}b't'=>match r[1]{b'z'=>match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'k'=>s(TZMSK),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'i'=>match r[2]{b'r'=>match r[3]{b'e'=>match r[4]{b't'=>s(UIRET),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[1]{b'r' => match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>s(WRMSR),_=>N}_=>N}b's'=>match r[3]{b's' => match r[4]{b'd'=>s(WRSSD),b'q'=>s(WRSSQ),_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'd'=>match r[2]{b'd' => match r[3]{b'p' => match r[4]{b'd'=>s(ADDPD),b's'=>s(ADDPS),_=>N}b's' => match r[4]{b'd'=>s(ADDSD),b's'=>s(ADDSS),_=>N}_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>match r[4]{b'x'=>s(AMULX),_=>N}_=>N}_=>N}b'n'=>match r[2]{b'd'=>match r[3]{b'p' => match r[4]{b'd'=>s(ANDPD),b's'=>s(ANDPS),_=>N}_=>N}_=>N}b'r'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'x'=>s(ARORX),_=>N}_=>N}_=>N}b'a' => match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b'n'=>s(AANDN),_=>N}_=>N}b'x'=>match r[3]{b'o'=>match r[4]{b'r'=>s(AAXOR),_=>N}_=>N}b'a' => match r[3]{b'd'=>match r[4]{b'd'=>s(AAADD),_=>N}b'n'=>match r[4]{b'd'=>s(AAAND),_=>N}_=>N}b'd' => match r[3]{b'c'=>match r[4]{b'x'=>s(AADCX),_=>N}b'o'=>match r[4]{b'x'=>s(AADOX),_=>N}_=>N}_=>N}b'b' => match r[2]{b'l'=>match r[3]{b's' => match r[4]{b'i'=>s(ABLSI),b'r'=>s(ABLSR),_=>N}_=>N}b'z'=>match r[3]{b'h'=>match r[4]{b'i'=>s(ABZHI),_=>N}_=>N}_=>N}b'i' => match r[2]{b'd'=>match r[3]{b'i'=>match r[4]{b'v'=>s(AIDIV),_=>N}_=>N}b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>s(AIMUL),_=>N}_=>N}_=>N}b'p' => match r[2]{b'd'=>match r[3]{b'e'=>match r[4]{b'p'=>s(APDEP),_=>N}_=>N}b'e'=>match r[3]{b'x'=>match r[4]{b't'=>s(APEXT),_=>N}_=>N}_=>N}b's' => match r[2]{b'a'=>match r[3]{b'r'=>match r[4]{b'x'=>s(ASARX),_=>N}_=>N}b'c'=>match r[3]{b'i'=>match r[4]{b'i'=>s(ASCII),_=>N}_=>N}b'h' => match r[3]{b'l' => match r[4]{b'd'=>s(ASHLD),b'x'=>s(ASHLX),_=>N}b'r' => match r[4]{b'd'=>s(ASHRD),b'x'=>s(ASHRX),_=>N}_=>N}_=>N}_=>N}b'b' => match r[1]{b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>s(BEXTR),_=>N}_=>N}_=>N}b's'=>match r[2]{b'w'=>match r[3]{b'a'=>match r[4]{b'p'=>s(BSWAP),_=>N}_=>N}_=>N}b'l' => match r[2]{b'c'=>match r[3]{b'i'=>match r[4]{b'c'=>s(BLCIC),_=>N}_=>N}b's'=>match r[3]{b'i'=>match r[4]{b'c'=>s(BLSIC),_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'a'=>s(CCMPA),b'b'=>s(CCMPB),b'c'=>s(CCMPC),b'e'=>s(CCMPE),b'f'=>s(CCMPF),b'g'=>s(CCMPG),b'l'=>s(CCMPL),b'o'=>s(CCMPO),b's'=>s(CCMPS),b't'=>s(CCMPT),b'z'=>s(CCMPZ),_=>N}_=>N}_=>N}b'p'=>match r[2]{b'u'=>match r[3]{b'i'=>match r[4]{b'd'=>s(CPUID),_=>N}_=>N}_=>N}b'r'=>match r[2]{b'c'=>match r[3]{b'3'=>match r[4]{b'2'=>s(CRC32),_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'a'=>s(CMOVA),b'b'=>s(CMOVB),b'c'=>s(CMOVC),b'e'=>s(CMOVE),b'g'=>s(CMOVG),b'l'=>s(CMOVL),b'o'=>s(CMOVO),b'p'=>s(CMOVP),b's'=>s(CMOVS),b'z'=>s(CMOVZ),_=>N}_=>N}b'p' => match r[3]{b'p' => match r[4]{b'd'=>s(CMPPD),b's'=>s(CMPPS),_=>N}b's' => match r[4]{b'd'=>s(CMPSD),b's'=>s(CMPSS),_=>N}_=>N}_=>N}_=>N}b'e' => match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b't'=>match r[4]{b'y'=>s(EMPTY),_=>N}_=>N}_=>N}b'n' => match r[2]{b'c'=>match r[3]{b'l' => match r[4]{b's'=>s(ENCLS),b'u'=>s(ENCLU),b'v'=>s(ENCLV),_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b'r'=>s(ENTER),_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'2'=>match r[2]{b'x'=>match r[3]{b'm'=>match r[4]{b'1'=>s(F2XM1),_=>N}_=>N}_=>N}b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'p'=>s(FADDP),_=>N}_=>N}_=>N}b'b'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'p'=>s(FBSTP),_=>N}_=>N}_=>N}b'd'=>match r[2]{b'i' => match r[3]{b's'=>match r[4]{b'i'=>s(FDISI),_=>N}b'v' => match r[4]{b'p'=>s(FDIVP),b'r'=>s(FDIVR),_=>N}_=>N}_=>N}b'f'=>match r[2]{b'r'=>match r[3]{b'e'=>match r[4]{b'e'=>s(FFREE),_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd' => match r[3]{b'c'=>match r[4]{b'w'=>s(FLDCW),_=>N}b'p'=>match r[4]{b'i'=>s(FLDPI),_=>N}_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>match r[4]{b'p'=>s(FMULP),_=>N}_=>N}_=>N}b'n'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'i'=>s(FNENI),_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>s(FUCOM),_=>N}_=>N}_=>N}b'w'=>match r[2]{b'a'=>match r[3]{b'i'=>match r[4]{b't'=>s(FWAIT),_=>N}_=>N}_=>N}b'y'=>match r[2]{b'l'=>match r[3]{b'2'=>match r[4]{b'x'=>s(FYL2X),_=>N}_=>N}_=>N}b'c' => match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'x'=>s(FCLEX),_=>N}_=>N}b'o'=>match r[3]{b'm' => match r[4]{b'i'=>s(FCOMI),b'p'=>s(FCOMP),_=>N}_=>N}_=>N}b'i' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>s(FIADD),_=>N}_=>N}b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>s(FICOM),_=>N}_=>N}b'd'=>match r[3]{b'i'=>match r[4]{b'v'=>s(FIDIV),_=>N}_=>N}b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>s(FIMUL),_=>N}_=>N}b'n'=>match r[3]{b'i'=>match r[4]{b't'=>s(FINIT),_=>N}_=>N}b's' => match r[3]{b't'=>match r[4]{b'p'=>s(FISTP),_=>N}b'u'=>match r[4]{b'b'=>s(FISUB),_=>N}_=>N}_=>N}b'p' => match r[2]{b'r'=>match r[3]{b'e'=>match r[4]{b'm'=>s(FPREM),_=>N}_=>N}b't'=>match r[3]{b'a'=>match r[4]{b'n'=>s(FPTAN),_=>N}_=>N}_=>N}b's' => match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>s(FSAVE),_=>N}_=>N}b'q'=>match r[3]{b'r'=>match r[4]{b't'=>s(FSQRT),_=>N}_=>N}b'u'=>match r[3]{b'b' => match r[4]{b'p'=>s(FSUBP),b'r'=>s(FSUBR),_=>N}_=>N}b't' => match r[3]{b'c'=>match r[4]{b'w'=>s(FSTCW),_=>N}b's'=>match r[4]{b'w'=>s(FSTSW),_=>N}_=>N}_=>N}_=>N}b'j' => match r[1]{b'e'=>match r[2]{b'c'=>match r[3]{b'x'=>match r[4]{b'z'=>s(JECXZ),_=>N}_=>N}_=>N}b'r'=>match r[2]{b'c'=>match r[3]{b'x'=>match r[4]{b'z'=>s(JRCXZ),_=>N}_=>N}_=>N}_=>N}b'k' => match r[1]{b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'b'=>s(KMOVB),b'd'=>s(KMOVD),b'q'=>s(KMOVQ),b'w'=>s(KMOVW),_=>N}_=>N}_=>N}b'n'=>match r[2]{b'o'=>match r[3]{b't' => match r[4]{b'b'=>s(KNOTB),b'd'=>s(KNOTD),b'q'=>s(KNOTQ),b'w'=>s(KNOTW),_=>N}_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r' => match r[4]{b'b'=>s(KXORB),b'd'=>s(KXORD),b'q'=>s(KXORQ),b'w'=>s(KXORW),_=>N}_=>N}_=>N}b'a' => match r[2]{b'd'=>match r[3]{b'd' => match r[4]{b'b'=>s(KADDB),b'd'=>s(KADDD),b'q'=>s(KADDQ),b'w'=>s(KADDW),_=>N}_=>N}b'n'=>match r[3]{b'd' => match r[4]{b'b'=>s(KANDB),b'd'=>s(KANDD),b'q'=>s(KANDQ),b'w'=>s(KANDW),_=>N}_=>N}_=>N}_=>N}b'l' => match r[1]{b'c'=>match r[2]{b'a'=>match r[3]{b'l'=>match r[4]{b'l'=>s(LCALL),_=>N}_=>N}_=>N}b'd'=>match r[2]{b'd'=>match r[3]{b'q'=>match r[4]{b'u'=>s(LDDQU),_=>N}_=>N}_=>N}b'e'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>s(LEAVE),_=>N}_=>N}_=>N}b'z'=>match r[2]{b'c'=>match r[3]{b'n'=>match r[4]{b't'=>s(LZCNT),_=>N}_=>N}_=>N}b'o' => match r[2]{b'd'=>match r[3]{b's' => match r[4]{b'b'=>s(LODSB),b'd'=>s(LODSD),b'q'=>s(LODSQ),b'w'=>s(LODSW),_=>N}_=>N}b'o'=>match r[3]{b'p'=>match r[4]{b'e'=>s(LOOPE),_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'a'=>match r[2]{b'x' => match r[3]{b'p' => match r[4]{b'd'=>s(MAXPD),b's'=>s(MAXPS),_=>N}b's' => match r[4]{b'd'=>s(MAXSD),b's'=>s(MAXSS),_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n' => match r[3]{b'p' => match r[4]{b'd'=>s(MINPD),b's'=>s(MINPS),_=>N}b's' => match r[4]{b'd'=>s(MINSD),b's'=>s(MINSS),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'v' => match r[3]{b'b'=>match r[4]{b'e'=>s(MOVBE),_=>N}b'z'=>match r[4]{b'x'=>s(MOVZX),_=>N}b's' => match r[4]{b'd'=>s(MOVSD),b's'=>s(MOVSS),b'x'=>s(MOVSX),_=>N}_=>N}_=>N}b'u'=>match r[2]{b'l' => match r[3]{b'p' => match r[4]{b'd'=>s(MULPD),b's'=>s(MULPS),_=>N}b's' => match r[4]{b'd'=>s(MULSD),b's'=>s(MULSS),_=>N}_=>N}_=>N}b'w'=>match r[2]{b'a'=>match r[3]{b'i'=>match r[4]{b't'=>s(MWAIT),_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'o'=>match r[2]{b'p' => match r[3]{b'2'=>match r[4]{b'p'=>s(POP2P),_=>N}b'a' => match r[4]{b'd'=>s(POPAD),b'q'=>s(POPAQ),b'w'=>s(POPAW),_=>N}b'f' => match r[4]{b'd'=>s(POPFD),b'q'=>s(POPFQ),_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>s(PTEST),_=>N}_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h' => match r[4]{b'2'=>s(PUSH2),b'f'=>s(PUSHF),b'p'=>s(PUSHP),_=>N}_=>N}_=>N}b'a' => match r[2]{b'b'=>match r[3]{b's' => match r[4]{b'b'=>s(PABSB),b'd'=>s(PABSD),b'w'=>s(PABSW),_=>N}_=>N}b'd'=>match r[3]{b'd' => match r[4]{b'b'=>s(PADDB),b'd'=>s(PADDD),b'q'=>s(PADDQ),b'w'=>s(PADDW),_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'n'=>s(PANDN),_=>N}_=>N}b'u'=>match r[3]{b's'=>match r[4]{b'e'=>s(PAUSE),_=>N}_=>N}b'v'=>match r[3]{b'g' => match r[4]{b'b'=>s(PAVGB),b'w'=>s(PAVGW),_=>N}_=>N}_=>N}b's' => match r[2]{b'l'=>match r[3]{b'l' => match r[4]{b'd'=>s(PSLLD),b'q'=>s(PSLLQ),b'w'=>s(PSLLW),_=>N}_=>N}b'u'=>match r[3]{b'b' => match r[4]{b'b'=>s(PSUBB),b'd'=>s(PSUBD),b'q'=>s(PSUBQ),b'w'=>s(PSUBW),_=>N}_=>N}b'r' => match r[3]{b'a' => match r[4]{b'd'=>s(PSRAD),b'w'=>s(PSRAW),_=>N}b'l' => match r[4]{b'd'=>s(PSRLD),b'q'=>s(PSRLQ),b'w'=>s(PSRLW),_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'c'=>match r[2]{b'p' => match r[3]{b'p'=>match r[4]{b's'=>s(RCPPS),_=>N}b's'=>match r[4]{b's'=>s(RCPSS),_=>N}_=>N}_=>N}b'e'=>match r[2]{b'p'=>match r[3]{b'n' => match r[4]{b'e'=>s(REPNE),b'z'=>s(REPNZ),_=>N}_=>N}_=>N}b'd' => match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>s(RDMSR),_=>N}_=>N}b't'=>match r[3]{b's'=>match r[4]{b'c'=>s(RDTSC),_=>N}_=>N}b'p' => match r[3]{b'i'=>match r[4]{b'd'=>s(RDPID),_=>N}b'm'=>match r[4]{b'c'=>s(RDPMC),_=>N}b'r'=>match r[4]{b'u'=>s(RDPRU),_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'c'=>match r[2]{b'a'=>match r[3]{b's' => match r[4]{b'b'=>s(SCASB),b'd'=>s(SCASD),b'q'=>s(SCASQ),b'w'=>s(SCASW),_=>N}_=>N}_=>N}b'e'=>match r[2]{b't' => match r[3]{b'a'=>match r[4]{b'e'=>s(SETAE),_=>N}b'b'=>match r[4]{b'e'=>s(SETBE),_=>N}b'g'=>match r[4]{b'e'=>s(SETGE),_=>N}b'l'=>match r[4]{b'e'=>s(SETLE),_=>N}b'n' => match r[4]{b'a'=>s(SETNA),b'b'=>s(SETNB),b'c'=>s(SETNC),b'e'=>s(SETNE),b'g'=>s(SETNG),b'l'=>s(SETNL),b'o'=>s(SETNO),b'p'=>s(SETNP),b's'=>s(SETNS),b'z'=>s(SETNZ),_=>N}b'p' => match r[4]{b'e'=>s(SETPE),b'o'=>s(SETPO),_=>N}_=>N}_=>N}b't'=>match r[2]{b'o'=>match r[3]{b's' => match r[4]{b'b'=>s(STOSB),b'd'=>s(STOSD),b'q'=>s(STOSQ),b'w'=>s(STOSW),_=>N}_=>N}_=>N}b'u'=>match r[2]{b'b' => match r[3]{b'p' => match r[4]{b'd'=>s(SUBPD),b's'=>s(SUBPS),_=>N}b's' => match r[4]{b'd'=>s(SUBSD),b's'=>s(SUBSS),_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'd'=>match r[2]{b'p'=>match r[3]{b'p' => match r[4]{b'd'=>s(VDPPD),b's'=>s(VDPPS),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'p' => match r[4]{b'd'=>s(VORPD),b's'=>s(VORPS),_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'd'=>s(VMOVD),b'q'=>s(VMOVQ),b'w'=>s(VMOVW),_=>N}_=>N}b'r'=>match r[3]{b'u'=>match r[4]{b'n'=>s(VMRUN),_=>N}_=>N}b'x'=>match r[3]{b'o'=>match r[4]{b'n'=>s(VMXON),_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b'n'=>match r[4]{b'd'=>s(VPAND),_=>N}_=>N}b'x'=>match r[3]{b'o'=>match r[4]{b'r'=>s(VPXOR),_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'l'=>match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'b'=>s(XLATB),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'p' => match r[4]{b'd'=>s(XORPD),b's'=>s(XORPS),_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>s(XSAVE),_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>s(XTEST),_=>N}_=>N}_=>N}_=>N}_=>N}6=>match r[0]{b'e'=>match r[1]{b'n'=>match r[2]{b'q'=>match r[3]{b'c'=>match r[4]{b'm'=>match r[5]{b'd'=>s(ENQCMD),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[1]{b'e'=>match r[2]{b't'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'c'=>s(GETSEC),_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'n'=>match r[2]{b'v' => match r[3]{b'e'=>match r[4]{b'p'=>match r[5]{b't'=>s(INVEPT),_=>N}_=>N}b'l'=>match r[4]{b'p'=>match r[5]{b'g'=>s(INVLPG),_=>N}_=>N}_=>N}_=>N}_=>N}b'j'=>match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b'a'=>match r[4]{b'b'=>match r[5]{b's'=>s(JMPABS),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[1]{b'd' => match r[2]{b'p'=>match r[3]{b'k'=>match r[4]{b'r'=>match r[5]{b'u'=>s(RDPKRU),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'a'=>match r[4]{b'n'=>match r[5]{b'd'=>s(RDRAND),_=>N}_=>N}_=>N}b't'=>match r[3]{b's'=>match r[4]{b'c'=>match r[5]{b'p'=>s(RDTSCP),_=>N}_=>N}_=>N}b's' => match r[3]{b'e'=>match r[4]{b'e'=>match r[5]{b'd'=>s(RDSEED),_=>N}_=>N}b's'=>match r[4]{b'p' => match r[5]{b'd'=>s(RDSSPD),b'q'=>s(RDSSPQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'm'=>match r[2]{b'w'=>match r[3]{b'a'=>match r[4]{b'i'=>match r[5]{b't'=>s(UMWAIT),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'b'=>match r[2]{b'e'=>match r[3]{b'x'=>match r[4]{b't'=>match r[5]{b'r'=>s(ABEXTR),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c'=>s(AESDEC),_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c'=>s(AESENC),_=>N}_=>N}b'i'=>match r[4]{b'm'=>match r[5]{b'c'=>s(AESIMC),_=>N}_=>N}_=>N}_=>N}b'k'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'b'=>s(AKMOVB),b'd'=>s(AKMOVD),b'q'=>s(AKMOVQ),b'w'=>s(AKMOVW),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'z'=>match r[3]{b'c'=>match r[4]{b'n'=>match r[5]{b't'=>s(ALZCNT),_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'b'=>match r[5]{b'e'=>s(AMOVBE),_=>N}b'r'=>match r[5]{b's'=>s(AMOVRS),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b'd'=>match r[3]{b'n'=>match r[4]{b'p' => match r[5]{b'd'=>s(ANDNPD),b's'=>s(ANDNPS),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'd'=>match r[3]{b'm'=>match r[4]{b's'=>match r[5]{b'r'=>s(ARDMSR),_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'z'=>match r[3]{b'c'=>match r[4]{b'n'=>match r[5]{b't'=>s(ATZCNT),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[2]{b'r'=>match r[3]{b's'=>match r[4]{b's' => match r[5]{b'd'=>s(AWRSSD),b'q'=>s(AWRSSQ),_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'a'=>s(ACMOVA),b'b'=>s(ACMOVB),b'c'=>s(ACMOVC),b'e'=>s(ACMOVE),b'g'=>s(ACMOVG),b'l'=>s(ACMOVL),b'o'=>s(ACMOVO),b'p'=>s(ACMOVP),b's'=>s(ACMOVS),b'z'=>s(ACMOVZ),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'c'=>match r[4]{b'3'=>match r[5]{b'2'=>s(ACRC32),_=>N}_=>N}_=>N}_=>N}_=>N}b'b' => match r[1]{b'y'=>match r[2]{b't'=>match r[3]{b'e' => match r[4]{b'b'=>match r[5]{b'e'=>s(BYTEBE),_=>N}b'l'=>match r[5]{b'e'=>s(BYTELE),_=>N}_=>N}_=>N}_=>N}b'l' => match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b's'=>match r[5]{b'k'=>s(BLCMSK),_=>N}_=>N}_=>N}b's'=>match r[3]{b'm'=>match r[4]{b's'=>match r[5]{b'k'=>s(BLSMSK),_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'a'=>match r[5]{b'e'=>s(CCMPAE),_=>N}b'b'=>match r[5]{b'e'=>s(CCMPBE),_=>N}b'g'=>match r[5]{b'e'=>s(CCMPGE),_=>N}b'l'=>match r[5]{b'e'=>s(CCMPLE),_=>N}b'n' => match r[5]{b'a'=>s(CCMPNA),b'b'=>s(CCMPNB),b'c'=>s(CCMPNC),b'e'=>s(CCMPNE),b'g'=>s(CCMPNG),b'l'=>s(CCMPNL),b'o'=>s(CCMPNO),b's'=>s(CCMPNS),b'z'=>s(CCMPNZ),_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'a'=>match r[5]{b'e'=>s(CMOVAE),_=>N}b'b'=>match r[5]{b'e'=>s(CMOVBE),_=>N}b'g'=>match r[5]{b'e'=>s(CMOVGE),_=>N}b'l'=>match r[5]{b'e'=>s(CMOVLE),_=>N}b'n' => match r[5]{b'a'=>s(CMOVNA),b'b'=>s(CMOVNB),b'c'=>s(CMOVNC),b'e'=>s(CMOVNE),b'g'=>s(CMOVNG),b'l'=>s(CMOVNL),b'o'=>s(CMOVNO),b'p'=>s(CMOVNP),b's'=>s(CMOVNS),b'z'=>s(CMOVNZ),_=>N}b'p' => match r[5]{b'e'=>s(CMOVPE),b'o'=>s(CMOVPO),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'm'=>match r[3]{b'i'=>match r[4]{b's' => match r[5]{b'd'=>s(COMISD),b's'=>s(COMISS),_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't' => match r[5]{b'a'=>s(CTESTA),b'b'=>s(CTESTB),b'c'=>s(CTESTC),b'e'=>s(CTESTE),b'f'=>s(CTESTF),b'g'=>s(CTESTG),b'l'=>s(CTESTL),b'o'=>s(CTESTO),b's'=>s(CTESTS),b't'=>s(CTESTT),b'z'=>s(CTESTZ),_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'd'=>match r[2]{b'i'=>match r[3]{b'v'=>match r[4]{b'r'=>match r[5]{b'p'=>s(FDIVRP),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd' => match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'v'=>s(FLDENV),_=>N}_=>N}b'l' => match r[4]{b'g'=>match r[5]{b'2'=>s(FLDLG2),_=>N}b'n'=>match r[5]{b'2'=>s(FLDLN2),_=>N}b'2' => match r[5]{b'e'=>s(FLDL2E),b't'=>s(FLDL2T),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>s(FRSTOR),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm' => match r[5]{b'i'=>s(FUCOMI),b'p'=>s(FUCOMP),_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b's'=>match r[3]{b'a'=>match r[4]{b'v'=>match r[5]{b'e'=>s(FXSAVE),_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'b'=>s(FCMOVB),b'e'=>s(FCMOVE),b'u'=>s(FCMOVU),_=>N}_=>N}_=>N}b'o'=>match r[3]{b'm' => match r[4]{b'i'=>match r[5]{b'p'=>s(FCOMIP),_=>N}b'p'=>match r[5]{b'p'=>s(FCOMPP),_=>N}_=>N}_=>N}_=>N}b'i' => match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>match r[5]{b'p'=>s(FICOMP),_=>N}_=>N}_=>N}b'd'=>match r[3]{b'i'=>match r[4]{b'v'=>match r[5]{b'r'=>s(FIDIVR),_=>N}_=>N}_=>N}b's' => match r[3]{b't'=>match r[4]{b't'=>match r[5]{b'p'=>s(FISTTP),_=>N}_=>N}b'u'=>match r[4]{b'b'=>match r[5]{b'r'=>s(FISUBR),_=>N}_=>N}_=>N}_=>N}b'n' => match r[2]{b'c'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'x'=>s(FNCLEX),_=>N}_=>N}_=>N}b'd'=>match r[3]{b'i'=>match r[4]{b's'=>match r[5]{b'i'=>s(FNDISI),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n'=>match r[4]{b'i'=>match r[5]{b't'=>s(FNINIT),_=>N}_=>N}_=>N}b's' => match r[3]{b'a'=>match r[4]{b'v'=>match r[5]{b'e'=>s(FNSAVE),_=>N}_=>N}b't' => match r[4]{b'c'=>match r[5]{b'w'=>s(FNSTCW),_=>N}b's'=>match r[5]{b'w'=>s(FNSTSW),_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'a'=>match r[5]{b'n'=>s(FPATAN),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'e'=>match r[4]{b'm'=>match r[5]{b'1'=>s(FPREM1),_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'c'=>match r[3]{b'a'=>match r[4]{b'l'=>match r[5]{b'e'=>s(FSCALE),_=>N}_=>N}_=>N}b'e'=>match r[3]{b't'=>match r[4]{b'p'=>match r[5]{b'm'=>s(FSETPM),_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'v'=>s(FSTENV),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b'r'=>match r[5]{b'p'=>s(FSUBRP),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'p' => match r[5]{b'd'=>s(HADDPD),b's'=>s(HADDPS),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b't'=>s(HRESET),_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b'b'=>match r[4]{b'p' => match r[5]{b'd'=>s(HSUBPD),b's'=>s(HSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'k' => match r[1]{b'a'=>match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b'n' => match r[5]{b'b'=>s(KANDNB),b'd'=>s(KANDND),b'q'=>s(KANDNQ),b'w'=>s(KANDNW),_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't' => match r[5]{b'b'=>s(KTESTB),b'd'=>s(KTESTD),b'q'=>s(KTESTQ),b'w'=>s(KTESTW),_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b'n'=>match r[3]{b'o'=>match r[4]{b'r' => match r[5]{b'b'=>s(KXNORB),b'd'=>s(KXNORD),b'q'=>s(KXNORQ),b'w'=>s(KXNORW),_=>N}_=>N}_=>N}_=>N}_=>N}b'l' => match r[1]{b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(LFENCE),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'o'=>match r[3]{b'p'=>match r[4]{b'n'=>match r[5]{b'e'=>s(LOOPNE),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(MFENCE),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'v' => match r[3]{b'a'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVAPD),b's'=>s(MOVAPS),_=>N}_=>N}b'd'=>match r[4]{b'q'=>match r[5]{b'a'=>s(MOVDQA),_=>N}_=>N}b'h'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVHPD),b's'=>s(MOVHPS),_=>N}_=>N}b'l'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVLPD),b's'=>s(MOVLPS),_=>N}_=>N}b'n'=>match r[4]{b't'=>match r[5]{b'i'=>s(MOVNTI),_=>N}_=>N}b's'=>match r[4]{b'x'=>match r[5]{b'd'=>s(MOVSXD),_=>N}_=>N}b'u'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVUPD),b's'=>s(MOVUPS),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[2]{b'a'=>match r[3]{b'i'=>match r[4]{b't'=>match r[5]{b'x'=>s(MWAITX),_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b's' => match r[5]{b'b'=>s(PADDSB),b'w'=>s(PADDSW),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b'k'=>match r[5]{b'b'=>s(PBNDKB),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r' => match r[5]{b'b'=>s(PEXTRB),b'd'=>s(PEXTRD),b'q'=>s(PEXTRQ),b'w'=>s(PEXTRW),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'r' => match r[5]{b'b'=>s(PINSRB),b'd'=>s(PINSRD),b'q'=>s(PINSRQ),b'w'=>s(PINSRW),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'p'=>match r[3]{b'c'=>match r[4]{b'n'=>match r[5]{b't'=>s(POPCNT),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h' => match r[4]{b'2'=>match r[5]{b'p'=>s(PUSH2P),_=>N}b'a' => match r[5]{b'd'=>s(PUSHAD),b'q'=>s(PUSHAQ),b'w'=>s(PUSHAW),_=>N}b'f' => match r[5]{b'd'=>s(PUSHFD),b'q'=>s(PUSHFQ),_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd' => match r[5]{b'd'=>s(PHADDD),b'w'=>s(PHADDW),_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b' => match r[5]{b'd'=>s(PHSUBD),b'w'=>s(PHSUBW),_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'x' => match r[4]{b's' => match r[5]{b'b'=>s(PMAXSB),b'd'=>s(PMAXSD),b'w'=>s(PMAXSW),_=>N}b'u' => match r[5]{b'd'=>s(PMAXUD),b'w'=>s(PMAXUW),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n' => match r[4]{b's' => match r[5]{b'b'=>s(PMINSB),b'd'=>s(PMINSD),b'w'=>s(PMINSW),_=>N}b'u'=>match r[5]{b'w'=>s(PMINUW),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'd'=>match r[5]{b'q'=>s(PMULDQ),_=>N}b'h'=>match r[5]{b'w'=>s(PMULHW),_=>N}b'l' => match r[5]{b'd'=>s(PMULLD),b'w'=>s(PMULLW),_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f' => match r[5]{b'b'=>s(PSHUFB),b'd'=>s(PSHUFD),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'g'=>match r[4]{b'n' => match r[5]{b'b'=>s(PSIGNB),b'd'=>s(PSIGND),b'w'=>s(PSIGNW),_=>N}_=>N}_=>N}b'l'=>match r[3]{b'l'=>match r[4]{b'd'=>match r[5]{b'q'=>s(PSLLDQ),_=>N}_=>N}_=>N}b'm'=>match r[3]{b'a'=>match r[4]{b's'=>match r[5]{b'h'=>s(PSMASH),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'l'=>match r[4]{b'd'=>match r[5]{b'q'=>s(PSRLDQ),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b's' => match r[5]{b'b'=>s(PSUBSB),b'w'=>s(PSUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b't' => match r[3]{b'a'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETAZU),_=>N}_=>N}b'b'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETBZU),_=>N}_=>N}b'c'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETCZU),_=>N}_=>N}b'e'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETEZU),_=>N}_=>N}b'g'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETGZU),_=>N}_=>N}b'l'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETLZU),_=>N}_=>N}b'o'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETOZU),_=>N}_=>N}b'p'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETPZU),_=>N}_=>N}b's'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETSZU),_=>N}_=>N}b'z'=>match r[4]{b'z'=>match r[5]{b'u'=>s(SETZZU),_=>N}_=>N}b'n' => match r[4]{b'a'=>match r[5]{b'e'=>s(SETNAE),_=>N}b'b'=>match r[5]{b'e'=>s(SETNBE),_=>N}b'g'=>match r[5]{b'e'=>s(SETNGE),_=>N}b'l'=>match r[5]{b'e'=>s(SETNLE),_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(SFENCE),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'u'=>match r[3]{b'f'=>match r[4]{b'p'=>match r[5]{b's'=>s(SHUFPS),_=>N}_=>N}_=>N}_=>N}b'k'=>match r[2]{b'i'=>match r[3]{b'n'=>match r[4]{b'i'=>match r[5]{b't'=>s(SKINIT),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[2]{b'r'=>match r[3]{b't' => match r[4]{b'p' => match r[5]{b'd'=>s(SQRTPD),b's'=>s(SQRTPS),_=>N}b's' => match r[5]{b'd'=>s(SQRTSD),b's'=>s(SQRTSS),_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'r'=>match r[3]{b'i'=>match r[4]{b'n'=>match r[5]{b'g'=>s(STRING),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[2]{b'a'=>match r[3]{b'p'=>match r[4]{b'g'=>match r[5]{b's'=>s(SWAPGS),_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's'=>match r[3]{b'r'=>match r[4]{b'e'=>match r[5]{b't'=>s(SYSRET),_=>N}_=>N}_=>N}_=>N}_=>N}b't' => match r[1]{b'1'=>match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'k'=>match r[5]{b'c'=>s(T1MSKC),_=>N}_=>N}_=>N}_=>N}b'd'=>match r[2]{b'c'=>match r[3]{b'a'=>match r[4]{b'l'=>match r[5]{b'l'=>s(TDCALL),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'u'=>match r[5]{b'i'=>s(TESTUI),_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'a'=>match r[3]{b'u'=>match r[4]{b's'=>match r[5]{b'e'=>s(TPAUSE),_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'p' => match r[5]{b'd'=>s(VCMPPD),b'h'=>s(VCMPPH),b's'=>s(VCMPPS),_=>N}b's' => match r[5]{b'd'=>s(VCMPSD),b'h'=>s(VCMPSH),b's'=>s(VCMPSS),_=>N}_=>N}_=>N}_=>N}b'd'=>match r[2]{b'i'=>match r[3]{b'v' => match r[4]{b'p' => match r[5]{b'd'=>s(VDIVPD),b'h'=>s(VDIVPH),b's'=>s(VDIVPS),_=>N}b's' => match r[5]{b'd'=>s(VDIVSD),b'h'=>s(VDIVSH),b's'=>s(VDIVSS),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'q'=>match r[5]{b'u'=>s(VLDDQU),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'c'=>match r[3]{b'p' => match r[4]{b'p' => match r[5]{b'h'=>s(VRCPPH),b's'=>s(VRCPPS),_=>N}b's' => match r[5]{b'h'=>s(VRCPSH),b's'=>s(VRCPSS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b'b' => match r[4]{b'p' => match r[5]{b'd'=>s(VSUBPD),b'h'=>s(VSUBPH),b's'=>s(VSUBPS),_=>N}b's' => match r[5]{b'd'=>s(VSUBSD),b'h'=>s(VSUBSH),b's'=>s(VSUBSS),_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'p' => match r[5]{b'd'=>s(VXORPD),b's'=>s(VXORPS),_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'd'=>match r[3]{b'd' => match r[4]{b'p' => match r[5]{b'd'=>s(VADDPD),b'h'=>s(VADDPH),b's'=>s(VADDPS),_=>N}b's' => match r[5]{b'd'=>s(VADDSD),b'h'=>s(VADDSH),b's'=>s(VADDSS),_=>N}_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'p' => match r[5]{b'd'=>s(VANDPD),b's'=>s(VANDPS),_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'x' => match r[4]{b'p' => match r[5]{b'd'=>s(VMAXPD),b'h'=>s(VMAXPH),b's'=>s(VMAXPS),_=>N}b's' => match r[5]{b'd'=>s(VMAXSD),b'h'=>s(VMAXSH),b's'=>s(VMAXSS),_=>N}_=>N}_=>N}b'c'=>match r[3]{b'a'=>match r[4]{b'l'=>match r[5]{b'l'=>s(VMCALL),_=>N}_=>N}_=>N}b'f'=>match r[3]{b'u'=>match r[4]{b'n'=>match r[5]{b'c'=>s(VMFUNC),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n' => match r[4]{b'p' => match r[5]{b'd'=>s(VMINPD),b'h'=>s(VMINPH),b's'=>s(VMINPS),_=>N}b's' => match r[5]{b'd'=>s(VMINSD),b'h'=>s(VMINSH),b's'=>s(VMINSS),_=>N}_=>N}_=>N}b'l'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>s(VMLOAD),_=>N}_=>N}_=>N}b'o'=>match r[3]{b'v'=>match r[4]{b's' => match r[5]{b'd'=>s(VMOVSD),b'h'=>s(VMOVSH),b's'=>s(VMOVSS),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'e'=>match r[4]{b'a'=>match r[5]{b'd'=>s(VMREAD),_=>N}_=>N}_=>N}b's'=>match r[3]{b'a'=>match r[4]{b'v'=>match r[5]{b'e'=>s(VMSAVE),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'p' => match r[5]{b'd'=>s(VMULPD),b'h'=>s(VMULPH),b's'=>s(VMULPS),_=>N}b's' => match r[5]{b'd'=>s(VMULSD),b'h'=>s(VMULSH),b's'=>s(VMULSS),_=>N}_=>N}_=>N}b'x'=>match r[3]{b'o'=>match r[4]{b'f'=>match r[5]{b'f'=>s(VMXOFF),_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'e'=>match r[3]{b'r'=>match r[4]{b'm' => match r[5]{b'b'=>s(VPERMB),b'd'=>s(VPERMD),b'q'=>s(VPERMQ),b'w'=>s(VPERMW),_=>N}_=>N}_=>N}b'p'=>match r[3]{b'e'=>match r[4]{b'r'=>match r[5]{b'm'=>s(VPPERM),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'o' => match r[4]{b'l' => match r[5]{b'd'=>s(VPROLD),b'q'=>s(VPROLQ),_=>N}b'r' => match r[5]{b'd'=>s(VPRORD),b'q'=>s(VPRORQ),_=>N}b't' => match r[5]{b'b'=>s(VPROTB),b'd'=>s(VPROTD),b'q'=>s(VPROTQ),b'w'=>s(VPROTW),_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b's'=>match r[5]{b't'=>s(VPTEST),_=>N}_=>N}_=>N}b'a' => match r[3]{b'd'=>match r[4]{b'd' => match r[5]{b'b'=>s(VPADDB),b'd'=>s(VPADDD),b'q'=>s(VPADDQ),b'w'=>s(VPADDW),_=>N}_=>N}b'n'=>match r[4]{b'd'=>match r[5]{b'n'=>s(VPANDN),_=>N}_=>N}b'v'=>match r[4]{b'g' => match r[5]{b'b'=>s(VPAVGB),b'w'=>s(VPAVGW),_=>N}_=>N}_=>N}b'c' => match r[3]{b'o'=>match r[4]{b'm' => match r[5]{b'b'=>s(VPCOMB),b'd'=>s(VPCOMD),b'q'=>s(VPCOMQ),b'w'=>s(VPCOMW),_=>N}_=>N}b'm' => match r[4]{b'o'=>match r[5]{b'v'=>s(VPCMOV),_=>N}b'p' => match r[5]{b'b'=>s(VPCMPB),b'd'=>s(VPCMPD),b'q'=>s(VPCMPQ),b'w'=>s(VPCMPW),_=>N}_=>N}_=>N}b's' => match r[3]{b'l'=>match r[4]{b'l' => match r[5]{b'd'=>s(VPSLLD),b'q'=>s(VPSLLQ),b'w'=>s(VPSLLW),_=>N}_=>N}b'u'=>match r[4]{b'b' => match r[5]{b'b'=>s(VPSUBB),b'd'=>s(VPSUBD),b'q'=>s(VPSUBQ),b'w'=>s(VPSUBW),_=>N}_=>N}b'h' => match r[4]{b'a' => match r[5]{b'b'=>s(VPSHAB),b'd'=>s(VPSHAD),b'q'=>s(VPSHAQ),b'w'=>s(VPSHAW),_=>N}b'l' => match r[5]{b'b'=>s(VPSHLB),b'd'=>s(VPSHLD),b'q'=>s(VPSHLQ),b'w'=>s(VPSHLW),_=>N}_=>N}b'r' => match r[4]{b'a' => match r[5]{b'd'=>s(VPSRAD),b'w'=>s(VPSRAW),_=>N}b'l' => match r[5]{b'd'=>s(VPSRLD),b'q'=>s(VPSRLQ),b'w'=>s(VPSRLW),_=>N}_=>N}_=>N}_=>N}_=>N}b'w' => match r[1]{b'b'=>match r[2]{b'i'=>match r[3]{b'n'=>match r[4]{b'v'=>match r[5]{b'd'=>s(WBINVD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'd' => match r[4]{b'b'=>match r[5]{b'e'=>s(WORDBE),_=>N}b'l'=>match r[5]{b'e'=>s(WORDLE),_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'p'=>match r[3]{b'k'=>match r[4]{b'r'=>match r[5]{b'u'=>s(WRPKRU),_=>N}_=>N}_=>N}b'u'=>match r[3]{b's'=>match r[4]{b's' => match r[5]{b'd'=>s(WRUSSD),b'q'=>s(WRUSSQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'b'=>match r[3]{b'o'=>match r[4]{b'r'=>match r[5]{b't'=>s(XABORT),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'e'=>match r[3]{b'g'=>match r[4]{b'i'=>match r[5]{b'n'=>s(XBEGIN),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[2]{b'e'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'v'=>s(XGETBV),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>s(XRSTOR),_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e' => match r[5]{b'c'=>s(XSAVEC),b's'=>s(XSAVES),_=>N}_=>N}_=>N}b'e'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'v'=>s(XSETBV),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}7=>match r[0]{b'b'=>match r[1]{b'l' => match r[2]{b'c'=>match r[3]{b'f'=>match r[4]{b'i'=>match r[5]{b'l'=>match r[6]{b'l'=>s(BLCFILL),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[3]{b'n'=>match r[4]{b'd'=>match r[5]{b'p' => match r[6]{b'd'=>s(BLENDPD),b's'=>s(BLENDPS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'f'=>match r[4]{b'i'=>match r[5]{b'l'=>match r[6]{b'l'=>s(BLSFILL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[1]{b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd' => match r[5]{b'b'=>match r[6]{b'e'=>s(DWORDBE),_=>N}b'l'=>match r[6]{b'e'=>s(DWORDLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[1]{b'n' => match r[2]{b'd'=>match r[3]{b'b'=>match r[4]{b'r' => match r[5]{b'3'=>match r[6]{b'2'=>s(ENDBR32),_=>N}b'6'=>match r[6]{b'4'=>s(ENDBR64),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[3]{b'c'=>match r[4]{b'm'=>match r[5]{b'd'=>match r[6]{b's'=>s(ENQCMDS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'n' => match r[2]{b'c'=>match r[3]{b's'=>match r[4]{b's'=>match r[5]{b'p' => match r[6]{b'd'=>s(INCSSPD),b'q'=>s(INCSSPQ),_=>N}_=>N}_=>N}_=>N}b'v' => match r[3]{b'l'=>match r[4]{b'p'=>match r[5]{b'g' => match r[6]{b'a'=>s(INVLPGA),b'b'=>s(INVLPGB),_=>N}_=>N}_=>N}b'p'=>match r[4]{b'c'=>match r[5]{b'i'=>match r[6]{b'd'=>s(INVPCID),_=>N}_=>N}_=>N}b'v'=>match r[4]{b'p'=>match r[5]{b'i'=>match r[6]{b'd'=>s(INVVPID),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[1]{b'd'=>match r[2]{b'm'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b's'=>match r[6]{b'r'=>s(LDMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'q'=>match r[1]{b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd' => match r[5]{b'b'=>match r[6]{b'e'=>s(QWORDBE),_=>N}b'l'=>match r[6]{b'e'=>s(QWORDLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'c'=>match r[2]{b'o'=>match r[3]{b'm'=>match r[4]{b'i'=>match r[5]{b's' => match r[6]{b'd'=>s(UCOMISD),b's'=>s(UCOMISS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b's'=>match r[4]{b'm'=>match r[5]{b's'=>match r[6]{b'k'=>s(ABLSMSK),_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'a'=>match r[6]{b'e'=>s(ACMOVAE),_=>N}b'b'=>match r[6]{b'e'=>s(ACMOVBE),_=>N}b'g'=>match r[6]{b'e'=>s(ACMOVGE),_=>N}b'l'=>match r[6]{b'e'=>s(ACMOVLE),_=>N}b'n' => match r[6]{b'a'=>s(ACMOVNA),b'b'=>s(ACMOVNB),b'c'=>s(ACMOVNC),b'e'=>s(ACMOVNE),b'g'=>s(ACMOVNG),b'l'=>s(ACMOVNL),b'o'=>s(ACMOVNO),b'p'=>s(ACMOVNP),b's'=>s(ACMOVNS),b'z'=>s(ACMOVNZ),_=>N}b'p' => match r[6]{b'e'=>s(ACMOVPE),b'o'=>s(ACMOVPO),_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'n'=>match r[3]{b'q'=>match r[4]{b'c'=>match r[5]{b'm'=>match r[6]{b'd'=>s(AENQCMD),_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'o'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'n'=>match r[6]{b't'=>s(APOPCNT),_=>N}_=>N}_=>N}_=>N}_=>N}b'w'=>match r[2]{b'r'=>match r[3]{b'u'=>match r[4]{b's'=>match r[5]{b's' => match r[6]{b'd'=>s(AWRUSSD),b'q'=>s(AWRUSSQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'i' => match r[2]{b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>match r[5]{b'z'=>match r[6]{b'u'=>s(AIMULZU),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[3]{b'v'=>match r[4]{b'e'=>match r[5]{b'p'=>match r[6]{b't'=>s(AINVEPT),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p'=>match r[4]{b'n' => match r[5]{b'a'=>match r[6]{b'e'=>s(CCMPNAE),_=>N}b'b'=>match r[6]{b'e'=>s(CCMPNBE),_=>N}b'g'=>match r[6]{b'e'=>s(CCMPNGE),_=>N}b'l'=>match r[6]{b'e'=>s(CCMPNLE),_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'o'=>match r[5]{b'v' => match r[6]{b'a'=>s(CFCMOVA),b'b'=>s(CFCMOVB),b'c'=>s(CFCMOVC),b'e'=>s(CFCMOVE),b'g'=>s(CFCMOVG),b'l'=>s(CFCMOVL),b'o'=>s(CFCMOVO),b'p'=>s(CFCMOVP),b's'=>s(CFCMOVS),b'z'=>s(CFCMOVZ),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'f'=>match r[3]{b'l'=>match r[4]{b'u'=>match r[5]{b's'=>match r[6]{b'h'=>s(CLFLUSH),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't' => match r[5]{b'a'=>match r[6]{b'e'=>s(CTESTAE),_=>N}b'b'=>match r[6]{b'e'=>s(CTESTBE),_=>N}b'g'=>match r[6]{b'e'=>s(CTESTGE),_=>N}b'l'=>match r[6]{b'e'=>s(CTESTLE),_=>N}b'n' => match r[6]{b'a'=>s(CTESTNA),b'b'=>s(CTESTNB),b'c'=>s(CTESTNC),b'e'=>s(CTESTNE),b'g'=>s(CTESTNG),b'l'=>s(CTESTNL),b'o'=>s(CTESTNO),b's'=>s(CTESTNS),b'z'=>s(CTESTNZ),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v'=>match r[4]{b'n' => match r[5]{b'a'=>match r[6]{b'e'=>s(CMOVNAE),_=>N}b'b'=>match r[6]{b'e'=>s(CMOVNBE),_=>N}b'g'=>match r[6]{b'e'=>s(CMOVNGE),_=>N}b'l'=>match r[6]{b'e'=>s(CMOVNLE),_=>N}_=>N}_=>N}_=>N}b'p' => match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(CMPSTRB),b'd'=>s(CMPSTRD),b'q'=>s(CMPSTRQ),b'w'=>s(CMPSTRW),_=>N}_=>N}_=>N}b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>s(CMPXCHG),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'b'=>match r[6]{b'e'=>s(FCMOVBE),_=>N}b'n' => match r[6]{b'b'=>s(FCMOVNB),b'e'=>s(FCMOVNE),b'u'=>s(FCMOVNU),_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[2]{b'e'=>match r[3]{b'c'=>match r[4]{b's'=>match r[5]{b't'=>match r[6]{b'p'=>s(FDECSTP),_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b'c'=>match r[4]{b's'=>match r[5]{b't'=>match r[6]{b'p'=>s(FINCSTP),_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b's' => match r[3]{b'e'=>match r[4]{b't'=>match r[5]{b'p'=>match r[6]{b'm'=>s(FNSETPM),_=>N}_=>N}_=>N}b't'=>match r[4]{b'e'=>match r[5]{b'n'=>match r[6]{b'v'=>s(FNSTENV),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b'i'=>match r[5]{b'n'=>match r[6]{b't'=>s(FRNDINT),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm' => match r[5]{b'i'=>match r[6]{b'p'=>s(FUCOMIP),_=>N}b'p'=>match r[6]{b'p'=>s(FUCOMPP),_=>N}_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b'l'=>match r[3]{b'2'=>match r[4]{b'x'=>match r[5]{b'p'=>match r[6]{b'1'=>s(FYL2XP1),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'i'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'o'=>match r[6]{b's'=>s(FSINCOS),_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b's'=>match r[4]{b'w'=>match r[5]{b'a'=>match r[6]{b'x'=>s(FSTSWAX),_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[2]{b'r'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'o'=>match r[6]{b'r'=>s(FXRSTOR),_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'r'=>match r[4]{b'a'=>match r[5]{b'c'=>match r[6]{b't'=>s(FXTRACT),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'o' => match r[2]{b'n'=>match r[3]{b'i'=>match r[4]{b't'=>match r[5]{b'o'=>match r[6]{b'r'=>s(MONITOR),_=>N}_=>N}_=>N}_=>N}b'v' => match r[3]{b'h'=>match r[4]{b'l'=>match r[5]{b'p'=>match r[6]{b's'=>s(MOVHLPS),_=>N}_=>N}_=>N}b'l'=>match r[4]{b'h'=>match r[5]{b'p'=>match r[6]{b's'=>s(MOVLHPS),_=>N}_=>N}_=>N}b'n'=>match r[4]{b't' => match r[5]{b'd'=>match r[6]{b'q'=>s(MOVNTDQ),_=>N}b'p'=>match r[6]{b'd'=>s(MOVNTPD),_=>N}_=>N}_=>N}b'q'=>match r[4]{b'2'=>match r[5]{b'd'=>match r[6]{b'q'=>s(MOVQ2DQ),_=>N}_=>N}_=>N}b's'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(MOVSTRB),b'd'=>s(MOVSTRD),b'q'=>s(MOVSTRQ),b'w'=>s(MOVSTRW),_=>N}_=>N}_=>N}b'd' => match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b'p'=>s(MOVDDUP),_=>N}_=>N}b'i'=>match r[5]{b'r'=>match r[6]{b'i'=>s(MOVDIRI),_=>N}_=>N}b'q'=>match r[5]{b'2'=>match r[6]{b'q'=>s(MOVDQ2Q),_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b's'=>match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'b'=>match r[6]{b'w'=>s(MPSADBW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'w'=>s(PBLENDW),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'w'=>match r[3]{b'r'=>match r[4]{b'i'=>match r[5]{b't'=>match r[6]{b'e'=>s(PTWRITE),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'u'=>match r[5]{b's' => match r[6]{b'b'=>s(PADDUSB),b'w'=>s(PADDUSW),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[3]{b'i'=>match r[4]{b'g'=>match r[5]{b'n'=>match r[6]{b'r'=>s(PALIGNR),_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'e'=>match r[5]{b'q' => match r[6]{b'b'=>s(PCMPEQB),b'd'=>s(PCMPEQD),b'q'=>s(PCMPEQQ),b'w'=>s(PCMPEQW),_=>N}_=>N}b'g'=>match r[5]{b't' => match r[6]{b'b'=>s(PCMPGTB),b'd'=>s(PCMPGTD),b'q'=>s(PCMPGTQ),b'w'=>s(PCMPGTW),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[3]{b'n'=>match r[4]{b'f'=>match r[5]{b'i'=>match r[6]{b'g'=>s(PCONFIG),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b's'=>match r[6]{b'w'=>s(PHADDSW),_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b's'=>match r[6]{b'w'=>s(PHSUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'w'=>match r[6]{b'd'=>s(PMADDWD),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'h'=>match r[5]{b'u'=>match r[6]{b'w'=>s(PMULHUW),_=>N}_=>N}b'u'=>match r[5]{b'd'=>match r[6]{b'q'=>s(PMULUDQ),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f' => match r[5]{b'h'=>match r[6]{b'w'=>s(PSHUFHW),_=>N}b'l'=>match r[6]{b'w'=>s(PSHUFLW),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b'u'=>match r[5]{b's' => match r[6]{b'b'=>s(PSUBUSB),b'w'=>s(PSUBUSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b'r'=>match r[4]{b'e'=>match r[5]{b'a'=>match r[6]{b'd'=>s(RMPREAD),_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'u'=>match r[3]{b'n'=>match r[4]{b'd' => match r[5]{b'p' => match r[6]{b'd'=>s(ROUNDPD),b's'=>s(ROUNDPS),_=>N}b's' => match r[6]{b'd'=>s(ROUNDSD),b's'=>s(ROUNDSS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'q'=>match r[3]{b'r'=>match r[4]{b't' => match r[5]{b'p'=>match r[6]{b's'=>s(RSQRTPS),_=>N}b's'=>match r[6]{b's'=>s(RSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b't'=>match r[2]{b'm'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b's'=>match r[6]{b'r'=>s(STMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's' => match r[3]{b'c'=>match r[4]{b'a'=>match r[5]{b'l'=>match r[6]{b'l'=>s(SYSCALL),_=>N}_=>N}_=>N}b'e'=>match r[4]{b'x'=>match r[5]{b'i'=>match r[6]{b't'=>s(SYSEXIT),_=>N}_=>N}_=>N}_=>N}_=>N}b'e' => match r[2]{b'a'=>match r[3]{b'm' => match r[4]{b'o'=>match r[5]{b'p'=>match r[6]{b's'=>s(SEAMOPS),_=>N}_=>N}b'r'=>match r[5]{b'e'=>match r[6]{b't'=>s(SEAMRET),_=>N}_=>N}_=>N}_=>N}b't' => match r[3]{b'a'=>match r[4]{b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETAEZU),_=>N}_=>N}_=>N}b'b'=>match r[4]{b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETBEZU),_=>N}_=>N}_=>N}b'g'=>match r[4]{b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETGEZU),_=>N}_=>N}_=>N}b'l'=>match r[4]{b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETLEZU),_=>N}_=>N}_=>N}b's'=>match r[4]{b's'=>match r[5]{b'b'=>match r[6]{b'y'=>s(SETSSBY),_=>N}_=>N}_=>N}b'n' => match r[4]{b'a'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNAZU),_=>N}_=>N}b'b'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNBZU),_=>N}_=>N}b'c'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNCZU),_=>N}_=>N}b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNEZU),_=>N}_=>N}b'g'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNGZU),_=>N}_=>N}b'l'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNLZU),_=>N}_=>N}b'o'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNOZU),_=>N}_=>N}b'p'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNPZU),_=>N}_=>N}b's'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNSZU),_=>N}_=>N}b'z'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETNZZU),_=>N}_=>N}_=>N}b'p' => match r[4]{b'e'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETPEZU),_=>N}_=>N}b'o'=>match r[5]{b'z'=>match r[6]{b'u'=>s(SETPOZU),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't' => match r[1]{b'd'=>match r[2]{b'p'=>match r[3]{b'b' => match r[4]{b's' => match r[5]{b's'=>match r[6]{b'd'=>s(TDPBSSD),_=>N}b'u'=>match r[6]{b'd'=>s(TDPBSUD),_=>N}_=>N}b'u' => match r[5]{b's'=>match r[6]{b'd'=>s(TDPBUSD),_=>N}b'u'=>match r[6]{b'd'=>s(TDPBUUD),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'b'=>match r[3]{b's'=>match r[4]{b'y'=>match r[5]{b'n'=>match r[6]{b'c'=>s(TLBSYNC),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'o'=>match r[3]{b'm' => match r[4]{b'i'=>match r[5]{b's' => match r[6]{b'd'=>s(VCOMISD),b'h'=>s(VCOMISH),b's'=>s(VCOMISS),_=>N}_=>N}b'x'=>match r[5]{b's' => match r[6]{b'd'=>s(VCOMXSD),b'h'=>s(VCOMXSH),b's'=>s(VCOMXSS),_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b'p'=>match r[4]{b'2'=>match r[5]{b'p' => match r[6]{b'd'=>s(VEXP2PD),b's'=>s(VEXP2PS),_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'r'=>match r[3]{b'c'=>match r[4]{b'z' => match r[5]{b'p' => match r[6]{b'd'=>s(VFRCZPD),b's'=>s(VFRCZPS),_=>N}b's' => match r[6]{b'd'=>s(VFRCZSD),b's'=>s(VFRCZSS),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'p' => match r[6]{b'd'=>s(VTESTPD),b's'=>s(VTESTPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'e'=>match r[3]{b's' => match r[4]{b'd'=>match r[5]{b'e'=>match r[6]{b'c'=>s(VAESDEC),_=>N}_=>N}b'e'=>match r[5]{b'n'=>match r[6]{b'c'=>s(VAESENC),_=>N}_=>N}b'i'=>match r[5]{b'm'=>match r[6]{b'c'=>s(VAESIMC),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[3]{b'i'=>match r[4]{b'g'=>match r[5]{b'n' => match r[6]{b'd'=>s(VALIGND),b'q'=>s(VALIGNQ),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'n'=>match r[5]{b'p' => match r[6]{b'd'=>s(VANDNPD),b's'=>s(VANDNPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'p' => match r[6]{b'd'=>s(VHADDPD),b's'=>s(VHADDPS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b'p' => match r[6]{b'd'=>s(VHSUBPD),b's'=>s(VHSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'c'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'a'=>match r[6]{b'r'=>s(VMCLEAR),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b'e'=>match r[4]{b'x'=>match r[5]{b'i'=>match r[6]{b't'=>s(VMGEXIT),_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'c'=>match r[4]{b'a'=>match r[5]{b'l'=>match r[6]{b'l'=>s(VMMCALL),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[3]{b'v' => match r[4]{b'a'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVAPD),b's'=>s(VMOVAPS),_=>N}_=>N}b'd'=>match r[5]{b'q'=>match r[6]{b'a'=>s(VMOVDQA),_=>N}_=>N}b'h'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVHPD),b's'=>s(VMOVHPS),_=>N}_=>N}b'l'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVLPD),b's'=>s(VMOVLPS),_=>N}_=>N}b'u'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVUPD),b's'=>s(VMOVUPS),_=>N}_=>N}_=>N}_=>N}b'p'=>match r[3]{b't'=>match r[4]{b'r' => match r[5]{b'l'=>match r[6]{b'd'=>s(VMPTRLD),_=>N}b's'=>match r[6]{b't'=>s(VMPTRST),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[3]{b'r'=>match r[4]{b'i'=>match r[5]{b't'=>match r[6]{b'e'=>s(VMWRITE),_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b's' => match r[6]{b'b'=>s(VPADDSB),b'w'=>s(VPADDSW),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n'=>match r[4]{b's'=>match r[5]{b'r' => match r[6]{b'b'=>s(VPINSRB),b'd'=>s(VPINSRD),b'q'=>s(VPINSRQ),b'w'=>s(VPINSRW),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b'o' => match r[4]{b'l'=>match r[5]{b'v' => match r[6]{b'd'=>s(VPROLVD),b'q'=>s(VPROLVQ),_=>N}_=>N}b'r'=>match r[5]{b'v' => match r[6]{b'd'=>s(VPRORVD),b'q'=>s(VPRORVQ),_=>N}_=>N}_=>N}_=>N}b'c' => match r[3]{b'm'=>match r[4]{b'p'=>match r[5]{b'u' => match r[6]{b'b'=>s(VPCMPUB),b'd'=>s(VPCMPUD),b'q'=>s(VPCMPUQ),b'w'=>s(VPCMPUW),_=>N}_=>N}_=>N}b'o'=>match r[4]{b'm'=>match r[5]{b'u' => match r[6]{b'b'=>s(VPCOMUB),b'd'=>s(VPCOMUD),b'q'=>s(VPCOMUQ),b'w'=>s(VPCOMUW),_=>N}_=>N}_=>N}_=>N}b'e' => match r[3]{b'r'=>match r[4]{b'm'=>match r[5]{b'p' => match r[6]{b'd'=>s(VPERMPD),b's'=>s(VPERMPS),_=>N}_=>N}_=>N}b'x'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(VPEXTRB),b'd'=>s(VPEXTRD),b'q'=>s(VPEXTRQ),b'w'=>s(VPEXTRW),_=>N}_=>N}_=>N}_=>N}b'h' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd' => match r[6]{b'd'=>s(VPHADDD),b'w'=>s(VPHADDW),_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b' => match r[6]{b'd'=>s(VPHSUBD),b'w'=>s(VPHSUBW),_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'a'=>match r[4]{b'x' => match r[5]{b's' => match r[6]{b'b'=>s(VPMAXSB),b'd'=>s(VPMAXSD),b'w'=>s(VPMAXSW),_=>N}b'u' => match r[6]{b'b'=>s(VPMAXUB),b'd'=>s(VPMAXUD),b'w'=>s(VPMAXUW),_=>N}_=>N}_=>N}b'i'=>match r[4]{b'n' => match r[5]{b's' => match r[6]{b'b'=>s(VPMINSB),b'd'=>s(VPMINSD),b'w'=>s(VPMINSW),_=>N}b'u' => match r[6]{b'b'=>s(VPMINUB),b'w'=>s(VPMINUW),_=>N}_=>N}_=>N}b'o'=>match r[4]{b'v' => match r[5]{b'w'=>match r[6]{b'b'=>s(VPMOVWB),_=>N}b'd' => match r[6]{b'b'=>s(VPMOVDB),b'w'=>s(VPMOVDW),_=>N}b'q' => match r[6]{b'b'=>s(VPMOVQB),b'd'=>s(VPMOVQD),b'w'=>s(VPMOVQW),_=>N}_=>N}_=>N}b'u'=>match r[4]{b'l' => match r[5]{b'd'=>match r[6]{b'q'=>s(VPMULDQ),_=>N}b'h'=>match r[6]{b'w'=>s(VPMULHW),_=>N}b'l' => match r[6]{b'd'=>s(VPMULLD),b'w'=>s(VPMULLW),_=>N}_=>N}_=>N}_=>N}b's' => match r[3]{b'i'=>match r[4]{b'g'=>match r[5]{b'n' => match r[6]{b'b'=>s(VPSIGNB),b'd'=>s(VPSIGND),b'w'=>s(VPSIGNW),_=>N}_=>N}_=>N}b'l'=>match r[4]{b'l'=>match r[5]{b'v' => match r[6]{b'd'=>s(VPSLLVD),b'q'=>s(VPSLLVQ),b'w'=>s(VPSLLVW),_=>N}_=>N}_=>N}b'u'=>match r[4]{b'b'=>match r[5]{b's' => match r[6]{b'b'=>s(VPSUBSB),b'w'=>s(VPSUBSW),_=>N}_=>N}_=>N}b'h' => match r[4]{b'l'=>match r[5]{b'd' => match r[6]{b'd'=>s(VPSHLDD),b'q'=>s(VPSHLDQ),b'w'=>s(VPSHLDW),_=>N}_=>N}b'r'=>match r[5]{b'd' => match r[6]{b'd'=>s(VPSHRDD),b'q'=>s(VPSHRDQ),b'w'=>s(VPSHRDW),_=>N}_=>N}_=>N}b'r' => match r[4]{b'a'=>match r[5]{b'v' => match r[6]{b'd'=>s(VPSRAVD),b'q'=>s(VPSRAVQ),b'w'=>s(VPSRAVW),_=>N}_=>N}b'l' => match r[5]{b'd'=>match r[6]{b'q'=>s(VPSRLDQ),_=>N}b'v' => match r[6]{b'd'=>s(VPSRLVD),b'q'=>s(VPSRLVQ),b'w'=>s(VPSRLVW),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f'=>match r[5]{b'p'=>match r[6]{b's'=>s(VSHUFPS),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[3]{b'r'=>match r[4]{b't' => match r[5]{b'p' => match r[6]{b'd'=>s(VSQRTPD),b'h'=>s(VSQRTPH),b's'=>s(VSQRTPS),_=>N}b's' => match r[6]{b'd'=>s(VSQRTSD),b'h'=>s(VSQRTSH),b's'=>s(VSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'l'=>match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'6'=>match r[6]{b'4'=>s(XLATB64),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b's'=>s(XRSTORS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>match r[5]{b'6'=>match r[6]{b'4'=>s(XSAVE64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}8=>match r[0]{b'b'=>match r[1]{b'l'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'd'=>match r[5]{b'v'=>match r[6]{b'p' => match r[7]{b'd'=>s(BLENDVPD),b's'=>s(BLENDVPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'n'=>match r[2]{b's'=>match r[3]{b'e'=>match r[4]{b'r'=>match r[5]{b't'=>match r[6]{b'p'=>match r[7]{b's'=>s(INSERTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[1]{b'o' => match r[2]{b'n'=>match r[3]{b'i'=>match r[4]{b't'=>match r[5]{b'o'=>match r[6]{b'r'=>match r[7]{b'x'=>s(MONITORX),_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[3]{b'm'=>match r[4]{b's'=>match r[5]{b'k'=>match r[6]{b'p'=>match r[7]{b'd'=>s(MOVMSKPD),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[4]{b't'=>match r[5]{b'd'=>match r[6]{b'q'=>match r[7]{b'a'=>s(MOVNTDQA),_=>N}_=>N}_=>N}_=>N}b's' => match r[4]{b'h'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(MOVSHDUP),_=>N}_=>N}_=>N}b'l'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(MOVSLDUP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[1]{b'i'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'z'=>match r[5]{b'e'=>match r[6]{b'r'=>match r[7]{b'o'=>s(TILEZERO),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v'=>match r[5]{b'n' => match r[6]{b'a'=>match r[7]{b'e'=>s(ACMOVNAE),_=>N}b'b'=>match r[7]{b'e'=>s(ACMOVNBE),_=>N}b'g'=>match r[7]{b'e'=>s(ACMOVNGE),_=>N}b'l'=>match r[7]{b'e'=>s(ACMOVNLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[2]{b'd'=>match r[3]{b's'=>match r[4]{b'u'=>match r[5]{b'b'=>match r[6]{b'p' => match r[7]{b'd'=>s(ADDSUBPD),b's'=>s(ADDSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'n'=>match r[3]{b'q'=>match r[4]{b'c'=>match r[5]{b'm'=>match r[6]{b'd'=>match r[7]{b's'=>s(AENQCMDS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b'v' => match r[4]{b'p'=>match r[5]{b'c'=>match r[6]{b'i'=>match r[7]{b'd'=>s(AINVPCID),_=>N}_=>N}_=>N}b'v'=>match r[5]{b'p'=>match r[6]{b'i'=>match r[7]{b'd'=>s(AINVVPID),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v'=>match r[4]{b'd'=>match r[5]{b'i'=>match r[6]{b'r'=>match r[7]{b'i'=>s(AMOVDIRI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'f'=>match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'o'=>match r[5]{b'v' => match r[6]{b'a'=>match r[7]{b'e'=>s(CFCMOVAE),_=>N}b'b'=>match r[7]{b'e'=>s(CFCMOVBE),_=>N}b'g'=>match r[7]{b'e'=>s(CFCMOVGE),_=>N}b'l'=>match r[7]{b'e'=>s(CFCMOVLE),_=>N}b'n' => match r[7]{b'a'=>s(CFCMOVNA),b'b'=>s(CFCMOVNB),b'c'=>s(CFCMOVNC),b'e'=>s(CFCMOVNE),b'g'=>s(CFCMOVNG),b'l'=>s(CFCMOVNL),b'o'=>s(CFCMOVNO),b'p'=>s(CFCMOVNP),b's'=>s(CFCMOVNS),b'z'=>s(CFCMOVNZ),_=>N}b'p' => match r[7]{b'e'=>s(CFCMOVPE),b'o'=>s(CFCMOVPO),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'p' => match r[3]{b'a'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPAXADD),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPBXADD),_=>N}_=>N}_=>N}_=>N}b'c'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPCXADD),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPEXADD),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPGXADD),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPLXADD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPOXADD),_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPSXADD),_=>N}_=>N}_=>N}_=>N}b'z'=>match r[4]{b'x'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd'=>s(CMPZXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'n' => match r[6]{b'a'=>match r[7]{b'e'=>s(CTESTNAE),_=>N}b'b'=>match r[7]{b'e'=>s(CTESTNBE),_=>N}b'g'=>match r[7]{b'e'=>s(CTESTNGE),_=>N}b'l'=>match r[7]{b'e'=>s(CTESTNLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v'=>match r[2]{b't' => match r[3]{b'd'=>match r[4]{b'q'=>match r[5]{b'2'=>match r[6]{b'p' => match r[7]{b'd'=>s(CVTDQ2PD),b's'=>s(CVTDQ2PS),_=>N}_=>N}_=>N}_=>N}b'p' => match r[4]{b'd'=>match r[5]{b'2' => match r[6]{b'd'=>match r[7]{b'q'=>s(CVTPD2DQ),_=>N}b'p' => match r[7]{b'i'=>s(CVTPD2PI),b's'=>s(CVTPD2PS),_=>N}_=>N}_=>N}b'i'=>match r[5]{b'2'=>match r[6]{b'p' => match r[7]{b'd'=>s(CVTPI2PD),b's'=>s(CVTPI2PS),_=>N}_=>N}_=>N}b's'=>match r[5]{b'2' => match r[6]{b'd'=>match r[7]{b'q'=>s(CVTPS2DQ),_=>N}b'p' => match r[7]{b'd'=>s(CVTPS2PD),b'i'=>s(CVTPS2PI),_=>N}_=>N}_=>N}_=>N}b's' => match r[4]{b'd'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'i'=>s(CVTSD2SI),b's'=>s(CVTSD2SS),_=>N}_=>N}_=>N}b'i'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'd'=>s(CVTSI2SD),b's'=>s(CVTSI2SS),_=>N}_=>N}_=>N}b's'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'd'=>s(CVTSS2SD),b'i'=>s(CVTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l' => match r[2]{b'd'=>match r[3]{b'e'=>match r[4]{b'm'=>match r[5]{b'o'=>match r[6]{b't'=>match r[7]{b'e'=>s(CLDEMOTE),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b's'=>match r[4]{b's'=>match r[5]{b'b'=>match r[6]{b's'=>match r[7]{b'y'=>s(CLRSSBSY),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v'=>match r[5]{b'n'=>match r[6]{b'b'=>match r[7]{b'e'=>s(FCMOVNBE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b's'=>match r[5]{b'w'=>match r[6]{b'a'=>match r[7]{b'x'=>s(FNSTSWAX),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b's'=>match r[3]{b'a'=>match r[4]{b'v'=>match r[5]{b'e'=>match r[6]{b'6'=>match r[7]{b'4'=>s(FXSAVE64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'k' => match r[1]{b'o'=>match r[2]{b'r'=>match r[3]{b't'=>match r[4]{b'e'=>match r[5]{b's'=>match r[6]{b't' => match r[7]{b'b'=>s(KORTESTB),b'd'=>s(KORTESTD),b'q'=>s(KORTESTQ),b'w'=>s(KORTESTW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'h'=>match r[3]{b'i'=>match r[4]{b'f'=>match r[5]{b't' => match r[6]{b'l' => match r[7]{b'b'=>s(KSHIFTLB),b'd'=>s(KSHIFTLD),b'q'=>s(KSHIFTLQ),b'w'=>s(KSHIFTLW),_=>N}b'r' => match r[7]{b'b'=>s(KSHIFTRB),b'd'=>s(KSHIFTRD),b'q'=>s(KSHIFTRQ),b'w'=>s(KSHIFTRW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'b'=>match r[7]{b'w'=>s(KUNPCKBW),_=>N}b'd'=>match r[7]{b'q'=>s(KUNPCKDQ),_=>N}b'w'=>match r[7]{b'd'=>s(KUNPCKWD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'c'=>match r[3]{b'k' => match r[4]{b's'=>match r[5]{b's' => match r[6]{b'd'=>match r[7]{b'w'=>s(PACKSSDW),_=>N}b'w'=>match r[7]{b'b'=>s(PACKSSWB),_=>N}_=>N}_=>N}b'u'=>match r[5]{b's' => match r[6]{b'd'=>match r[7]{b'w'=>s(PACKUSDW),_=>N}b'w'=>match r[7]{b'b'=>s(PACKUSWB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'v'=>match r[7]{b'b'=>s(PBLENDVB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b's'=>match r[5]{b'x' => match r[6]{b'd'=>match r[7]{b'q'=>s(PMOVSXDQ),_=>N}b'b' => match r[7]{b'd'=>s(PMOVSXBD),b'q'=>s(PMOVSXBQ),b'w'=>s(PMOVSXBW),_=>N}b'w' => match r[7]{b'd'=>s(PMOVSXWD),b'q'=>s(PMOVSXWQ),_=>N}_=>N}_=>N}b'z'=>match r[5]{b'x' => match r[6]{b'd'=>match r[7]{b'q'=>s(PMOVZXDQ),_=>N}b'b' => match r[7]{b'd'=>s(PMOVZXBD),b'q'=>s(PMOVZXBQ),b'w'=>s(PMOVZXBW),_=>N}b'w' => match r[7]{b'd'=>s(PMOVZXWD),b'q'=>s(PMOVZXWQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l'=>match r[4]{b'h'=>match r[5]{b'r'=>match r[6]{b's'=>match r[7]{b'w'=>s(PMULHRSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b'q'=>match r[4]{b'u'=>match r[5]{b'e'=>match r[6]{b'r'=>match r[7]{b'y'=>s(RMPQUERY),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b't'=>match r[3]{b'o'=>match r[4]{b'r'=>match r[5]{b's'=>match r[6]{b's'=>match r[7]{b'p'=>s(RSTORSSP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd' => match r[2]{b'f'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(RDFSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(RDGSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'h'=>match r[2]{b'a'=>match r[3]{b'1'=>match r[4]{b'm'=>match r[5]{b's'=>match r[6]{b'g' => match r[7]{b'1'=>s(SHA1MSG1),b'2'=>s(SHA1MSG2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b't'=>match r[6]{b'e'=>match r[7]{b'r'=>s(SYSENTER),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e' => match r[2]{b'a'=>match r[3]{b'm'=>match r[4]{b'c'=>match r[5]{b'a'=>match r[6]{b'l'=>match r[7]{b'l'=>s(SEAMCALL),_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'u'=>match r[5]{b'i'=>match r[6]{b'p'=>match r[7]{b'i'=>s(SENDUIPI),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'n' => match r[4]{b'a'=>match r[5]{b'e'=>match r[6]{b'z'=>match r[7]{b'u'=>s(SETNAEZU),_=>N}_=>N}_=>N}b'b'=>match r[5]{b'e'=>match r[6]{b'z'=>match r[7]{b'u'=>s(SETNBEZU),_=>N}_=>N}_=>N}b'g'=>match r[5]{b'e'=>match r[6]{b'z'=>match r[7]{b'u'=>s(SETNGEZU),_=>N}_=>N}_=>N}b'l'=>match r[5]{b'e'=>match r[6]{b'z'=>match r[7]{b'u'=>s(SETNLEZU),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u' => match r[1]{b'm'=>match r[2]{b'o'=>match r[3]{b'n'=>match r[4]{b'i'=>match r[5]{b't'=>match r[6]{b'o'=>match r[7]{b'r'=>s(UMONITOR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b'p'=>match r[3]{b'c'=>match r[4]{b'k' => match r[5]{b'h'=>match r[6]{b'p'=>match r[7]{b's'=>s(UNPCKHPS),_=>N}_=>N}b'l'=>match r[6]{b'p'=>match r[7]{b's'=>s(UNPCKLPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'b'=>match r[5]{b'f'=>match r[6]{b'1'=>match r[7]{b'6'=>s(VADDBF16),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'p' => match r[7]{b'd'=>s(VBLENDPD),b's'=>s(VBLENDPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'v'=>match r[3]{b't' => match r[4]{b'p'=>match r[5]{b'h'=>match r[6]{b'2'=>match r[7]{b'w'=>s(VCVTPH2W),_=>N}_=>N}_=>N}b'w'=>match r[5]{b'2'=>match r[6]{b'p'=>match r[7]{b'h'=>s(VCVTW2PH),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[2]{b'i'=>match r[3]{b'v'=>match r[4]{b'b'=>match r[5]{b'f'=>match r[6]{b'1'=>match r[7]{b'6'=>s(VDIVBF16),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd' => match r[6]{b'p' => match r[7]{b'd'=>s(VFMADDPD),b's'=>s(VFMADDPS),_=>N}b's' => match r[7]{b'd'=>s(VFMADDSD),b's'=>s(VFMADDSS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b' => match r[6]{b'p' => match r[7]{b'd'=>s(VFMSUBPD),b's'=>s(VFMSUBPS),_=>N}b's' => match r[7]{b'd'=>s(VFMSUBSD),b's'=>s(VFMSUBSS),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[4]{b'l'=>match r[5]{b'c' => match r[6]{b'p'=>match r[7]{b'h'=>s(VFMULCPH),_=>N}b's'=>match r[7]{b'h'=>s(VFMULCSH),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b'm'=>match r[4]{b'x'=>match r[5]{b'c'=>match r[6]{b's'=>match r[7]{b'r'=>s(VLDMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm' => match r[5]{b'i'=>match r[6]{b's' => match r[7]{b'd'=>s(VUCOMISD),b'h'=>s(VUCOMISH),b's'=>s(VUCOMISS),_=>N}_=>N}b'x'=>match r[6]{b's' => match r[7]{b'd'=>s(VUCOMXSD),b'h'=>s(VUCOMXSH),b's'=>s(VUCOMXSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'z'=>match r[2]{b'e'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'a'=>match r[6]{b'l'=>match r[7]{b'l'=>s(VZEROALL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'x'=>match r[4]{b'b'=>match r[5]{b'f'=>match r[6]{b'1'=>match r[7]{b'6'=>s(VMAXBF16),_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n'=>match r[4]{b'b'=>match r[5]{b'f'=>match r[6]{b'1'=>match r[7]{b'6'=>s(VMINBF16),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[3]{b'a'=>match r[4]{b'u'=>match r[5]{b'n'=>match r[6]{b'c'=>match r[7]{b'h'=>s(VMLAUNCH),_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[3]{b'v' => match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(VMOVDDUP),_=>N}_=>N}_=>N}b'h'=>match r[5]{b'l'=>match r[6]{b'p'=>match r[7]{b's'=>s(VMOVHLPS),_=>N}_=>N}_=>N}b'l'=>match r[5]{b'h'=>match r[6]{b'p'=>match r[7]{b's'=>s(VMOVLHPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[3]{b's'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'b'=>match r[7]{b'w'=>s(VMPSADBW),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b'e'=>match r[4]{b's'=>match r[5]{b'u'=>match r[6]{b'm'=>match r[7]{b'e'=>s(VMRESUME),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l'=>match r[4]{b'b'=>match r[5]{b'f'=>match r[6]{b'1'=>match r[7]{b'6'=>s(VMULBF16),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'b'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'n'=>match r[6]{b'd' => match r[7]{b'd'=>s(VPBLENDD),b'w'=>s(VPBLENDW),_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[3]{b'm'=>match r[4]{b'p' => match r[5]{b'e'=>match r[6]{b'q' => match r[7]{b'b'=>s(VPCMPEQB),b'd'=>s(VPCMPEQD),b'q'=>s(VPCMPEQQ),b'w'=>s(VPCMPEQW),_=>N}_=>N}b'g'=>match r[6]{b't' => match r[7]{b'b'=>s(VPCMPGTB),b'd'=>s(VPCMPGTD),b'q'=>s(VPCMPGTQ),b'w'=>s(VPCMPGTW),_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[3]{b'p' => match r[4]{b'b' => match r[5]{b's' => match r[6]{b's'=>match r[7]{b'd'=>s(VPDPBSSD),_=>N}b'u'=>match r[7]{b'd'=>s(VPDPBSUD),_=>N}_=>N}b'u' => match r[6]{b's'=>match r[7]{b'd'=>s(VPDPBUSD),_=>N}b'u'=>match r[7]{b'd'=>s(VPDPBUUD),_=>N}_=>N}_=>N}b'w' => match r[5]{b's' => match r[6]{b's'=>match r[7]{b'd'=>s(VPDPWSSD),_=>N}b'u'=>match r[7]{b'd'=>s(VPDPWSUD),_=>N}_=>N}b'u' => match r[6]{b's'=>match r[7]{b'd'=>s(VPDPWUSD),_=>N}b'u'=>match r[7]{b'd'=>s(VPDPWUUD),_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[3]{b'r'=>match r[4]{b'm' => match r[5]{b'i'=>match r[6]{b'2' => match r[7]{b'b'=>s(VPERMI2B),b'd'=>s(VPERMI2D),b'q'=>s(VPERMI2Q),b'w'=>s(VPERMI2W),_=>N}_=>N}b't'=>match r[6]{b'2' => match r[7]{b'b'=>s(VPERMT2B),b'd'=>s(VPERMT2D),b'q'=>s(VPERMT2Q),b'w'=>s(VPERMT2W),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[3]{b'z'=>match r[4]{b'c'=>match r[5]{b'n'=>match r[6]{b't' => match r[7]{b'd'=>s(VPLZCNTD),b'q'=>s(VPLZCNTQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'n'=>match r[6]{b't' => match r[7]{b'b'=>s(VPOPCNTB),b'd'=>s(VPOPCNTD),b'q'=>s(VPOPCNTQ),b'w'=>s(VPOPCNTW),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b's'=>match r[5]{b't'=>match r[6]{b'm' => match r[7]{b'b'=>s(VPTESTMB),b'd'=>s(VPTESTMD),b'q'=>s(VPTESTMQ),b'w'=>s(VPTESTMW),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b's' => match r[7]{b'b'=>s(VPADDUSB),b'w'=>s(VPADDUSW),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[4]{b'i'=>match r[5]{b'g'=>match r[6]{b'n'=>match r[7]{b'r'=>s(VPALIGNR),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd' => match r[6]{b'd'=>match r[7]{b'q'=>s(VPHADDDQ),_=>N}b'b' => match r[7]{b'd'=>s(VPHADDBD),b'q'=>s(VPHADDBQ),b'w'=>s(VPHADDBW),_=>N}b'w' => match r[7]{b'd'=>s(VPHADDWD),b'q'=>s(VPHADDWQ),_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b' => match r[6]{b'b'=>match r[7]{b'w'=>s(VPHSUBBW),_=>N}b'd'=>match r[7]{b'q'=>s(VPHSUBDQ),_=>N}b'w'=>match r[7]{b'd'=>s(VPHSUBWD),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'b'=>match r[6]{b'2'=>match r[7]{b'm'=>s(VPMOVB2M),_=>N}_=>N}b'd'=>match r[6]{b'2'=>match r[7]{b'm'=>s(VPMOVD2M),_=>N}_=>N}b'm'=>match r[6]{b'2' => match r[7]{b'b'=>s(VPMOVM2B),b'd'=>s(VPMOVM2D),b'q'=>s(VPMOVM2Q),b'w'=>s(VPMOVM2W),_=>N}_=>N}b'q'=>match r[6]{b'2'=>match r[7]{b'm'=>s(VPMOVQ2M),_=>N}_=>N}b'w'=>match r[6]{b'2'=>match r[7]{b'm'=>s(VPMOVW2M),_=>N}_=>N}b's' => match r[6]{b'w'=>match r[7]{b'b'=>s(VPMOVSWB),_=>N}b'd' => match r[7]{b'b'=>s(VPMOVSDB),b'w'=>s(VPMOVSDW),_=>N}b'q' => match r[7]{b'b'=>s(VPMOVSQB),b'd'=>s(VPMOVSQD),b'w'=>s(VPMOVSQW),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[4]{b'l' => match r[5]{b'h'=>match r[6]{b'u'=>match r[7]{b'w'=>s(VPMULHUW),_=>N}_=>N}b'u'=>match r[6]{b'd'=>match r[7]{b'q'=>s(VPMULUDQ),_=>N}_=>N}_=>N}_=>N}b'a' => match r[4]{b'c'=>match r[5]{b's' => match r[6]{b'd'=>match r[7]{b'd'=>s(VPMACSDD),_=>N}b'w' => match r[7]{b'd'=>s(VPMACSWD),b'w'=>s(VPMACSWW),_=>N}_=>N}_=>N}b'd'=>match r[5]{b'd'=>match r[6]{b'w'=>match r[7]{b'd'=>s(VPMADDWD),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b'u'=>match r[6]{b's' => match r[7]{b'b'=>s(VPSUBUSB),b'w'=>s(VPSUBUSW),_=>N}_=>N}_=>N}_=>N}b'h' => match r[4]{b'l'=>match r[5]{b'd'=>match r[6]{b'v' => match r[7]{b'd'=>s(VPSHLDVD),b'q'=>s(VPSHLDVQ),b'w'=>s(VPSHLDVW),_=>N}_=>N}_=>N}b'r'=>match r[5]{b'd'=>match r[6]{b'v' => match r[7]{b'd'=>s(VPSHRDVD),b'q'=>s(VPSHRDVQ),b'w'=>s(VPSHRDVW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'a'=>match r[3]{b'n'=>match r[4]{b'g'=>match r[5]{b'e' => match r[6]{b'p' => match r[7]{b'd'=>s(VRANGEPD),b's'=>s(VRANGEPS),_=>N}b's' => match r[7]{b'd'=>s(VRANGESD),b's'=>s(VRANGESS),_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[3]{b'p' => match r[4]{b'1'=>match r[5]{b'4' => match r[6]{b'p' => match r[7]{b'd'=>s(VRCP14PD),b's'=>s(VRCP14PS),_=>N}b's' => match r[7]{b'd'=>s(VRCP14SD),b's'=>s(VRCP14SS),_=>N}_=>N}_=>N}b'2'=>match r[5]{b'8' => match r[6]{b'p' => match r[7]{b'd'=>s(VRCP28PD),b's'=>s(VRCP28PS),_=>N}b's' => match r[7]{b'd'=>s(VRCP28SD),b's'=>s(VRCP28SS),_=>N}_=>N}_=>N}b'b'=>match r[5]{b'f'=>match r[6]{b'1'=>match r[7]{b'6'=>s(VRCPBF16),_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[3]{b'u'=>match r[4]{b'n'=>match r[5]{b'd' => match r[6]{b'p' => match r[7]{b'd'=>s(VROUNDPD),b's'=>s(VROUNDPS),_=>N}b's' => match r[7]{b'd'=>s(VROUNDSD),b's'=>s(VROUNDSS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'q'=>match r[4]{b'r'=>match r[5]{b't' => match r[6]{b'p' => match r[7]{b'h'=>s(VRSQRTPH),b's'=>s(VRSQRTPS),_=>N}b's' => match r[7]{b'h'=>s(VRSQRTSH),b's'=>s(VRSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b't'=>match r[3]{b'm'=>match r[4]{b'x'=>match r[5]{b'c'=>match r[6]{b's'=>match r[7]{b'r'=>s(VSTMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b'b'=>match r[5]{b'f'=>match r[6]{b'1'=>match r[7]{b'6'=>s(VSUBBF16),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'3'=>match r[4]{b'm'=>match r[5]{b's'=>match r[6]{b'g' => match r[7]{b'1'=>s(VSM3MSG1),b'2'=>s(VSM3MSG2),_=>N}_=>N}_=>N}_=>N}b'4'=>match r[4]{b'k'=>match r[5]{b'e'=>match r[6]{b'y'=>match r[7]{b'4'=>s(VSM4KEY4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'w' => match r[1]{b'b'=>match r[2]{b'n'=>match r[3]{b'o'=>match r[4]{b'i'=>match r[5]{b'n'=>match r[6]{b'v'=>match r[7]{b'd'=>s(WBNOINVD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'f'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(WRFSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(WRGSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'c'=>match r[3]{b'q'=>match r[4]{b'u'=>match r[5]{b'i'=>match r[6]{b'r'=>match r[7]{b'e'=>s(XACQUIRE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e' => match r[5]{b'c'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XSAVEC64),_=>N}_=>N}b'o'=>match r[6]{b'p'=>match r[7]{b't'=>s(XSAVEOPT),_=>N}_=>N}b's'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XSAVES64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'e'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(XRELEASE),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XRSTOR64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}9=>match r[0]{b'a'=>match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'a'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPAXADD),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPBXADD),_=>N}_=>N}_=>N}_=>N}b'c'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPCXADD),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPEXADD),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPGXADD),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPLXADD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPOXADD),_=>N}_=>N}_=>N}_=>N}b's'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPSXADD),_=>N}_=>N}_=>N}_=>N}b'z'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(ACMPZXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[1]{b'x'=>match r[2]{b't'=>match r[3]{b'r'=>match r[4]{b'a'=>match r[5]{b'c'=>match r[6]{b't'=>match r[7]{b'p'=>match r[8]{b's'=>s(EXTRACTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[1]{b'x'=>match r[2]{b'r'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'o'=>match r[6]{b'r'=>match r[7]{b'6'=>match r[8]{b'4'=>s(FXRSTOR64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[1]{b'f'=>match r[2]{b'2'=>match r[3]{b'p'=>match r[4]{b'8'=>match r[5]{b'm'=>match r[6]{b'u'=>match r[7]{b'l'=>match r[8]{b'b'=>s(GF2P8MULB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[1]{b'o'=>match r[2]{b'v'=>match r[3]{b'd'=>match r[4]{b'i'=>match r[5]{b'r'=>match r[6]{b'6'=>match r[7]{b'4'=>match r[8]{b'b'=>s(MOVDIR64B),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'w'=>match r[1]{b'r'=>match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>match r[5]{b'l'=>match r[6]{b'i'=>match r[7]{b's'=>match r[8]{b't'=>s(WRMSRLIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'f'=>match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'o'=>match r[5]{b'v'=>match r[6]{b'n' => match r[7]{b'a'=>match r[8]{b'e'=>s(CFCMOVNAE),_=>N}b'b'=>match r[8]{b'e'=>s(CFCMOVNBE),_=>N}b'g'=>match r[8]{b'e'=>s(CFCMOVNGE),_=>N}b'l'=>match r[8]{b'e'=>s(CFCMOVNLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'p' => match r[3]{b'a'=>match r[4]{b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPAEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[4]{b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPBEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[4]{b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPGEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[4]{b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPLEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>match r[7]{b'8'=>match r[8]{b'b'=>s(CMPXCHG8B),_=>N}_=>N}_=>N}_=>N}_=>N}b'n' => match r[4]{b'a'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNAXADD),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNBXADD),_=>N}_=>N}_=>N}_=>N}b'c'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNCXADD),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNEXADD),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNGXADD),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNLXADD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNOXADD),_=>N}_=>N}_=>N}_=>N}b's'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNSXADD),_=>N}_=>N}_=>N}_=>N}b'z'=>match r[5]{b'x'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(CMPNZXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v'=>match r[2]{b't'=>match r[3]{b't' => match r[4]{b'p' => match r[5]{b'd'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(CVTTPD2DQ),_=>N}b'p'=>match r[8]{b'i'=>s(CVTTPD2PI),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(CVTTPS2DQ),_=>N}b'p'=>match r[8]{b'i'=>s(CVTTPS2PI),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b's'=>match r[8]{b'i'=>s(CVTTSD2SI),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2'=>match r[7]{b's'=>match r[8]{b'i'=>s(CVTTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l' => match r[1]{b'd'=>match r[2]{b't'=>match r[3]{b'i'=>match r[4]{b'l'=>match r[5]{b'e'=>match r[6]{b'c'=>match r[7]{b'f'=>match r[8]{b'g'=>s(LDTILECFG),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'i'=>match r[5]{b'w'=>match r[6]{b'k'=>match r[7]{b'e'=>match r[8]{b'y'=>s(LOADIWKEY),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'm'=>match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b'b'=>match r[7]{b's'=>match r[8]{b'w'=>s(PMADDUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b'f'=>match r[4]{b'e'=>match r[5]{b't'=>match r[6]{b'c'=>match r[7]{b'h' => match r[8]{b'0'=>s(PREFETCH0),b'1'=>s(PREFETCH1),b'2'=>s(PREFETCH2),b'a'=>s(PREFETCHA),b'w'=>s(PREFETCHW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h' => match r[7]{b'b'=>match r[8]{b'w'=>s(PUNPCKHBW),_=>N}b'd'=>match r[8]{b'q'=>s(PUNPCKHDQ),_=>N}b'w'=>match r[8]{b'd'=>s(PUNPCKHWD),_=>N}_=>N}b'l' => match r[7]{b'b'=>match r[8]{b'w'=>s(PUNPCKLBW),_=>N}b'd'=>match r[8]{b'q'=>s(PUNPCKLDQ),_=>N}b'w'=>match r[8]{b'd'=>s(PUNPCKLWD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v'=>match r[2]{b'a'=>match r[3]{b'l'=>match r[4]{b'i'=>match r[5]{b'd'=>match r[6]{b'a'=>match r[7]{b't'=>match r[8]{b'e'=>s(PVALIDATE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'l'=>match r[3]{b'm'=>match r[4]{b'u'=>match r[5]{b'l'=>match r[6]{b'q'=>match r[7]{b'd'=>match r[8]{b'q'=>s(PCLMULQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'p' => match r[4]{b'e'=>match r[5]{b's'=>match r[6]{b't'=>match r[7]{b'r' => match r[8]{b'i'=>s(PCMPESTRI),b'm'=>s(PCMPESTRM),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[5]{b's'=>match r[6]{b't'=>match r[7]{b'r' => match r[8]{b'i'=>s(PCMPISTRI),b'm'=>s(PCMPISTRM),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'd'=>match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>match r[5]{b'l'=>match r[6]{b'i'=>match r[7]{b's'=>match r[8]{b't'=>s(RDMSRLIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'p' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'j'=>match r[6]{b'u'=>match r[7]{b's'=>match r[8]{b't'=>s(RMPADJUST),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[4]{b'p'=>match r[5]{b'd'=>match r[6]{b'a'=>match r[7]{b't'=>match r[8]{b'e'=>s(RMPUPDATE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b'r'=>match r[3]{b'i'=>match r[4]{b'a'=>match r[5]{b'l'=>match r[6]{b'i'=>match r[7]{b'z'=>match r[8]{b'e'=>s(SERIALIZE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'a'=>match r[3]{b'1' => match r[4]{b'n'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b't'=>match r[8]{b'e'=>s(SHA1NEXTE),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b's'=>match r[8]{b'4'=>s(SHA1RNDS4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b't'=>match r[3]{b'i'=>match r[4]{b'l'=>match r[5]{b'e'=>match r[6]{b'c'=>match r[7]{b'f'=>match r[8]{b'g'=>s(STTILECFG),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't' => match r[1]{b'd'=>match r[2]{b'p' => match r[3]{b'b'=>match r[4]{b'f'=>match r[5]{b'1'=>match r[6]{b'6'=>match r[7]{b'p'=>match r[8]{b's'=>s(TDPBF16PS),_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[4]{b'p'=>match r[5]{b'1'=>match r[6]{b'6'=>match r[7]{b'p'=>match r[8]{b's'=>s(TDPFP16PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'l'=>match r[5]{b'o'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>s(TILELOADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'4'=>match r[2]{b'f'=>match r[3]{b'm'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'd' => match r[7]{b'p'=>match r[8]{b's'=>s(V4FMADDPS),_=>N}b's'=>match r[8]{b's'=>s(V4FMADDSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b's'=>match r[5]{b'u'=>match r[6]{b'b'=>match r[7]{b'p' => match r[8]{b'd'=>s(VADDSUBPD),b's'=>s(VADDSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd' => match r[6]{b'm'=>match r[7]{b'p' => match r[8]{b'd'=>s(VBLENDMPD),b's'=>s(VBLENDMPS),_=>N}_=>N}b'v'=>match r[7]{b'p' => match r[8]{b'd'=>s(VBLENDVPD),b's'=>s(VBLENDVPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'v'=>match r[3]{b't' => match r[4]{b'd'=>match r[5]{b'q'=>match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VCVTDQ2PD),b'h'=>s(VCVTDQ2PH),b's'=>s(VCVTDQ2PS),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[5]{b'q'=>match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VCVTQQ2PD),b'h'=>s(VCVTQQ2PH),b's'=>s(VCVTQQ2PS),_=>N}_=>N}_=>N}_=>N}b't'=>match r[5]{b'p'=>match r[6]{b'h'=>match r[7]{b'2'=>match r[8]{b'w'=>s(VCVTTPH2W),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[5]{b'w'=>match r[6]{b'2'=>match r[7]{b'p'=>match r[8]{b'h'=>s(VCVTUW2PH),_=>N}_=>N}_=>N}_=>N}b'p' => match r[5]{b'd'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(VCVTPD2DQ),_=>N}b'q'=>match r[8]{b'q'=>s(VCVTPD2QQ),_=>N}b'p' => match r[8]{b'h'=>s(VCVTPD2PH),b's'=>s(VCVTPD2PS),_=>N}_=>N}_=>N}b'h'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(VCVTPH2DQ),_=>N}b'q'=>match r[8]{b'q'=>s(VCVTPH2QQ),_=>N}b'u'=>match r[8]{b'w'=>s(VCVTPH2UW),_=>N}b'p' => match r[8]{b'd'=>s(VCVTPH2PD),b's'=>s(VCVTPH2PS),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(VCVTPS2DQ),_=>N}b'q'=>match r[8]{b'q'=>s(VCVTPS2QQ),_=>N}b'p' => match r[8]{b'd'=>s(VCVTPS2PD),b'h'=>s(VCVTPS2PH),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'h'=>s(VCVTSD2SH),b'i'=>s(VCVTSD2SI),b's'=>s(VCVTSD2SS),_=>N}_=>N}_=>N}b'h'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'd'=>s(VCVTSH2SD),b'i'=>s(VCVTSH2SI),b's'=>s(VCVTSH2SS),_=>N}_=>N}_=>N}b'i'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'd'=>s(VCVTSI2SD),b'h'=>s(VCVTSI2SH),b's'=>s(VCVTSI2SS),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'd'=>s(VCVTSS2SD),b'h'=>s(VCVTSS2SH),b'i'=>s(VCVTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b'p'=>match r[4]{b'a'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b'p' => match r[8]{b'd'=>s(VEXPANDPD),b's'=>s(VEXPANDPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[2]{b'e'=>match r[3]{b't'=>match r[4]{b'e'=>match r[5]{b'x'=>match r[6]{b'p' => match r[7]{b'p' => match r[8]{b'd'=>s(VGETEXPPD),b'h'=>s(VGETEXPPH),b's'=>s(VGETEXPPS),_=>N}b's' => match r[8]{b'd'=>s(VGETEXPSD),b'h'=>s(VGETEXPSH),b's'=>s(VGETEXPSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'r'=>match r[6]{b't'=>match r[7]{b'p'=>match r[8]{b's'=>s(VINSERTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b'd'=>match r[4]{b'u'=>match r[5]{b'c'=>match r[6]{b'e' => match r[7]{b'p' => match r[8]{b'd'=>s(VREDUCEPD),b'h'=>s(VREDUCEPH),b's'=>s(VREDUCEPS),_=>N}b's' => match r[8]{b'd'=>s(VREDUCESD),b'h'=>s(VREDUCESH),b's'=>s(VREDUCESS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h'=>match r[7]{b'p'=>match r[8]{b's'=>s(VUNPCKHPS),_=>N}_=>N}b'l'=>match r[7]{b'p'=>match r[8]{b's'=>s(VUNPCKLPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd' => match r[2]{b'b'=>match r[3]{b'p'=>match r[4]{b's'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'b'=>match r[8]{b'w'=>s(VDBPSADBW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[3]{b'b'=>match r[4]{b'f'=>match r[5]{b'1'=>match r[6]{b'6'=>match r[7]{b'p'=>match r[8]{b's'=>s(VDPBF16PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'u'=>match r[5]{b'l'=>match r[6]{b'c' => match r[7]{b'p'=>match r[8]{b'h'=>s(VFCMULCPH),_=>N}b's'=>match r[8]{b'h'=>s(VFCMULCSH),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'c' => match r[7]{b'p'=>match r[8]{b'h'=>s(VFMADDCPH),_=>N}b's'=>match r[8]{b'h'=>s(VFMADDCSH),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[3]{b'm' => match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'd' => match r[7]{b'p' => match r[8]{b'd'=>s(VFNMADDPD),b's'=>s(VFNMADDPS),_=>N}b's' => match r[8]{b'd'=>s(VFNMADDSD),b's'=>s(VFNMADDSS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[5]{b'u'=>match r[6]{b'b' => match r[7]{b'p' => match r[8]{b'd'=>s(VFNMSUBPD),b's'=>s(VFNMSUBPS),_=>N}b's' => match r[8]{b'd'=>s(VFNMSUBSD),b's'=>s(VFNMSUBSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'i'=>match r[3]{b'n'=>match r[4]{b'm'=>match r[5]{b'a'=>match r[6]{b'x' => match r[7]{b'p' => match r[8]{b'd'=>s(VMINMAXPD),b'h'=>s(VMINMAXPH),b's'=>s(VMINMAXPS),_=>N}b's' => match r[8]{b'd'=>s(VMINMAXSD),b'h'=>s(VMINMAXSH),b's'=>s(VMINMAXSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[3]{b'v' => match r[4]{b'm'=>match r[5]{b's'=>match r[6]{b'k'=>match r[7]{b'p' => match r[8]{b'd'=>s(VMOVMSKPD),b's'=>s(VMOVMSKPS),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[5]{b't'=>match r[6]{b'd'=>match r[7]{b'q'=>match r[8]{b'a'=>s(VMOVNTDQA),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'h'=>match r[6]{b'd'=>match r[7]{b'u'=>match r[8]{b'p'=>s(VMOVSHDUP),_=>N}_=>N}_=>N}b'l'=>match r[6]{b'd'=>match r[7]{b'u'=>match r[8]{b'p'=>s(VMOVSLDUP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'4'=>match r[3]{b'd'=>match r[4]{b'p'=>match r[5]{b'w'=>match r[6]{b's'=>match r[7]{b's'=>match r[8]{b'd'=>s(VP4DPWSSD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a'=>match r[3]{b'c'=>match r[4]{b'k' => match r[5]{b's'=>match r[6]{b's' => match r[7]{b'd'=>match r[8]{b'w'=>s(VPACKSSDW),_=>N}b'w'=>match r[8]{b'b'=>s(VPACKSSWB),_=>N}_=>N}_=>N}b'u'=>match r[6]{b's' => match r[7]{b'd'=>match r[8]{b'w'=>s(VPACKUSDW),_=>N}b'w'=>match r[8]{b'b'=>s(VPACKUSWB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'n'=>match r[6]{b'd' => match r[7]{b'm' => match r[8]{b'b'=>s(VPBLENDMB),b'd'=>s(VPBLENDMD),b'q'=>s(VPBLENDMQ),b'w'=>s(VPBLENDMW),_=>N}b'v'=>match r[8]{b'b'=>s(VPBLENDVB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[3]{b'p' => match r[4]{b'b' => match r[5]{b's' => match r[6]{b's'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPBSSDS),_=>N}_=>N}b'u'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPBSUDS),_=>N}_=>N}_=>N}b'u' => match r[6]{b's'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPBUSDS),_=>N}_=>N}b'u'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPBUUDS),_=>N}_=>N}_=>N}_=>N}b'w' => match r[5]{b's' => match r[6]{b's'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPWSSDS),_=>N}_=>N}b'u'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPWSUDS),_=>N}_=>N}_=>N}b'u' => match r[6]{b's'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPWUSDS),_=>N}_=>N}b'u'=>match r[7]{b'd'=>match r[8]{b's'=>s(VPDPWUUDS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'u' => match r[7]{b'd'=>match r[8]{b'q'=>s(VPHADDUDQ),_=>N}b'b' => match r[8]{b'd'=>s(VPHADDUBD),b'q'=>s(VPHADDUBQ),b'w'=>s(VPHADDUBW),_=>N}b'w' => match r[8]{b'd'=>s(VPHADDUWD),b'q'=>s(VPHADDUWQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b's'=>match r[5]{b't'=>match r[6]{b'n'=>match r[7]{b'm' => match r[8]{b'b'=>s(VPTESTNMB),b'd'=>s(VPTESTNMD),b'q'=>s(VPTESTNMQ),b'w'=>s(VPTESTNMW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e' => match r[3]{b'r'=>match r[4]{b'm' => match r[5]{b't'=>match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VPERMT2PD),b's'=>s(VPERMT2PS),_=>N}_=>N}_=>N}b'i' => match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VPERMI2PD),b's'=>s(VPERMI2PS),_=>N}_=>N}b'l'=>match r[7]{b'p' => match r[8]{b'd'=>s(VPERMILPD),b's'=>s(VPERMILPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[4]{b'p'=>match r[5]{b'a'=>match r[6]{b'n'=>match r[7]{b'd' => match r[8]{b'b'=>s(VPEXPANDB),b'd'=>s(VPEXPANDD),b'q'=>s(VPEXPANDQ),b'w'=>s(VPEXPANDW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b's'=>match r[6]{b'x' => match r[7]{b'd'=>match r[8]{b'q'=>s(VPMOVSXDQ),_=>N}b'b' => match r[8]{b'd'=>s(VPMOVSXBD),b'q'=>s(VPMOVSXBQ),b'w'=>s(VPMOVSXBW),_=>N}b'w' => match r[8]{b'd'=>s(VPMOVSXWD),b'q'=>s(VPMOVSXWQ),_=>N}_=>N}_=>N}b'u'=>match r[6]{b's' => match r[7]{b'w'=>match r[8]{b'b'=>s(VPMOVUSWB),_=>N}b'd' => match r[8]{b'b'=>s(VPMOVUSDB),b'w'=>s(VPMOVUSDW),_=>N}b'q' => match r[8]{b'b'=>s(VPMOVUSQB),b'd'=>s(VPMOVUSQD),b'w'=>s(VPMOVUSQW),_=>N}_=>N}_=>N}b'z'=>match r[6]{b'x' => match r[7]{b'd'=>match r[8]{b'q'=>s(VPMOVZXDQ),_=>N}b'b' => match r[8]{b'd'=>s(VPMOVZXBD),b'q'=>s(VPMOVZXBQ),b'w'=>s(VPMOVZXBW),_=>N}b'w' => match r[8]{b'd'=>s(VPMOVZXWD),b'q'=>s(VPMOVZXWQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[4]{b'l'=>match r[5]{b'h'=>match r[6]{b'r'=>match r[7]{b's'=>match r[8]{b'w'=>s(VPMULHRSW),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[4]{b'c'=>match r[5]{b's' => match r[6]{b'd'=>match r[7]{b'q' => match r[8]{b'h'=>s(VPMACSDQH),b'l'=>s(VPMACSDQL),_=>N}_=>N}b's' => match r[7]{b'd'=>match r[8]{b'd'=>s(VPMACSSDD),_=>N}b'w' => match r[8]{b'd'=>s(VPMACSSWD),b'w'=>s(VPMACSSWW),_=>N}_=>N}_=>N}_=>N}b'd'=>match r[5]{b'c'=>match r[6]{b's'=>match r[7]{b'w'=>match r[8]{b'd'=>s(VPMADCSWD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'c'=>match r[3]{b'a'=>match r[4]{b'l'=>match r[5]{b'e'=>match r[6]{b'f' => match r[7]{b'p' => match r[8]{b'd'=>s(VSCALEFPD),b'h'=>s(VSCALEFPH),b's'=>s(VSCALEFPS),_=>N}b's' => match r[8]{b'd'=>s(VSCALEFSD),b'h'=>s(VSCALEFSH),b's'=>s(VSCALEFSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'q'=>match r[3]{b'r'=>match r[4]{b't'=>match r[5]{b'b'=>match r[6]{b'f'=>match r[7]{b'1'=>match r[8]{b'6'=>s(VSQRTBF16),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'3'=>match r[4]{b'r'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b's'=>match r[8]{b'2'=>s(VSM3RNDS2),_=>N}_=>N}_=>N}_=>N}_=>N}b'4'=>match r[4]{b'r'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b's'=>match r[8]{b'4'=>s(VSM4RNDS4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'r' => match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'l'=>match r[5]{b'd'=>match r[6]{b't'=>match r[7]{b'r'=>match r[8]{b'k'=>s(XRESLDTRK),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b's'=>match r[7]{b'6'=>match r[8]{b'4'=>s(XRSTORS64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b's'=>match r[4]{b'l'=>match r[5]{b'd'=>match r[6]{b't'=>match r[7]{b'r'=>match r[8]{b'k'=>s(XSUSLDTRK),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}10=>match r[0]{b'c'=>match r[1]{b'm'=>match r[2]{b'p' => match r[3]{b'n' => match r[4]{b'a'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(CMPNAEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(CMPNBEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(CMPNGEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(CMPNLEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>match r[7]{b'1'=>match r[8]{b'6'=>match r[9]{b'b'=>s(CMPXCHG16B),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[1]{b'a'=>match r[2]{b's'=>match r[3]{b'k'=>match r[4]{b'm'=>match r[5]{b'o'=>match r[6]{b'v'=>match r[7]{b'd'=>match r[8]{b'q'=>match r[9]{b'u'=>s(MASKMOVDQU),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[1]{b'h'=>match r[2]{b'a'=>match r[3]{b'2'=>match r[4]{b'5'=>match r[5]{b'6'=>match r[6]{b'm'=>match r[7]{b's'=>match r[8]{b'g' => match r[9]{b'1'=>s(SHA256MSG1),b'2'=>s(SHA256MSG2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[1]{b'i'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b's'=>match r[5]{b't'=>match r[6]{b'o'=>match r[7]{b'r'=>match r[8]{b'e'=>match r[9]{b'd'=>s(TILESTORED),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[1]{b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>match r[5]{b'o'=>match r[6]{b'p'=>match r[7]{b't'=>match r[8]{b'6'=>match r[9]{b'4'=>s(XSAVEOPT64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'a'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPAEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPBEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPGEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPLEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'n' => match r[5]{b'a'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNAXADD),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNBXADD),_=>N}_=>N}_=>N}_=>N}b'c'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNCXADD),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNEXADD),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNGXADD),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNLXADD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNOXADD),_=>N}_=>N}_=>N}_=>N}b's'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNSXADD),_=>N}_=>N}_=>N}_=>N}b'z'=>match r[6]{b'x'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ACMPNZXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c'=>match r[6]{b'l'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>s(AESDECLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c'=>match r[6]{b'l'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>s(AESENCLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b't'=>match r[4]{b'i'=>match r[5]{b'l'=>match r[6]{b'e'=>match r[7]{b'c'=>match r[8]{b'f'=>match r[9]{b'g'=>s(ALDTILECFG),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b't'=>match r[3]{b't'=>match r[4]{b'i'=>match r[5]{b'l'=>match r[6]{b'e'=>match r[7]{b'c'=>match r[8]{b'f'=>match r[9]{b'g'=>s(ASTTILECFG),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'i'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'l'=>match r[6]{b'o'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>s(ATILELOADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'h'=>match r[2]{b'm'=>match r[3]{b'i'=>match r[4]{b'n'=>match r[5]{b'p'=>match r[6]{b'o'=>match r[7]{b's'=>match r[8]{b'u'=>match r[9]{b'w'=>s(PHMINPOSUW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(PUNPCKHQDQ),_=>N}_=>N}_=>N}b'l'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(PUNPCKLQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'4'=>match r[2]{b'f'=>match r[3]{b'n'=>match r[4]{b'm'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'd' => match r[8]{b'p'=>match r[9]{b's'=>s(V4FNMADDPS),_=>N}b's'=>match r[9]{b's'=>s(V4FNMADDSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'v'=>match r[3]{b't' => match r[4]{b'p' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b'u' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTPD2UDQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTPD2UQQ),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[6]{b'2' => match r[7]{b'i'=>match r[8]{b'b'=>match r[9]{b's'=>s(VCVTPH2IBS),_=>N}_=>N}b'p'=>match r[8]{b's'=>match r[9]{b'x'=>s(VCVTPH2PSX),_=>N}_=>N}b'u' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTPH2UDQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTPH2UQQ),_=>N}_=>N}_=>N}_=>N}b's'=>match r[6]{b'2' => match r[7]{b'i'=>match r[8]{b'b'=>match r[9]{b's'=>s(VCVTPS2IBS),_=>N}_=>N}b'p'=>match r[8]{b'h'=>match r[9]{b'x'=>s(VCVTPS2PHX),_=>N}_=>N}b'u' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTPS2UDQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTPS2UQQ),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b'u'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTSD2USI),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[6]{b'2'=>match r[7]{b'u'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTSH2USI),_=>N}_=>N}_=>N}_=>N}b's'=>match r[6]{b'2'=>match r[7]{b'u'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTSS2USI),_=>N}_=>N}_=>N}_=>N}_=>N}b't' => match r[5]{b'p' => match r[6]{b'd'=>match r[7]{b'2' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTTPD2DQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTTPD2QQ),_=>N}_=>N}_=>N}b'h'=>match r[7]{b'2' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTTPH2DQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTTPH2QQ),_=>N}b'u'=>match r[9]{b'w'=>s(VCVTTPH2UW),_=>N}_=>N}_=>N}b's'=>match r[7]{b'2' => match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTTPS2DQ),_=>N}b'q'=>match r[9]{b'q'=>s(VCVTTPS2QQ),_=>N}_=>N}_=>N}_=>N}b's' => match r[6]{b'd'=>match r[7]{b'2'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTTSD2SI),_=>N}_=>N}_=>N}b'h'=>match r[7]{b'2'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTTSH2SI),_=>N}_=>N}_=>N}b's'=>match r[7]{b'2'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}b'u' => match r[5]{b'd'=>match r[6]{b'q'=>match r[7]{b'2'=>match r[8]{b'p' => match r[9]{b'd'=>s(VCVTUDQ2PD),b'h'=>s(VCVTUDQ2PH),b's'=>s(VCVTUDQ2PS),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[6]{b'q'=>match r[7]{b'2'=>match r[8]{b'p' => match r[9]{b'd'=>s(VCVTUQQ2PD),b'h'=>s(VCVTUQQ2PH),b's'=>s(VCVTUQQ2PS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[6]{b'i'=>match r[7]{b'2'=>match r[8]{b's' => match r[9]{b'd'=>s(VCVTUSI2SD),b'h'=>s(VCVTUSI2SH),b's'=>s(VCVTUSI2SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>match r[5]{b'a'=>match r[6]{b'c'=>match r[7]{b't'=>match r[8]{b'p'=>match r[9]{b's'=>s(VEXTRACTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'a'=>match r[3]{b's'=>match r[4]{b'k'=>match r[5]{b'm'=>match r[6]{b'o'=>match r[7]{b'v'=>match r[8]{b'p' => match r[9]{b'd'=>s(VMASKMOVPD),b's'=>s(VMASKMOVPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b'q'=>match r[4]{b'r'=>match r[5]{b't' => match r[6]{b'1'=>match r[7]{b'4' => match r[8]{b'p' => match r[9]{b'd'=>s(VRSQRT14PD),b's'=>s(VRSQRT14PS),_=>N}b's' => match r[9]{b'd'=>s(VRSQRT14SD),b's'=>s(VRSQRT14SS),_=>N}_=>N}_=>N}b'2'=>match r[7]{b'8' => match r[8]{b'p' => match r[9]{b'd'=>s(VRSQRT28PD),b's'=>s(VRSQRT28PS),_=>N}b's' => match r[9]{b'd'=>s(VRSQRT28SD),b's'=>s(VRSQRT28SS),_=>N}_=>N}_=>N}b'b'=>match r[7]{b'f'=>match r[8]{b'1'=>match r[9]{b'6'=>s(VRSQRTBF16),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f' => match r[5]{b'f' => match r[6]{b'3'=>match r[7]{b'2'=>match r[8]{b'x'=>match r[9]{b'4'=>s(VSHUFF32X4),_=>N}_=>N}_=>N}b'6'=>match r[7]{b'4'=>match r[8]{b'x'=>match r[9]{b'2'=>s(VSHUFF64X2),_=>N}_=>N}_=>N}_=>N}b'i' => match r[6]{b'3'=>match r[7]{b'2'=>match r[8]{b'x'=>match r[9]{b'4'=>s(VSHUFI32X4),_=>N}_=>N}_=>N}b'6'=>match r[7]{b'4'=>match r[8]{b'x'=>match r[9]{b'2'=>s(VSHUFI64X2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'z'=>match r[2]{b'e'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'u'=>match r[6]{b'p'=>match r[7]{b'p'=>match r[8]{b'e'=>match r[9]{b'r'=>s(VZEROUPPER),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'd'=>match r[7]{b'c' => match r[8]{b'p'=>match r[9]{b'h'=>s(VFCMADDCPH),_=>N}b's'=>match r[9]{b'h'=>s(VFCMADDCSH),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[3]{b'c'=>match r[4]{b'l'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b's' => match r[8]{b'p' => match r[9]{b'd'=>s(VFPCLASSPD),b'h'=>s(VFPCLASSPH),b's'=>s(VFPCLASSPS),_=>N}b's' => match r[9]{b'd'=>s(VFPCLASSSD),b'h'=>s(VFPCLASSSH),b's'=>s(VFPCLASSSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g' => match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'h'=>match r[5]{b'e'=>match r[6]{b'r' => match r[7]{b'd'=>match r[8]{b'p' => match r[9]{b'd'=>s(VGATHERDPD),b's'=>s(VGATHERDPS),_=>N}_=>N}b'q'=>match r[8]{b'p' => match r[9]{b'd'=>s(VGATHERQPD),b's'=>s(VGATHERQPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[3]{b't'=>match r[4]{b'm'=>match r[5]{b'a'=>match r[6]{b'n'=>match r[7]{b't' => match r[8]{b'p' => match r[9]{b'd'=>s(VGETMANTPD),b'h'=>s(VGETMANTPH),b's'=>s(VGETMANTPS),_=>N}b's' => match r[9]{b'd'=>s(VGETMANTSD),b'h'=>s(VGETMANTSH),b's'=>s(VGETMANTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[3]{b'2'=>match r[4]{b'p'=>match r[5]{b'8'=>match r[6]{b'm'=>match r[7]{b'u'=>match r[8]{b'l'=>match r[9]{b'b'=>s(VGF2P8MULB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'4'=>match r[3]{b'd'=>match r[4]{b'p'=>match r[5]{b'w'=>match r[6]{b's'=>match r[7]{b's'=>match r[8]{b'd'=>match r[9]{b's'=>s(VP4DPWSSDS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[3]{b'r'=>match r[4]{b'm'=>match r[5]{b'2' => match r[6]{b'f'=>match r[7]{b'1'=>match r[8]{b'2'=>match r[9]{b'8'=>s(VPERM2F128),_=>N}_=>N}_=>N}b'i'=>match r[7]{b'1'=>match r[8]{b'2'=>match r[9]{b'8'=>s(VPERM2I128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b'a'=>match r[4]{b't'=>match r[5]{b'h'=>match r[6]{b'e'=>match r[7]{b'r' => match r[8]{b'd' => match r[9]{b'd'=>s(VPGATHERDD),b'q'=>s(VPGATHERDQ),_=>N}b'q' => match r[9]{b'd'=>s(VPGATHERQD),b'q'=>s(VPGATHERQQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'a' => match r[4]{b'c'=>match r[5]{b's'=>match r[6]{b's'=>match r[7]{b'd'=>match r[8]{b'q' => match r[9]{b'h'=>s(VPMACSSDQH),b'l'=>s(VPMACSSDQL),_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[5]{b'c'=>match r[6]{b's'=>match r[7]{b's'=>match r[8]{b'w'=>match r[9]{b'd'=>s(VPMADCSSWD),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[5]{b'k'=>match r[6]{b'm'=>match r[7]{b'o'=>match r[8]{b'v' => match r[9]{b'd'=>s(VPMASKMOVD),b'q'=>s(VPMASKMOVQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b'r'=>match r[5]{b'n'=>match r[6]{b'l'=>match r[7]{b'o'=>match r[8]{b'g' => match r[9]{b'd'=>s(VPTERNLOGD),b'q'=>s(VPTERNLOGQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'n'=>match r[4]{b'p'=>match r[5]{b'c'=>match r[6]{b'k' => match r[7]{b'h' => match r[8]{b'b'=>match r[9]{b'w'=>s(VPUNPCKHBW),_=>N}b'd'=>match r[9]{b'q'=>s(VPUNPCKHDQ),_=>N}b'w'=>match r[9]{b'd'=>s(VPUNPCKHWD),_=>N}_=>N}b'l' => match r[8]{b'b'=>match r[9]{b'w'=>s(VPUNPCKLBW),_=>N}b'd'=>match r[9]{b'q'=>s(VPUNPCKLDQ),_=>N}b'w'=>match r[9]{b'd'=>s(VPUNPCKLWD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[3]{b'l'=>match r[4]{b'm'=>match r[5]{b'u'=>match r[6]{b'l'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(VPCLMULQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[4]{b'p' => match r[5]{b'e'=>match r[6]{b's'=>match r[7]{b't'=>match r[8]{b'r' => match r[9]{b'i'=>s(VPCMPESTRI),b'm'=>s(VPCMPESTRM),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[6]{b's'=>match r[7]{b't'=>match r[8]{b'r' => match r[9]{b'i'=>s(VPCMPISTRI),b'm'=>s(VPCMPISTRM),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}11=>match r[0]{b'p'=>match r[1]{b'r'=>match r[2]{b'e'=>match r[3]{b'f'=>match r[4]{b'e'=>match r[5]{b't'=>match r[6]{b'c'=>match r[7]{b'h' => match r[8]{b'i'=>match r[9]{b't' => match r[10]{b'0'=>s(PREFETCHIT0),b'1'=>s(PREFETCHIT1),_=>N}_=>N}b'w'=>match r[9]{b't'=>match r[10]{b'1'=>s(PREFETCHWT1),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[1]{b'i'=>match r[2]{b'l'=>match r[3]{b'e' => match r[4]{b'l'=>match r[5]{b'o'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>match r[9]{b't'=>match r[10]{b'1'=>s(TILELOADDT1),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[5]{b'e'=>match r[6]{b'l'=>match r[7]{b'e'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b'e'=>s(TILERELEASE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p'=>match r[4]{b'n' => match r[5]{b'a'=>match r[6]{b'e'=>match r[7]{b'x'=>match r[8]{b'a'=>match r[9]{b'd'=>match r[10]{b'd'=>s(ACMPNAEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[6]{b'e'=>match r[7]{b'x'=>match r[8]{b'a'=>match r[9]{b'd'=>match r[10]{b'd'=>s(ACMPNBEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[6]{b'e'=>match r[7]{b'x'=>match r[8]{b'a'=>match r[9]{b'd'=>match r[10]{b'd'=>s(ACMPNGEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[6]{b'e'=>match r[7]{b'x'=>match r[8]{b'a'=>match r[9]{b'd'=>match r[10]{b'd'=>s(ACMPNLEXADD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c' => match r[6]{b'1'=>match r[7]{b'2'=>match r[8]{b'8'=>match r[9]{b'k'=>match r[10]{b'l'=>s(AESDEC128KL),_=>N}_=>N}_=>N}_=>N}b'2'=>match r[7]{b'5'=>match r[8]{b'6'=>match r[9]{b'k'=>match r[10]{b'l'=>s(AESDEC256KL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c' => match r[6]{b'1'=>match r[7]{b'2'=>match r[8]{b'8'=>match r[9]{b'k'=>match r[10]{b'l'=>s(AESENC128KL),_=>N}_=>N}_=>N}_=>N}b'2'=>match r[7]{b'5'=>match r[8]{b'6'=>match r[9]{b'k'=>match r[10]{b'l'=>s(AESENC256KL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'i'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b's'=>match r[6]{b't'=>match r[7]{b'o'=>match r[8]{b'r'=>match r[9]{b'e'=>match r[10]{b'd'=>s(ATILESTORED),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'a'=>match r[2]{b'v'=>match r[3]{b'e'=>match r[4]{b'p'=>match r[5]{b'r'=>match r[6]{b'e'=>match r[7]{b'v'=>match r[8]{b's'=>match r[9]{b's'=>match r[10]{b'p'=>s(SAVEPREVSSP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'a'=>match r[3]{b'2'=>match r[4]{b'5'=>match r[5]{b'6'=>match r[6]{b'r'=>match r[7]{b'n'=>match r[8]{b'd'=>match r[9]{b's'=>match r[10]{b'2'=>s(SHA256RNDS2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'a'=>match r[2]{b'e'=>match r[3]{b's' => match r[4]{b'd'=>match r[5]{b'e'=>match r[6]{b'c'=>match r[7]{b'l'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't'=>s(VAESDECLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[5]{b'n'=>match r[6]{b'c'=>match r[7]{b'l'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't'=>s(VAESENCLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'r'=>match r[6]{b't' => match r[7]{b'f'=>match r[8]{b'1'=>match r[9]{b'2'=>match r[10]{b'8'=>s(VINSERTF128),_=>N}_=>N}_=>N}b'i'=>match r[8]{b'1'=>match r[9]{b'2'=>match r[10]{b'8'=>s(VINSERTI128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'i'=>match r[3]{b'n'=>match r[4]{b'm'=>match r[5]{b'a'=>match r[6]{b'x'=>match r[7]{b'b'=>match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'6'=>s(VMINMAXBF16),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b's'=>match r[5]{b'c'=>match r[6]{b'a'=>match r[7]{b'l'=>match r[8]{b'e' => match r[9]{b'p' => match r[10]{b'd'=>s(VRNDSCALEPD),b'h'=>s(VRNDSCALEPH),b's'=>s(VRNDSCALEPS),_=>N}b's' => match r[10]{b'd'=>s(VRNDSCALESD),b'h'=>s(VRNDSCALESH),b's'=>s(VRNDSCALESS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'o'=>match r[3]{b'm'=>match r[4]{b'p'=>match r[5]{b'r'=>match r[6]{b'e'=>match r[7]{b's'=>match r[8]{b's'=>match r[9]{b'p' => match r[10]{b'd'=>s(VCOMPRESSPD),b's'=>s(VCOMPRESSPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v'=>match r[3]{b't' => match r[4]{b'p' => match r[5]{b'h'=>match r[6]{b'2'=>match r[7]{b'i'=>match r[8]{b'u'=>match r[9]{b'b'=>match r[10]{b's'=>s(VCVTPH2IUBS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[6]{b'2'=>match r[7]{b'i'=>match r[8]{b'u'=>match r[9]{b'b'=>match r[10]{b's'=>s(VCVTPS2IUBS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't' => match r[5]{b'p' => match r[6]{b'd'=>match r[7]{b'2'=>match r[8]{b'u' => match r[9]{b'd'=>match r[10]{b'q'=>s(VCVTTPD2UDQ),_=>N}b'q'=>match r[10]{b'q'=>s(VCVTTPD2UQQ),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[7]{b'2' => match r[8]{b'i'=>match r[9]{b'b'=>match r[10]{b's'=>s(VCVTTPH2IBS),_=>N}_=>N}b'u' => match r[9]{b'd'=>match r[10]{b'q'=>s(VCVTTPH2UDQ),_=>N}b'q'=>match r[10]{b'q'=>s(VCVTTPH2UQQ),_=>N}_=>N}_=>N}_=>N}b's'=>match r[7]{b'2' => match r[8]{b'i'=>match r[9]{b'b'=>match r[10]{b's'=>s(VCVTTPS2IBS),_=>N}_=>N}b'u' => match r[9]{b'd'=>match r[10]{b'q'=>s(VCVTTPS2UDQ),_=>N}b'q'=>match r[10]{b'q'=>s(VCVTTPS2UQQ),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[6]{b'd'=>match r[7]{b'2'=>match r[8]{b'u'=>match r[9]{b's'=>match r[10]{b'i'=>s(VCVTTSD2USI),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[7]{b'2'=>match r[8]{b'u'=>match r[9]{b's'=>match r[10]{b'i'=>s(VCVTTSH2USI),_=>N}_=>N}_=>N}_=>N}b's'=>match r[7]{b'2'=>match r[8]{b'u'=>match r[9]{b's'=>match r[10]{b'i'=>s(VCVTTSS2USI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[2]{b'i'=>match r[3]{b'x'=>match r[4]{b'u'=>match r[5]{b'p'=>match r[6]{b'i'=>match r[7]{b'm'=>match r[8]{b'm' => match r[9]{b'p' => match r[10]{b'd'=>s(VFIXUPIMMPD),b's'=>s(VFIXUPIMMPS),_=>N}b's' => match r[10]{b'd'=>s(VFIXUPIMMSD),b's'=>s(VFIXUPIMMSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd' => match r[6]{b'1'=>match r[7]{b'3'=>match r[8]{b'2' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD132PD),b'h'=>s(VFMADD132PH),b's'=>s(VFMADD132PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD132SD),b'h'=>s(VFMADD132SH),b's'=>s(VFMADD132SS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[7]{b'u'=>match r[8]{b'b'=>match r[9]{b'p' => match r[10]{b'd'=>s(VFMADDSUBPD),b's'=>s(VFMADDSUBPS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[7]{b'1'=>match r[8]{b'3' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD213PD),b'h'=>s(VFMADD213PH),b's'=>s(VFMADD213PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD213SD),b'h'=>s(VFMADD213SH),b's'=>s(VFMADD213SS),_=>N}_=>N}_=>N}b'3'=>match r[8]{b'1' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD231PD),b'h'=>s(VFMADD231PH),b's'=>s(VFMADD231PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD231SD),b'h'=>s(VFMADD231SH),b's'=>s(VFMADD231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b' => match r[6]{b'1'=>match r[7]{b'3'=>match r[8]{b'2' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB132PD),b'h'=>s(VFMSUB132PH),b's'=>s(VFMSUB132PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB132SD),b'h'=>s(VFMSUB132SH),b's'=>s(VFMSUB132SS),_=>N}_=>N}_=>N}_=>N}b'a'=>match r[7]{b'd'=>match r[8]{b'd'=>match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUBADDPD),b's'=>s(VFMSUBADDPS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[7]{b'1'=>match r[8]{b'3' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB213PD),b'h'=>s(VFMSUB213PH),b's'=>s(VFMSUB213PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB213SD),b'h'=>s(VFMSUB213SH),b's'=>s(VFMSUB213SS),_=>N}_=>N}_=>N}b'3'=>match r[8]{b'1' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB231PD),b'h'=>s(VFMSUB231PH),b's'=>s(VFMSUB231PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB231SD),b'h'=>s(VFMSUB231SH),b's'=>s(VFMSUB231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'c'=>match r[3]{b'o' => match r[4]{b'm'=>match r[5]{b'p'=>match r[6]{b'r'=>match r[7]{b'e'=>match r[8]{b's'=>match r[9]{b's' => match r[10]{b'b'=>s(VPCOMPRESSB),b'd'=>s(VPCOMPRESSD),b'q'=>s(VPCOMPRESSQ),b'w'=>s(VPCOMPRESSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[5]{b'f'=>match r[6]{b'l'=>match r[7]{b'i'=>match r[8]{b'c'=>match r[9]{b't' => match r[10]{b'd'=>s(VPCONFLICTD),b'q'=>s(VPCONFLICTQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[3]{b'm'=>match r[4]{b'i'=>match r[5]{b'n'=>match r[6]{b'p'=>match r[7]{b'o'=>match r[8]{b's'=>match r[9]{b'u'=>match r[10]{b'w'=>s(VPHMINPOSUW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'5'=>match r[7]{b'2' => match r[8]{b'h'=>match r[9]{b'u'=>match r[10]{b'q'=>s(VPMADD52HUQ),_=>N}_=>N}b'l'=>match r[9]{b'u'=>match r[10]{b'q'=>s(VPMADD52LUQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'c'=>match r[4]{b'a'=>match r[5]{b't'=>match r[6]{b't'=>match r[7]{b'e'=>match r[8]{b'r' => match r[9]{b'd' => match r[10]{b'd'=>s(VPSCATTERDD),b'q'=>s(VPSCATTERDQ),_=>N}b'q' => match r[10]{b'd'=>s(VPSCATTERQD),b'q'=>s(VPSCATTERQQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'c'=>match r[3]{b'a' => match r[4]{b'l'=>match r[5]{b'e'=>match r[6]{b'f'=>match r[7]{b'b'=>match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'6'=>s(VSCALEFBF16),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[5]{b't'=>match r[6]{b'e'=>match r[7]{b'r' => match r[8]{b'd'=>match r[9]{b'p' => match r[10]{b'd'=>s(VSCATTERDPD),b's'=>s(VSCATTERDPS),_=>N}_=>N}b'q'=>match r[9]{b'p' => match r[10]{b'd'=>s(VSCATTERQPD),b's'=>s(VSCATTERQPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[3]{b'a'=>match r[4]{b'5'=>match r[5]{b'1'=>match r[6]{b'2'=>match r[7]{b'm'=>match r[8]{b's'=>match r[9]{b'g' => match r[10]{b'1'=>s(VSHA512MSG1),b'2'=>s(VSHA512MSG2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}12=>match r[0]{b'a'=>match r[1]{b't'=>match r[2]{b'i'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'l'=>match r[6]{b'o'=>match r[7]{b'a'=>match r[8]{b'd'=>match r[9]{b'd'=>match r[10]{b't'=>match r[11]{b'1'=>s(ATILELOADDT1),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[1]{b'n'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'd'=>match r[5]{b'e'=>match r[6]{b'k'=>match r[7]{b'e'=>match r[8]{b'y' => match r[9]{b'1'=>match r[10]{b'2'=>match r[11]{b'8'=>s(ENCODEKEY128),_=>N}_=>N}b'2'=>match r[10]{b'5'=>match r[11]{b'6'=>s(ENCODEKEY256),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'v'=>match r[3]{b't' => match r[4]{b'b'=>match r[5]{b'f'=>match r[6]{b'1'=>match r[7]{b'6'=>match r[8]{b'2'=>match r[9]{b'i'=>match r[10]{b'b'=>match r[11]{b's'=>s(VCVTBF162IBS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[5]{b'e' => match r[6]{b'e'=>match r[7]{b'p'=>match r[8]{b'h'=>match r[9]{b'2'=>match r[10]{b'p'=>match r[11]{b's'=>s(VCVTNEEPH2PS),_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[7]{b'p'=>match r[8]{b'h'=>match r[9]{b'2'=>match r[10]{b'p'=>match r[11]{b's'=>s(VCVTNEOPH2PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[5]{b'p' => match r[6]{b'h'=>match r[7]{b'2'=>match r[8]{b'i'=>match r[9]{b'u'=>match r[10]{b'b'=>match r[11]{b's'=>s(VCVTTPH2IUBS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[7]{b'2'=>match r[8]{b'i'=>match r[9]{b'u'=>match r[10]{b'b'=>match r[11]{b's'=>s(VCVTTPS2IUBS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>match r[5]{b'a'=>match r[6]{b'c'=>match r[7]{b't' => match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'2'=>match r[11]{b'8'=>s(VEXTRACTF128),_=>N}_=>N}_=>N}b'i'=>match r[9]{b'1'=>match r[10]{b'2'=>match r[11]{b'8'=>s(VEXTRACTI128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'n'=>match r[3]{b'm' => match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'd' => match r[7]{b'1'=>match r[8]{b'3'=>match r[9]{b'2' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD132PD),b'h'=>s(VFNMADD132PH),b's'=>s(VFNMADD132PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD132SD),b'h'=>s(VFNMADD132SH),b's'=>s(VFNMADD132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[8]{b'1'=>match r[9]{b'3' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD213PD),b'h'=>s(VFNMADD213PH),b's'=>s(VFNMADD213PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD213SD),b'h'=>s(VFNMADD213SH),b's'=>s(VFNMADD213SS),_=>N}_=>N}_=>N}b'3'=>match r[9]{b'1' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD231PD),b'h'=>s(VFNMADD231PH),b's'=>s(VFNMADD231PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD231SD),b'h'=>s(VFNMADD231SH),b's'=>s(VFNMADD231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[5]{b'u'=>match r[6]{b'b' => match r[7]{b'1'=>match r[8]{b'3'=>match r[9]{b'2' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB132PD),b'h'=>s(VFNMSUB132PH),b's'=>s(VFNMSUB132PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB132SD),b'h'=>s(VFNMSUB132SH),b's'=>s(VFNMSUB132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[8]{b'1'=>match r[9]{b'3' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB213PD),b'h'=>s(VFNMSUB213PH),b's'=>s(VFNMSUB213PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB213SD),b'h'=>s(VFNMSUB213SH),b's'=>s(VFNMSUB213SS),_=>N}_=>N}_=>N}b'3'=>match r[9]{b'1' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB231PD),b'h'=>s(VFNMSUB231PH),b's'=>s(VFNMSUB231PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB231SD),b'h'=>s(VFNMSUB231SH),b's'=>s(VFNMSUB231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'r'=>match r[6]{b't' => match r[7]{b'f' => match r[8]{b'3'=>match r[9]{b'2'=>match r[10]{b'x' => match r[11]{b'4'=>s(VINSERTF32X4),b'8'=>s(VINSERTF32X8),_=>N}_=>N}_=>N}b'6'=>match r[9]{b'4'=>match r[10]{b'x' => match r[11]{b'2'=>s(VINSERTF64X2),b'4'=>s(VINSERTF64X4),_=>N}_=>N}_=>N}_=>N}b'i' => match r[8]{b'3'=>match r[9]{b'2'=>match r[10]{b'x' => match r[11]{b'4'=>s(VINSERTI32X4),b'8'=>s(VINSERTI32X8),_=>N}_=>N}_=>N}b'6'=>match r[9]{b'4'=>match r[10]{b'x' => match r[11]{b'2'=>s(VINSERTI64X2),b'4'=>s(VINSERTI64X4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'h'=>match r[3]{b'a'=>match r[4]{b'5'=>match r[5]{b'1'=>match r[6]{b'2'=>match r[7]{b'r'=>match r[8]{b'n'=>match r[9]{b'd'=>match r[10]{b's'=>match r[11]{b'2'=>s(VSHA512RNDS2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b' => match r[2]{b'c'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'n'=>match r[6]{b'e'=>match r[7]{b's'=>match r[8]{b'h'=>match r[9]{b'2'=>match r[10]{b'p'=>match r[11]{b's'=>s(VBCSTNESH2PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'c'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>match r[10]{b's' => match r[11]{b'd'=>s(VBROADCASTSD),b's'=>s(VBROADCASTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'b'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'c'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't' => match r[11]{b'b'=>s(VPBROADCASTB),b'd'=>s(VPBROADCASTD),b'q'=>s(VPBROADCASTQ),b'w'=>s(VPBROADCASTW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'h'=>match r[4]{b'u'=>match r[5]{b'f'=>match r[6]{b'b'=>match r[7]{b'i'=>match r[8]{b't'=>match r[9]{b'q'=>match r[10]{b'm'=>match r[11]{b'b'=>s(VPSHUFBITQMB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}13=>match r[0]{b'g'=>match r[1]{b'f'=>match r[2]{b'2'=>match r[3]{b'p'=>match r[4]{b'8'=>match r[5]{b'a'=>match r[6]{b'f'=>match r[7]{b'f'=>match r[8]{b'i'=>match r[9]{b'n'=>match r[10]{b'e'=>match r[11]{b'q'=>match r[12]{b'b'=>s(GF2P8AFFINEQB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'v'=>match r[3]{b't' => match r[4]{b'b'=>match r[5]{b'f'=>match r[6]{b'1'=>match r[7]{b'6'=>match r[8]{b'2'=>match r[9]{b'i'=>match r[10]{b'u'=>match r[11]{b'b'=>match r[12]{b's'=>s(VCVTBF162IUBS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[5]{b'e'=>match r[6]{b'p'=>match r[7]{b's'=>match r[8]{b'2'=>match r[9]{b'b'=>match r[10]{b'f'=>match r[11]{b'1'=>match r[12]{b'6'=>s(VCVTNEPS2BF16),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[5]{b'b'=>match r[6]{b'f'=>match r[7]{b'1'=>match r[8]{b'6'=>match r[9]{b'2'=>match r[10]{b'i'=>match r[11]{b'b'=>match r[12]{b's'=>s(VCVTTBF162IBS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>match r[5]{b'a'=>match r[6]{b'c'=>match r[7]{b't' => match r[8]{b'f' => match r[9]{b'3'=>match r[10]{b'2'=>match r[11]{b'x' => match r[12]{b'4'=>s(VEXTRACTF32X4),b'8'=>s(VEXTRACTF32X8),_=>N}_=>N}_=>N}b'6'=>match r[10]{b'4'=>match r[11]{b'x' => match r[12]{b'2'=>s(VEXTRACTF64X2),b'4'=>s(VEXTRACTF64X4),_=>N}_=>N}_=>N}_=>N}b'i' => match r[9]{b'3'=>match r[10]{b'2'=>match r[11]{b'x' => match r[12]{b'4'=>s(VEXTRACTI32X4),b'8'=>s(VEXTRACTI32X8),_=>N}_=>N}_=>N}b'6'=>match r[10]{b'4'=>match r[11]{b'x' => match r[12]{b'2'=>s(VEXTRACTI64X2),b'4'=>s(VEXTRACTI64X4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'h'=>match r[5]{b'e'=>match r[6]{b'r'=>match r[7]{b'p'=>match r[8]{b'f' => match r[9]{b'0' => match r[10]{b'd'=>match r[11]{b'p' => match r[12]{b'd'=>s(VGATHERPF0DPD),b's'=>s(VGATHERPF0DPS),_=>N}_=>N}b'q'=>match r[11]{b'p' => match r[12]{b'd'=>s(VGATHERPF0QPD),b's'=>s(VGATHERPF0QPS),_=>N}_=>N}_=>N}b'1' => match r[10]{b'd'=>match r[11]{b'p' => match r[12]{b'd'=>s(VGATHERPF1DPD),b's'=>s(VGATHERPF1DPS),_=>N}_=>N}b'q'=>match r[11]{b'p' => match r[12]{b'd'=>s(VGATHERPF1QPD),b's'=>s(VGATHERPF1QPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'2'=>match r[3]{b'i'=>match r[4]{b'n'=>match r[5]{b't'=>match r[6]{b'e'=>match r[7]{b'r'=>match r[8]{b's'=>match r[9]{b'e'=>match r[10]{b'c'=>match r[11]{b't' => match r[12]{b'd'=>s(VP2INTERSECTD),b'q'=>s(VP2INTERSECTQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}14=>match r[0]{b'v' => match r[1]{b'c'=>match r[2]{b'v'=>match r[3]{b't' => match r[4]{b'n'=>match r[5]{b'e' => match r[6]{b'2'=>match r[7]{b'p'=>match r[8]{b's'=>match r[9]{b'2'=>match r[10]{b'b'=>match r[11]{b'f'=>match r[12]{b'1'=>match r[13]{b'6'=>s(VCVTNE2PS2BF16),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[7]{b'b'=>match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'6'=>match r[11]{b'2'=>match r[12]{b'p'=>match r[13]{b's'=>s(VCVTNEEBF162PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'o'=>match r[7]{b'b'=>match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'6'=>match r[11]{b'2'=>match r[12]{b'p'=>match r[13]{b's'=>s(VCVTNEOBF162PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[5]{b'b'=>match r[6]{b'f'=>match r[7]{b'1'=>match r[8]{b'6'=>match r[9]{b'2'=>match r[10]{b'i'=>match r[11]{b'u'=>match r[12]{b'b'=>match r[13]{b's'=>s(VCVTTBF162IUBS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b's'=>match r[7]{b'u'=>match r[8]{b'b' => match r[9]{b'1'=>match r[10]{b'3'=>match r[11]{b'2'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB132PD),b'h'=>s(VFMADDSUB132PH),b's'=>s(VFMADDSUB132PS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[10]{b'1'=>match r[11]{b'3'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB213PD),b'h'=>s(VFMADDSUB213PH),b's'=>s(VFMADDSUB213PS),_=>N}_=>N}_=>N}b'3'=>match r[11]{b'1'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB231PD),b'h'=>s(VFMADDSUB231PH),b's'=>s(VFMADDSUB231PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd' => match r[9]{b'1'=>match r[10]{b'3'=>match r[11]{b'2'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD132PD),b'h'=>s(VFMSUBADD132PH),b's'=>s(VFMSUBADD132PS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[10]{b'1'=>match r[11]{b'3'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD213PD),b'h'=>s(VFMSUBADD213PH),b's'=>s(VFMSUBADD213PS),_=>N}_=>N}_=>N}b'3'=>match r[11]{b'1'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD231PD),b'h'=>s(VFMSUBADD231PH),b's'=>s(VFMSUBADD231PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[2]{b'f'=>match r[3]{b'2'=>match r[4]{b'p'=>match r[5]{b'8'=>match r[6]{b'a'=>match r[7]{b'f'=>match r[8]{b'f'=>match r[9]{b'i'=>match r[10]{b'n'=>match r[11]{b'e'=>match r[12]{b'q'=>match r[13]{b'b'=>s(VGF2P8AFFINEQB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>match r[5]{b't'=>match r[6]{b'i'=>match r[7]{b's'=>match r[8]{b'h'=>match r[9]{b'i'=>match r[10]{b'f'=>match r[11]{b't'=>match r[12]{b'q'=>match r[13]{b'b'=>s(VPMULTISHIFTQB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'c'=>match r[3]{b'a'=>match r[4]{b't'=>match r[5]{b't'=>match r[6]{b'e'=>match r[7]{b'r'=>match r[8]{b'p'=>match r[9]{b'f' => match r[10]{b'0' => match r[11]{b'd'=>match r[12]{b'p' => match r[13]{b'd'=>s(VSCATTERPF0DPD),b's'=>s(VSCATTERPF0DPS),_=>N}_=>N}b'q'=>match r[12]{b'p' => match r[13]{b'd'=>s(VSCATTERPF0QPD),b's'=>s(VSCATTERPF0QPS),_=>N}_=>N}_=>N}b'1' => match r[11]{b'd'=>match r[12]{b'p' => match r[13]{b'd'=>s(VSCATTERPF1DPD),b's'=>s(VSCATTERPF1DPS),_=>N}_=>N}b'q'=>match r[12]{b'p' => match r[13]{b'd'=>s(VSCATTERPF1QPD),b's'=>s(VSCATTERPF1QPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b' => match r[2]{b'c'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'n'=>match r[6]{b'e'=>match r[7]{b'b'=>match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'6'=>match r[11]{b'2'=>match r[12]{b'p'=>match r[13]{b's'=>s(VBCSTNEBF162PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'c'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>match r[10]{b'f'=>match r[11]{b'1'=>match r[12]{b'2'=>match r[13]{b'8'=>s(VBROADCASTF128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}15=>match r[0]{b'a'=>match r[1]{b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c'=>match r[6]{b'w'=>match r[7]{b'i'=>match r[8]{b'd'=>match r[9]{b'e' => match r[10]{b'1'=>match r[11]{b'2'=>match r[12]{b'8'=>match r[13]{b'k'=>match r[14]{b'l'=>s(AESDECWIDE128KL),_=>N}_=>N}_=>N}_=>N}b'2'=>match r[11]{b'5'=>match r[12]{b'6'=>match r[13]{b'k'=>match r[14]{b'l'=>s(AESDECWIDE256KL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c'=>match r[6]{b'w'=>match r[7]{b'i'=>match r[8]{b'd'=>match r[9]{b'e' => match r[10]{b'1'=>match r[11]{b'2'=>match r[12]{b'8'=>match r[13]{b'k'=>match r[14]{b'l'=>s(AESENCWIDE128KL),_=>N}_=>N}_=>N}_=>N}b'2'=>match r[11]{b'5'=>match r[12]{b'6'=>match r[13]{b'k'=>match r[14]{b'l'=>s(AESENCWIDE256KL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'k'=>match r[4]{b'e'=>match r[5]{b'y'=>match r[6]{b'g'=>match r[7]{b'e'=>match r[8]{b'n'=>match r[9]{b'a'=>match r[10]{b's'=>match r[11]{b's'=>match r[12]{b'i'=>match r[13]{b's'=>match r[14]{b't'=>s(AESKEYGENASSIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'b'=>match r[2]{b'r'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'c'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>match r[10]{b'f' => match r[11]{b'3'=>match r[12]{b'2'=>match r[13]{b'x' => match r[14]{b'2'=>s(VBROADCASTF32X2),b'4'=>s(VBROADCASTF32X4),b'8'=>s(VBROADCASTF32X8),_=>N}_=>N}_=>N}b'6'=>match r[12]{b'4'=>match r[13]{b'x' => match r[14]{b'2'=>s(VBROADCASTF64X2),b'4'=>s(VBROADCASTF64X4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'b'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'c'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't' => match r[11]{b'i'=>match r[12]{b'1'=>match r[13]{b'2'=>match r[14]{b'8'=>s(VPBROADCASTI128),_=>N}_=>N}_=>N}b'm' => match r[12]{b'b'=>match r[13]{b'2'=>match r[14]{b'q'=>s(VPBROADCASTMB2Q),_=>N}_=>N}b'w'=>match r[13]{b'2'=>match r[14]{b'd'=>s(VPBROADCASTMW2D),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}16=>match r[0]{b'g'=>match r[1]{b'f'=>match r[2]{b'2'=>match r[3]{b'p'=>match r[4]{b'8'=>match r[5]{b'a'=>match r[6]{b'f'=>match r[7]{b'f'=>match r[8]{b'i'=>match r[9]{b'n'=>match r[10]{b'e'=>match r[11]{b'i'=>match r[12]{b'n'=>match r[13]{b'v'=>match r[14]{b'q'=>match r[15]{b'b'=>s(GF2P8AFFINEINVQB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'a'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'k'=>match r[5]{b'e'=>match r[6]{b'y'=>match r[7]{b'g'=>match r[8]{b'e'=>match r[9]{b'n'=>match r[10]{b'a'=>match r[11]{b's'=>match r[12]{b's'=>match r[13]{b'i'=>match r[14]{b's'=>match r[15]{b't'=>s(VAESKEYGENASSIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'b'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'c'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't'=>match r[11]{b'i' => match r[12]{b'3'=>match r[13]{b'2'=>match r[14]{b'x' => match r[15]{b'2'=>s(VPBROADCASTI32X2),b'4'=>s(VPBROADCASTI32X4),b'8'=>s(VPBROADCASTI32X8),_=>N}_=>N}_=>N}b'6'=>match r[13]{b'4'=>match r[14]{b'x' => match r[15]{b'2'=>s(VPBROADCASTI64X2),b'4'=>s(VPBROADCASTI64X4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}17=>match r[0]{b'v'=>match r[1]{b'g'=>match r[2]{b'f'=>match r[3]{b'2'=>match r[4]{b'p'=>match r[5]{b'8'=>match r[6]{b'a'=>match r[7]{b'f'=>match r[8]{b'f'=>match r[9]{b'i'=>match r[10]{b'n'=>match r[11]{b'e'=>match r[12]{b'i'=>match r[13]{b'n'=>match r[14]{b'v'=>match r[15]{b'q'=>match r[16]{b'b'=>s(VGF2P8AFFINEINVQB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_ =>N,
 	}
 }
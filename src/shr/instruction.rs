@@ -39,6 +39,13 @@ const FPFX_VEX: u16 = 0b0001;
 const FPFX_EVEX: u16 = 0b0010;
 const FPFX_APX: u16 = 0b0011;
 
+const HINT_DISP_MASK: u8 = 0b0000_0011;
+const HINT_DISP8: u8 = 0b0000_0001;
+const HINT_DISP32: u8 = 0b0000_0010;
+const HINT_VEX3: u8 = 0b0000_0100;
+const HINT_STORE: u8 = 0b0000_1000;
+const HINT_LOAD: u8 = 0b0001_0000;
+
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub enum IVariant {
     #[default]
@@ -136,6 +143,15 @@ pub struct Instruction<'a> {
     //      else:
     //          0000_0000_0000
     pub metadata: u16,
+    //  0b000L_SVDD (encoding-selection pseudo-prefixes):
+    //   - DD: displacement width:
+    //      0b00 - default (shortest)
+    //      0b01 - {disp8}
+    //      0b10 - {disp32}
+    //   - V: {vex3}
+    //   - S: {store}
+    //   - L: {load}
+    pub hints: u8,
 
     // I'm forced to re-add it again
     // I'll try to remove it later, but it stays for now
@@ -224,6 +240,14 @@ impl<'a> Instruction<'a> {
         }
     }
     #[inline(always)]
+    pub fn symbol_mut(&mut self, idx: usize) -> Option<&mut SymbolRef<'a>> {
+        if self.gett(idx) == SYM {
+            unsafe { Some(&mut self.operands[idx].sym) }
+        } else {
+            None
+        }
+    }
+    #[inline(always)]
     pub fn dst(&'a self) -> Option<Operand<'a>> {
         self.get(0)
     }
@@ -304,6 +328,48 @@ impl<'a> Instruction<'a> {
     }
     // metadata
     #[inline(always)]
+    pub const fn set_disp8(&mut self) {
+        self.hints = (self.hints & !HINT_DISP_MASK) | HINT_DISP8;
+    }
+    #[inline(always)]
+    pub const fn set_disp32(&mut self) {
+        self.hints = (self.hints & !HINT_DISP_MASK) | HINT_DISP32;
+    }
+    /// returns displacement size forced with `{disp8}` or `{disp32}`
+    #[inline(always)]
+    pub const fn forced_disp(&self) -> Option<Size> {
+        match self.hints & HINT_DISP_MASK {
+            HINT_DISP8 => Some(Size::Byte),
+            HINT_DISP32 => Some(Size::Dword),
+            _ => None,
+        }
+    }
+    #[inline(always)]
+    pub const fn set_vex3(&mut self) {
+        self.set_vex();
+        self.hints |= HINT_VEX3;
+    }
+    #[inline(always)]
+    pub const fn vex3(&self) -> bool {
+        self.hints & HINT_VEX3 == HINT_VEX3
+    }
+    #[inline(always)]
+    pub const fn set_store(&mut self) {
+        self.hints = (self.hints & !HINT_LOAD) | HINT_STORE;
+    }
+    #[inline(always)]
+    pub const fn store(&self) -> bool {
+        self.hints & HINT_STORE == HINT_STORE
+    }
+    #[inline(always)]
+    pub const fn set_load(&mut self) {
+        self.hints = (self.hints & !HINT_STORE) | HINT_LOAD;
+    }
+    #[inline(always)]
+    pub const fn load(&self) -> bool {
+        self.hints & HINT_LOAD == HINT_LOAD
+    }
+    #[inline(always)]
     pub const fn set_vex(&mut self) {
         self.set_fpfx(FPFX_VEX);
     }
@@ -591,14 +657,9 @@ impl<'a> Instruction<'a> {
                 }
             } else if MEM == self.gett(i) {
                 let mem = unsafe { self.get_as_mem(i) };
-                if let Some(base) = mem.base() {
-                    if base.ebits()[0] {
-                        return true;
-                    }
-                } else if let Some(idx) = mem.index() {
-                    if idx.ebits()[0] {
-                        return true;
-                    }
+                // VSIB index (xmm16-31) is extended by EVEX.V', not by APX
+                if mem.base_evex() || (mem.index_evex() && !mem.is_vsib()) {
+                    return true;
                 }
             }
         }
@@ -879,6 +940,7 @@ impl Default for Instruction<'_> {
                 mnemonic: Mnemonic::__LAST,
                 operand_data: 0,
                 metadata: 0,
+                hints: 0,
                 additional: MaybeUninit::uninit(),
                 operands: MaybeUninit::uninit().assume_init_read(),
             }
@@ -890,6 +952,7 @@ impl Default for Instruction<'_> {
 mod tests {
     use super::*;
     #[test]
+    #[allow(clippy::identity_op, clippy::erasing_op)]
     fn tinstruction_0() {
         assert_eq!(0b111 << (4 << (0 * 3)) >> (0 * 3) >> 4, 0b111);
         let mut ins = Instruction::default();
@@ -905,7 +968,7 @@ mod tests {
         assert_eq!(ins.len(), 2);
         ins.set_fpfx(FPFX_EVEX);
         ins.set_fpfx(FPFX_EVEX);
-        assert_eq!(ins.is_evex(), true);
+        assert!(ins.is_evex());
         ins.set_evex_mask(1);
         assert_eq!(ins.evex_mask(), Some(1));
         ins.set_evex_z();
@@ -0,0 +1,665 @@
+// pasm - src/shr/isa.rs
+// ---------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+// ISA extensions (CPUID features) required by instructions, used by `cpu` directive
+// (and `--cpu` flag) to reject instructions, that target CPU does not support.
+
+use crate::shr::{error::Error, mnemonic::Mnemonic};
+
+#[repr(u8)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Isa {
+    // instructions supported by every x86-64 CPU (can't be disabled)
+    Base,
+
+    X87,
+    Cmov,
+    Cx8,
+    Fxsr,
+    Mmx,
+    Sse,
+    Sse2,
+    Sse3,
+    Ssse3,
+    Sse4_1,
+    Sse4_2,
+    Popcnt,
+    Cx16,
+    LahfSahf,
+    Monitor,
+
+    Avx,
+    Avx2,
+    Fma,
+    F16c,
+    Bmi1,
+    Bmi2,
+    Lzcnt,
+    Movbe,
+    Adx,
+    Xsave,
+    Xsaveopt,
+    Xsavec,
+    Xsaves,
+    Aes,
+    Vaes,
+    Pclmul,
+    Vpclmulqdq,
+    Sha,
+    Gfni,
+
+    Avx512f,
+    Avx512bw,
+    Avx512dq,
+    Avx512cd,
+    Avx512fp16,
+    Avx512bf16,
+    Avx512vbmi,
+    Avx512vbmi2,
+    Avx512ifma,
+    Avx512vnni,
+    Avx512bitalg,
+    Avx512vpopcntdq,
+    Avx512vp2intersect,
+    Avx512er,
+    Avx512pf,
+    Avx5124fmaps,
+    Avx5124vnniw,
+    AvxVnni,
+    AvxIfma,
+    AvxVnniInt8,
+    AvxVnniInt16,
+    AvxNeConvert,
+    Sha512,
+    Sm3,
+    Sm4,
+    Avx10_2,
+
+    ApxF,
+    AmxTile,
+    AmxInt8,
+    AmxBf16,
+    AmxFp16,
+
+    Xop,
+    Fma4,
+    Tbm,
+    Mwaitx,
+    Rdpru,
+    Invlpgb,
+
+    Rdrnd,
+    Rdseed,
+    Rdpid,
+    Rdtscp,
+    Fsgsbase,
+    Pku,
+    Shstk,
+    Rtm,
+    Smap,
+    Invpcid,
+    Clwb,
+    Cldemote,
+    Movdiri,
+    Movdir64b,
+    Enqcmd,
+    Serialize,
+    Tsxldtrk,
+    Uintr,
+    Waitpkg,
+    Hreset,
+    Prfchw,
+    Prefetchwt1,
+    Prefetchi,
+    Ptwrite,
+    Pconfig,
+    Pbndkb,
+    Kl,
+    Widekl,
+    Cmpccxadd,
+    Msrlist,
+    Wbnoinvd,
+    Sgx,
+    Svm,
+    Vmx,
+    Smx,
+}
+
+impl Isa {
+    #[rustfmt::skip]
+    pub const ALL: [Isa; 107] = {
+        use Isa::*;
+        [
+            Base, X87, Cmov, Cx8, Fxsr, Mmx, Sse, Sse2, Sse3, Ssse3, Sse4_1, Sse4_2, Popcnt,
+            Cx16, LahfSahf, Monitor, Avx, Avx2, Fma, F16c, Bmi1, Bmi2, Lzcnt, Movbe, Adx, Xsave,
+            Xsaveopt, Xsavec, Xsaves, Aes, Vaes, Pclmul, Vpclmulqdq, Sha, Gfni, Avx512f,
+            Avx512bw, Avx512dq, Avx512cd, Avx512fp16, Avx512bf16, Avx512vbmi, Avx512vbmi2,
+            Avx512ifma, Avx512vnni, Avx512bitalg, Avx512vpopcntdq, Avx512vp2intersect, Avx512er,
+            Avx512pf, Avx5124fmaps, Avx5124vnniw, AvxVnni, AvxIfma, AvxVnniInt8, AvxVnniInt16,
+            AvxNeConvert, Sha512, Sm3, Sm4, Avx10_2, ApxF, AmxTile, AmxInt8, AmxBf16, AmxFp16,
+            Xop, Fma4, Tbm, Mwaitx, Rdpru, Invlpgb, Rdrnd, Rdseed, Rdpid, Rdtscp, Fsgsbase, Pku,
+            Shstk, Rtm, Smap, Invpcid, Clwb, Cldemote, Movdiri, Movdir64b, Enqcmd, Serialize,
+            Tsxldtrk, Uintr, Waitpkg, Hreset, Prfchw, Prefetchwt1, Prefetchi, Ptwrite, Pconfig,
+            Pbndkb, Kl, Widekl, Cmpccxadd, Msrlist, Wbnoinvd, Sgx, Svm, Vmx, Smx,
+        ]
+    };
+
+    // names are the same as used by GCC and LLVM in `-m<name>`
+    #[rustfmt::skip]
+    pub const fn name(&self) -> &'static str {
+        use Isa::*;
+        match self {
+            Base => "base", X87 => "x87", Cmov => "cmov", Cx8 => "cx8", Fxsr => "fxsr",
+            Mmx => "mmx", Sse => "sse", Sse2 => "sse2", Sse3 => "sse3", Ssse3 => "ssse3",
+            Sse4_1 => "sse4.1", Sse4_2 => "sse4.2", Popcnt => "popcnt", Cx16 => "cx16",
+            LahfSahf => "sahf", Monitor => "monitor",
+            Avx => "avx", Avx2 => "avx2", Fma => "fma", F16c => "f16c", Bmi1 => "bmi",
+            Bmi2 => "bmi2", Lzcnt => "lzcnt", Movbe => "movbe", Adx => "adx", Xsave => "xsave",
+            Xsaveopt => "xsaveopt", Xsavec => "xsavec", Xsaves => "xsaves", Aes => "aes",
+            Vaes => "vaes", Pclmul => "pclmul", Vpclmulqdq => "vpclmulqdq", Sha => "sha",
+            Gfni => "gfni",
+            Avx512f => "avx512f", Avx512bw => "avx512bw", Avx512dq => "avx512dq",
+            Avx512cd => "avx512cd", Avx512fp16 => "avx512fp16", Avx512bf16 => "avx512bf16",
+            Avx512vbmi => "avx512vbmi", Avx512vbmi2 => "avx512vbmi2", Avx512ifma => "avx512ifma",
+            Avx512vnni => "avx512vnni", Avx512bitalg => "avx512bitalg",
+            Avx512vpopcntdq => "avx512vpopcntdq", Avx512vp2intersect => "avx512vp2intersect",
+            Avx512er => "avx512er", Avx512pf => "avx512pf", Avx5124fmaps => "avx5124fmaps",
+            Avx5124vnniw => "avx5124vnniw", AvxVnni => "avxvnni", AvxIfma => "avxifma",
+            AvxVnniInt8 => "avxvnniint8", AvxVnniInt16 => "avxvnniint16",
+            AvxNeConvert => "avxneconvert", Sha512 => "sha512", Sm3 => "sm3", Sm4 => "sm4",
+            Avx10_2 => "avx10.2",
+            ApxF => "apxf", AmxTile => "amx-tile", AmxInt8 => "amx-int8", AmxBf16 => "amx-bf16",
+            AmxFp16 => "amx-fp16",
+            Xop => "xop", Fma4 => "fma4", Tbm => "tbm", Mwaitx => "mwaitx", Rdpru => "rdpru",
+            Invlpgb => "invlpgb",
+            Rdrnd => "rdrnd", Rdseed => "rdseed", Rdpid => "rdpid", Rdtscp => "rdtscp",
+            Fsgsbase => "fsgsbase", Pku => "pku", Shstk => "shstk", Rtm => "rtm", Smap => "smap",
+            Invpcid => "invpcid", Clwb => "clwb", Cldemote => "cldemote", Movdiri => "movdiri",
+            Movdir64b => "movdir64b", Enqcmd => "enqcmd", Serialize => "serialize",
+            Tsxldtrk => "tsxldtrk", Uintr => "uintr", Waitpkg => "waitpkg", Hreset => "hreset",
+            Prfchw => "prfchw", Prefetchwt1 => "prefetchwt1", Prefetchi => "prefetchi",
+            Ptwrite => "ptwrite", Pconfig => "pconfig", Pbndkb => "pbndkb", Kl => "kl", Widekl => "widekl",
+            Cmpccxadd => "cmpccxadd", Msrlist => "msrlist", Wbnoinvd => "wbnoinvd", Sgx => "sgx",
+            Svm => "svm", Vmx => "vmx", Smx => "smx",
+        }
+    }
+    pub fn from_name(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|i| *i != Isa::Base && i.name().eq_ignore_ascii_case(s))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsaSet {
+    data: u128,
+}
+
+impl Default for IsaSet {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl IsaSet {
+    pub const fn all() -> Self {
+        Self { data: u128::MAX }
+    }
+    pub const fn new(isa: &[Isa]) -> Self {
+        let mut s = Self {
+            data: 1 << Isa::Base as u8,
+        };
+        let mut i = 0;
+        while i < isa.len() {
+            s.data |= 1 << isa[i] as u8;
+            i += 1;
+        }
+        s
+    }
+    pub const fn has(&self, isa: Isa) -> bool {
+        self.data & (1 << isa as u8) != 0
+    }
+    pub fn set(&mut self, isa: Isa, b: bool) {
+        if b {
+            self.data |= 1 << isa as u8;
+        } else if !matches!(isa, Isa::Base) {
+            self.data &= !(1 << isa as u8);
+        }
+    }
+    // microarchitecture levels (x86-64 psABI)
+    pub fn level(name: &str) -> Option<Self> {
+        use Isa::*;
+        const V1: [Isa; 7] = [X87, Cmov, Cx8, Fxsr, Mmx, Sse, Sse2];
+        const V2: [Isa; 7] = [Cx16, LahfSahf, Popcnt, Sse3, Sse4_1, Sse4_2, Ssse3];
+        const V3: [Isa; 9] = [Avx, Avx2, Bmi1, Bmi2, F16c, Fma, Lzcnt, Movbe, Xsave];
+        const V4: [Isa; 4] = [Avx512f, Avx512bw, Avx512cd, Avx512dq];
+        let n = match name.to_ascii_lowercase().as_str() {
+            "all" | "any" => return Some(Self::all()),
+            "x86-64" | "x86-64-v1" => 1,
+            "x86-64-v2" => 2,
+            "x86-64-v3" => 3,
+            "x86-64-v4" => 4,
+            _ => return None,
+        };
+        let mut s = Self::new(&V1);
+        for (l, isa) in [(2, &V2[..]), (3, &V3[..]), (4, &V4[..])] {
+            if n >= l {
+                for i in isa {
+                    s.set(*i, true);
+                }
+            }
+        }
+        Some(s)
+    }
+    // applies specification in format used by `cpu` directive (and `--cpu` flag):
+    // comma or whitespace separated list of levels (replace whole set), `+feature` and `-feature`
+    pub fn apply(&mut self, spec: &str) -> Result<(), Error> {
+        for item in spec.split([',', ' ', '\t']).filter(|s| !s.is_empty()) {
+            let (b, name) = if let Some(n) = item.strip_prefix('+') {
+                (true, n)
+            } else if let Some(n) = item.strip_prefix('-') {
+                (false, n)
+            } else if let Some(s) = Self::level(item) {
+                *self = s;
+                continue;
+            } else {
+                (true, item)
+            };
+            match Isa::from_name(name) {
+                Some(isa) => self.set(isa, b),
+                None => {
+                    return Err(Error::new(
+                        format!("unknown cpu level or feature `{item}`"),
+                        19,
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Mnemonic {
+    // returns ISA extension required by (base form of) instruction;
+    // forms requiring other extensions (EVEX, 256-bit integer AVX, APX) are handled in `pre::cpu`.
+    pub const fn isa(&self) -> Isa {
+        use Isa::*;
+        use Mnemonic::*;
+        match self {
+            CMOVA | CMOVB | CMOVC | CMOVE | CMOVG | CMOVL | CMOVO | CMOVP | CMOVS | CMOVZ
+            | CMOVAE | CMOVBE | CMOVGE | CMOVLE | CMOVNA | CMOVNB | CMOVNC | CMOVNE | CMOVNG
+            | CMOVNL | CMOVNO | CMOVNP | CMOVNS | CMOVNZ | CMOVPE | CMOVPO | CMOVNBE | CMOVNAE
+            | CMOVNGE | CMOVNLE => Cmov,
+            FCMOVB | FCMOVE | FCMOVBE | FCMOVU | FCMOVNB | FCMOVNE | FCMOVNBE | FCMOVNU | FCOMI
+            | FCOMIP | FUCOMI | FUCOMIP => Cmov,
+            CMPXCHG8B => Cx8,
+            CMPXCHG16B => Cx16,
+            LAHF | SAHF => LahfSahf,
+            FXSAVE | FXRSTOR | FXSAVE64 | FXRSTOR64 => Fxsr,
+            FISTTP => Sse3,
+            FNINIT | FLDCW | FNSTCW | FNSTSW | FNCLEX | FLDENV | FNSTENV | FNSAVE | FRSTOR
+            | FNENI | FNDISI | FINIT | FSTCW | FSTSW | FCLEX | FSTENV | FSAVE | FENI | FDISI
+            | FLD | FST | FSTP | FLDZ | FLD1 | FLDPI | FLDL2T | FLDL2E | FLDLG2 | FLDLN2 | FXCH
+            | FILD | FIST | FISTP | FBLD | FBSTP | FADD | FMUL | FSUB | FSUBR | FDIV | FDIVR
+            | FCOM | FADDP | FMULP | FSUBP | FSUBRP | FDIVP | FDIVRP | FCOMP | FCOMPP | FIADD
+            | FIMUL | FISUB | FIDIV | FIDIVR | FICOM | FICOMP | FCHS | FABS | FTST | FXAM
+            | FXTRACT | FPREM | FSQRT | FRNDINT | FSCALE | F2XM1 | FYL2X | FPTAN | FPATAN
+            | FYL2XP1 | FISUBR | FNOP | FDECSTP | FINCSTP | FFREE | FNSETPM | FNSTSWAX
+            | FSTSWAX | FUCOM | FUCOMP | FUCOMPP | FPREM1 | FSINCOS | FSIN | FCOS | FSETPM => X87,
+
+            // MMX (forms with XMM registers require SSE2)
+            MOVD | MOVQ | PADDB | PADDW | PADDD | PADDSB | PADDSW | PADDUSB | PADDUSW | PSUBB
+            | PSUBW | PSUBD | PSUBSB | PSUBSW | PSUBUSB | PSUBUSW | PANDN | PMULHW | PMULLW
+            | PMADDWD | PCMPEQB | PCMPEQW | PCMPEQD | PCMPGTB | PCMPGTW | PCMPGTD | PACKUSWB
+            | PACKSSWB | PACKSSDW | PUNPCKLBW | PUNPCKLWD | PUNPCKLDQ | PUNPCKHBW | PUNPCKHWD
+            | PUNPCKHDQ | POR | PAND | PXOR | PSLLW | PSLLD | PSLLQ | PSRLW | PSRLD | PSRLQ
+            | PSRAW | PSRAD | EMMS => Mmx,
+
+            ADDPS | ADDSS | SUBPS | SUBSS | MULPS | MULSS | DIVPS | DIVSS | RCPPS | RCPSS
+            | SQRTPS | SQRTSS | RSQRTPS | RSQRTSS | MINPS | MINSS | MAXPS | MAXSS | ORPS
+            | ANDPS | ANDNPS | XORPS | CMPPS | CMPSS | COMISS | UCOMISS | SHUFPS | UNPCKLPS
+            | UNPCKHPS | MOVAPS | MOVUPS | MOVSS | MOVLPS | MOVHPS | MOVLHPS | MOVHLPS
+            | STMXCSR | LDMXCSR | SFENCE | PREFETCH0 | PREFETCH1 | PREFETCH2 | PREFETCHA
+            | CVTPS2PI | CVTPI2PS | CVTSI2SS | CVTSS2SI | CVTTPS2PI | CVTTSS2SI => Sse,
+            // MMX instructions added by SSE (forms with XMM registers require SSE2)
+            PAVGB | PAVGW | PINSRW | PMAXSW | PMINSW | PMULHUW => Mmx,
+
+            ADDPD | ADDSD | SUBPD | SUBSD | MULPD | MULSD | DIVPD | DIVSD | SQRTPD | SQRTSD
+            | MINPD | MINSD | MAXPD | MAXSD | ORPD | ANDPD | ANDNPD | XORPD | CMPPD | CMPSD
+            | COMISD | UCOMISD | MOVAPD | MOVUPD | MOVHPD | MOVLPD | MOVSD | MOVMSKPD | MOVDQA
+            | MOVQ2DQ | MOVDQ2Q | PSUBQ | PADDQ | PSHUFD | PSLLDQ | PSRLDQ | PMULUDQ | PSHUFLW
+            | PSHUFHW | PUNPCKHQDQ | PUNPCKLQDQ | MASKMOVDQU | MFENCE | LFENCE | CLFLUSH
+            | MOVNTPD | MOVNTDQ | MOVNTI | PEXTRW | CVTPS2DQ | CVTPS2PD | CVTPI2PD | CVTPD2DQ
+            | CVTPD2PI | CVTPD2PS | CVTDQ2PD | CVTDQ2PS | CVTSD2SI | CVTSD2SS | CVTSI2SD
+            | CVTSS2SD | CVTTPD2DQ | CVTTPD2PI | CVTTPS2DQ | CVTTSD2SI => Sse2,
+
+            ADDSUBPS | ADDSUBPD | HADDPS | HSUBPS | HADDPD | HSUBPD | MOVSLDUP | MOVSHDUP
+            | MOVDDUP | LDDQU => Sse3,
+            MONITOR | MWAIT => Monitor,
+
+            PABSW | PABSD | PABSB | PSIGNW | PSIGND | PSIGNB | PHSUBW | PHSUBD | PHADDW
+            | PHADDD | PSHUFB | PHSUBSW | PHADDSW | PALIGNR | PMULHRSW | PMADDUBSW => Ssse3,
+
+            DPPS | DPPD | PTEST | PEXTRB | PEXTRD | PEXTRQ | PINSRB | PINSRD | PINSRQ | PMAXSB
+            | PMAXSD | PMAXUW | PMINSB | PMINSD | PMINUW | PMULDQ | PMULLD | BLENDPS | BLENDPD
+            | PBLENDW | PCMPEQQ | ROUNDPD | ROUNDPS | ROUNDSD | ROUNDSS | MPSADBW | BLENDVPS
+            | BLENDVPD | PBLENDVB | INSERTPS | PACKUSDW | MOVNTDQA | EXTRACTPS | PHMINPOSUW
+            | PMAXUD | PMOVSXBW | PMOVSXBD | PMOVSXBQ | PMOVSXWD | PMOVSXWQ | PMOVSXDQ
+            | PMOVZXBW | PMOVZXBD | PMOVZXBQ | PMOVZXWD | PMOVZXWQ | PMOVZXDQ => Sse4_1,
+            CRC32 | PCMPGTQ | PCMPESTRI | PCMPESTRM | PCMPISTRI | PCMPISTRM => Sse4_2,
+            POPCNT => Popcnt,
+
+            VMOVAPS | VMOVUPS | VADDPS | VADDSS | VSUBPS | VSUBSS | VMULPS | VMULSS | VDIVPS
+            | VDIVSS | VRCPPS | VRCPSS | VSQRTPS | VSQRTSS | VRSQRTPS | VRSQRTSS | VMINPS
+            | VMINSS | VMAXPS | VMAXSS | VORPS | VANDPS | VANDNPS | VXORPS | VCMPPS | VCMPSS
+            | VCOMISS | VUCOMISS | VSHUFPS | VUNPCKLPS | VUNPCKHPS | VMOVSS | VMOVLPS | VMOVHPS
+            | VMOVLHPS | VMOVHLPS | VADDPD | VADDSD | VSUBPD | VSUBSD | VMULPD | VMULSD
+            | VDIVPD | VDIVSD | VSQRTPD | VSQRTSD | VMINPD | VMINSD | VMAXPD | VMAXSD | VORPD
+            | VANDPD | VANDNPD | VXORPD | VCMPPD | VCMPSD | VCOMISD | VUCOMISD | VMOVAPD
+            | VMOVUPD | VMOVHPD | VMOVLPD | VMOVSD | VMOVMSKPD | VMOVDQA | VADDSUBPS
+            | VADDSUBPD | VHADDPS | VHSUBPS | VHADDPD | VHSUBPD | VMOVSLDUP | VMOVSHDUP
+            | VMOVDDUP | VLDDQU | VDPPS | VDPPD | VPTEST | VPEXTRB | VPEXTRW | VPEXTRD
+            | VPEXTRQ | VPINSRB | VPINSRD | VPINSRQ | VPINSRW | VBLENDPS | VBLENDPD | VROUNDPD
+            | VROUNDPS | VROUNDSD | VROUNDSS | VBLENDVPS | VBLENDVPD | VINSERTPS | VPCMPESTRI
+            | VPCMPESTRM | VPCMPISTRI | VPCMPISTRM | VEXTRACTPS | VPHMINPOSUW | VMOVD | VMOVQ
+            | VZEROALL | VZEROUPPER | VINSERTF128 | VEXTRACTF128 | VBROADCASTSS | VBROADCASTSD
+            | VBROADCASTF128 | VLDMXCSR | VSTMXCSR | VMOVMSKPS | VPERMILPD | VPERMILPS
+            | VPERM2F128 | VTESTPD | VTESTPS | VMASKMOVPS | VMASKMOVPD | VCVTPD2DQ | VCVTPD2PS
+            | VCVTPS2DQ | VCVTPS2PD | VCVTSD2SI | VCVTSD2SS | VCVTSI2SD | VCVTSI2SS | VCVTSS2SD
+            | VCVTSS2SI | VCVTDQ2PD | VCVTDQ2PS | VCVTTPD2DQ | VCVTTPS2DQ | VCVTTSD2SI
+            | VCVTTSS2SI => Avx,
+            // 256-bit forms require AVX2 (see `is_avx_int`)
+            VPMAXSB | VPMAXSD | VPMAXUW | VPMINSB | VPMINSD | VPMINUW | VPMULDQ | VPMULLD
+            | VPMAXUB | VPMINUB | VPBLENDW | VPCMPEQQ | VMPSADBW | VPCMPGTQ | VPBLENDVB
+            | VPACKUSDW | VMOVNTDQA | VPOR | VPAND | VPXOR | VPADDB | VPADDW | VPADDD | VPADDQ
+            | VPSUBB | VPSUBW | VPSUBD | VPSUBQ | VPANDN | VPSLLW | VPSLLD | VPSLLQ | VPSRLW
+            | VPSRLD | VPSRLQ | VPSRAW | VPSRAD | VPSUBSB | VPMULHW | VPMULLW | VPADDSB
+            | VPADDSW | VPSUBSW | VPSUBUSB | VPADDUSB | VPADDUSW | VPSUBUSW | VPMADDWD
+            | VPCMPEQB | VPCMPEQW | VPCMPEQD | VPCMPGTB | VPCMPGTW | VPCMPGTD | VPACKUSWB
+            | VPACKSSWB | VPACKSSDW | VPUNPCKLBW | VPUNPCKLWD | VPUNPCKLDQ | VPUNPCKHBW
+            | VPUNPCKHWD | VPUNPCKHDQ | VPAVGB | VPAVGW | VPHADDW | VPHADDD | VPHSUBW | VPHSUBD
+            | VPALIGNR | VPMAXSW | VPMINSW | VPSRLDQ | VPSIGND | VPSIGNB | VPSIGNW | VPMULUDQ
+            | VPMULHUW | VPMULHRSW | VPMAXUD | VPMOVSXBW | VPMOVSXBD | VPMOVSXBQ | VPMOVSXWD
+            | VPMOVSXWQ | VPMOVSXDQ | VPMOVZXBW | VPMOVZXBD | VPMOVZXBQ | VPMOVZXWD | VPMOVZXWQ
+            | VPMOVZXDQ => Avx,
+            VEXTRACTI128 | VINSERTI128 | VPERM2I128 | VPBLENDD | VPBROADCASTB | VPBROADCASTW
+            | VPBROADCASTD | VPBROADCASTQ | VPBROADCASTI128 | VPERMD | VPERMPD | VPERMPS
+            | VPERMQ | VPMASKMOVD | VPMASKMOVQ | VPSLLVD | VPSLLVQ | VPSRAVD | VPSRLVD
+            | VPSRLVQ | VGATHERDPS | VGATHERDPD | VGATHERQPS | VGATHERQPD | VPGATHERDD
+            | VPGATHERDQ | VPGATHERQD | VPGATHERQQ => Avx2,
+
+            VFMADD132PD | VFMADD132PS | VFMADD132SS | VFMADD132SD | VFMADD213PD | VFMADD213PS
+            | VFMADD213SS | VFMADD213SD | VFMADD231PD | VFMADD231PS | VFMADD231SS | VFMADD231SD
+            | VFMSUB132PD | VFMSUB132PS | VFMSUB132SS | VFMSUB132SD | VFMSUB213PD | VFMSUB213PS
+            | VFMSUB213SS | VFMSUB213SD | VFMSUB231PD | VFMSUB231PS | VFMSUB231SS | VFMSUB231SD
+            | VFNMADD132PD | VFNMADD132PS | VFNMADD132SS | VFNMADD132SD | VFNMADD213PD
+            | VFNMADD213PS | VFNMADD213SS | VFNMADD213SD | VFNMADD231PD | VFNMADD231PS
+            | VFNMADD231SS | VFNMADD231SD | VFNMSUB132PD | VFNMSUB132PS | VFNMSUB132SS
+            | VFNMSUB132SD | VFNMSUB213PD | VFNMSUB213PS | VFNMSUB213SS | VFNMSUB213SD
+            | VFNMSUB231PD | VFNMSUB231PS | VFNMSUB231SS | VFNMSUB231SD | VFMADDSUB132PD
+            | VFMADDSUB132PS | VFMADDSUB213PD | VFMADDSUB213PS | VFMADDSUB231PD
+            | VFMADDSUB231PS | VFMSUBADD132PD | VFMSUBADD132PS | VFMSUBADD213PD
+            | VFMSUBADD213PS | VFMSUBADD231PD | VFMSUBADD231PS => Fma,
+            VCVTPH2PS | VCVTPS2PH => F16c,
+
+            ANDN | BEXTR | BLSI | BLSR | BLSMSK => Bmi1,
+            BZHI | MULX | PDEP | PEXT | RORX | SARX | SHLX | SHRX => Bmi2,
+            LZCNT => Lzcnt,
+            MOVBE => Movbe,
+            ADCX | ADOX => Adx,
+            XSAVE | XSAVE64 | XRSTOR | XRSTOR64 | XGETBV | XSETBV => Xsave,
+            XSAVEOPT | XSAVEOPT64 => Xsaveopt,
+            XSAVEC | XSAVEC64 => Xsavec,
+            XSAVES | XSAVES64 | XRSTORS | XRSTORS64 => Xsaves,
+            // 256-bit forms of VAES* require VAES
+            AESDEC | AESENC | AESIMC | AESDECLAST | AESENCLAST | AESKEYGENASSIST | VAESDEC
+            | VAESENC | VAESIMC | VAESDECLAST | VAESENCLAST | VAESKEYGENASSIST => Aes,
+            PCLMULQDQ | VPCLMULQDQ => Pclmul,
+            SHA1MSG1 | SHA1MSG2 | SHA1NEXTE | SHA1RNDS4 | SHA256MSG1 | SHA256MSG2 | SHA256RNDS2 => {
+                Sha
+            }
+            GF2P8AFFINEQB | GF2P8AFFINEINVQB | GF2P8MULB | VGF2P8AFFINEQB | VGF2P8AFFINEINVQB
+            | VGF2P8MULB => Gfni,
+
+            // AVX-512
+            KADDB | KADDW | KANDB | KANDNB | KMOVB | KNOTB | KORB | KXORB | KTESTB | KTESTW
+            | KXNORB | KSHIFTLB | KSHIFTRB | KORTESTB | VCVTPD2QQ | VCVTPD2UQQ | VCVTPS2QQ
+            | VCVTPS2UQQ | VCVTQQ2PD | VCVTQQ2PS | VCVTTPD2QQ | VCVTTPD2UQQ | VCVTTPS2QQ
+            | VCVTTPS2UQQ | VCVTUQQ2PD | VCVTUQQ2PS | VBROADCASTF32X2 | VBROADCASTF64X2
+            | VBROADCASTF32X8 | VPBROADCASTI32X2 | VPBROADCASTI64X2 | VPBROADCASTI32X8
+            | VEXTRACTF64X2 | VEXTRACTF32X8 | VEXTRACTI64X2 | VEXTRACTI32X8 | VINSERTF64X2
+            | VINSERTF32X8 | VINSERTI64X2 | VINSERTI32X8 | VFPCLASSPD | VFPCLASSPS | VFPCLASSSD
+            | VFPCLASSSS | VPMOVD2M | VPMOVQ2M | VPMOVM2D | VPMOVM2Q | VRANGEPD | VRANGEPS
+            | VRANGESD | VRANGESS | VREDUCEPD | VREDUCEPS | VREDUCESD | VREDUCESS => Avx512dq,
+            KADDD | KADDQ | KANDD | KANDQ | KANDND | KANDNQ | KMOVD | KMOVQ | KNOTD | KNOTQ
+            | KORD | KORQ | KXORD | KXORQ | KTESTD | KTESTQ | KXNORD | KXNORQ | KSHIFTLD
+            | KSHIFTLQ | KSHIFTRD | KSHIFTRQ | KORTESTD | KORTESTQ | KUNPCKDQ | KUNPCKWD
+            | VDBPSADBW | VPBLENDMB | VPBLENDMW | VPCMPB | VPCMPUB | VPCMPW | VPCMPUW | VPERMW
+            | VPERMI2W | VPERMT2W | VPMOVB2M | VPMOVW2M | VPMOVM2B | VPMOVM2W | VPMOVWB
+            | VPMOVSWB | VPMOVUSWB | VPSLLVW | VPSRAVW | VPSRLVW | VPTESTMB | VPTESTMW
+            | VPTESTNMB | VPTESTNMW => Avx512bw,
+            VPCONFLICTD | VPCONFLICTQ | VPLZCNTD | VPLZCNTQ | VPBROADCASTMB2Q | VPBROADCASTMW2D => {
+                Avx512cd
+            }
+            VADDPH | VADDSH | VCMPPH | VCMPSH | VCOMISH | VCVTDQ2PH | VCVTPD2PH | VCVTPH2DQ
+            | VCVTPH2PD | VCVTPH2PSX | VCVTPH2QQ | VCVTPH2UDQ | VCVTPH2UQQ | VCVTPH2UW
+            | VCVTPH2W | VCVTPS2PHX | VCVTQQ2PH | VCVTSD2SH | VCVTSH2SD | VCVTSH2SI | VCVTSH2SS
+            | VCVTSH2USI | VCVTSI2SH | VCVTSS2SH | VCVTTPH2DQ | VCVTTPH2QQ | VCVTTPH2UDQ
+            | VCVTTPH2UQQ | VCVTTPH2UW | VCVTTPH2W | VCVTTSH2SI | VCVTTSH2USI | VCVTUDQ2PH
+            | VCVTUQQ2PH | VCVTUSI2SH | VCVTUW2PH | VCVTW2PH | VDIVPH | VDIVSH | VFCMADDCPH
+            | VFMADDCPH | VFMADDCSH | VFCMADDCSH | VFCMULCPH | VFCMULCSH | VFMULCPH | VFMULCSH
+            | VFMADD132PH | VFMADD213PH | VFMADD231PH | VFNMADD132PH | VFNMADD213PH
+            | VFNMADD231PH | VFMADD132SH | VFMADD213SH | VFMADD231SH | VFNMADD132SH
+            | VFNMADD213SH | VFNMADD231SH | VFMADDSUB132PH | VFMADDSUB213PH | VFMADDSUB231PH
+            | VFMSUB132PH | VFMSUB213PH | VFMSUB231PH | VFNMSUB132PH | VFNMSUB213PH
+            | VFNMSUB231PH | VFMSUB132SH | VFMSUB213SH | VFMSUB231SH | VFNMSUB132SH
+            | VFNMSUB213SH | VFNMSUB231SH | VFMSUBADD132PH | VFMSUBADD213PH | VFMSUBADD231PH
+            | VFPCLASSPH | VFPCLASSSH | VGETEXPPH | VGETEXPSH | VGETMANTPH | VGETMANTSH
+            | VMAXPH | VMAXSH | VMINPH | VMINSH | VMOVSH | VMOVW | VMULPH | VMULSH | VRCPPH
+            | VRCPSH | VREDUCEPH | VREDUCESH | VRNDSCALEPH | VRNDSCALESH | VRSQRTPH | VRSQRTSH
+            | VSCALEFPH | VSCALEFSH | VSQRTPH | VSQRTSH | VSUBPH | VSUBSH | VUCOMISH => Avx512fp16,
+            VCVTNE2PS2BF16 | VCVTNEPS2BF16 | VDPBF16PS => Avx512bf16,
+            VBCSTNEBF162PS | VBCSTNESH2PS | VCVTNEEBF162PS | VCVTNEEPH2PS | VCVTNEOBF162PS
+            | VCVTNEOPH2PS => AvxNeConvert,
+            VPERMB | VPERMI2B | VPERMT2B | VPMULTISHIFTQB => Avx512vbmi,
+            VPCOMPRESSB | VPCOMPRESSW | VPEXPANDB | VPEXPANDW | VPSHLDW | VPSHLDD | VPSHLDQ
+            | VPSHLDVW | VPSHLDVD | VPSHLDVQ | VPSHRDW | VPSHRDD | VPSHRDQ | VPSHRDVW
+            | VPSHRDVD | VPSHRDVQ => Avx512vbmi2,
+            // VEX forms require AVX-IFMA and AVX-VNNI
+            VPMADD52HUQ | VPMADD52LUQ => Avx512ifma,
+            VPDPBUSD | VPDPBUSDS | VPDPWSSD | VPDPWSSDS => Avx512vnni,
+            VPDPBSSD | VPDPBSSDS | VPDPBSUD | VPDPBSUDS | VPDPBUUD | VPDPBUUDS => AvxVnniInt8,
+            VPDPWSUD | VPDPWSUDS | VPDPWUSD | VPDPWUSDS | VPDPWUUD | VPDPWUUDS => AvxVnniInt16,
+            VPOPCNTB | VPOPCNTW | VPSHUFBITQMB => Avx512bitalg,
+            VPOPCNTD | VPOPCNTQ => Avx512vpopcntdq,
+            VP2INTERSECTD | VP2INTERSECTQ => Avx512vp2intersect,
+            VEXP2PD | VEXP2PS | VRCP28PD | VRCP28SD | VRCP28PS | VRCP28SS | VRSQRT28PD
+            | VRSQRT28SD | VRSQRT28PS | VRSQRT28SS => Avx512er,
+            VGATHERPF0DPS | VGATHERPF0QPS | VGATHERPF0DPD | VGATHERPF0QPD | VGATHERPF1DPS
+            | VGATHERPF1QPS | VGATHERPF1DPD | VGATHERPF1QPD | VSCATTERPF0DPS | VSCATTERPF0QPS
+            | VSCATTERPF0DPD | VSCATTERPF0QPD | VSCATTERPF1DPS | VSCATTERPF1QPS
+            | VSCATTERPF1DPD | VSCATTERPF1QPD => Avx512pf,
+            V4FMADDPS | V4FNMADDPS | V4FMADDSS | V4FNMADDSS => Avx5124fmaps,
+            VP4DPWSSD | VP4DPWSSDS => Avx5124vnniw,
+            PREFETCHWT1 => Prefetchwt1,
+            VSHA512MSG1 | VSHA512MSG2 | VSHA512RNDS2 => Sha512,
+            VSM3MSG1 | VSM3MSG2 | VSM3RNDS2 => Sm3,
+            VSM4KEY4 | VSM4RNDS4 => Sm4,
+            VALIGND | VALIGNQ | VBLENDMPD | VBLENDMPS | VBROADCASTF32X4 | VBROADCASTF64X4
+            | VCOMPRESSPD | VCOMPRESSPS | KANDNW | KANDW | KMOVW | KNOTW | KORW | KXORW
+            | KXNORW | KSHIFTLW | KSHIFTRW | KORTESTW | KUNPCKBW | VCVTPD2UDQ | VCVTPS2UDQ
+            | VCVTSD2USI | VCVTSS2USI | VCVTTPD2UDQ | VCVTTPS2UDQ | VCVTTSD2USI | VCVTTSS2USI
+            | VCVTUDQ2PD | VCVTUDQ2PS | VCVTUSI2SD | VCVTUSI2SS | VEXPANDPD | VEXPANDPS
+            | VEXTRACTF32X4 | VEXTRACTF64X4 | VEXTRACTI32X4 | VEXTRACTI64X4 | VFIXUPIMMPD
+            | VFIXUPIMMPS | VFIXUPIMMSD | VFIXUPIMMSS | VGETEXPPD | VGETEXPPS | VGETEXPSD
+            | VGETEXPSS | VGETMANTPD | VGETMANTPS | VGETMANTSD | VGETMANTSS | VINSERTF32X4
+            | VINSERTF64X4 | VINSERTI32X4 | VINSERTI64X4 | VPBLENDMD | VPBLENDMQ
+            | VPBROADCASTI32X4 | VPBROADCASTI64X4 | VPCMPD | VPCMPUD | VPCMPQ | VPCMPUQ
+            | VPCOMPRESSD | VPCOMPRESSQ | VPERMI2D | VPERMI2Q | VPERMI2PS | VPERMI2PD
+            | VPERMT2D | VPERMT2Q | VPERMT2PS | VPERMT2PD | VPEXPANDD | VPEXPANDQ | VPMOVDB
+            | VPMOVSDB | VPMOVUSDB | VPMOVDW | VPMOVSDW | VPMOVUSDW | VPMOVQB | VPMOVSQB
+            | VPMOVUSQB | VPMOVQD | VPMOVSQD | VPMOVUSQD | VPMOVQW | VPMOVSQW | VPMOVUSQW
+            | VPROLVD | VPROLVQ | VPROLD | VPROLQ | VPRORD | VPRORVD | VPRORQ | VPRORVQ
+            | VPSRAVQ | VPTERNLOGD | VPTERNLOGQ | VPTESTMD | VPTESTMQ | VPTESTNMD | VPTESTNMQ
+            | VRCP14PD | VRCP14PS | VRCP14SD | VRCP14SS | VRNDSCALEPD | VRNDSCALEPS
+            | VRNDSCALESD | VRNDSCALESS | VRSQRT14PD | VRSQRT14PS | VRSQRT14SD | VRSQRT14SS
+            | VSCALEFPD | VSCALEFPS | VSCALEFSD | VSCALEFSS | VSHUFF32X4 | VSHUFF64X2
+            | VSHUFI32X4 | VSHUFI64X2 | VPSCATTERDD | VPSCATTERDQ | VPSCATTERQD | VPSCATTERQQ
+            | VSCATTERDPS | VSCATTERDPD | VSCATTERQPS | VSCATTERQPD => Avx512f,
+
+            VMINMAXBF16 | VMINMAXPD | VMINMAXPH | VMINMAXPS | VMINMAXSD | VMINMAXSH | VMINMAXSS
+            | VCVTBF162IBS | VCVTBF162IUBS | VCVTTBF162IBS | VCVTTBF162IUBS | VCVTPH2IBS
+            | VCVTPH2IUBS | VCVTTPH2IBS | VCVTTPH2IUBS | VCVTPS2IBS | VCVTPS2IUBS | VCVTTPS2IBS
+            | VCVTTPS2IUBS | VADDBF16 | VSUBBF16 | VMULBF16 | VDIVBF16 | VMAXBF16 | VMINBF16
+            | VSQRTBF16 | VSCALEFBF16 | VRCPBF16 | VRSQRTBF16 | VCOMXSD | VCOMXSH | VCOMXSS
+            | VUCOMXSD | VUCOMXSH | VUCOMXSS => Avx10_2,
+
+            // APX (APX forms of instructions from other extensions also require only APX)
+            AAADD | AAAND | AADC | AADCX | AADD | AADOX | AAND | AANDN | AAOR | AAXOR | ABEXTR
+            | ABLSI | ABLSMSK | ABLSR | ABZHI | ACMOVA | ACMOVB | ACMOVC | ACMOVE | ACMOVG
+            | ACMOVL | ACMOVO | ACMOVP | ACMOVS | ACMOVZ | ACMOVAE | ACMOVBE | ACMOVGE
+            | ACMOVLE | ACMOVNA | ACMOVNB | ACMOVNC | ACMOVNE | ACMOVNG | ACMOVNL | ACMOVNO
+            | ACMOVNP | ACMOVNS | ACMOVNZ | ACMOVPE | ACMOVPO | ACMOVNBE | ACMOVNAE | ACMOVNGE
+            | ACMOVNLE | ACRC32 | ADEC | ADIV | AIDIV | AIMUL | AIMULZU | AINC | AINVEPT
+            | AINVPCID | AINVVPID | AKMOVB | AKMOVD | AKMOVQ | AKMOVW | ALZCNT | AMOVBE
+            | AMOVDIRI | AMOVRS | AMUL | AMULX | ANEG | ANOT | AOR | APDEP | APEXT | APOPCNT
+            | ARCL | ARCR | ARDMSR | AROL | AROR | ARORX | ASAR | ASARX | ASBB | ASHL | ASHR
+            | ASHRD | ASHRX | ASHLD | ASHLX | ASUB | ATZCNT | AXOR | JMPABS | CCMPB | CCMPBE
+            | CCMPF | CCMPL | CCMPLE | CCMPNB | CCMPZ | CCMPNBE | CCMPNL | CCMPNLE | CCMPNO
+            | CCMPNS | CCMPNZ | CCMPO | CCMPS | CCMPT | CCMPNA | CCMPA | CCMPNAE | CCMPAE
+            | CCMPE | CCMPNE | CCMPGE | CCMPG | CCMPNG | CCMPNGE | CCMPC | CCMPNC | CTESTB
+            | CTESTBE | CTESTF | CTESTL | CTESTLE | CTESTNB | CTESTZ | CTESTNBE | CTESTNL
+            | CTESTNLE | CTESTNO | CTESTNS | CTESTNZ | CTESTO | CTESTS | CTESTT | CTESTNA
+            | CTESTA | CTESTNAE | CTESTAE | CTESTE | CTESTNE | CTESTGE | CTESTG | CTESTNG
+            | CTESTNGE | CTESTC | CTESTNC | CFCMOVA | CFCMOVB | CFCMOVE | CFCMOVG | CFCMOVL
+            | CFCMOVO | CFCMOVP | CFCMOVS | CFCMOVZ | CFCMOVAE | CFCMOVBE | CFCMOVGE | CFCMOVLE
+            | CFCMOVNA | CFCMOVNB | CFCMOVNE | CFCMOVNG | CFCMOVNL | CFCMOVNO | CFCMOVNP
+            | CFCMOVNS | CFCMOVNZ | CFCMOVPE | CFCMOVPO | CFCMOVNC | CFCMOVC | CFCMOVNBE
+            | CFCMOVNAE | CFCMOVNGE | CFCMOVNLE | SETAZU | SETAEZU | SETBZU | SETBEZU | SETCZU
+            | SETEZU | SETGZU | SETGEZU | SETLZU | SETLEZU | SETNAZU | SETNAEZU | SETNBZU
+            | SETNBEZU | SETNCZU | SETNEZU | SETNGZU | SETNLZU | SETNGEZU | SETNLEZU | SETNOZU
+            | SETNPZU | SETNSZU | SETNZZU | SETOZU | SETPZU | SETPEZU | SETPOZU | SETSZU
+            | SETZZU | POP2 | POP2P | POPP | PUSH2 | PUSH2P | PUSHP | AWRSSD | AWRSSQ | AWRUSSD
+            | AWRUSSQ | AENQCMDS | AENQCMD | ACMPBXADD | ACMPBEXADD | ACMPLXADD | ACMPLEXADD
+            | ACMPNBXADD | ACMPZXADD | ACMPNBEXADD | ACMPNLXADD | ACMPNLEXADD | ACMPNOXADD
+            | ACMPNSXADD | ACMPNZXADD | ACMPOXADD | ACMPSXADD | ACMPNAXADD | ACMPAXADD
+            | ACMPNAEXADD | ACMPAEXADD | ACMPEXADD | ACMPNEXADD | ACMPGEXADD | ACMPGXADD
+            | ACMPNGXADD | ACMPNGEXADD | ACMPCXADD | ACMPNCXADD | ALDTILECFG | ASTTILECFG
+            | ATILELOADD | ATILELOADDT1 | ATILESTORED => ApxF,
+
+            LDTILECFG | STTILECFG | TILELOADD | TILELOADDT1 | TILESTORED | TILEZERO
+            | TILERELEASE => AmxTile,
+            TDPBSSD | TDPBSUD | TDPBUSD | TDPBUUD => AmxInt8,
+            TDPBF16PS => AmxBf16,
+            TDPFP16PS => AmxFp16,
+
+            VPCMOV | VPPERM | VPROTB | VPROTW | VPROTD | VPROTQ | VPSHAB | VPSHAW | VPSHAD
+            | VPSHAQ | VPSHLB | VPSHLW | VPSHLD | VPSHLQ | VPCOMB | VPCOMW | VPCOMD | VPCOMQ
+            | VPCOMUB | VPCOMUW | VPCOMUD | VPCOMUQ | VFRCZPS | VFRCZPD | VFRCZSS | VFRCZSD
+            | VPHADDBW | VPHADDBD | VPHADDBQ | VPHADDWD | VPHADDWQ | VPHADDDQ | VPHADDUBW
+            | VPHADDUBD | VPHADDUBQ | VPHADDUWD | VPHADDUWQ | VPHADDUDQ | VPHSUBBW | VPHSUBWD
+            | VPHSUBDQ | VPMACSWW | VPMACSSWW | VPMACSWD | VPMACSSWD | VPMACSDD | VPMACSSDD
+            | VPMACSDQL | VPMACSSDQL | VPMACSDQH | VPMACSSDQH | VPMADCSWD | VPMADCSSWD => Xop,
+            VFMADDPS | VFMADDPD | VFMADDSS | VFMADDSD | VFMSUBPS | VFMSUBPD | VFMSUBSS
+            | VFMSUBSD | VFNMADDPS | VFNMADDPD | VFNMADDSS | VFNMADDSD | VFNMSUBPS | VFNMSUBPD
+            | VFNMSUBSS | VFNMSUBSD | VFMADDSUBPS | VFMADDSUBPD | VFMSUBADDPS | VFMSUBADDPD => Fma4,
+            BLCFILL | BLCI | BLCIC | BLCMSK | BLCS | BLSFILL | BLSIC | T1MSKC | TZMSK => Tbm,
+            MONITORX | MWAITX => Mwaitx,
+            RDPRU => Rdpru,
+            INVLPGB | TLBSYNC => Invlpgb,
+
+            RDRAND => Rdrnd,
+            RDSEED => Rdseed,
+            RDPID => Rdpid,
+            RDTSCP => Rdtscp,
+            RDFSBASE | RDGSBASE | WRFSBASE | WRGSBASE => Fsgsbase,
+            RDPKRU | WRPKRU => Pku,
+            INCSSPD | INCSSPQ | RDSSPD | RDSSPQ | SAVEPREVSSP | RSTORSSP | WRSSD | WRSSQ
+            | WRUSSD | WRUSSQ | SETSSBY | CLRSSBSY => Shstk,
+            XBEGIN | XEND | XABORT | XTEST => Rtm,
+            CLAC | STAC => Smap,
+            INVPCID => Invpcid,
+            CLWB => Clwb,
+            CLDEMOTE => Cldemote,
+            MOVDIRI => Movdiri,
+            MOVDIR64B => Movdir64b,
+            ENQCMD | ENQCMDS => Enqcmd,
+            SERIALIZE => Serialize,
+            XSUSLDTRK | XRESLDTRK => Tsxldtrk,
+            CLUI | STUI | TESTUI | UIRET | SENDUIPI => Uintr,
+            TPAUSE | UMONITOR | UMWAIT => Waitpkg,
+            HRESET => Hreset,
+            PREFETCHW => Prfchw,
+            PREFETCHIT0 | PREFETCHIT1 => Prefetchi,
+            PTWRITE => Ptwrite,
+            PCONFIG => Pconfig,
+            PBNDKB => Pbndkb,
+            LOADIWKEY | ENCODEKEY128 | ENCODEKEY256 | AESENC128KL | AESDEC128KL | AESENC256KL
+            | AESDEC256KL => Kl,
+            AESENCWIDE128KL | AESDECWIDE128KL | AESENCWIDE256KL | AESDECWIDE256KL => Widekl,
+            CMPBXADD | CMPBEXADD | CMPLXADD | CMPLEXADD | CMPNBXADD | CMPZXADD | CMPNBEXADD
+            | CMPNLXADD | CMPNLEXADD | CMPNOXADD | CMPNSXADD | CMPNZXADD | CMPOXADD | CMPSXADD
+            | CMPNAXADD | CMPAXADD | CMPNAEXADD | CMPAEXADD | CMPEXADD | CMPNEXADD | CMPGEXADD
+            | CMPGXADD | CMPNGXADD | CMPNGEXADD | CMPCXADD | CMPNCXADD => Cmpccxadd,
+            RDMSRLIST | WRMSRLIST => Msrlist,
+            WBNOINVD => Wbnoinvd,
+            ENCLS | ENCLU | ENCLV => Sgx,
+            INVLPGA | VMRUN | VMLOAD | VMSAVE | STGI | CLGI | VMMCALL | SKINIT | VMGEXIT
+            | PSMASH | RMPUPDATE | PVALIDATE | RMPADJUST | RMPQUERY | RMPREAD => Svm,
+            VMXON | VMXOFF | VMPTRLD | VMPTRST | VMCLEAR | VMLAUNCH | VMRESUME | VMREAD
+            | VMWRITE | VMCALL | INVEPT | INVVPID | VMFUNC | SEAMOPS | SEAMRET | SEAMCALL
+            | TDCALL => Vmx,
+            GETSEC => Smx,
+            // instructions supported by every x86-64 CPU
+            MOV | ADD | SUB | IMUL | MUL | DIV | IDIV | AND | OR | NOT | NEG | XOR | SHR | SAR
+            | SHL | SAL | LEA | INC | DEC | CMP | TEST | JMP | CALL | SYSCALL | RET | NOP | POP
+            | POPF | POPFD | POPFQ | PUSH | PUSHF | PUSHFD | PUSHFQ | CPUID | JA | JC | JE | JZ
+            | JL | JG | JO | JP | JS | JB | JAE | JBE | JNZ | JNE | JNO | JNP | JNS | JPE | JPO
+            | JLE | JGE | JNA | JNB | JNC | JNL | JNG | JNAE | JNBE | JNGE | JNLE | PAUSE | BT
+            | CLC | CMC | CWD | CDQ | CQO | DAA | DAS | CLD | CLI | AAA | AAD | AAM | AAS | ADC
+            | BSF | BSR | BTC | BTR | BTS | CBW | ARPL | CWDE | CDQE | CLTS | BSWAP | CMPSTRB
+            | CMPSTRD | CMPSTRQ | CMPSTRW | CMPXCHG | ENDBR32 | ENDBR64 | ENTER | HLT | INSB
+            | INSD | INSW | INT | INT3 | INTO | INT1 | INVD | INVLPG | IRET | IRETD | IRETQ
+            | LAR | LEAVE | LLDT | LMSW | LODSB | LODSW | LODSD | LODSQ | LOOP | LOOPE | LOOPNE
+            | LSL | LTR | MOVSTRB | MOVSTRW | MOVSTRD | MOVSTRQ | MOVZX | OUTSB | OUTSD | OUTSW
+            | RCL | RCR | ROL | ROR | RDMSR | RDPMC | RDTSC | RSM | SBB | SCASB | SCASW | SCASD
+            | SCASQ | SETA | SETAE | SETB | SETBE | SETC | SETE | SETG | SETGE | SETL | SETLE
+            | SETNA | SETNAE | SETNB | SETNBE | SETNC | SETNE | SETNG | SETNL | SETNGE | SETNLE
+            | SETNO | SETNP | SETNS | SETNZ | SETO | SETP | SETPE | SETPO | SETS | SETZ | SHLD
+            | SHRD | SMSW | STC | STD | STI | STOSB | STOSW | STOSD | STOSQ | STR | SYSENTER
+            | SYSEXIT | SYSRET | UD0 | UD1 | UD2 | VERR | VERW | WAIT | FWAIT | WBINVD | WRMSR
+            | XRELEASE | XACQUIRE | XADD | XCHG | XLAT | XLATB | XLATB64 | IN | OUT | LGDT
+            | LIDT | SGDT | SIDT | SLDT | MOVSX | MOVSXD | LFS | LGS | NOPL | SWAPGS | LJMP
+            | LCALL | POPAW | POPAD | POPAQ | PUSHAW | PUSHAD | PUSHAQ | LOCK | REPNE | REPNZ
+            | REPZ | REPE | REP | JCXZ | JECXZ | JRCXZ => Base,
+            // data directives
+            BYTEBE | WORDBE | DWORDBE | QWORDBE | BYTELE | WORDLE | DWORDLE | QWORDLE | EMPTY
+            | ASCII | STRING | __LAST => Base,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isa_set_t() {
+        assert_eq!(Isa::ALL.len(), Isa::Smx as usize + 1);
+        for (i, isa) in Isa::ALL.iter().enumerate() {
+            assert_eq!(*isa as usize, i);
+        }
+        let v2 = IsaSet::level("x86-64-v2").unwrap();
+        assert!(v2.has(Isa::Sse4_2) && v2.has(Isa::Base) && !v2.has(Isa::Avx));
+        let mut s = v2;
+        s.apply("x86-64-v3, -avx2 +avx512f").unwrap();
+        assert!(s.has(Isa::Avx) && !s.has(Isa::Avx2) && s.has(Isa::Avx512f));
+        assert!(s.apply("+avx1024").is_err());
+        s.apply("-base").unwrap_err();
+        assert_eq!(Mnemonic::VPERMB.isa(), Isa::Avx512vbmi);
+        assert_eq!(Mnemonic::ADD.isa(), Isa::Base);
+    }
+}
@@ -6,13 +6,14 @@
 use crate::shr::{
     booltable::BoolTable8,
     error::Error,
+    expr,
     num::Number,
     reg::{Purpose as RPurpose, Register},
     size::Size,
     stackvec::StackVec,
 };
 
-use std::str::FromStr;
+use std::{borrow::Cow, str::FromStr};
 
 pub const RIP_ADDRESSING: u8 = 0x0;
 pub const OBY_OFFSET: u8 = 0x1;
@@ -23,6 +24,12 @@ pub const HAS_INDEX: u8 = 0x4;
 // is index a vector register
 pub const IS_VSIB_IDX: u8 = 0x5;
 
+// memory has only index (base is set to BP, so it can be encoded in SIB)
+pub const NO_BASE: u8 = 0x6;
+
+// displacement is above i32::MAX (valid only in 32-bit addressing, where it wraps)
+pub const U32_OFFSET: u8 = 0x7;
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(C)]
 pub struct Mem {
@@ -71,8 +78,12 @@ pub struct Mem {
 impl FromStr for Mem {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match mem_par(mem_tok(s)) {
+        let (s, u32_offset) = mem_fold(s)?;
+        match mem_par(mem_tok(&s)) {
             Ok(mut o) => {
+                if u32_offset {
+                    o.set_flag(U32_OFFSET);
+                }
                 if o == Mem::blank() {
                     Err(Error::new(
                         "assembler was unable to parse memory addressing in this instruction",
@@ -112,6 +123,12 @@ impl Mem {
     pub fn is_vsib(&self) -> bool {
         self.get_flag(IS_VSIB_IDX).unwrap_or(false)
     }
+    pub fn is_nobase(&self) -> bool {
+        self.get_flag(NO_BASE).unwrap_or(false)
+    }
+    pub fn is_u32_offset(&self) -> bool {
+        self.get_flag(U32_OFFSET).unwrap_or(false)
+    }
     pub fn is_sib(&self) -> bool {
         self.index().is_some() && self.base().is_some() && !self.is_riprel()
     }
@@ -364,8 +381,11 @@ fn mem_chk(mem: &mut Mem) {
         };
     }
 
+    // only displacement: RIP-relative in 64-bit mode and absolute otherwise (both use
+    // mod = 00, r/m = 101); address size has to be set, as `Size::Any` equals every size
     if let (None, None, Some(_)) = (base, index, offset) {
         mem.set_flag(RIP_ADDRESSING);
+        mem.set_addrsize(Size::Qword);
     }
 }
 
@@ -434,7 +454,7 @@ fn mem_par(toks: StackVec<Token, 8>) -> Result<Mem, Error> {
                     }
                     mul_modf = false;
                 } else {
-                    let n = if num_ismin { -n } else { n };
+                    let n = if num_ismin { n.wrapping_neg() } else { n };
                     offset = Some(n);
                 }
             }
@@ -482,6 +502,7 @@ fn mem_par(toks: StackVec<Token, 8>) -> Result<Mem, Error> {
                 }
             }
             (None, Some(index)) => {
+                mem.set_flag(NO_BASE);
                 mem.set_base(Register::BP);
                 mem.set_addrsize(index.size());
                 mem.set_index(index);
@@ -497,6 +518,96 @@ fn mem_par(toks: StackVec<Token, 8>) -> Result<Mem, Error> {
     Ok(mem)
 }
 
+// folds constant expressions inside of memory addressing into single displacement,
+// so `[rbp - 8*3 + 4]` becomes `[rbp - 20]` (returns `s` if there is nothing to fold)
+// and tells if displacement is above i32::MAX
+fn mem_fold(s: &str) -> Result<(Cow<'_, str>, bool), Error> {
+    let (pfx, inner, sfx) = match (s.find(MS as char), s.rfind(ME as char)) {
+        (Some(st), Some(en)) if st < en => (&s[..=st], &s[st + 1..en], &s[en..]),
+        _ => ("", s, ""),
+    };
+
+    // split into terms on `+` and `-`, that are not inside parentheses or after other operator
+    let mut terms: Vec<(bool, &str)> = Vec::new();
+    let mut neg = false;
+    let mut start = 0;
+    let mut depth = 0usize;
+    for (i, b) in inner.bytes().enumerate() {
+        match b {
+            b'(' => depth += 1,
+            b')' => depth = depth.saturating_sub(1),
+            b'+' | b'-' if depth == 0 => {
+                let term = inner[start..i].trim();
+                if term.is_empty() {
+                    neg ^= b == b'-';
+                    start = i + 1;
+                } else if !term.ends_with(['*', '/', '%', '<', '>', '&', '|', '^', '~', '!']) {
+                    terms.push((neg, term));
+                    neg = b == b'-';
+                    start = i + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    let term = inner[start..].trim();
+    if !term.is_empty() {
+        terms.push((neg, term));
+    }
+
+    let mut consts = 0;
+    let mut fold = false;
+    let mut disp: i128 = 0;
+    let mut regs = String::new();
+    for (neg, term) in terms {
+        if expr::is_const(term) {
+            consts += 1;
+            fold |= Number::from_str(term).is_err();
+            let n = expr::eval_raw(term)?;
+            disp = if neg { disp - n } else { disp + n };
+        } else {
+            // register term (can have scale as expression)
+            if !regs.is_empty() || neg {
+                regs.push_str(if neg { " - " } else { " + " });
+            }
+            for (i, f) in term.split('*').enumerate() {
+                if i != 0 {
+                    regs.push_str(" * ");
+                }
+                let f = f.trim();
+                if expr::is_expr(f) {
+                    fold = true;
+                    regs.push_str(&expr::eval_raw(f)?.to_string());
+                } else {
+                    regs.push_str(f);
+                }
+            }
+        }
+    }
+    // displacement above i32::MAX wraps in 32-bit addressing (64-bit one is checked by chk.rs)
+    if disp < i32::MIN as i128 || disp > u32::MAX as i128 {
+        return Err(Error::new(
+            format!("displacement {disp} is outside of range -0x8000_0000..=0xFFFF_FFFF"),
+            11,
+        ));
+    }
+    let u32_offset = disp > i32::MAX as i128;
+    if !fold && consts < 2 {
+        return Ok((Cow::Borrowed(s), u32_offset));
+    }
+    let mut out = String::from(pfx);
+    out.push_str(&regs);
+    if disp != 0 || regs.is_empty() {
+        match (regs.is_empty(), disp < 0) {
+            (true, _) => out.push_str(&disp.to_string()),
+            (false, true) => out.push_str(&format!(" - {}", -disp)),
+            (false, false) => out.push_str(&format!(" + {disp}")),
+        }
+    }
+    out.push_str(sfx);
+    Ok((Cow::Owned(out), u32_offset))
+}
+
 const MS: u8 = b'[';
 const ME: u8 = b']';
 fn mem_tok(str: &str) -> StackVec<Token, 8> {
@@ -620,9 +731,9 @@ mod tests {
         assert_eq!(mem.offset(), Some(0x01));
         mem.clear_offset();
         assert_eq!(mem.offset(), None);
-        let _ = mem.set_base(Register::EAX);
+        mem.set_base(Register::EAX);
         assert_eq!(mem.base(), Some(Register::EAX));
-        let _ = mem.set_index(Register::EAX);
+        mem.set_index(Register::EAX);
         assert_eq!(mem.index(), Some(Register::EAX));
         assert_eq!(mem.base(), Some(Register::EAX));
         mem.set_scale(Size::Byte);
@@ -710,4 +821,38 @@ mod tests {
         assert_eq!(mem.base(), Some(Register::RAX));
         assert_eq!(mem.index(), Some(Register::ZMM23));
     }
+    #[test]
+    fn tmem_fold_3() {
+        let mem = Mem::from_str("[rbp - 8*3]").unwrap();
+        assert_eq!(mem.base(), Some(Register::RBP));
+        assert_eq!(mem.offset(), Some(-24));
+        let mem = Mem::from_str("rax + rcx * (1 << 2) + 4 - 2").unwrap();
+        assert_eq!(mem.index(), Some(Register::RCX));
+        assert_eq!(mem.scale(), Size::Dword);
+        assert_eq!(mem.offset(), Some(2));
+        let mem = Mem::from_str("rax - -(16 / 2)").unwrap();
+        assert_eq!(mem.offset(), Some(8));
+        assert_eq!(
+            mem_fold("rax + 8").unwrap(),
+            (Cow::Borrowed("rax + 8"), false)
+        );
+        assert_eq!(Mem::from_str("rax + 1 / 0"), Err(Error::new("", 15)));
+        let mem = Mem::from_str("rax + 0x4000_0000 + 0x3FFF_FFFF").unwrap();
+        assert_eq!(mem.offset(), Some(i32::MAX));
+        let mem = Mem::from_str("rax - 0x8000_0000").unwrap();
+        assert_eq!(mem.offset(), Some(i32::MIN));
+        assert!(!mem.is_u32_offset());
+        // wraps, but is accepted only in 32-bit addressing
+        let mem = Mem::from_str("ebx + 0x4000_0000 * 2").unwrap();
+        assert_eq!(mem.offset(), Some(i32::MIN));
+        assert!(mem.is_u32_offset());
+        let mem = Mem::from_str("0xFFFF_FFFF").unwrap();
+        assert_eq!(mem.offset(), Some(-1));
+        assert!(mem.is_u32_offset());
+        assert_eq!(
+            Mem::from_str("rax + 0x1_0000_0000"),
+            Err(Error::new("", 11))
+        );
+        assert_eq!(Mem::from_str("rax - 0x8000_0001"), Err(Error::new("", 11)));
+    }
 }
@@ -348,6 +348,10 @@ pub enum Mnemonic {
     AESKEYGENASSIST,
     VAESKEYGENASSIST, // 16 chars :o
 
+    // GFNI
+    GF2P8AFFINEQB, GF2P8AFFINEINVQB, GF2P8MULB,
+    VGF2P8AFFINEQB, VGF2P8AFFINEINVQB, VGF2P8MULB,
+
     // i hate coding conversions, but i guess someone has to do them and actually test them :)
     // so here are conversions from MMX/SSE/AVX
     // /tests/*/cvt-part1.asm
@@ -751,6 +755,52 @@ pub enum Mnemonic {
     // SGX
     ENCLS, ENCLU, ENCLV,
 
+    // AMX
+    LDTILECFG, STTILECFG, TILELOADD, TILELOADDT1, TILESTORED,
+    TILEZERO, TILERELEASE,
+    TDPBSSD, TDPBSUD, TDPBUSD, TDPBUUD, TDPBF16PS, TDPFP16PS,
+    // APX extension
+    ALDTILECFG, ASTTILECFG, ATILELOADD, ATILELOADDT1, ATILESTORED,
+
+    // AMD XOP
+    VPCMOV, VPPERM,
+    VPROTB, VPROTW, VPROTD, VPROTQ,
+    VPSHAB, VPSHAW, VPSHAD, VPSHAQ,
+    VPSHLB, VPSHLW, VPSHLD, VPSHLQ,
+    VPCOMB, VPCOMW, VPCOMD, VPCOMQ,
+    VPCOMUB, VPCOMUW, VPCOMUD, VPCOMUQ,
+    VFRCZPS, VFRCZPD, VFRCZSS, VFRCZSD,
+    VPHADDBW, VPHADDBD, VPHADDBQ, VPHADDWD, VPHADDWQ, VPHADDDQ,
+    VPHADDUBW, VPHADDUBD, VPHADDUBQ, VPHADDUWD, VPHADDUWQ, VPHADDUDQ,
+    VPHSUBBW, VPHSUBWD, VPHSUBDQ,
+    VPMACSWW, VPMACSSWW, VPMACSWD, VPMACSSWD,
+    VPMACSDD, VPMACSSDD, VPMACSDQL, VPMACSSDQL,
+    VPMACSDQH, VPMACSSDQH, VPMADCSWD, VPMADCSSWD,
+
+    // AMD FMA4
+    VFMADDPS, VFMADDPD, VFMADDSS, VFMADDSD,
+    VFMSUBPS, VFMSUBPD, VFMSUBSS, VFMSUBSD,
+    VFNMADDPS, VFNMADDPD, VFNMADDSS, VFNMADDSD,
+    VFNMSUBPS, VFNMSUBPD, VFNMSUBSS, VFNMSUBSD,
+    VFMADDSUBPS, VFMADDSUBPD, VFMSUBADDPS, VFMSUBADDPD,
+
+    // AMD TBM
+    BLCFILL, BLCI, BLCIC, BLCMSK, BLCS,
+    BLSFILL, BLSIC, T1MSKC, TZMSK,
+
+    // AMD system instructions
+    MONITORX, MWAITX, RDPRU, INVLPGB, TLBSYNC,
+
+    // AVX10.2
+    VMINMAXBF16, VMINMAXPD, VMINMAXPH, VMINMAXPS,
+    VMINMAXSD, VMINMAXSH, VMINMAXSS,
+    VCVTBF162IBS, VCVTBF162IUBS, VCVTTBF162IBS, VCVTTBF162IUBS,
+    VCVTPH2IBS, VCVTPH2IUBS, VCVTTPH2IBS, VCVTTPH2IUBS,
+    VCVTPS2IBS, VCVTPS2IUBS, VCVTTPS2IBS, VCVTTPS2IUBS,
+    VADDBF16, VSUBBF16, VMULBF16, VDIVBF16, VMAXBF16, VMINBF16,
+    VSQRTBF16, VSCALEFBF16, VRCPBF16, VRSQRTBF16,
+    VCOMXSD, VCOMXSH, VCOMXSS, VUCOMXSD, VUCOMXSH, VUCOMXSS,
+
     // long jump
     LJMP, LCALL,
 
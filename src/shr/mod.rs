@@ -1,9 +1,11 @@
 pub mod atype;
 pub mod booltable;
 pub mod error;
+pub mod expr;
 #[cfg(not(feature = "refresh"))]
 pub mod ins_switch;
 pub mod instruction;
+pub mod isa;
 pub mod label;
 pub mod mem;
 pub mod mnemonic;
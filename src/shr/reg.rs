@@ -21,6 +21,7 @@ pub enum Purpose {
     F512 = 9,   // zmmX
     Sgmnt = 10, // segment registers (cs, ss, ds, es, ...)
     X87 = 11,   // st0-7
+    Tile = 12,  // tmm0-7 (AMX)
 }
 
 #[repr(transparent)]
@@ -195,6 +196,15 @@ impl Register {
     pub const ST6: Self = Self::new(Purpose::X87, Size::B80, [false, false], 0b110);
     pub const ST7: Self = Self::new(Purpose::X87, Size::B80, [false, false], 0b111);
 
+    pub const TMM0: Self = Self::new(Purpose::Tile, Size::Unknown, [false, false], 0b000);
+    pub const TMM1: Self = Self::new(Purpose::Tile, Size::Unknown, [false, false], 0b001);
+    pub const TMM2: Self = Self::new(Purpose::Tile, Size::Unknown, [false, false], 0b010);
+    pub const TMM3: Self = Self::new(Purpose::Tile, Size::Unknown, [false, false], 0b011);
+    pub const TMM4: Self = Self::new(Purpose::Tile, Size::Unknown, [false, false], 0b100);
+    pub const TMM5: Self = Self::new(Purpose::Tile, Size::Unknown, [false, false], 0b101);
+    pub const TMM6: Self = Self::new(Purpose::Tile, Size::Unknown, [false, false], 0b110);
+    pub const TMM7: Self = Self::new(Purpose::Tile, Size::Unknown, [false, false], 0b111);
+
     pub const K0: Self = Self::new(Purpose::Mask, Size::Qword, [false, false], 0b000);
     pub const K1: Self = Self::new(Purpose::Mask, Size::Qword, [false, false], 0b001);
     pub const K2: Self = Self::new(Purpose::Mask, Size::Qword, [false, false], 0b010);
@@ -501,6 +511,14 @@ impl ToString for Register {
             Self::K5 => String::from("k5"),
             Self::K6 => String::from("k6"),
             Self::K7 => String::from("k7"),
+            Self::TMM0 => String::from("tmm0"),
+            Self::TMM1 => String::from("tmm1"),
+            Self::TMM2 => String::from("tmm2"),
+            Self::TMM3 => String::from("tmm3"),
+            Self::TMM4 => String::from("tmm4"),
+            Self::TMM5 => String::from("tmm5"),
+            Self::TMM6 => String::from("tmm6"),
+            Self::TMM7 => String::from("tmm7"),
             Self::XMM0 => String::from("xmm0"),
             Self::XMM1 => String::from("xmm1"),
             Self::XMM2 => String::from("xmm2"),
@@ -967,6 +985,23 @@ pub fn reg_fromstr(str: &str) -> Option<Register> {
             _ => N,
         },
         4 => match r[0] {
+            b't' => match r[1] {
+                b'm' => match r[2] {
+                    b'm' => match r[3] {
+                        b'0' => s(Register::TMM0),
+                        b'1' => s(Register::TMM1),
+                        b'2' => s(Register::TMM2),
+                        b'3' => s(Register::TMM3),
+                        b'4' => s(Register::TMM4),
+                        b'5' => s(Register::TMM5),
+                        b'6' => s(Register::TMM6),
+                        b'7' => s(Register::TMM7),
+                        _ => N,
+                    },
+                    _ => N,
+                },
+                _ => N,
+            },
             b'c' => match r[1] {
                 b'r' => match r[2] {
                     b'1' => match r[3] {
@@ -1351,6 +1386,9 @@ impl Purpose {
     pub const fn is_dbg(&self) -> bool {
         *self as u8 == Self::Dbg as u8
     }
+    pub const fn is_tile(&self) -> bool {
+        *self as u8 == Self::Tile as u8
+    }
 }
 
 impl PartialEq for Purpose {
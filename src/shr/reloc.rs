@@ -3,34 +3,92 @@
 // made by matissoss
 // licensed under MPL 2.0
 
-use crate::shr::{error::Error, symbol::Symbol};
+use crate::shr::{error::Error, mnemonic::Mnemonic, symbol::Symbol, visibility::Visibility};
 
 impl RelType {
     pub fn to_elf64_rtype(&self) -> u64 {
         match self {
             Self::ABS32 => 11,
+            Self::ABS32U => 10,
+            Self::ABS64 => 1,
+            Self::ABS16 => 12,
+            Self::ABS8 => 14,
+            Self::REL64 => 24,
             Self::REL32 => 2,
             Self::REL16 => 13,
             Self::REL8 => 15,
+            Self::PLT32 => 4,
+            Self::GOTPCREL => 9,
+            Self::GOTPCRELX => 41,
+            Self::REX_GOTPCRELX => 42,
+            Self::TLSGD => 19,
+            Self::TLSLD => 20,
+            Self::DTPOFF32 => 21,
+            Self::GOTTPOFF => 22,
+            Self::TPOFF32 => 23,
+            Self::GOTPC => 26,
+            // x86-64 has only 8-byte GOTOFF64
+            Self::GOTOFF => 0,
         }
     }
     pub fn to_elf32_rtype(&self) -> u32 {
         match self {
-            Self::ABS32 => 1,
+            Self::ABS32 | Self::ABS32U => 1,
+            // i386 does not have 64-bit relocations
+            Self::ABS64 | Self::REL64 => 0,
+            Self::ABS16 => 20,
+            Self::ABS8 => 22,
             Self::REL32 => 2,
             Self::REL16 => 21,
             Self::REL8 => 23,
+            Self::PLT32 => 4,
+            // i386 does not have PC-relative GOT relocations
+            Self::GOTPCREL | Self::GOTPCRELX | Self::REX_GOTPCRELX => 0,
+            // TLS models of i386 use different instruction sequences
+            Self::TLSGD | Self::TLSLD | Self::DTPOFF32 | Self::GOTTPOFF | Self::TPOFF32 => 0,
+            Self::GOTOFF => 9,
+            Self::GOTPC => 10,
         }
     }
 }
 
 #[derive(PartialEq, Default, Clone, Debug, Copy)]
 pub enum RelType {
+    // sign-extended
     ABS32,
+    // zero-extended
+    ABS32U,
+    // used for pointers and label differences in 8-byte fields
+    ABS64,
+    ABS16,
+    ABS8,
+    REL64,
     #[default]
     REL32,
     REL16,
     REL8,
+    // PC-relative reference to PLT entry of symbol
+    PLT32,
+    // PC-relative reference to GOT entry of symbol
+    GOTPCREL,
+    // relaxable variants of GOTPCREL (linker may replace GOT load with `lea`)
+    GOTPCRELX,
+    #[allow(non_camel_case_types)]
+    REX_GOTPCRELX,
+    // TLS: general-dynamic (`lea rdi, qword @[sym, tlsgd]`)
+    TLSGD,
+    // TLS: local-dynamic (`lea rdi, qword @[sym, tlsld]`)
+    TLSLD,
+    // TLS: offset of symbol in its module's TLS block (used with `tlsld`)
+    DTPOFF32,
+    // TLS: initial-exec (`mov reg, qword @[sym, gottpoff]`)
+    GOTTPOFF,
+    // TLS: local-exec (offset of symbol from thread pointer)
+    TPOFF32,
+    // PC-relative address of GOT (`add ebx, @[_GLOBAL_OFFSET_TABLE_, gotpc]`)
+    GOTPC,
+    // offset of symbol from GOT
+    GOTOFF,
 }
 
 impl std::str::FromStr for RelType {
@@ -38,44 +96,198 @@ impl std::str::FromStr for RelType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "abs32" => Ok(Self::ABS32),
+            "abs32u" => Ok(Self::ABS32U),
+            "abs64" => Ok(Self::ABS64),
+            "abs16" => Ok(Self::ABS16),
+            "abs8" => Ok(Self::ABS8),
+            "rel64" => Ok(Self::REL64),
             "rel32" => Ok(Self::REL32),
             "rel16" => Ok(Self::REL16),
             "rel8" => Ok(Self::REL8),
+            "plt32" => Ok(Self::PLT32),
+            "gotpcrel" => Ok(Self::GOTPCREL),
+            "gotpcrelx" => Ok(Self::GOTPCRELX),
+            "rex_gotpcrelx" => Ok(Self::REX_GOTPCRELX),
+            "tlsgd" => Ok(Self::TLSGD),
+            "tlsld" => Ok(Self::TLSLD),
+            "dtpoff32" => Ok(Self::DTPOFF32),
+            "gottpoff" => Ok(Self::GOTTPOFF),
+            "tpoff32" => Ok(Self::TPOFF32),
+            "gotpc" => Ok(Self::GOTPC),
+            "gotoff" => Ok(Self::GOTOFF),
             _ => Err(()),
         }
     }
 }
 
 impl RelType {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::ABS32 => "abs32",
+            Self::ABS32U => "abs32u",
+            Self::ABS64 => "abs64",
+            Self::ABS16 => "abs16",
+            Self::ABS8 => "abs8",
+            Self::REL64 => "rel64",
+            Self::REL32 => "rel32",
+            Self::REL16 => "rel16",
+            Self::REL8 => "rel8",
+            Self::PLT32 => "plt32",
+            Self::GOTPCREL => "gotpcrel",
+            Self::GOTPCRELX => "gotpcrelx",
+            Self::REX_GOTPCRELX => "rex_gotpcrelx",
+            Self::TLSGD => "tlsgd",
+            Self::TLSLD => "tlsld",
+            Self::DTPOFF32 => "dtpoff32",
+            Self::GOTTPOFF => "gottpoff",
+            Self::TPOFF32 => "tpoff32",
+            Self::GOTPC => "gotpc",
+            Self::GOTOFF => "gotoff",
+        }
+    }
+    /// returns true, if relocated value fits into field
+    /// (relative relocations are signed, `abs32` is sign-extended, `abs32u` is zero-extended
+    /// and `abs16`/`abs8` accept both signed and unsigned values)
+    pub const fn fits(&self, val: i64) -> bool {
+        match self {
+            Self::ABS64 | Self::REL64 => true,
+            Self::ABS32U => val >= 0 && val <= u32::MAX as i64,
+            Self::ABS16 => val >= i16::MIN as i64 && val <= u16::MAX as i64,
+            Self::ABS8 => val >= i8::MIN as i64 && val <= u8::MAX as i64,
+            Self::ABS32
+            | Self::REL32
+            | Self::PLT32
+            | Self::GOTPCREL
+            | Self::GOTPCRELX
+            | Self::REX_GOTPCRELX
+            | Self::TLSGD
+            | Self::TLSLD
+            | Self::DTPOFF32
+            | Self::GOTTPOFF
+            | Self::TPOFF32
+            | Self::GOTPC
+            | Self::GOTOFF => val >= i32::MIN as i64 && val <= i32::MAX as i64,
+            Self::REL16 => val >= i16::MIN as i64 && val <= i16::MAX as i64,
+            Self::REL8 => val >= i8::MIN as i64 && val <= i8::MAX as i64,
+        }
+    }
     pub const fn size(&self) -> usize {
         match self {
-            Self::ABS32 => 4,
+            Self::ABS32 | Self::ABS32U => 4,
+            Self::ABS64 | Self::REL64 => 8,
+            Self::ABS16 => 2,
+            Self::ABS8 => 1,
             Self::REL32 => 4,
             Self::REL16 => 2,
             Self::REL8 => 1,
+            Self::PLT32 | Self::GOTPCREL | Self::GOTPCRELX | Self::REX_GOTPCRELX => 4,
+            Self::TLSGD | Self::TLSLD | Self::DTPOFF32 | Self::GOTTPOFF | Self::TPOFF32 => 4,
+            Self::GOTPC | Self::GOTOFF => 4,
+        }
+    }
+    /// returns true, if relocation refers to thread-local symbol
+    pub const fn is_tls(&self) -> bool {
+        matches!(
+            self,
+            Self::TLSGD | Self::TLSLD | Self::DTPOFF32 | Self::GOTTPOFF | Self::TPOFF32
+        )
+    }
+    /// returns true, if relocation refers to GOT entry of symbol
+    pub const fn is_got(&self) -> bool {
+        matches!(
+            self,
+            Self::GOTPCREL | Self::GOTPCRELX | Self::REX_GOTPCRELX | Self::GOTPC | Self::GOTOFF
+        )
+    }
+    /// selects relaxable variant of `gotpcrel` for instructions, that linker can rewrite
+    /// (`call`, `jmp`, `test`, and `mov` and binary ALU instructions, that load from memory);
+    /// `load` is true, if memory is source operand and `rex` is true, if instruction is encoded
+    /// with REX prefix (not REX2).
+    pub const fn relax(self, mnemonic: Mnemonic, load: bool, rex: bool) -> Self {
+        use Mnemonic::*;
+        if !matches!(self, Self::GOTPCREL) {
+            return self;
+        }
+        match mnemonic {
+            CALL | JMP => Self::GOTPCRELX,
+            MOV | ADC | ADD | AND | CMP | OR | SBB | SUB | XOR if !load => self,
+            MOV | TEST | ADC | ADD | AND | CMP | OR | SBB | SUB | XOR => {
+                if rex {
+                    Self::REX_GOTPCRELX
+                } else {
+                    Self::GOTPCRELX
+                }
+            }
+            _ => self,
         }
     }
     pub const fn is_rel(&self) -> bool {
-        !matches!(self, Self::ABS32)
+        !matches!(
+            self,
+            Self::ABS32
+                | Self::ABS32U
+                | Self::ABS64
+                | Self::ABS16
+                | Self::ABS8
+                | Self::DTPOFF32
+                | Self::TPOFF32
+                | Self::GOTOFF
+        )
+    }
+    /// returns absolute relocation, that fills field of `size` bytes
+    /// (used for symbols in data directives)
+    pub const fn abs(size: usize) -> Self {
+        match size {
+            1 => Self::ABS8,
+            2 => Self::ABS16,
+            8 => Self::ABS64,
+            _ => Self::ABS32U,
+        }
+    }
+    // PC-relative relocations are relative to end of field, not its start
+    pub const fn pc_adjust(&self) -> i32 {
+        if self.is_rel() {
+            self.size() as i32
+        } else {
+            0
+        }
     }
 }
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct Relocation<'a> {
     pub symbol: &'a str,
+    // if set, then relocation is label difference: `symbol + addend - sub`
+    // (it is resolved with `resolve_differences` before relocations are applied)
+    pub sub: Option<&'a str>,
     pub offset: usize,
     pub addend: i32,
     pub shidx: u16,
     pub reltype: RelType,
+    // line (in preprocessed source) of instruction, that uses relocation
+    pub line: usize,
 }
 
-impl Relocation<'_> {
+impl<'a> Relocation<'a> {
     pub const fn is_rel(&self) -> bool {
         self.reltype.is_rel()
     }
     pub const fn size(&self) -> usize {
         self.reltype.size()
     }
+    /// makes symbol (or subtracted symbol, if `sub` is true) of relocation point to `offset`
+    /// in section `section` (which starts at `start`); used for labels, that are not
+    /// in symbol table (like numeric labels).
+    pub fn retarget(&mut self, sub: bool, section: &'a str, start: usize, offset: usize) {
+        let offset = (offset - start) as i32;
+        if sub {
+            self.sub = Some(section);
+            self.addend -= offset;
+        } else {
+            self.symbol = section;
+            self.addend += offset;
+        }
+    }
     pub fn lea(&self, addr: usize) -> usize {
         let addend: i64 = self.addend.into();
         // we can cast offset as i64, because it would be currently impossible (?) to utilize 63-bit address (?)
@@ -89,9 +301,120 @@ impl Relocation<'_> {
             // S = Offset, A = Addend, P = Symbol
             (addr as i64 + addend - offset) as usize
         } else {
-            (offset + addend).abs_diff(0) as usize
+            // S + A
+            (addr as i64 + addend) as usize
+        }
+    }
+}
+
+/// resolves label differences:
+/// - if both symbols are in the same section (or `flat` is true, like in `bin` target),
+///   then difference is written into `buf` and relocation is removed,
+/// - if subtracted symbol is in the same section as relocation, then it is converted into
+///   PC-relative relocation (`S + A - B = S + (A + P - B) - P`),
+/// - otherwise error is returned.
+pub fn resolve_differences(
+    buf: &mut [u8],
+    rels: &mut Vec<Relocation>,
+    symbols: &[Symbol],
+    flat: bool,
+) -> Result<(), Error> {
+    let mut i = 0;
+    while i < rels.len() {
+        let rel = &mut rels[i];
+        let sub = match rel.sub {
+            Some(s) => s,
+            None => {
+                i += 1;
+                continue;
+            }
+        };
+        let find = |name: &str| {
+            symbols
+                .iter()
+                .find(|s| s.name == name && s.visibility != Visibility::Extern)
+        };
+        let b = match find(sub) {
+            Some(b) => b,
+            None => {
+                return Err(Error::new_wline(
+                    format!("subtracted symbol \"{sub}\" has to be defined in this file"),
+                    8,
+                    rel.line,
+                ))
+            }
+        };
+        match find(rel.symbol) {
+            Some(a) if flat || a.sindex == b.sindex => {
+                let val = a.offset as i64 + rel.addend as i64 - b.offset as i64;
+                let size = rel.size();
+                if size < 8 && (val < -(1 << (size * 8 - 1)) || val >= 1 << (size * 8)) {
+                    return Err(Error::new_wline(
+                        format!(
+                            "difference of symbols \"{}\" and \"{sub}\" ({val}) does not fit into {size} bytes",
+                            rel.symbol
+                        ),
+                        8,
+                        rel.line,
+                    ));
+                }
+                if buf.len() < rel.offset + size {
+                    return Err(Error::new(
+                        "src/shr/reloc.rs: tried to resolve label difference, but we tried to write out of bounds",
+                        500,
+                    ));
+                }
+                buf[rel.offset..rel.offset + size].copy_from_slice(&val.to_le_bytes()[..size]);
+                rels.remove(i);
+            }
+            _ if b.sindex == rel.shidx && rel.size() == 4 => {
+                rel.addend += rel.offset as i32 - b.offset as i32;
+                rel.reltype = RelType::REL32;
+                rel.sub = None;
+                i += 1;
+            }
+            _ => {
+                return Err(Error::new_wline(
+                    format!(
+                        "difference of symbols \"{}\" and \"{sub}\" cannot be expressed with relocation: symbols have to be in the same section (or \"{sub}\" in section where it is used)",
+                        rel.symbol
+                    ),
+                    8,
+                    rel.line,
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// stores addends of relocations in fields, that they are applied to
+/// (used by targets with implicit addends, like `elf32`)
+pub fn write_addends(buf: &mut [u8], rels: &mut [Relocation]) -> Result<(), Error> {
+    for rel in rels {
+        let addend: i64 = rel.addend.into();
+        let size = rel.size();
+        if size < 8 && (addend < -(1 << (size * 8 - 1)) || addend >= 1 << (size * 8)) {
+            return Err(Error::new_wline(
+                format!(
+                    "relocation truncated to fit: addend of {} against symbol \"{}\" ({addend}) does not fit into {size} bytes",
+                    rel.reltype.name(),
+                    rel.symbol
+                ),
+                18,
+                rel.line,
+            ));
         }
+        if buf.len() < rel.offset + size {
+            return Err(Error::new(
+                "src/shr/reloc.rs: tried to write addend, but we tried to write out of bounds",
+                500,
+            ));
+        }
+        buf[rel.offset..rel.offset + size].copy_from_slice(&addend.to_le_bytes()[..size]);
+        rel.addend = 0;
     }
+    Ok(())
 }
 
 pub fn relocate_addresses(
@@ -109,20 +432,51 @@ pub fn relocate(buf: &mut [u8], rel: Relocation, symbols: &[Symbol]) -> Result<(
     let symbol = if let Some(symbol) = symbols.iter().find(|e| e.name == rel.symbol) {
         symbol
     } else {
-        return Err(Error::new(
+        return Err(Error::new_wline(
             format!(
                 "you tried to use relocation on undeclared symbol \"{}\"",
                 rel.symbol
             ),
             4,
+            rel.line,
         ));
     };
+    let unavailable = if rel.reltype.is_got() {
+        Some("GOT")
+    } else if rel.reltype.is_tls() {
+        Some("thread-local storage")
+    } else {
+        None
+    };
+    if let Some(what) = unavailable {
+        return Err(Error::new_wline(
+            format!(
+                "relocation {} against symbol \"{}\" requires {what}, which is not available in this target",
+                rel.reltype.name(),
+                rel.symbol
+            ),
+            20,
+            rel.line,
+        ));
+    }
     let addr = rel.lea(symbol.offset);
+    if !rel.reltype.fits(addr as i64) {
+        return Err(Error::new_wline(
+            format!(
+                "relocation truncated to fit: {} against symbol \"{}\" (value {})",
+                rel.reltype.name(),
+                rel.symbol,
+                addr as i64
+            ),
+            18,
+            rel.line,
+        ));
+    }
 
     let addr = addr.to_le_bytes();
     let buf_offset = rel.offset;
 
-    if buf.len() + rel.size() < buf_offset {
+    if buf_offset + rel.size() > buf.len() {
         return Err(Error::new(
             "src/shr/rel.rs: tried to perform relocation, but we tried to write out of bounds",
             500,
@@ -160,25 +514,244 @@ mod tests {
         };
         let relocation = Relocation {
             symbol: "Symbol",
+            sub: None,
             offset: 0x02,
             addend: 0,
             reltype: RelType::REL32,
             shidx: 0,
+            line: 0,
         };
         assert_eq!(relocation.lea(0x01), (-1i64) as usize);
-        assert_eq!(relocate(&mut bytes, relocation, &[symbol.clone()]), Ok(()));
+        assert_eq!(
+            relocate(&mut bytes, relocation, std::slice::from_ref(&symbol)),
+            Ok(())
+        );
         //                                       -1
         //                              +-----+--++--+----+
         //                              |     |      |    |
         assert_eq!(bytes, [0x00, 0x71, 0xFF, 0xFF, 0xFF, 0xFF, 0x81, 0x91]);
         let relocation = Relocation {
             symbol: "Symbol",
+            sub: None,
             offset: 0x03,
             addend: -1,
             reltype: RelType::REL32,
             shidx: 0,
+            line: 0,
         };
         assert_eq!(relocate(&mut bytes, relocation, &[symbol]), Ok(()));
         assert_eq!(bytes, [0x00, 0x71, 0xFF, 0xFD, 0xFF, 0xFF, 0xFF, 0x91]);
     }
+    #[test]
+    fn trel_1() {
+        use crate::shr::symbol::SymbolType;
+        let symbol = |offset| Symbol {
+            name: "Symbol",
+            offset,
+            stype: SymbolType::NoType,
+            size: 0,
+            sindex: 0,
+            visibility: Visibility::Local,
+            valid: true,
+        };
+        let rel = |reltype, addend| Relocation {
+            symbol: "Symbol",
+            sub: None,
+            offset: 0,
+            addend,
+            reltype,
+            shidx: 0,
+            line: 3,
+        };
+        let mut bytes = [0u8; 8];
+        assert_eq!(
+            relocate(&mut bytes, rel(RelType::REL8, -1), &[symbol(128)]),
+            Ok(())
+        );
+        assert_eq!(bytes[0], 127);
+        let e = relocate(&mut bytes, rel(RelType::REL8, -1), &[symbol(129)]).unwrap_err();
+        assert_eq!(e, Error::new("", 18));
+        assert_eq!(e.get_line(), Some(3));
+        assert!(relocate(&mut bytes, rel(RelType::REL16, -2), &[symbol(0x8002)]).is_err());
+        assert!(relocate(&mut bytes, rel(RelType::ABS32, 0), &[symbol(0x8000_0000)]).is_err());
+        assert_eq!(
+            relocate(&mut bytes, rel(RelType::ABS64, 0), &[symbol(0x1_0000_0000)]),
+            Ok(())
+        );
+        assert_eq!(
+            relocate(&mut bytes, rel(RelType::ABS32U, 0), &[symbol(0x8000_0000)]),
+            Ok(())
+        );
+        assert_eq!(bytes[..4], [0x00, 0x00, 0x00, 0x80]);
+        assert!(relocate(&mut bytes, rel(RelType::ABS32U, -1), &[symbol(0)]).is_err());
+        assert!(relocate(&mut bytes, rel(RelType::ABS16, 0), &[symbol(0xFFFF)]).is_ok());
+        assert!(relocate(&mut bytes, rel(RelType::ABS8, 0), &[symbol(0x100)]).is_err());
+        // field crosses end of buffer
+        let mut end = rel(RelType::ABS64, 0);
+        end.offset = 4;
+        assert_eq!(
+            relocate(&mut bytes, end, &[symbol(0)]),
+            Err(Error::new("", 500))
+        );
+        assert_eq!(RelType::abs(2), RelType::ABS16);
+        assert_eq!(
+            "rel64".parse::<RelType>().map(|r| r.to_elf64_rtype()),
+            Ok(24)
+        );
+    }
+    #[test]
+    fn tdiff_1() {
+        use crate::shr::symbol::SymbolType;
+        let symbol = |name, offset, sindex| Symbol {
+            name,
+            offset,
+            stype: SymbolType::NoType,
+            size: 0,
+            sindex,
+            visibility: Visibility::Local,
+            valid: true,
+        };
+        let symbols = [
+            symbol("a", 0x10, 0),
+            symbol("b", 0x02, 0),
+            symbol("c", 0x20, 1),
+        ];
+        let diff = |symbol, sub, offset, reltype| Relocation {
+            symbol,
+            sub: Some(sub),
+            offset,
+            addend: 0,
+            reltype,
+            shidx: 0,
+            line: 0,
+        };
+        let mut bytes = [0u8; 12];
+        let mut rels = vec![
+            diff("a", "b", 0, RelType::ABS32),
+            diff("b", "a", 4, RelType::ABS64),
+            diff("c", "b", 0, RelType::ABS32),
+        ];
+        assert_eq!(
+            resolve_differences(&mut bytes, &mut rels, &symbols, false),
+            Ok(())
+        );
+        assert_eq!(&bytes[..4], &[0x0E, 0, 0, 0]);
+        assert_eq!(&bytes[4..], &(-0x0Ei64).to_le_bytes());
+        // `c` is in other section, so it is converted into PC-relative relocation
+        assert_eq!(rels.len(), 1);
+        assert_eq!(rels[0].reltype, RelType::REL32);
+        assert_eq!(rels[0].addend, -2);
+        assert_eq!(rels[0].sub, None);
+
+        let mut rels = vec![diff("b", "c", 0, RelType::ABS32)];
+        assert!(resolve_differences(&mut bytes, &mut rels, &symbols, false).is_err());
+        let mut rels = vec![diff("b", "c", 0, RelType::ABS32)];
+        assert_eq!(
+            resolve_differences(&mut bytes, &mut rels, &symbols, true),
+            Ok(())
+        );
+        assert_eq!(&bytes[..4], &(-0x1Ei32).to_le_bytes());
+        // difference fills only its field
+        let mut bytes = [0xFFu8; 4];
+        let mut rels = vec![
+            diff("a", "b", 0, RelType::ABS16),
+            diff("b", "a", 2, RelType::ABS8),
+        ];
+        assert_eq!(
+            resolve_differences(&mut bytes, &mut rels, &symbols, false),
+            Ok(())
+        );
+        assert_eq!(bytes, [0x0E, 0x00, 0xF2, 0xFF]);
+    }
+    #[test]
+    fn tpic_0() {
+        use crate::shr::symbol::SymbolType;
+        assert_eq!("plt32".parse(), Ok(RelType::PLT32));
+        assert_eq!("rex_gotpcrelx".parse(), Ok(RelType::REX_GOTPCRELX));
+        assert_eq!(RelType::PLT32.to_elf64_rtype(), 4);
+        assert_eq!(RelType::GOTPCREL.to_elf64_rtype(), 9);
+        assert_eq!(
+            RelType::GOTPCREL.relax(Mnemonic::MOV, true, true),
+            RelType::REX_GOTPCRELX
+        );
+        assert_eq!(
+            RelType::GOTPCREL.relax(Mnemonic::MOV, false, true),
+            RelType::GOTPCREL
+        );
+        assert_eq!(
+            RelType::GOTPCREL.relax(Mnemonic::TEST, false, false),
+            RelType::GOTPCRELX
+        );
+        assert_eq!(
+            RelType::GOTPCREL.relax(Mnemonic::CALL, false, false),
+            RelType::GOTPCRELX
+        );
+        assert_eq!(
+            RelType::GOTPCREL.relax(Mnemonic::LEA, true, true),
+            RelType::GOTPCREL
+        );
+        assert_eq!(
+            RelType::REL32.relax(Mnemonic::MOV, true, true),
+            RelType::REL32
+        );
+
+        let symbol = Symbol {
+            name: "Symbol",
+            offset: 0x10,
+            stype: SymbolType::NoType,
+            size: 0,
+            sindex: 0,
+            visibility: Visibility::Local,
+            valid: true,
+        };
+        let rel = |reltype| Relocation {
+            symbol: "Symbol",
+            sub: None,
+            offset: 0,
+            addend: -4,
+            reltype,
+            shidx: 0,
+            line: 0,
+        };
+        let mut bytes = [0u8; 4];
+        // `plt32` against local symbol is resolved like `rel32`
+        assert_eq!(
+            relocate(
+                &mut bytes,
+                rel(RelType::PLT32),
+                std::slice::from_ref(&symbol)
+            ),
+            Ok(())
+        );
+        assert_eq!(bytes, [0x0C, 0, 0, 0]);
+        assert_eq!(
+            relocate(&mut bytes, rel(RelType::GOTPCREL), &[symbol]),
+            Err(Error::new("", 20))
+        );
+    }
+    #[test]
+    fn taddend_0() {
+        let rel = |offset, addend, reltype| Relocation {
+            symbol: "Symbol",
+            sub: None,
+            offset,
+            addend,
+            reltype,
+            shidx: 0,
+            line: 0,
+        };
+        let mut bytes = [0u8; 8];
+        let mut rels = [rel(0, -4, RelType::REL32), rel(4, 0x1FF, RelType::ABS16)];
+        assert_eq!(write_addends(&mut bytes, &mut rels), Ok(()));
+        assert_eq!(bytes, [0xFC, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0x00, 0x00]);
+        assert!(rels.iter().all(|r| r.addend == 0));
+        let mut rels = [rel(0, 0x100, RelType::ABS8)];
+        assert_eq!(
+            write_addends(&mut bytes, &mut rels),
+            Err(Error::new("", 18))
+        );
+        assert_eq!(RelType::GOTPC.to_elf32_rtype(), 10);
+        assert_eq!(RelType::GOTOFF.to_elf32_rtype(), 9);
+        assert_eq!(RelType::GOTOFF.to_elf64_rtype(), 0);
+    }
 }
@@ -10,6 +10,7 @@ const ALLOC_FLAG: u8 = 0x2;
 const WRITE_FLAG: u8 = 0x3;
 const EXEC_FLAG: u8 = 0x4;
 const NOBITS_FLAG: u8 = 0x5;
+const TLS_FLAG: u8 = 0x6;
 
 #[derive(PartialEq, Clone, Debug, Default)]
 pub struct Section<'a> {
@@ -51,6 +52,12 @@ impl SectionAttributes {
     pub const fn set_write(&mut self, b: bool) {
         self.flags.set(WRITE_FLAG, b);
     }
+    pub const fn set_tls(&mut self, b: bool) {
+        self.flags.set(TLS_FLAG, b);
+    }
+    pub fn tls(&self) -> bool {
+        self.flags.get(TLS_FLAG).unwrap_or(false)
+    }
     pub fn write(&self) -> bool {
         self.flags.get(WRITE_FLAG).unwrap_or(false)
     }
@@ -221,6 +221,7 @@ mod tests {
         // probably not;
         // if we actually implement proper Drop,
         // then Rust says something double free :D
+        #[allow(clippy::drop_non_drop)]
         drop(myvec);
     }
 }
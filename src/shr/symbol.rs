@@ -19,6 +19,7 @@ pub enum SymbolType {
     Func = 2,
     Section = 3,
     File = 4,
+    Tls = 6,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -34,7 +35,29 @@ pub struct Symbol<'a> {
 
 impl Symbol<'_> {
     pub fn is_global(&self) -> bool {
-        self.visibility == Visibility::Public || self.visibility == Visibility::Weak
+        matches!(
+            self.visibility,
+            Visibility::Public | Visibility::Weak | Visibility::Extern
+        )
+    }
+}
+
+/// returns number of numeric label (`1:` is declared as `1`)
+pub fn numeric_label(s: &str) -> Option<u32> {
+    if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// parses reference to numeric label: `1b` (last `1:` before reference)
+/// or `1f` (first `1:` after reference); returns number and true if reference is forward.
+pub fn numeric_ref(s: &str) -> Option<(u32, bool)> {
+    if let Some(n) = s.strip_suffix('f') {
+        Some((numeric_label(n)?, true))
+    } else {
+        Some((numeric_label(s.strip_suffix('b')?)?, false))
     }
 }
 
@@ -46,6 +69,8 @@ const IS_DEREF: u8 = 0x3;
 #[derive(PartialEq, Clone, Debug)]
 pub struct SymbolRef<'a> {
     pub symbol: &'a str,
+    // symbol that is subtracted from `symbol` (`@[end] - @[start]`)
+    sub: Option<&'a str>,
     addend: i32,
     size: Size,
     reltype: RelType,
@@ -56,6 +81,7 @@ impl Default for SymbolRef<'_> {
     fn default() -> Self {
         Self {
             symbol: "",
+            sub: None,
             addend: 0,
             size: Size::Unknown,
             reltype: RelType::REL32,
@@ -71,6 +97,21 @@ impl<'a> SymbolRef<'a> {
     /// expected input: in format @[<SYMBOL REF>] already without any semicolons or other trash.
     #[allow(clippy::result_unit_err)]
     pub fn from_str(s: &'a str) -> Result<SymbolRef<'a>, ()> {
+        // label difference: @[<SYMBOL REF>] - @[<SYMBOL>]
+        if let Some(end) = s.find(']') {
+            if let Some(rhs) = s[end + 1..].trim_start().strip_prefix('-') {
+                let mut symbolref = SymbolRef::from_str(&s[..=end])?;
+                let rhs = SymbolRef::from_str(rhs.trim_start())?;
+                if rhs.guardians.get(ADED_GUARDIAN).unwrap()
+                    || rhs.guardians.get(RELT_GUARDIAN).unwrap()
+                    || rhs.sub.is_some()
+                {
+                    return Err(());
+                }
+                symbolref.sub = Some(rhs.symbol);
+                return Ok(symbolref);
+            }
+        }
         if let Some(s) = s.strip_prefix("@[") {
             if let Some(s) = s.strip_suffix("]") {
                 let mut symbolref = SymbolRef::default();
@@ -78,7 +119,9 @@ impl<'a> SymbolRef<'a> {
                 while let Some((mut str, mut rest)) = split_once_intelligent(symbol_str, ',') {
                     str = str.trim_end();
                     rest = rest.trim_start();
-                    if let Ok(reltype) = RelType::from_str(str) {
+                    if symbolref.symbol.is_empty() && numeric_ref(str).is_some() {
+                        symbolref.symbol = str;
+                    } else if let Ok(reltype) = RelType::from_str(str) {
                         symbolref.set_reltype(reltype);
                     } else if let Ok(n) = Number::from_str(str) {
                         symbolref.set_addend(n.get_as_i32());
@@ -90,7 +133,9 @@ impl<'a> SymbolRef<'a> {
 
                     symbol_str = rest;
                 }
-                if let Ok(reltype) = RelType::from_str(symbol_str) {
+                if symbolref.symbol.is_empty() && numeric_ref(symbol_str).is_some() {
+                    symbolref.symbol = symbol_str;
+                } else if let Ok(reltype) = RelType::from_str(symbol_str) {
                     symbolref.set_reltype(reltype);
                 } else if let Ok(n) = Number::from_str(symbol_str) {
                     symbolref.set_addend(n.get_as_i32());
@@ -120,6 +165,7 @@ impl<'a> SymbolRef<'a> {
     ) -> Self {
         Self {
             symbol: symb,
+            sub: None,
             addend: addend.unwrap_or(0),
             size: sz.unwrap_or(Size::Unknown),
             reltype: reltype.unwrap_or(RelType::REL32),
@@ -155,6 +201,12 @@ impl<'a> SymbolRef<'a> {
         self.guardians.set(RELT_GUARDIAN, true);
         self.reltype = reltype;
     }
+    pub fn sub(&self) -> Option<&'a str> {
+        self.sub
+    }
+    pub fn set_sub(&mut self, sub: &'a str) {
+        self.sub = Some(sub);
+    }
     pub fn set_addend(&mut self, addend: i32) {
         self.guardians.set(ADED_GUARDIAN, true);
         self.addend = addend;
@@ -171,6 +223,10 @@ impl<'a> SymbolRef<'a> {
 impl ToString for SymbolRef<'_> {
     fn to_string(&self) -> String {
         let mut string = self.symbol.to_string();
+        if let Some(sub) = self.sub {
+            string.push('-');
+            string.push_str(sub);
+        }
         string.push(':');
         string.push_str(if self.reltype().unwrap_or(RelType::REL32).is_rel() {
             "rel"
@@ -228,4 +284,41 @@ mod tests {
             ))
         );
     }
+    #[test]
+    fn tsymbol_1() {
+        let mut expected = SymbolRef::new("end", None, false, None, None);
+        expected.set_sub("start");
+        assert_eq!(SymbolRef::from_str("@[end] - @[start]"), Ok(expected));
+        let mut expected = SymbolRef::new("end", Some(1), false, None, Some(RelType::ABS32));
+        expected.set_sub("start");
+        assert_eq!(
+            SymbolRef::from_str("@[end, 1, abs32]-@[start]"),
+            Ok(expected)
+        );
+        assert_eq!(SymbolRef::from_str("@[end] - @[start, 1]"), Err(()));
+        assert_eq!(SymbolRef::from_str("@[end] - start"), Err(()));
+    }
+    #[test]
+    fn tsymbol_2() {
+        assert_eq!(
+            SymbolRef::from_str("@[1b]"),
+            Ok(SymbolRef::new("1b", None, false, None, None))
+        );
+        assert_eq!(
+            SymbolRef::from_str("@[10f, 2, rel8]"),
+            Ok(SymbolRef::new(
+                "10f",
+                Some(2),
+                false,
+                None,
+                Some(RelType::REL8)
+            ))
+        );
+        assert_eq!(numeric_ref("10f"), Some((10, true)));
+        assert_eq!(numeric_ref("0b"), Some((0, false)));
+        assert_eq!(numeric_ref("b"), None);
+        assert_eq!(numeric_ref("1x"), None);
+        assert_eq!(numeric_label("1"), Some(1));
+        assert_eq!(numeric_label("+1"), None);
+    }
 }
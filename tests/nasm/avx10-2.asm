@@ -0,0 +1,14 @@
+[bits 64]
+_start:
+	vminmaxpd zmm1, zmm2, zmm3, 5
+	vminmaxps ymm1{k1}, ymm2, [rax + 32], 1
+	vminmaxsd xmm1, xmm2, xmm3, 2
+	vminmaxss xmm1, xmm2, [rax], 3
+	vcvtps2ibs zmm1, zmm2
+	vcvttps2iubs xmm1, xmm2
+	vaddbf16 zmm1, zmm2, zmm3
+	vmulbf16 ymm1{k1}{z}, ymm2, ymm3
+	vcomxsd xmm1, xmm2
+	vucomxss xmm1, [rax + 4]
+	vmovw xmm1, xmm2
+	vaddps ymm1, ymm2, ymm3, {rz-sae}
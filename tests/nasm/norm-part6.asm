@@ -35,6 +35,9 @@ _start:
 	xsaves64 [rax]
 
 	lea eax, [rax + rcx * 4 + 10]
+	lea rax, [rbx]
+	lea r9, [rbx]
+	lea r9d, [r10]
 
 	lidt [rbx]
 	lgdt [rbx]
@@ -0,0 +1,19 @@
+[bits 64]
+_start:
+	blcfill eax, ecx
+	blcfill rax, [rbx]
+	blci r9d, [rax + 8]
+	blcic rcx, r10
+	blcmsk edx, ebx
+	blcs r8, [rsp]
+	blsfill esi, edi
+	blsic rax, [rbp - 16]
+	t1mskc r11d, r12d
+	tzmsk rdi, rsi
+	bextr eax, ecx, 0x1234
+	bextr r8, [rax], 7
+	monitorx
+	mwaitx
+	rdpru
+	invlpgb
+	tlbsync
@@ -0,0 +1,17 @@
+bits 64
+_start:
+	ldtilecfg zword [rax]
+	sttilecfg zword [rsp + 64]
+	tilerelease
+	tilezero tmm5
+	tilezero tmm0
+	tileloadd tmm1, dword [rax + rcx * 4]
+	tileloadd tmm0, dword [rax]
+	tileloaddt1 tmm7, dword [r8 + r9 * 1 + 0x40]
+	tilestored dword [rdi + rsi * 2 + 8], tmm3
+	tdpbssd tmm0, tmm1, tmm2
+	tdpbsud tmm3, tmm4, tmm5
+	tdpbusd tmm6, tmm7, tmm0
+	tdpbuud tmm1, tmm2, tmm3
+	tdpbf16ps tmm4, tmm5, tmm6
+	tdpfp16ps tmm7, tmm0, tmm1
@@ -0,0 +1,12 @@
+bits 64
+_start:
+	gf2p8affineqb xmm0, xmm1, 1
+	gf2p8affineinvqb xmm8, xword [rax + 16], 2
+	gf2p8mulb xmm0, xmm9
+	vgf2p8affineqb xmm0, xmm1, xmm2, 3
+	vgf2p8affineinvqb ymm0, ymm1, yword [rax], 4
+	vgf2p8mulb ymm0, ymm1, ymm2
+	vgf2p8affineqb zmm0, {k1}, {z}, zmm1, zmm2, 5
+	vgf2p8affineinvqb zmm0, zmm1, {bcst}, qword [rax + 8], 6
+	vgf2p8mulb zmm16, zmm17, zword [rax + 64]
+	vgf2p8mulb xmm0, {k2}, xmm1, xmm2
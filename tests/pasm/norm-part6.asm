@@ -33,6 +33,9 @@ _start:
 	xsaves64 qword [rax]
 	
 	lea eax, dword [rax + rcx * 4 + 10]
+	lea rax, qword [rbx]
+	lea r9, qword [rbx]
+	lea r9d, dword [r10]
 	
 	lidt word [rbx]
 	lgdt word [rbx]
@@ -0,0 +1,19 @@
+bits 64
+_start:
+	blcfill eax, ecx
+	blcfill rax, qword [rbx]
+	blci r9d, dword [rax + 8]
+	blcic rcx, r10
+	blcmsk edx, ebx
+	blcs r8, qword [rsp]
+	blsfill esi, edi
+	blsic rax, qword [rbp - 16]
+	t1mskc r11d, r12d
+	tzmsk rdi, rsi
+	bextr eax, ecx, 0x1234
+	bextr r8, qword [rax], 7
+	monitorx
+	mwaitx
+	rdpru
+	invlpgb
+	tlbsync
@@ -0,0 +1,176 @@
+bits 64
+_start:
+	vpcmov xmm0, xmm1, xmm2, xmm3
+	vpcmov ymm8, ymm9, yword [rax + 8], ymm10
+	vpcmov xmm0, xmm1, xmm12, xword [r8 + rcx * 2]
+	vpperm xmm0, xmm1, xmm2, xmm3
+	vpperm xmm0, xmm1, xmm2, xword [rax]
+	vpperm xmm0, xmm1, xword [rax], xmm3
+	vprotb xmm0, xmm1, xmm2
+	vprotb xmm0, xword [rax], xmm2
+	vprotb xmm0, xmm1, xword [rax]
+	vprotb xmm9, xmm10, 7
+	vpshab xmm0, xmm1, xmm2
+	vpshab xmm0, xmm1, xword [rax]
+	vpshlb xmm0, xmm11, xmm2
+	vpshlb xmm0, xword [rax + 16], xmm2
+	vprotw xmm0, xmm1, xmm2
+	vprotw xmm0, xword [rax], xmm2
+	vprotw xmm0, xmm1, xword [rax]
+	vprotw xmm9, xmm10, 7
+	vpshaw xmm0, xmm1, xmm2
+	vpshaw xmm0, xmm1, xword [rax]
+	vpshlw xmm0, xmm11, xmm2
+	vpshlw xmm0, xword [rax + 16], xmm2
+	vprotd xmm0, xmm1, xmm2
+	vprotd xmm0, xword [rax], xmm2
+	vprotd xmm0, xmm1, xword [rax]
+	vprotd xmm9, xmm10, 7
+	vpshad xmm0, xmm1, xmm2
+	vpshad xmm0, xmm1, xword [rax]
+	vpshld xmm0, xmm11, xmm2
+	vpshld xmm0, xword [rax + 16], xmm2
+	vprotq xmm0, xmm1, xmm2
+	vprotq xmm0, xword [rax], xmm2
+	vprotq xmm0, xmm1, xword [rax]
+	vprotq xmm9, xmm10, 7
+	vpshaq xmm0, xmm1, xmm2
+	vpshaq xmm0, xmm1, xword [rax]
+	vpshlq xmm0, xmm11, xmm2
+	vpshlq xmm0, xword [rax + 16], xmm2
+	vpcomb xmm0, xmm1, xmm2, 3
+	vpcomb xmm0, xmm1, xword [rax], 5
+	vpcomw xmm0, xmm1, xmm2, 3
+	vpcomw xmm0, xmm1, xword [rax], 5
+	vpcomd xmm0, xmm1, xmm2, 3
+	vpcomd xmm0, xmm1, xword [rax], 5
+	vpcomq xmm0, xmm1, xmm2, 3
+	vpcomq xmm0, xmm1, xword [rax], 5
+	vpcomub xmm0, xmm1, xmm2, 3
+	vpcomub xmm0, xmm1, xword [rax], 5
+	vpcomuw xmm0, xmm1, xmm2, 3
+	vpcomuw xmm0, xmm1, xword [rax], 5
+	vpcomud xmm0, xmm1, xmm2, 3
+	vpcomud xmm0, xmm1, xword [rax], 5
+	vpcomuq xmm0, xmm1, xmm2, 3
+	vpcomuq xmm0, xmm1, xword [rax], 5
+	vfrczps xmm0, xmm1
+	vfrczps ymm0, yword [rax]
+	vfrczpd ymm3, ymm4
+	vfrczss xmm0, dword [rax]
+	vfrczsd xmm0, qword [rax]
+	vfrczsd xmm8, xmm1
+	vphaddbw xmm0, xmm1
+	vphaddbw xmm8, xword [rax]
+	vphaddbd xmm0, xmm1
+	vphaddbd xmm8, xword [rax]
+	vphaddbq xmm0, xmm1
+	vphaddbq xmm8, xword [rax]
+	vphaddwd xmm0, xmm1
+	vphaddwd xmm8, xword [rax]
+	vphaddwq xmm0, xmm1
+	vphaddwq xmm8, xword [rax]
+	vphadddq xmm0, xmm1
+	vphadddq xmm8, xword [rax]
+	vphaddubw xmm0, xmm1
+	vphaddubw xmm8, xword [rax]
+	vphaddubd xmm0, xmm1
+	vphaddubd xmm8, xword [rax]
+	vphaddubq xmm0, xmm1
+	vphaddubq xmm8, xword [rax]
+	vphadduwd xmm0, xmm1
+	vphadduwd xmm8, xword [rax]
+	vphadduwq xmm0, xmm1
+	vphadduwq xmm8, xword [rax]
+	vphaddudq xmm0, xmm1
+	vphaddudq xmm8, xword [rax]
+	vphsubbw xmm0, xmm1
+	vphsubbw xmm8, xword [rax]
+	vphsubwd xmm0, xmm1
+	vphsubwd xmm8, xword [rax]
+	vphsubdq xmm0, xmm1
+	vphsubdq xmm8, xword [rax]
+	vpmacssww xmm0, xmm1, xmm2, xmm3
+	vpmacssww xmm0, xmm1, xword [rax], xmm13
+	vpmacsswd xmm0, xmm1, xmm2, xmm3
+	vpmacsswd xmm0, xmm1, xword [rax], xmm13
+	vpmacssdql xmm0, xmm1, xmm2, xmm3
+	vpmacssdql xmm0, xmm1, xword [rax], xmm13
+	vpmacssdd xmm0, xmm1, xmm2, xmm3
+	vpmacssdd xmm0, xmm1, xword [rax], xmm13
+	vpmacssdqh xmm0, xmm1, xmm2, xmm3
+	vpmacssdqh xmm0, xmm1, xword [rax], xmm13
+	vpmacsww xmm0, xmm1, xmm2, xmm3
+	vpmacsww xmm0, xmm1, xword [rax], xmm13
+	vpmacswd xmm0, xmm1, xmm2, xmm3
+	vpmacswd xmm0, xmm1, xword [rax], xmm13
+	vpmacsdql xmm0, xmm1, xmm2, xmm3
+	vpmacsdql xmm0, xmm1, xword [rax], xmm13
+	vpmacsdd xmm0, xmm1, xmm2, xmm3
+	vpmacsdd xmm0, xmm1, xword [rax], xmm13
+	vpmacsdqh xmm0, xmm1, xmm2, xmm3
+	vpmacsdqh xmm0, xmm1, xword [rax], xmm13
+	vpmadcsswd xmm0, xmm1, xmm2, xmm3
+	vpmadcsswd xmm0, xmm1, xword [rax], xmm13
+	vpmadcswd xmm0, xmm1, xmm2, xmm3
+	vpmadcswd xmm0, xmm1, xword [rax], xmm13
+	vfmaddsubps xmm0, xmm1, xmm2, xmm3
+	vfmaddsubps ymm0, ymm1, yword [rax], ymm3
+	vfmaddsubps xmm0, xmm1, xmm2, xword [rax]
+	vfmaddsubpd xmm0, xmm1, xmm2, xmm3
+	vfmaddsubpd ymm0, ymm1, yword [rax], ymm3
+	vfmaddsubpd xmm0, xmm1, xmm2, xword [rax]
+	vfmsubaddps xmm0, xmm1, xmm2, xmm3
+	vfmsubaddps ymm0, ymm1, yword [rax], ymm3
+	vfmsubaddps xmm0, xmm1, xmm2, xword [rax]
+	vfmsubaddpd xmm0, xmm1, xmm2, xmm3
+	vfmsubaddpd ymm0, ymm1, yword [rax], ymm3
+	vfmsubaddpd xmm0, xmm1, xmm2, xword [rax]
+	vfmaddps xmm0, xmm1, xmm2, xmm3
+	vfmaddps ymm0, ymm1, yword [rax], ymm3
+	vfmaddps xmm0, xmm1, xmm2, xword [rax]
+	vfmaddpd xmm0, xmm1, xmm2, xmm3
+	vfmaddpd ymm0, ymm1, yword [rax], ymm3
+	vfmaddpd xmm0, xmm1, xmm2, xword [rax]
+	vfmsubps xmm0, xmm1, xmm2, xmm3
+	vfmsubps ymm0, ymm1, yword [rax], ymm3
+	vfmsubps xmm0, xmm1, xmm2, xword [rax]
+	vfmsubpd xmm0, xmm1, xmm2, xmm3
+	vfmsubpd ymm0, ymm1, yword [rax], ymm3
+	vfmsubpd xmm0, xmm1, xmm2, xword [rax]
+	vfnmaddps xmm0, xmm1, xmm2, xmm3
+	vfnmaddps ymm0, ymm1, yword [rax], ymm3
+	vfnmaddps xmm0, xmm1, xmm2, xword [rax]
+	vfnmaddpd xmm0, xmm1, xmm2, xmm3
+	vfnmaddpd ymm0, ymm1, yword [rax], ymm3
+	vfnmaddpd xmm0, xmm1, xmm2, xword [rax]
+	vfnmsubps xmm0, xmm1, xmm2, xmm3
+	vfnmsubps ymm0, ymm1, yword [rax], ymm3
+	vfnmsubps xmm0, xmm1, xmm2, xword [rax]
+	vfnmsubpd xmm0, xmm1, xmm2, xmm3
+	vfnmsubpd ymm0, ymm1, yword [rax], ymm3
+	vfnmsubpd xmm0, xmm1, xmm2, xword [rax]
+	vfmaddss xmm0, xmm1, xmm2, xmm3
+	vfmaddss xmm0, xmm1, dword [rax], xmm3
+	vfmaddss xmm0, xmm1, xmm2, dword [rax]
+	vfmsubss xmm0, xmm1, xmm2, xmm3
+	vfmsubss xmm0, xmm1, dword [rax], xmm3
+	vfmsubss xmm0, xmm1, xmm2, dword [rax]
+	vfnmaddss xmm0, xmm1, xmm2, xmm3
+	vfnmaddss xmm0, xmm1, dword [rax], xmm3
+	vfnmaddss xmm0, xmm1, xmm2, dword [rax]
+	vfnmsubss xmm0, xmm1, xmm2, xmm3
+	vfnmsubss xmm0, xmm1, dword [rax], xmm3
+	vfnmsubss xmm0, xmm1, xmm2, dword [rax]
+	vfmaddsd xmm0, xmm1, xmm2, xmm3
+	vfmaddsd xmm0, xmm1, qword [rax], xmm3
+	vfmaddsd xmm8, xmm9, xmm10, qword [rax]
+	vfmsubsd xmm0, xmm1, xmm2, xmm3
+	vfmsubsd xmm0, xmm1, qword [rax], xmm3
+	vfmsubsd xmm8, xmm9, xmm10, qword [rax]
+	vfnmaddsd xmm0, xmm1, xmm2, xmm3
+	vfnmaddsd xmm0, xmm1, qword [rax], xmm3
+	vfnmaddsd xmm8, xmm9, xmm10, qword [rax]
+	vfnmsubsd xmm0, xmm1, xmm2, xmm3
+	vfnmsubsd xmm0, xmm1, qword [rax], xmm3
+	vfnmsubsd xmm8, xmm9, xmm10, qword [rax]